@@ -1510,8 +1510,11 @@ mod tests {
 
         single_test_roundtrip(ethioaa, "mundi", 7000, "M13", 1);
         single_test_roundtrip(ethioaa, "mundi", 7000, "M13", 1);
-        // Fails ISO roundtrip due to https://github.com/unicode-org/icu4x/issues/2254
-        // single_test_roundtrip(ethioaa, "mundi", 100, "M03", 1);
+        single_test_roundtrip(ethioaa, "mundi", 100, "M03", 1);
+        single_test_roundtrip(ethioaa, "mundi", 5500, "M13", 5);
+        single_test_roundtrip(ethioaa, "mundi", 5501, "M01", 1);
+        single_test_roundtrip(ethiopian, "pre-incar", 1, "M13", 5);
+        single_test_roundtrip(ethiopian, "incar", 1, "M01", 1);
         single_test_error(
             ethiopian,
             "mundi",
@@ -432,4 +432,111 @@ mod test {
         let recovered_iso = ethiopian.to_iso();
         assert_eq!(iso_date, recovered_iso);
     }
+
+    #[test]
+    fn test_amete_mihret_epoch() {
+        // 1 Meskerem 1 Incarnation is 27 August 8 CE in the proleptic Gregorian calendar,
+        // and is preceded by 5 Pagumen 1 pre-Incarnation
+        let first_day = Date::try_new_iso_date(8, 8, 27).unwrap();
+        let last_day = Date::try_new_iso_date(8, 8, 26).unwrap();
+
+        for (era_style, first_era, first_year, last_era, last_year) in [
+            (EthiopianEraStyle::AmeteMihret, "incar", 1, "pre-incar", 1),
+            (EthiopianEraStyle::AmeteAlem, "mundi", 5501, "mundi", 5500),
+        ] {
+            let calendar = Ethiopian::new_with_era_style(era_style);
+
+            let date = first_day.to_calendar(calendar);
+            assert_eq!(date.year().era.0, first_era, "{era_style:?}");
+            assert_eq!(date.year().number, first_year, "{era_style:?}");
+            assert_eq!(date.month().code.0, "M01", "{era_style:?}");
+            assert_eq!(date.day_of_month().0, 1, "{era_style:?}");
+            assert_eq!(date.to_iso(), first_day, "{era_style:?}");
+            assert_eq!(date.day_of_year_info().prev_year.era.0, last_era);
+
+            let date = last_day.to_calendar(calendar);
+            assert_eq!(date.year().era.0, last_era, "{era_style:?}");
+            assert_eq!(date.year().number, last_year, "{era_style:?}");
+            assert_eq!(date.month().code.0, "M13", "{era_style:?}");
+            assert_eq!(date.day_of_month().0, 5, "{era_style:?}");
+            assert_eq!(date.to_iso(), last_day, "{era_style:?}");
+            assert_eq!(date.day_of_year_info().next_year.era.0, first_era);
+
+            let mut next = date;
+            next.add(DateDuration::new(0, 0, 0, 1));
+            assert_eq!(next.to_iso(), first_day, "{era_style:?}");
+
+            for (era, year, month_code, day, iso) in [
+                (first_era, first_year, "M01", 1, first_day),
+                (last_era, last_year, "M13", 5, last_day),
+            ] {
+                let date = Date::try_new_from_codes(
+                    types::Era(era.parse().unwrap()),
+                    year,
+                    types::MonthCode(month_code.parse().unwrap()),
+                    day,
+                    calendar,
+                )
+                .unwrap();
+                assert_eq!(date.to_iso(), iso, "{era_style:?} {era} {year}");
+            }
+        }
+
+        // Both era styles agree on the day
+        let mihret = Date::try_new_ethiopian_date(EthiopianEraStyle::AmeteMihret, 1, 1, 1).unwrap();
+        let alem = Date::try_new_ethiopian_date(EthiopianEraStyle::AmeteAlem, 5501, 1, 1).unwrap();
+        assert_eq!(mihret.to_iso(), first_day);
+        assert_eq!(alem.to_iso(), first_day);
+        assert_eq!(
+            mihret.to_calendar(Ethiopian::new_with_era_style(EthiopianEraStyle::AmeteAlem)),
+            alem
+        );
+
+        // There is no year 0 in either era of the Amete Mihret style
+        for era in ["incar", "pre-incar"] {
+            assert_eq!(
+                Date::try_new_from_codes(
+                    types::Era(era.parse().unwrap()),
+                    0,
+                    types::MonthCode(tinystr!(4, "M01")),
+                    1,
+                    Ethiopian::new(),
+                ),
+                Err(CalendarError::OutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_across_epochs() {
+        // Every day around the Amete Mihret epoch and the start of the Amete Alem era
+        for start in [
+            Date::try_new_iso_date(7, 1, 1).unwrap(),
+            Date::try_new_iso_date(-5493, 1, 1).unwrap(),
+        ] {
+            for era_style in [EthiopianEraStyle::AmeteMihret, EthiopianEraStyle::AmeteAlem] {
+                let calendar = Ethiopian::new_with_era_style(era_style);
+                let mut iso = start;
+                let mut previous = iso.to_calendar(calendar);
+                for _ in 0..3 * 366 {
+                    iso.add(DateDuration::new(0, 0, 0, 1));
+                    let date = iso.to_calendar(calendar);
+                    assert_eq!(date.to_iso(), iso, "{era_style:?}");
+                    let mut expected = previous;
+                    expected.add(DateDuration::new(0, 0, 0, 1));
+                    assert_eq!(date, expected, "{era_style:?}");
+                    let recovered = Date::try_new_from_codes(
+                        date.year().era,
+                        date.year().number,
+                        date.month().code,
+                        date.day_of_month().0 as u8,
+                        calendar,
+                    )
+                    .unwrap();
+                    assert_eq!(recovered, date, "{era_style:?}");
+                    previous = date;
+                }
+            }
+        }
+    }
 }
@@ -146,6 +146,7 @@ pub mod week {
     use crate::week_of;
     pub use week_of::RelativeUnit;
     pub use week_of::WeekCalculator;
+    pub use week_of::WeekInfo;
     pub use week_of::WeekOf;
     #[doc(hidden)]
     pub use week_of::MIN_UNIT_DAYS;
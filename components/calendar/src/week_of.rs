@@ -7,6 +7,7 @@ use crate::{
     provider::*,
     types::{DayOfMonth, DayOfYearInfo, IsoWeekday, WeekOfMonth},
 };
+use icu_locid::subtags::Region;
 use icu_provider::prelude::*;
 
 /// Minimum number of days in a month unit required for using this module
@@ -208,6 +209,105 @@ impl Default for WeekCalculator {
     }
 }
 
+/// Regional week conventions: the first day of the week, the minimum number of days in the
+/// first week of a year, and which days form the weekend.
+///
+/// This is backed by CLDR's `weekData`, loaded per region.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::types::IsoWeekday;
+/// use icu::calendar::week::WeekInfo;
+/// use icu::locid::subtags::region;
+///
+/// let week_info = WeekInfo::try_new(region!("EG")).expect("data should be present");
+///
+/// assert_eq!(week_info.first_weekday, IsoWeekday::Saturday);
+/// assert_eq!(week_info.weekend_start(), Some(IsoWeekday::Friday));
+/// assert_eq!(week_info.weekend_end(), Some(IsoWeekday::Saturday));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct WeekInfo {
+    /// The first day of a week.
+    pub first_weekday: IsoWeekday,
+    /// The minimum number of days of a week that need to be in a year for the week to be
+    /// considered the first week of that year.
+    pub min_days_in_first_week: u8,
+    /// The set of weekend days. These may not be contiguous.
+    pub weekend: WeekdaySet,
+}
+
+impl WeekInfo {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: skip,
+        region: Region,
+        error: CalendarError,
+        /// Creates a new [`WeekInfo`] for the given region from compiled data.
+        ///
+        /// Regions without specific data use the values for the world (`001`).
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(provider: &P, region: Region) -> Result<Self, CalendarError>
+    where
+        P: DataProvider<crate::provider::WeekDataV2Marker> + ?Sized,
+    {
+        let mut locale = DataLocale::default();
+        locale.set_region(Some(region));
+        provider
+            .load(DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            })
+            .and_then(DataResponse::take_payload)
+            .map(|payload| WeekInfo {
+                first_weekday: payload.get().first_weekday,
+                min_days_in_first_week: payload.get().min_week_days,
+                weekend: payload.get().weekend,
+            })
+            .map_err(Into::into)
+    }
+
+    /// Weekdays that are part of the weekend, ordered from [`Self::first_weekday`].
+    pub fn weekend(&self) -> impl Iterator<Item = IsoWeekday> {
+        WeekdaySetIterator::new(self.first_weekday, self.weekend)
+    }
+
+    /// The first day of the weekend, or `None` if there are no weekend days.
+    ///
+    /// For a non-contiguous weekend, this is the start of the shortest span of days
+    /// covering all weekend days; for example, Friday for a Friday and Sunday weekend.
+    pub fn weekend_start(&self) -> Option<IsoWeekday> {
+        self.weekend_span().map(|(start, _)| start)
+    }
+
+    /// The last day of the weekend, or `None` if there are no weekend days.
+    ///
+    /// For a non-contiguous weekend, this is the end of the shortest span of days
+    /// covering all weekend days; for example, Sunday for a Friday and Sunday weekend.
+    pub fn weekend_end(&self) -> Option<IsoWeekday> {
+        self.weekend_span()
+            .map(|(start, len)| add_to_weekday(start, len as i32))
+    }
+
+    /// Returns the weekend day that starts the shortest span covering the weekend, along
+    /// with the number of days from that start to the last weekend day.
+    fn weekend_span(&self) -> Option<(IsoWeekday, u8)> {
+        let days = || WeekdaySetIterator::new(IsoWeekday::Monday, self.weekend);
+        days()
+            .map(|start| {
+                let len = days()
+                    .map(|day| ((7 + day as i8 - start as i8) % 7) as u8)
+                    .max()
+                    .unwrap_or_default();
+                (start, len)
+            })
+            .min_by_key(|&(_, len)| len)
+    }
+}
+
 /// Returns the weekday that's `num_days` after `weekday`.
 fn add_to_weekday(weekday: IsoWeekday, num_days: i32) -> IsoWeekday {
     let new_weekday = (7 + (weekday as i32) + (num_days % 7)) % 7;
@@ -787,3 +887,67 @@ fn test_weekdays_iter() {
         multiple_non_contiguous_days.collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn test_week_info() {
+    use icu_locid::subtags::region;
+    use IsoWeekday::*;
+
+    let us = WeekInfo::try_new(region!("US")).unwrap();
+    assert_eq!(us.first_weekday, Sunday);
+    assert_eq!(us.min_days_in_first_week, 1);
+    assert_eq!(us.weekend_start(), Some(Saturday));
+    assert_eq!(us.weekend_end(), Some(Sunday));
+
+    let fr = WeekInfo::try_new(region!("FR")).unwrap();
+    assert_eq!(fr.first_weekday, Monday);
+    assert_eq!(fr.min_days_in_first_week, 4);
+    assert_eq!(fr.weekend_start(), Some(Saturday));
+    assert_eq!(fr.weekend_end(), Some(Sunday));
+
+    let eg = WeekInfo::try_new(region!("EG")).unwrap();
+    assert_eq!(eg.first_weekday, Saturday);
+    assert_eq!(eg.weekend_start(), Some(Friday));
+    assert_eq!(eg.weekend_end(), Some(Saturday));
+    assert_eq!(eg.weekend().collect::<Vec<_>>(), vec![Saturday, Friday]);
+
+    let in_ = WeekInfo::try_new(region!("IN")).unwrap();
+    assert_eq!(in_.first_weekday, Sunday);
+    assert_eq!(in_.weekend_start(), Some(Sunday));
+    assert_eq!(in_.weekend_end(), Some(Sunday));
+    assert_eq!(in_.weekend().collect::<Vec<_>>(), vec![Sunday]);
+
+    // Unknown regions fall back to the world defaults
+    let unknown = WeekInfo::try_new(region!("XY")).unwrap();
+    assert_eq!(unknown, WeekInfo::try_new(region!("001")).unwrap());
+    assert_eq!(unknown.first_weekday, Monday);
+    assert_eq!(unknown.min_days_in_first_week, 1);
+    assert_eq!(unknown.weekend_start(), Some(Saturday));
+    assert_eq!(unknown.weekend_end(), Some(Sunday));
+}
+
+#[test]
+fn test_week_info_weekend_span() {
+    use IsoWeekday::*;
+
+    let week_info = |weekend: &[IsoWeekday]| WeekInfo {
+        first_weekday: Monday,
+        min_days_in_first_week: 1,
+        weekend: WeekdaySet::new(weekend),
+    };
+
+    // Non-contiguous weekend, as in BN
+    let bn = week_info(&[Friday, Sunday]);
+    assert_eq!(bn.weekend_start(), Some(Friday));
+    assert_eq!(bn.weekend_end(), Some(Sunday));
+    assert_eq!(bn.weekend().collect::<Vec<_>>(), vec![Friday, Sunday]);
+
+    // Weekend wrapping around the end of the ISO week
+    let wrapping = week_info(&[Sunday, Monday]);
+    assert_eq!(wrapping.weekend_start(), Some(Sunday));
+    assert_eq!(wrapping.weekend_end(), Some(Monday));
+
+    let empty = week_info(&[]);
+    assert_eq!(empty.weekend_start(), None);
+    assert_eq!(empty.weekend_end(), None);
+}
@@ -117,5 +117,41 @@ fn greek_uppercasing(c: &mut Criterion) {
         group.finish();
     }
 }
-criterion_group!(benches, overview_bench, greek_uppercasing);
+fn ascii_lowercasing(c: &mut Criterion) {
+    #[cfg(feature = "bench")]
+    {
+        let casemapper = CaseMapper::new();
+        let root = langid!("und");
+        let tr = langid!("tr");
+
+        // About 1 MB of mixed-case ASCII text
+        let document = TEST_STRING_EN.repeat(1_000_000 / TEST_STRING_EN.len() + 1);
+        let lowercase_document = document.to_ascii_lowercase();
+
+        let mut group = c.benchmark_group("icu_casemap/ascii_lowercasing");
+        group.bench_function("root", |b| {
+            b.iter(|| {
+                black_box(casemapper.lowercase_to_string(black_box(&document), &root));
+            });
+        });
+        group.bench_function("root_from_lower", |b| {
+            b.iter(|| {
+                black_box(casemapper.lowercase_to_string(black_box(&lowercase_document), &root));
+            });
+        });
+        group.bench_function("turkish", |b| {
+            b.iter(|| {
+                black_box(casemapper.lowercase_to_string(black_box(&document), &tr));
+            });
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(
+    benches,
+    overview_bench,
+    greek_uppercasing,
+    ascii_lowercasing
+);
 criterion_main!(benches);
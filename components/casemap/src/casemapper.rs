@@ -137,6 +137,32 @@ impl CaseMapper {
         )
     }
 
+    /// Returns the full uppercase mapping of the given string as a [`Writeable`], without
+    /// the Greek-specific removal of accents and breathing marks.
+    ///
+    /// This behaves like [`Self::uppercase()`], except that for Greek (`el`) it applies the
+    /// default Unicode mappings, like it does for the root locale. Other language-sensitive
+    /// behavior is unaffected.
+    ///
+    /// See [`Self::uppercase_without_greek_accent_removal_to_string()`] for the equivalent convenience function that returns a String,
+    /// as well as for an example.
+    pub fn uppercase_without_greek_accent_removal<'a>(
+        &'a self,
+        src: &'a str,
+        langid: &LanguageIdentifier,
+    ) -> impl Writeable + 'a {
+        let locale = match CaseMapLocale::from_langid(langid) {
+            CaseMapLocale::Greek => CaseMapLocale::Root,
+            locale => locale,
+        };
+        self.data.get().full_helper_writeable::<false>(
+            src,
+            locale,
+            MappingKind::Upper,
+            TrailingCase::default(),
+        )
+    }
+
     /// Returns the full titlecase mapping of the given string as a [`Writeable`], treating
     /// the string as a single segment (and thus only titlecasing the beginning of it). Performs
     /// the specified leading adjustment behavior from the options without loading additional data.
@@ -299,11 +325,43 @@ impl CaseMapper {
     ///
     /// assert_eq!(cm.uppercase_to_string("և Երևանի", &root), "ԵՒ ԵՐԵՒԱՆԻ");
     /// assert_eq!(cm.uppercase_to_string("և Երևանի", &langid!("hy")), "ԵՎ ԵՐԵՎԱՆԻ"); // Eastern Armenian ech-yiwn ligature
+    ///
+    /// assert_eq!(cm.uppercase_to_string("Γειά σου Κόσμε", &langid!("el")), "ΓΕΙΑ ΣΟΥ ΚΟΣΜΕ"); // Greek accent removal
     /// ```
     pub fn uppercase_to_string(&self, src: &str, langid: &LanguageIdentifier) -> String {
         self.uppercase(src, langid).write_to_string().into_owned()
     }
 
+    /// Returns the full uppercase mapping of the given string as a String, without
+    /// the Greek-specific removal of accents and breathing marks.
+    ///
+    /// See [`Self::uppercase_without_greek_accent_removal()`] for the equivalent lower-level function that returns a [`Writeable`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::casemap::CaseMapper;
+    /// use icu::locid::langid;
+    ///
+    /// let cm = CaseMapper::new();
+    /// let el = langid!("el");
+    ///
+    /// assert_eq!(cm.uppercase_to_string("Το ένα άσπρο άλογο", &el), "ΤΟ ΕΝΑ ΑΣΠΡΟ ΑΛΟΓΟ");
+    /// assert_eq!(
+    ///     cm.uppercase_without_greek_accent_removal_to_string("Το ένα άσπρο άλογο", &el),
+    ///     "ΤΟ ΈΝΑ ΆΣΠΡΟ ΆΛΟΓΟ"
+    /// );
+    /// ```
+    pub fn uppercase_without_greek_accent_removal_to_string(
+        &self,
+        src: &str,
+        langid: &LanguageIdentifier,
+    ) -> String {
+        self.uppercase_without_greek_accent_removal(src, langid)
+            .write_to_string()
+            .into_owned()
+    }
+
     /// Returns the full titlecase mapping of the given string as a [`Writeable`], treating
     /// the string as a single segment (and thus only titlecasing the beginning of it). Performs
     /// the specified leading adjustment behavior from the options without loading additional data.
@@ -747,4 +805,35 @@ mod tests {
         assert_eq!(case_mapping.fold_turkic_string("Ꭰ"), "Ꭰ");
         assert_eq!(case_mapping.fold_turkic_string("ꭰ"), "Ꭰ");
     }
+
+    #[test]
+    fn test_simple_fold_closure() {
+        use icu_collections::codepointinvlist::CodePointInversionListBuilder;
+
+        let cm = CaseMapper::new();
+        let closure = |c| {
+            let mut builder = CodePointInversionListBuilder::new();
+            cm.add_case_closure_to(c, &mut builder);
+            builder.build()
+        };
+
+        // KELVIN SIGN
+        let set = closure('k');
+        assert!(set.contains('K'));
+        assert!(set.contains('\u{212A}'));
+        assert_eq!(set.size(), 2);
+        assert_eq!(cm.simple_fold('\u{212A}'), 'k');
+        assert_eq!(cm.simple_fold('K'), 'k');
+
+        // LATIN CAPITAL LETTER SHARP S
+        assert!(closure('ß').contains('ẞ'));
+        assert!(closure('ẞ').contains('ß'));
+        assert_eq!(cm.simple_fold('ẞ'), 'ß');
+
+        let set = closure('Σ');
+        assert!(set.contains('σ'));
+        assert!(set.contains('ς'));
+        assert_eq!(cm.simple_fold('ς'), 'σ');
+        assert_eq!(cm.simple_fold('Σ'), 'σ');
+    }
 }
@@ -63,6 +63,9 @@ pub(crate) struct FullCaseWriteable<'a, const IS_TITLE_CONTEXT: bool> {
 impl<'a, const IS_TITLE_CONTEXT: bool> Writeable for FullCaseWriteable<'a, IS_TITLE_CONTEXT> {
     #[allow(clippy::indexing_slicing)] // last_uncopied_index and i are known to be in bounds
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        if !IS_TITLE_CONTEXT && self.mapping == MappingKind::Lower {
+            return self.write_lowercase_to(sink);
+        }
         let src = self.src;
         let mut mapping = self.mapping;
         let mut iter = src.char_indices();
@@ -89,6 +92,91 @@ impl<'a, const IS_TITLE_CONTEXT: bool> Writeable for FullCaseWriteable<'a, IS_TI
     }
 }
 
+impl<'a, const IS_TITLE_CONTEXT: bool> FullCaseWriteable<'a, IS_TITLE_CONTEXT> {
+    /// Writes the full lowercase mapping of the source string.
+    ///
+    /// Blocks of 16 ASCII bytes are checked a `u64` at a time: blocks without uppercase letters
+    /// are copied as they are, and blocks with them are lowercased without consulting the data.
+    /// Everything else, including `I` and `J` in locales that map them specially, goes through
+    /// [`CaseMapV1::full_helper()`] one character at a time, with the full string as context.
+    #[allow(clippy::indexing_slicing)] // copied and i are char boundaries in bounds
+    fn write_lowercase_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let src = self.src;
+        let bytes = src.as_bytes();
+        let special_i_and_j = matches!(
+            self.locale,
+            CaseMapLocale::Turkish | CaseMapLocale::Lithuanian
+        );
+        // Everything before `copied` has been written, and everything between `copied`
+        // and `i` is unchanged by the mapping.
+        let mut copied = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if let Some(block) = bytes.get(i..i + 16) {
+                let (low, high) = block.split_at(8);
+                let words = [ascii_word(low), ascii_word(high)];
+                if let [Some(low), Some(high)] = words {
+                    let upper = [ascii_upper_mask(low), ascii_upper_mask(high)];
+                    if upper == [0, 0] {
+                        i += 16;
+                        continue;
+                    }
+                    let is_special =
+                        |word| contains_ascii_byte(word, b'I') || contains_ascii_byte(word, b'J');
+                    if !special_i_and_j || !(is_special(low) || is_special(high)) {
+                        sink.write_str(&src[copied..i])?;
+                        for (word, upper) in [(low, upper[0]), (high, upper[1])] {
+                            // Setting bit 5 lowercases an ASCII uppercase letter
+                            let lower = (word | (upper >> 2)).to_ne_bytes();
+                            sink.write_str(core::str::from_utf8(&lower).map_err(|_| fmt::Error)?)?;
+                        }
+                        i += 16;
+                        copied = i;
+                        continue;
+                    }
+                }
+            }
+            let Some(c) = src[i..].chars().next() else {
+                break;
+            };
+            sink.write_str(&src[copied..i])?;
+            let context = ContextIterator::new(&src[..i], &src[i..]);
+            self.data
+                .full_helper::<false, W>(c, context, self.locale, MappingKind::Lower, sink)?;
+            i += c.len_utf8();
+            copied = i;
+        }
+        sink.write_str(&src[copied..])
+    }
+}
+
+const ASCII_ONES: u64 = 0x0101_0101_0101_0101;
+const ASCII_HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Reads 8 bytes as a `u64`, returning `None` unless they are all ASCII.
+#[inline]
+fn ascii_word(bytes: &[u8]) -> Option<u64> {
+    let word = u64::from_ne_bytes(<[u8; 8]>::try_from(bytes).ok()?);
+    (word & ASCII_HIGH_BITS == 0).then_some(word)
+}
+
+/// Returns a word with the high bit set in each byte of the ASCII `word` that is in `A..=Z`.
+#[inline]
+fn ascii_upper_mask(word: u64) -> u64 {
+    // Bytes are below 0x80, so neither sum carries into the next byte: the high bit of
+    // `byte + 0x3F` is set from `A` up, and that of `byte + 0x25` from the byte after `Z`.
+    (word + 0x3F * ASCII_ONES) & !(word + 0x25 * ASCII_ONES) & ASCII_HIGH_BITS
+}
+
+/// Whether the ASCII `word` contains the ASCII `byte`.
+#[inline]
+fn contains_ascii_byte(word: u64, byte: u8) -> bool {
+    // The bytes of `diff` are below 0x80 and zero where they match, and adding 0x7F sets
+    // the high bit of every byte but those.
+    let diff = word ^ (u64::from(byte) * ASCII_ONES);
+    !(diff + 0x7F * ASCII_ONES) & ASCII_HIGH_BITS != 0
+}
+
 impl<'data> CaseMapV1<'data> {
     fn simple_helper(&self, c: char, kind: MappingKind) -> char {
         let data = self.lookup_data(c);
@@ -192,6 +192,45 @@ fn test_full_mappings() {
     assert_eq!(case_mapping.fold_turkic_string(initial), turkic);
 }
 
+#[test]
+fn test_long_ascii_lowercase() {
+    // Long runs of ASCII are lowercased in blocks, which must agree with the per-character mapping
+    let case_mapping = CaseMapper::new();
+    let root = langid!("und");
+    let tr = langid!("tr");
+    let lt = langid!("lt");
+
+    let ascii: String = (0..2).flat_map(|_| (0u8..0x80).map(char::from)).collect();
+    let lower = ascii.to_ascii_lowercase();
+    assert_eq!(case_mapping.lowercase_to_string(&ascii, &root), lower);
+    assert_eq!(case_mapping.lowercase_to_string(&ascii, &lt), lower);
+    let lower_tr: String = ascii
+        .chars()
+        .map(|c| {
+            if c == 'I' {
+                'ı'
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect();
+    assert_eq!(case_mapping.lowercase_to_string(&ascii, &tr), lower_tr);
+
+    let mixed = "THE WINE-DARK SEA OF THE ODYSSEY: ΟΔΥΣΣΕΥΣ AND ΠΗΝΕΛΟΠΗ WAIT";
+    assert_eq!(
+        case_mapping.lowercase_to_string(mixed, &root),
+        "the wine-dark sea of the odyssey: οδυσσευς and πηνελοπη wait"
+    );
+    assert_eq!(
+        case_mapping.lowercase_to_string("ISTANBUL AND DIYARBAKIR, NOT I\u{307}ZMIR", &tr),
+        "ıstanbul and dıyarbakır, not izmır"
+    );
+    assert_eq!(
+        case_mapping.lowercase_to_string("JUMPING OVER THE LAZY DOGS: J\u{301} AND I\u{303}", &lt),
+        "jumping over the lazy dogs: j\u{307}\u{301} and i\u{307}\u{303}"
+    );
+}
+
 #[test]
 fn test_armenian() {
     let cm = CaseMapper::new();
@@ -406,4 +445,35 @@ fn test_greek_upper() {
 
     // Handle breathing marks on rho
     assert_greek_uppercase("ῥήματα ῤήματα", "ΡΗΜΑΤΑ ΡΗΜΑΤΑ");
+
+    // Matches ICU4C's u_strToUpper with the el locale
+    assert_greek_uppercase("Το ένα άσπρο άλογο", "ΤΟ ΕΝΑ ΑΣΠΡΟ ΑΛΟΓΟ");
+    // Dialytika is kept, and added where an accent marked a non-diphthong
+    assert_greek_uppercase("ϊ ΐ ϋ ΰ", "Ϊ Ϊ Ϋ Ϋ");
+    assert_greek_uppercase("προϊόν, αΐδιος", "ΠΡΟΪΟΝ, ΑΪΔΙΟΣ");
+    // Final sigma is irrelevant when uppercasing
+    assert_greek_uppercase("ὁδός, ὁδόσ", "ΟΔΟΣ, ΟΔΟΣ");
+}
+
+#[test]
+fn test_greek_upper_without_accent_removal() {
+    let cm = CaseMapper::new();
+    let modern_greek = &langid!("el");
+    let root = &langid!("und");
+
+    let input = "Το ένα άσπρο άλογο";
+    assert_eq!(
+        cm.uppercase_without_greek_accent_removal_to_string(input, modern_greek),
+        "ΤΟ ΈΝΑ ΆΣΠΡΟ ΆΛΟΓΟ"
+    );
+    assert_eq!(
+        cm.uppercase_without_greek_accent_removal_to_string(input, modern_greek),
+        cm.uppercase_to_string(input, root)
+    );
+
+    // Other languages are unaffected
+    assert_eq!(
+        cm.uppercase_without_greek_accent_removal_to_string("istanbul", &langid!("tr")),
+        "İSTANBUL"
+    );
 }
@@ -10,8 +10,9 @@
 //! the comparison of collation element sequences.
 
 use crate::elements::{
-    CollationElement, CollationElements, NonPrimary, JAMO_COUNT, NO_CE, NO_CE_PRIMARY,
-    NO_CE_SECONDARY, NO_CE_TERTIARY, OPTIMIZED_DIACRITICS_MAX_COUNT, QUATERNARY_MASK,
+    decomposition_starts_with_non_starter, CollationElement, CollationElements, NonPrimary,
+    JAMO_COUNT, NO_CE, NO_CE_PRIMARY, NO_CE_SECONDARY, NO_CE_TERTIARY,
+    OPTIMIZED_DIACRITICS_MAX_COUNT, QUATERNARY_MASK,
 };
 use crate::error::CollatorError;
 use crate::options::CollatorOptionsBitField;
@@ -33,7 +34,7 @@ use utf16_iter::Utf16CharsEx;
 use utf8_iter::Utf8CharsEx;
 use zerovec::ule::AsULE;
 
-const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000; // for U+FFFE
+pub(crate) const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000; // for U+FFFE
 
 struct AnyQuaternaryAccumulator(u32);
 
@@ -301,48 +302,101 @@ impl Collator {
         ret
     }
 
-    fn compare_impl<I: Iterator<Item = char>>(&self, left_chars: I, right_chars: I) -> Ordering {
-        let tailoring: &DataPayload<CollationDataV1Marker> =
-            if let Some(tailoring) = &self.tailoring {
-                tailoring
-            } else {
-                // If the root collation is valid for the locale,
-                // use the root as the tailoring so that reads from the
-                // tailoring always succeed.
-                //
-                // TODO(#2011): Do we instead want to have an untailored
-                // copypaste of the iterator that omits the tailoring
-                // branches for performance at the expense of code size
-                // and having to maintain both a tailoring-capable and
-                // a tailoring-incapable version of the iterator?
-                // Or, in order not to flip the branch prediction around,
-                // should we have a no-op tailoring that contains a
-                // specially-crafted CodePointTrie that always returns
-                // a FALLBACK_CE32 after a single branch?
-                &self.root
-            };
+    /// Write the sort key of a guaranteed well-formed UTF-8 string to `sink`.
+    ///
+    /// Comparing the bytes of two sort keys gives the same result as comparing the
+    /// strings with [`Collator::compare`]. This is useful when the same string is compared
+    /// many times, such as when sorting or when storing the keys in a database index.
+    ///
+    /// Sort keys are only comparable with other sort keys from a collator with the same
+    /// locale and options, built with the same ICU4X version and collation data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collator::*;
+    /// use icu::locid::locale;
+    ///
+    /// let collator =
+    ///     Collator::try_new(&locale!("es").into(), CollatorOptions::new()).unwrap();
+    ///
+    /// let sort_key = |s| {
+    ///     let mut key = Vec::new();
+    ///     collator.write_sort_key(s, &mut key);
+    ///     key
+    /// };
+    ///
+    /// assert!(sort_key("manna") < sort_key("mañana"));
+    /// assert!(sort_key("mañana") < sort_key("nube"));
+    /// ```
+    pub fn write_sort_key<S: Extend<u8>>(&self, s: &str, sink: &mut S) {
+        self.write_sort_key_impl(s.chars(), sink);
+        if self.options.strength() == Strength::Identical {
+            write_identical_level(
+                Decomposition::new(s.chars(), self.decompositions.get(), self.tables.get()),
+                sink,
+            );
+        }
+    }
 
-        // Sadly, it looks like variable CEs and backward second level
-        // require us to store the full 64-bit CEs instead of storing only
-        // the NonPrimary part.
-        //
-        // TODO(#2008): Consider having two monomorphizations of this method:
-        // one that can deal with variables shifted to quaternary and
-        // backward second level and another that doesn't support that
-        // and only stores `NonPrimary` in `left_ces` and `right_ces`
-        // with double the number of stack allocated elements.
+    /// Write the sort key of potentially ill-formed UTF-8 to `sink`. Ill-formed input is
+    /// treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+    /// to the WHATWG Encoding Standard.
+    ///
+    /// See [`Collator::write_sort_key`].
+    pub fn write_sort_key_utf8<S: Extend<u8>>(&self, s: &[u8], sink: &mut S) {
+        self.write_sort_key_impl(s.chars(), sink);
+        if self.options.strength() == Strength::Identical {
+            write_identical_level(
+                Decomposition::new(s.chars(), self.decompositions.get(), self.tables.get()),
+                sink,
+            );
+        }
+    }
 
-        // TODO(#2007): figure out a proper stack buffer length for these
-        let mut left_ces: SmallVec<[CollationElement; 8]> = SmallVec::new();
-        let mut right_ces: SmallVec<[CollationElement; 8]> = SmallVec::new();
+    /// Write the sort key of potentially ill-formed UTF-16 to `sink`. Unpaired surrogates
+    /// are treated as if each one was a REPLACEMENT CHARACTER.
+    ///
+    /// See [`Collator::write_sort_key`].
+    pub fn write_sort_key_utf16<S: Extend<u8>>(&self, s: &[u16], sink: &mut S) {
+        self.write_sort_key_impl(s.chars(), sink);
+        if self.options.strength() == Strength::Identical {
+            write_identical_level(
+                Decomposition::new(s.chars(), self.decompositions.get(), self.tables.get()),
+                sink,
+            );
+        }
+    }
 
-        // The algorithm comes from CollationCompare::compareUpToQuaternary in ICU4C.
+    /// The tailoring to use, which is the root if the locale has no tailoring.
+    fn tailoring_or_root(&self) -> &DataPayload<CollationDataV1Marker> {
+        if let Some(tailoring) = &self.tailoring {
+            tailoring
+        } else {
+            // If the root collation is valid for the locale,
+            // use the root as the tailoring so that reads from the
+            // tailoring always succeed.
+            //
+            // TODO(#2011): Do we instead want to have an untailored
+            // copypaste of the iterator that omits the tailoring
+            // branches for performance at the expense of code size
+            // and having to maintain both a tailoring-capable and
+            // a tailoring-incapable version of the iterator?
+            // Or, in order not to flip the branch prediction around,
+            // should we have a no-op tailoring that contains a
+            // specially-crafted CodePointTrie that always returns
+            // a FALLBACK_CE32 after a single branch?
+            &self.root
+        }
+    }
 
-        let mut any_variable = false;
-        // Attribute belongs closer to `unwrap`, but
-        // https://github.com/rust-lang/rust/issues/15701
-        #[allow(clippy::unwrap_used)]
-        let variable_top = if self.options.alternate_handling() == AlternateHandling::NonIgnorable {
+    /// The primary weight above the last variable primary, or 0 if variable collation
+    /// elements are not ignorable.
+    // Attribute belongs closer to `unwrap`, but
+    // https://github.com/rust-lang/rust/issues/15701
+    #[allow(clippy::unwrap_used)]
+    pub(crate) fn variable_top(&self) -> u32 {
+        if self.options.alternate_handling() == AlternateHandling::NonIgnorable {
             0
         } else {
             // +1 so that we can use "<" and primary ignorables test out early.
@@ -354,11 +408,18 @@ impl Collator {
                 .get()
                 .last_primary_for_group(self.options.max_variable())
                 + 1
-        };
+        }
+    }
 
-        // Attribute belongs on inner expression, but
-        // https://github.com/rust-lang/rust/issues/15701
-        #[allow(clippy::unwrap_used)]
+    /// Returns the collation elements of `chars`, followed by an unlimited number of
+    /// [`NO_CE`].
+    // Attribute belongs on inner expressions, but
+    // https://github.com/rust-lang/rust/issues/15701
+    #[allow(clippy::unwrap_used)]
+    pub(crate) fn collation_elements<I: Iterator<Item = char>>(
+        &self,
+        chars: I,
+    ) -> CollationElements<'_, I> {
         let numeric_primary = if self.options.numeric() {
             Some(
                 self.special_primaries
@@ -372,28 +433,10 @@ impl Collator {
             None
         };
 
-        // Attribute belongs on inner expression, but
-        // https://github.com/rust-lang/rust/issues/15701
-        #[allow(clippy::unwrap_used)]
-        let mut left = CollationElements::new(
-            left_chars,
-            self.root.get(),
-            tailoring.get(),
-            <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(self.jamo.get().ce32s.as_ule_slice())
-                .unwrap(), // `unwrap` OK, because length already validated
-            &self.diacritics.get().secondaries,
-            self.decompositions.get(),
-            self.tables.get(),
-            numeric_primary,
-            self.lithuanian_dot_above,
-        );
-        // Attribute belongs on inner expression, but
-        // https://github.com/rust-lang/rust/issues/15701
-        #[allow(clippy::unwrap_used)]
-        let mut right = CollationElements::new(
-            right_chars,
+        CollationElements::new(
+            chars,
             self.root.get(),
-            tailoring.get(),
+            self.tailoring_or_root().get(),
             <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(self.jamo.get().ce32s.as_ule_slice())
                 .unwrap(), // `unwrap` OK, because length already validated
             &self.diacritics.get().secondaries,
@@ -401,7 +444,37 @@ impl Collator {
             self.tables.get(),
             numeric_primary,
             self.lithuanian_dot_above,
-        );
+        )
+    }
+
+    /// Whether the canonical decomposition of `c` starts with a non-starter, such as a
+    /// combining mark.
+    pub(crate) fn starts_with_non_starter(&self, c: char) -> bool {
+        decomposition_starts_with_non_starter(self.decompositions.get().trie.get(c))
+    }
+
+    fn compare_impl<I: Iterator<Item = char>>(&self, left_chars: I, right_chars: I) -> Ordering {
+        // Sadly, it looks like variable CEs and backward second level
+        // require us to store the full 64-bit CEs instead of storing only
+        // the NonPrimary part.
+        //
+        // TODO(#2008): Consider having two monomorphizations of this method:
+        // one that can deal with variables shifted to quaternary and
+        // backward second level and another that doesn't support that
+        // and only stores `NonPrimary` in `left_ces` and `right_ces`
+        // with double the number of stack allocated elements.
+
+        // TODO(#2007): figure out a proper stack buffer length for these
+        let mut left_ces: SmallVec<[CollationElement; 8]> = SmallVec::new();
+        let mut right_ces: SmallVec<[CollationElement; 8]> = SmallVec::new();
+
+        // The algorithm comes from CollationCompare::compareUpToQuaternary in ICU4C.
+
+        let mut any_variable = false;
+        let variable_top = self.variable_top();
+
+        let mut left = self.collation_elements(left_chars);
+        let mut right = self.collation_elements(right_chars);
         loop {
             let mut left_primary;
             'left_primary_loop: loop {
@@ -843,4 +916,138 @@ impl Collator {
 
         Ordering::Equal
     }
+    /// Writes the levels up to quaternary of the sort key of `chars`.
+    ///
+    /// Each level is written with the same weights that `compare_impl()` compares, in
+    /// the same order. A level ends with the weight of [`NO_CE`] on that level, which
+    /// sorts below all other weights, so the levels of two sort keys line up exactly when
+    /// all previous levels are equal.
+    fn write_sort_key_impl<I: Iterator<Item = char>, S: Extend<u8>>(&self, chars: I, sink: &mut S) {
+        let mut ces: SmallVec<[CollationElement; 8]> = SmallVec::new();
+
+        let variable_top = self.variable_top();
+        let reorder = |primary: u32| match &self.reordering {
+            Some(reordering) => reordering.get().reorder(primary),
+            None => primary,
+        };
+
+        // Buffer the collation elements with variable CEs shifted as in `compare_impl()`,
+        // and write the primary level.
+        let mut iter = self.collation_elements(chars);
+        let mut shifting = false;
+        loop {
+            let ce = iter.next();
+            let primary = ce.primary();
+            if (primary < variable_top && primary > MERGE_SEPARATOR_PRIMARY)
+                || (shifting && primary == 0)
+            {
+                // Variable CE, or a primary ignorable after one: shift it to the
+                // quaternary level.
+                shifting = true;
+                ces.push(ce.clone_with_non_primary_zeroed());
+                continue;
+            }
+            shifting = false;
+            ces.push(ce);
+            if primary != 0 {
+                sink.extend(reorder(primary).to_be_bytes());
+            }
+            if primary == NO_CE_PRIMARY {
+                break;
+            }
+        }
+
+        if self.options.strength() >= Strength::Secondary {
+            if !self.options.backward_second_level() {
+                for ce in ces.iter() {
+                    let secondary = ce.secondary();
+                    if secondary != 0 {
+                        sink.extend(secondary.to_be_bytes());
+                    }
+                }
+            } else {
+                // The secondary weights are written backwards in each segment between
+                // merge separators, and each segment ends with the weight of `NO_CE`.
+                // The last CE is `NO_CE` itself, which ends the last segment.
+                let segments = ces.split_last().map(|(_, ces)| ces).unwrap_or_default();
+                for segment in segments.split(|ce| {
+                    let primary = ce.primary();
+                    primary != 0 && primary <= MERGE_SEPARATOR_PRIMARY
+                }) {
+                    for ce in segment.iter().rev() {
+                        let secondary = ce.secondary();
+                        if secondary != 0 {
+                            sink.extend(secondary.to_be_bytes());
+                        }
+                    }
+                    sink.extend(NO_CE_SECONDARY.to_be_bytes());
+                }
+            }
+        }
+
+        if self.options.case_level() {
+            // There is one case weight for each weight of the previous level, so the case
+            // level needs no terminator.
+            for ce in ces.iter() {
+                let non_primary = ce.non_primary();
+                let ignorable = if self.options.strength() == Strength::Primary {
+                    ce.either_half_zero()
+                } else {
+                    non_primary.secondary() == 0
+                };
+                if ignorable {
+                    continue;
+                }
+                let case = (non_primary.case() >> 14) as u8;
+                sink.extend([if self.options.upper_first() {
+                    3 - case
+                } else {
+                    case
+                }]);
+            }
+        }
+
+        let Some(tertiary_mask) = self.options.tertiary_mask() else {
+            return;
+        };
+        for ce in ces.iter() {
+            let non_primary = ce.non_primary();
+            let mut tertiary = non_primary.tertiary_case_quarternary(tertiary_mask);
+            if tertiary == 0 {
+                continue;
+            }
+            if self.options.upper_first() && tertiary > NO_CE_TERTIARY {
+                // Same adjustment as in `compare_impl()`.
+                if non_primary.secondary() != 0 {
+                    tertiary ^= 0xC000;
+                } else {
+                    tertiary += 0x4000;
+                }
+            }
+            sink.extend(tertiary.to_be_bytes());
+        }
+
+        if self.options.strength() <= Strength::Tertiary {
+            return;
+        }
+        for ce in ces.iter() {
+            let quaternary = if ce.tertiary_ignorable() {
+                ce.primary()
+            } else {
+                ce.quaternary()
+            };
+            if quaternary != 0 {
+                sink.extend(reorder(quaternary).to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Writes the identical level of a sort key: the NFD form of the input as UTF-8, which
+/// sorts bytewise in code point order.
+fn write_identical_level<S: Extend<u8>>(nfd: impl Iterator<Item = char>, sink: &mut S) {
+    let mut buf = [0; 4];
+    for c in nfd {
+        sink.extend(c.encode_utf8(&mut buf).bytes());
+    }
 }
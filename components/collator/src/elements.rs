@@ -64,7 +64,7 @@ fn trie_value_indicates_special_non_starter_decomposition(trie_value: u32) -> bo
 
 /// Checks if a trie value signifies a character whose decomposition
 /// starts with a non-starter.
-fn decomposition_starts_with_non_starter(trie_value: u32) -> bool {
+pub(crate) fn decomposition_starts_with_non_starter(trie_value: u32) -> bool {
     trie_value_has_ccc(trie_value)
         || trie_value_indicates_special_non_starter_decomposition(trie_value)
 }
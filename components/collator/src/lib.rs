@@ -280,6 +280,7 @@ mod elements;
 mod error;
 mod options;
 pub mod provider;
+mod search;
 
 extern crate alloc;
 
@@ -294,6 +295,7 @@ pub use options::MaxVariable;
 pub use options::Numeric;
 pub use options::ResolvedCollatorOptions;
 pub use options::Strength;
+pub use search::CollationSearcher;
 
 #[doc(no_inline)]
 pub use CollatorError as Error;
@@ -0,0 +1,235 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module holds the `CollationSearcher` struct, which finds a string in
+//! another one by comparing collation elements.
+
+use crate::comparison::{Collator, MERGE_SEPARATOR_PRIMARY};
+use crate::elements::{CASE_MASK, NO_CE_PRIMARY};
+use crate::{AlternateHandling, CaseLevel, Strength};
+use core::ops::Range;
+use smallvec::SmallVec;
+
+/// Masks of the bits of a collation element that are compared at each strength.
+const PRIMARY_MASK: u64 = 0xFFFFFFFF00000000;
+const SECONDARY_MASK: u64 = 0xFFFFFFFFFFFF0000;
+/// Everything except for the quaternary weight.
+const TERTIARY_MASK: u64 = 0xFFFFFFFFFFFFFF3F;
+
+/// The collation elements of a string, masked according to the strength, without the
+/// ones that are ignorable at that strength.
+type SearchKey = SmallVec<[u64; 8]>;
+
+/// Finds a string in another one, treating strings that a [`Collator`] considers equal
+/// as matches.
+///
+/// The strings are compared by their collation elements, so the search respects the
+/// contractions and expansions of the collator's locale, such as "ch" being a letter in
+/// Slovak and "ß" matching "ss" at primary strength. Characters that are ignorable at the strength of
+/// the collator, such as a soft hyphen, are skipped both in the haystack and in the needle.
+///
+/// A match never starts or ends in the middle of a contraction or a combining character
+/// sequence of the haystack. Among the matches that start at the same position, the shortest
+/// is returned, so a match neither starts nor ends with an ignorable character.
+///
+/// The quaternary and identical strengths are treated as tertiary. If variable collation
+/// elements are shifted by [`AlternateHandling::Shifted`], they are ignored.
+///
+/// Since the collation elements of "ß" differ from those of "ss" at secondary strength,
+/// "strasse" only matches "Straße" at primary strength, where [`Collator::compare`] also
+/// considers them equal.
+///
+/// # Performance
+///
+/// The haystack is collated once from every position where a match could start, until its
+/// collation elements stop matching the needle, so a search usually takes time linear in the
+/// length of the haystack. Only at positions whose collation elements start with those of the
+/// needle is every candidate end of the match collated again, which costs time quadratic in the
+/// length of the match.
+///
+/// # Examples
+///
+/// ```
+/// use icu::collator::*;
+/// use icu::locid::locale;
+///
+/// let mut options = CollatorOptions::new();
+/// options.strength = Some(Strength::Primary);
+/// let collator = Collator::try_new(&locale!("de").into(), options).unwrap();
+/// let searcher = CollationSearcher::new(&collator);
+///
+/// let haystack = "Die Straße ist lang.";
+/// let range = searcher.find(haystack, "strasse").expect("found");
+/// assert_eq!(&haystack[range], "Straße");
+///
+/// assert_eq!(searcher.find(haystack, "strase"), None);
+///
+/// assert_eq!(
+///     searcher.find_all("Masse, Maße", "masse").collect::<Vec<_>>(),
+///     [0..5, 7..12]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CollationSearcher<'a> {
+    collator: &'a Collator,
+    mask: u64,
+    variable_top: u32,
+}
+
+impl<'a> CollationSearcher<'a> {
+    /// Creates a searcher that compares strings with `collator`.
+    pub fn new(collator: &'a Collator) -> Self {
+        let options = collator.resolved_options();
+        let mut mask = match options.strength {
+            Strength::Primary => PRIMARY_MASK,
+            Strength::Secondary => SECONDARY_MASK,
+            _ => TERTIARY_MASK,
+        };
+        if options.case_level == CaseLevel::On {
+            mask |= u64::from(CASE_MASK);
+        }
+        let variable_top = if options.alternate_handling == AlternateHandling::Shifted {
+            collator.variable_top()
+        } else {
+            0
+        };
+        Self {
+            collator,
+            mask,
+            variable_top,
+        }
+    }
+
+    /// Returns the byte range of the first match of `needle` in `haystack`, or `None` if
+    /// there is none.
+    ///
+    /// A needle that is empty or ignorable never matches.
+    pub fn find(&self, haystack: &str, needle: &str) -> Option<Range<usize>> {
+        self.find_from(haystack, &self.key(needle), 0)
+    }
+
+    /// Returns an iterator over the byte ranges of the non-overlapping matches of `needle`
+    /// in `haystack`.
+    ///
+    /// A needle that is empty or ignorable never matches.
+    pub fn find_all<'h>(
+        &'h self,
+        haystack: &'h str,
+        needle: &str,
+    ) -> impl Iterator<Item = Range<usize>> + 'h {
+        let needle = self.key(needle);
+        let mut from = 0;
+        core::iter::from_fn(move || {
+            let found = self.find_from(haystack, &needle, from)?;
+            from = found.end;
+            Some(found)
+        })
+    }
+
+    fn find_from(&self, haystack: &str, needle: &[u64], from: usize) -> Option<Range<usize>> {
+        if needle.is_empty() {
+            return None;
+        }
+        for (offset, c) in haystack.get(from..)?.char_indices() {
+            if self.collator.starts_with_non_starter(c) {
+                continue;
+            }
+            let start = from + offset;
+            let rest = haystack.get(start..)?;
+            if !self.starts_with(rest, needle) {
+                continue;
+            }
+            for end in rest.char_indices().map(|(i, c)| start + i + c.len_utf8()) {
+                let key = self.key(haystack.get(start..end)?);
+                // An empty key means that the match would start with an ignorable, and a
+                // longer key can no longer turn into the needle.
+                if key.is_empty() || key.len() > needle.len() {
+                    break;
+                }
+                if *key == *needle && self.is_match_boundary(haystack, start, end) {
+                    return Some(start..end);
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether `haystack[start..end]` neither shares a contraction with the character before it
+    /// nor with the character after it, nor is followed by a combining character.
+    fn is_match_boundary(&self, haystack: &str, start: usize, end: usize) -> bool {
+        let before = haystack
+            .get(..start)
+            .and_then(|before| before.char_indices().next_back());
+        if let Some((previous, _)) = before {
+            if !self.is_split_point(haystack.get(previous..end), start - previous) {
+                return false;
+            }
+        }
+        if let Some(next) = haystack.get(end..).and_then(|after| after.chars().next()) {
+            if self.collator.starts_with_non_starter(next)
+                || !self.is_split_point(haystack.get(start..end + next.len_utf8()), end - start)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether the collation elements of `text` are those of the text before `index` followed
+    /// by those of the text after it.
+    fn is_split_point(&self, text: Option<&str>, index: usize) -> bool {
+        let Some(text) = text else {
+            return false;
+        };
+        let (Some(before), Some(after)) = (text.get(..index), text.get(index..)) else {
+            return false;
+        };
+        let mut split = self.key(before);
+        split.extend(self.key(after));
+        split == self.key(text)
+    }
+
+    /// Whether the collation elements of `text` start with `needle`, which is not empty.
+    ///
+    /// This stops collating `text` at the first collation element that differs from `needle`.
+    /// Since a match must not share a contraction with the text after it, a match of `needle` at
+    /// the start of `text` implies that this returns `true`.
+    fn starts_with(&self, text: &str, needle: &[u64]) -> bool {
+        let mut elements = self.elements(text);
+        needle.iter().all(|&ce| elements.next() == Some(ce))
+    }
+
+    fn key(&self, text: &str) -> SearchKey {
+        self.elements(text).collect()
+    }
+
+    /// Returns the collation elements of `text`, masked according to the strength, without the
+    /// ones that are ignorable at that strength.
+    fn elements<'t>(&'t self, text: &'t str) -> impl Iterator<Item = u64> + 't {
+        let mut elements = self.collator.collation_elements(text.chars());
+        let mut after_variable = false;
+        core::iter::from_fn(move || loop {
+            let ce = elements.next();
+            let primary = ce.primary();
+            if primary == NO_CE_PRIMARY {
+                return None;
+            }
+            if primary < self.variable_top && primary > MERGE_SEPARATOR_PRIMARY {
+                // Variable collation elements and the primary ignorables after them are ignored.
+                after_variable = true;
+                continue;
+            }
+            if primary != 0 {
+                after_variable = false;
+            } else if after_variable {
+                continue;
+            }
+            let masked =
+                ((u64::from(primary) << 32) | u64::from(ce.non_primary().bits())) & self.mask;
+            if masked != 0 {
+                return Some(masked);
+            }
+        })
+    }
+}
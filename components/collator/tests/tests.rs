@@ -1321,6 +1321,163 @@ fn test_unpaired_surrogates() {
     );
 }
 
+fn sort_key(collator: &Collator, s: &str) -> Vec<u8> {
+    let mut key = Vec::new();
+    collator.write_sort_key(s, &mut key);
+    key
+}
+
+fn check_sort_keys(collator: &Collator, strings: &[&str]) {
+    for left in strings {
+        let left_key = sort_key(collator, left);
+        for right in strings {
+            assert_eq!(
+                left_key.cmp(&sort_key(collator, right)),
+                collator.compare(left, right),
+                "{left:?} vs {right:?} with {:?}",
+                collator.resolved_options()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sort_key() {
+    let mut words = ["nube", "mañana", "luz", "manna"];
+    let collator = Collator::try_new(&locale!("es").into(), CollatorOptions::new()).unwrap();
+    words.sort_by_key(|w| sort_key(&collator, w));
+    assert_eq!(words, ["luz", "manna", "mañana", "nube"]);
+
+    let mut words = ["a10", "a2", "a1"];
+    let mut options = CollatorOptions::new();
+    options.numeric = Some(Numeric::On);
+    let collator = Collator::try_new(&Default::default(), options).unwrap();
+    words.sort_by_key(|w| sort_key(&collator, w));
+    assert_eq!(words, ["a1", "a2", "a10"]);
+
+    // The UTF-8 and UTF-16 variants write the same key
+    let mut utf8 = Vec::new();
+    collator.write_sort_key_utf8("mañana".as_bytes(), &mut utf8);
+    let mut utf16 = Vec::new();
+    collator.write_sort_key_utf16(&"mañana".encode_utf16().collect::<Vec<_>>(), &mut utf16);
+    assert_eq!(utf8, sort_key(&collator, "mañana"));
+    assert_eq!(utf16, sort_key(&collator, "mañana"));
+}
+
+#[test]
+fn test_sort_key_options() {
+    let strings = [
+        "",
+        "a",
+        "A",
+        "ab",
+        "aB",
+        "Ab",
+        "abc",
+        "ä",
+        "Ä",
+        "a\u{308}",
+        "à",
+        "cote",
+        "coté",
+        "côte",
+        "côté",
+        "cote\u{FFFE}coté",
+        "côte\u{FFFE}cote",
+        "black bird",
+        "black-bird",
+        "blackbird",
+        "Black-Bird",
+        "a1",
+        "a2",
+        "a10",
+        "a01",
+        "\u{05D3}\u{05D7}\u{05D9}",
+        "\u{05D3}\u{05D7}\u{05D9}\u{05AD}",
+        "ゃ",
+        "や",
+        "ャ",
+        "ヤ",
+        "\u{FFFD}",
+        "\u{1F600}",
+    ];
+
+    for strength in [
+        Strength::Primary,
+        Strength::Secondary,
+        Strength::Tertiary,
+        Strength::Quaternary,
+        Strength::Identical,
+    ] {
+        for alternate_handling in [AlternateHandling::NonIgnorable, AlternateHandling::Shifted] {
+            for case_first in [CaseFirst::Off, CaseFirst::LowerFirst, CaseFirst::UpperFirst] {
+                for case_level in [CaseLevel::Off, CaseLevel::On] {
+                    for backward_second_level in [BackwardSecondLevel::Off, BackwardSecondLevel::On]
+                    {
+                        let mut options = CollatorOptions::new();
+                        options.strength = Some(strength);
+                        options.alternate_handling = Some(alternate_handling);
+                        options.case_first = Some(case_first);
+                        options.case_level = Some(case_level);
+                        options.backward_second_level = Some(backward_second_level);
+                        options.numeric = Some(Numeric::On);
+                        let collator = Collator::try_new(&Default::default(), options).unwrap();
+                        check_sort_keys(&collator, &strings);
+                    }
+                }
+            }
+        }
+    }
+
+    // Tailorings and reordering
+    for locale in [locale!("es"), locale!("da"), locale!("ja"), locale!("th")] {
+        let mut options = CollatorOptions::new();
+        options.strength = Some(Strength::Quaternary);
+        let collator = Collator::try_new(&locale.into(), options).unwrap();
+        check_sort_keys(&collator, &strings);
+    }
+    let collator = Collator::try_new(&locale!("bn").into(), CollatorOptions::new()).unwrap();
+    check_sort_keys(&collator, &["অ", "ऄ", "a", "α", "1", "!", ""]);
+}
+
+#[test]
+fn test_sort_key_conformance() {
+    for (dict, alternate_handling) in [
+        (
+            &include_bytes!("data/CollationTest_CLDR_SHIFTED.txt")[..],
+            AlternateHandling::Shifted,
+        ),
+        (
+            &include_bytes!("data/CollationTest_CLDR_NON_IGNORABLE.txt")[..],
+            AlternateHandling::NonIgnorable,
+        ),
+    ] {
+        let mut options = CollatorOptions::new();
+        options.strength = Some(Strength::Identical);
+        options.alternate_handling = Some(alternate_handling);
+        let collator = Collator::try_new(&Default::default(), options).unwrap();
+
+        let mut prev: Option<(StackString, Vec<u8>)> = None;
+        for line in dict.split(|b| b == &b'\n') {
+            if line.is_empty() || line.starts_with(&[b'#']) {
+                continue;
+            }
+            let Some(parsed) = parse_hex(line) else {
+                continue;
+            };
+            let key = sort_key(&collator, &parsed);
+            if let Some((prev, prev_key)) = prev {
+                assert_eq!(
+                    prev_key.cmp(&key),
+                    collator.compare(&prev, &parsed),
+                    "{prev:?} vs {parsed:?}"
+                );
+            }
+            prev = Some((parsed, key));
+        }
+    }
+}
+
 #[test]
 fn test_backward_second_level() {
     let mut options = CollatorOptions::new();
@@ -1728,3 +1885,97 @@ fn test_ecma_sensitivity() {
 // TODO: Test Tibetan
 
 // TODO: Test de-AT-u-co-phonebk vs de-DE-u-co-phonebk
+
+#[test]
+fn test_search_de_sharp_s() {
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(&locale!("de").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+
+    assert_eq!(searcher.find("Die Straße", "strasse"), Some(4..11));
+    assert_eq!(searcher.find("Die Strasse", "STRAßE"), Some(4..11));
+    assert_eq!(searcher.find("Die Straße", "strase"), None);
+    // An expansion is not split
+    assert_eq!(searcher.find("Maß", "mas"), None);
+    assert_eq!(
+        searcher
+            .find_all("Fuß, Fuss, Fus", "fuss")
+            .collect::<Vec<_>>(),
+        [0..4, 6..10]
+    );
+
+    // At secondary strength, ß differs from ss, as in comparison
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(&locale!("de").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+    assert_ne!(collator.compare("straße", "strasse"), Ordering::Equal);
+    assert_eq!(searcher.find("Die Straße", "strasse"), None);
+    assert_eq!(searcher.find("Die Straße", "STRAßE"), Some(4..11));
+
+    // At tertiary strength, case matters too
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new(&locale!("de").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+    assert_eq!(searcher.find("Die Straße", "straße"), None);
+    assert_eq!(searcher.find("Die Straße", "Straße"), Some(4..11));
+}
+
+#[test]
+fn test_search_ignorable() {
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(&locale!("de").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+
+    // A soft hyphen inside the haystack is skipped
+    let haystack = "Die Stra\u{AD}sse";
+    assert_eq!(searcher.find(haystack, "strasse"), Some(4..13));
+    // but is not part of a match at its edges
+    assert_eq!(searcher.find("\u{AD}ab\u{AD}", "ab"), Some(2..4));
+    // An ignorable needle does not match
+    assert_eq!(searcher.find(haystack, "\u{AD}"), None);
+    assert_eq!(searcher.find(haystack, ""), None);
+}
+
+#[test]
+fn test_search_combining_sequence() {
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(&Default::default(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+
+    // The base letter of a combining sequence does not match on its own
+    assert_eq!(searcher.find("cafe\u{301}", "cafe"), None);
+    assert_eq!(searcher.find("cafe\u{301}", "café"), Some(0..6));
+    assert_eq!(searcher.find("café", "cafe\u{301}"), Some(0..5));
+
+    // At primary strength, it matches with the combining mark
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(&Default::default(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+    assert_eq!(searcher.find("cafe\u{301}", "cafe"), Some(0..6));
+}
+
+#[test]
+fn test_search_sk_contraction() {
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(&locale!("sk").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+
+    assert_eq!(searcher.find("ochota", "ch"), Some(1..3));
+    assert_eq!(searcher.find("Chlieb", "ch"), Some(0..2));
+    // Parts of the contraction do not match
+    assert_eq!(searcher.find("chlieb", "c"), None);
+    assert_eq!(searcher.find("chlieb", "h"), None);
+    assert_eq!(searcher.find("chlieb, cena", "c"), Some(8..9));
+
+    // Without the contraction, they do
+    let collator = Collator::try_new(&locale!("en").into(), options).unwrap();
+    let searcher = CollationSearcher::new(&collator);
+    assert_eq!(searcher.find("chlieb", "h"), Some(1..2));
+}
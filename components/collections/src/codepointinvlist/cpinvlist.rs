@@ -4,9 +4,9 @@
 
 #[cfg(feature = "serde")]
 use alloc::format;
-#[cfg(feature = "serde")]
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
 use core::{char, ops::RangeBounds, ops::RangeInclusive};
 use yoke::Yokeable;
 use zerofrom::ZeroFrom;
@@ -471,7 +471,8 @@ impl<'data> CodePointInversionList<'data> {
             None
         };
         let end = if let Some(last) = self.inv_list.last() {
-            if last == char::MAX as u32 {
+            // The inversion list end is exclusive
+            if last > char::MAX as u32 {
                 None
             } else {
                 Some(last..=char::MAX as u32)
@@ -738,6 +739,82 @@ impl<'data> CodePointInversionList<'data> {
                 .take_while(|&x| self.contains(x) == contained)
                 .count()
     }
+
+    /// Returns a UnicodeSet pattern string representing this set, such as `[a-z\ ãç]`.
+    ///
+    /// Characters with a special meaning in patterns are escaped with a backslash, and control
+    /// characters and surrogates are written as `\uXXXX`. Sets that contain both U+0000 and
+    /// U+10FFFF are written in their negated form (`[^...]`).
+    ///
+    /// The result can be parsed back with `icu::experimental::unicodeset_parse::TryFromPattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collections::codepointinvlist::CodePointInversionList;
+    ///
+    /// let example_list = [0x20, 0x21, 0x61, 0x7B, 0xE3, 0xE4, 0xE7, 0xE8];
+    /// let example =
+    ///     CodePointInversionList::try_from_inversion_list_slice(&example_list)
+    ///         .unwrap();
+    /// assert_eq!(example.to_pattern_string(), "[\\ a-zãç]");
+    ///
+    /// let example_list = [0x0, 0x2D, 0x2E, 0x110000];
+    /// let example =
+    ///     CodePointInversionList::try_from_inversion_list_slice(&example_list)
+    ///         .unwrap();
+    /// assert_eq!(example.to_pattern_string(), "[^\\-]");
+    /// ```
+    pub fn to_pattern_string(&self) -> String {
+        let mut pattern = String::from("[");
+        if self.contains32(0) && self.contains32(char::MAX as u32) {
+            pattern.push('^');
+            write_pattern_ranges(&mut pattern, self.iter_ranges_complemented());
+        } else {
+            write_pattern_ranges(&mut pattern, self.iter_ranges());
+        }
+        pattern.push(']');
+        pattern
+    }
+}
+
+/// Appends the ranges to a UnicodeSet pattern, writing ranges of length two as two characters.
+pub(crate) fn write_pattern_ranges(
+    pattern: &mut String,
+    ranges: impl Iterator<Item = RangeInclusive<u32>>,
+) {
+    for range in ranges {
+        let (start, end) = range.into_inner();
+        write_pattern_char(pattern, start);
+        if start != end {
+            if start + 1 != end {
+                pattern.push('-');
+            }
+            write_pattern_char(pattern, end);
+        }
+    }
+}
+
+/// Appends a single code point to a UnicodeSet pattern, escaping it if necessary.
+pub(crate) fn write_pattern_char(pattern: &mut String, cp: u32) {
+    match char::from_u32(cp) {
+        Some(c) if !c.is_control() => {
+            if matches!(
+                c,
+                '[' | ']' | '-' | '^' | '&' | '\\' | '{' | '}' | '$' | ':'
+                // Pattern_White_Space that isn't a control character
+                | ' ' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+            ) {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        // Control characters and surrogates
+        _ => {
+            // Writing to a String cannot fail
+            let _infallible = write!(pattern, "\\u{cp:04X}");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -937,6 +1014,24 @@ mod tests {
         assert_eq!(None, ranges.next());
     }
 
+    #[test]
+    fn test_codepointinversionlist_iter_ranges_complemented_with_max_code_point() {
+        // The set contains U+10FFFF, so the complement ends before the set
+        let ex = vec![0x80, (char::MAX as u32) + 1];
+        let set = CodePointInversionList::try_from_inversion_list_slice(&ex).unwrap();
+        let mut ranges = set.iter_ranges_complemented();
+        assert_eq!(Some(0..=0x7F), ranges.next());
+        assert_eq!(None, ranges.next());
+
+        // The set ends at U+10FFFE, so the complement contains U+10FFFF
+        let ex = vec![0x80, char::MAX as u32];
+        let set = CodePointInversionList::try_from_inversion_list_slice(&ex).unwrap();
+        let mut ranges = set.iter_ranges_complemented();
+        assert_eq!(Some(0..=0x7F), ranges.next());
+        assert_eq!(Some((char::MAX as u32)..=(char::MAX as u32)), ranges.next());
+        assert_eq!(None, ranges.next());
+    }
+
     #[test]
     fn test_codepointinversionlist_span_contains() {
         let ex = vec![0x41, 0x44, 0x46, 0x4B]; // A - D, F - K
@@ -981,6 +1076,27 @@ mod tests {
         assert_eq!(round_trip_inv_list, inv_list);
     }
 
+    #[test]
+    fn test_to_pattern_string() {
+        let check = |inv_list: &[u32], expected: &str| {
+            let set = CodePointInversionList::try_from_inversion_list_slice(inv_list).unwrap();
+            assert_eq!(set.to_pattern_string(), expected, "{inv_list:X?}");
+        };
+        check(&[], "[]");
+        check(&[0x61, 0x62], "[a]");
+        check(&[0x61, 0x63], "[ab]");
+        check(&[0x61, 0x64], "[a-c]");
+        check(&[0x2D, 0x2E, 0x5B, 0x5F], r"[\-\[-\^]");
+        check(
+            &[0x9, 0xB, 0x20, 0x21, 0x2028, 0x2029],
+            "[\\u0009\\u000A\\ \\\u{2028}]",
+        );
+        check(&[0xD800, 0xE000], r"[\uD800-\uDFFF]");
+        check(&[0x1F600, 0x1F650], "[😀-🙏]");
+        check(&[0x0, 0x110000], "[^]");
+        check(&[0x0, 0x61, 0x7B, 0x110000], "[^a-z]");
+    }
+
     #[test]
     fn test_serde_serialize() {
         let inv_list = [0x41, 0x46, 0x4B, 0x55];
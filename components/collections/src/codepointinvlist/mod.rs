@@ -66,6 +66,7 @@ use alloc::vec::Vec;
 pub use builder::CodePointInversionListBuilder;
 pub use cpinvlist::CodePointInversionList;
 pub use cpinvlist::CodePointInversionListULE;
+pub(crate) use cpinvlist::{write_pattern_char, write_pattern_ranges};
 use displaydoc::Display;
 
 /// Custom Errors for [`CodePointInversionList`].
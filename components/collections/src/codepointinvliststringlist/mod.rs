@@ -10,8 +10,8 @@
 //! It is an implementation of the existing [ICU4C UnicodeSet API](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/classicu_1_1UnicodeSet.html).
 
 use crate::codepointinvlist::{
-    CodePointInversionList, CodePointInversionListBuilder, CodePointInversionListError,
-    CodePointInversionListULE,
+    write_pattern_char, write_pattern_ranges, CodePointInversionList,
+    CodePointInversionListBuilder, CodePointInversionListError, CodePointInversionListULE,
 };
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -221,6 +221,43 @@ impl<'data> CodePointInversionListAndStringList<'data> {
     pub fn strings(&self) -> &VarZeroSlice<str> {
         &self.str_list
     }
+
+    /// Returns a UnicodeSet pattern string representing this set, with the strings written
+    /// in braces after the code points.
+    ///
+    /// Unlike [`CodePointInversionList::to_pattern_string`], the negated form is never used,
+    /// as negation does not apply to strings.
+    ///
+    /// The result can be parsed back with `icu::experimental::unicodeset_parse::TryFromPattern`.
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::collections::codepointinvlist::CodePointInversionList;
+    /// use icu::collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let cp_slice = &[0x61, 0x64];
+    /// let cp_list =
+    ///    CodePointInversionList::try_clone_from_inversion_list_slice(cp_slice).unwrap();
+    /// let str_list = VarZeroVec::<str>::from(&["ch", "{x}"]);
+    ///
+    /// let cpilsl = CodePointInversionListAndStringList::try_from(cp_list, str_list).unwrap();
+    ///
+    /// assert_eq!(cpilsl.to_pattern_string(), "[a-c{ch}{\\{x\\}}]");
+    /// ```
+    pub fn to_pattern_string(&self) -> String {
+        let mut pattern = String::from("[");
+        write_pattern_ranges(&mut pattern, self.cp_inv_list.iter_ranges());
+        for s in self.str_list.iter() {
+            pattern.push('{');
+            for c in s.chars() {
+                write_pattern_char(&mut pattern, c as u32);
+            }
+            pattern.push('}');
+        }
+        pattern.push(']');
+        pattern
+    }
 }
 
 impl<'a> FromIterator<&'a str> for CodePointInversionListAndStringList<'_> {
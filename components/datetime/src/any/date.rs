@@ -66,6 +66,48 @@ size_test!(DateFormatter, date_formatter_size, 4456);
 #[derive(Debug)]
 pub struct DateFormatter(pub(crate) raw::DateFormatter, pub(crate) AnyCalendar);
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for DateFormatter where
+    P: DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<BuddhistDateLengthsV1Marker>
+        + DataProvider<BuddhistDateSymbolsV1Marker>
+        + DataProvider<ChineseCacheV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<CopticDateLengthsV1Marker>
+        + DataProvider<CopticDateSymbolsV1Marker>
+        + DataProvider<DangiCacheV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<EthiopianDateLengthsV1Marker>
+        + DataProvider<EthiopianDateSymbolsV1Marker>
+        + DataProvider<GregorianDateLengthsV1Marker>
+        + DataProvider<GregorianDateSymbolsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<IndianDateLengthsV1Marker>
+        + DataProvider<IndianDateSymbolsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
+        + DataProvider<IslamicObservationalCacheV1Marker>
+        + DataProvider<IslamicUmmAlQuraCacheV1Marker>
+        + DataProvider<JapaneseDateLengthsV1Marker>
+        + DataProvider<JapaneseDateSymbolsV1Marker>
+        + DataProvider<JapaneseErasV1Marker>
+        + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+        + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+        + DataProvider<JapaneseExtendedErasV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
+        + ?Sized
+{
+}
+
 impl DateFormatter {
     /// Construct a new [`DateFormatter`] from compiled data.
     ///
@@ -219,11 +261,53 @@ impl DateFormatter {
     /// Takes a [`DateInput`] implementer and returns an instance of a [`FormattedDateTime`]
     /// that contains all information necessary to display a formatted date and operate on it.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter. Use [`Self::format_strict()`] to reject them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, Date};
+    /// use icu::datetime::{options::length, DateFormatter};
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let df = DateFormatter::try_new_with_length(
+    ///     &locale!("en-u-ca-gregory").into(),
+    ///     length::Date::Medium,
+    /// )
+    /// .expect("Failed to create DateFormatter instance.");
+    ///
+    /// let date = Date::try_new_iso_date(2020, 9, 1)
+    ///     .expect("Failed to construct Date.")
+    ///     .to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+    ///
+    /// // The Buddhist date is formatted in the Gregorian calendar
+    /// assert_writeable_eq!(df.format(&date).unwrap(), "Sep 1, 2020");
+    ///
+    /// // unless strict formatting is requested
+    /// assert!(df.format_strict(&date).is_err());
+    /// ```
     #[inline]
     pub fn format<'l, T>(&'l self, value: &T) -> Result<FormattedDateTime<'l>, DateTimeError>
+    where
+        T: DateInput<Calendar = AnyCalendar>,
+    {
+        if let Some(converted) = calendar::convert_date_from_any_calendar(&self.1, value) {
+            Ok(self.0.format(&converted))
+        } else {
+            Ok(self.0.format(value))
+        }
+    }
+
+    /// Takes a [`DateInput`] implementer and returns an instance of a [`FormattedDateTime`]
+    /// that contains all information necessary to display a formatted date and operate on it.
+    ///
+    /// Unlike [`Self::format()`], this function will fail if the date passed in uses a different
+    /// calendar than that of the AnyCalendar. It will still automatically convert and format
+    /// dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format_strict<'l, T>(&'l self, value: &T) -> Result<FormattedDateTime<'l>, DateTimeError>
     where
         T: DateInput<Calendar = AnyCalendar>,
     {
@@ -236,9 +320,8 @@ impl DateFormatter {
 
     /// Takes a [`DateInput`] implementer and returns it formatted as a string.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter, see [`Self::format()`].
     #[inline]
     pub fn format_to_string(
         &self,
@@ -117,6 +117,48 @@ size_test!(DateTimeFormatter, date_time_formatter_size, 5208);
 #[derive(Debug)]
 pub struct DateTimeFormatter(pub(crate) raw::DateTimeFormatter, AnyCalendar);
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for DateTimeFormatter where
+    P: DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<BuddhistDateLengthsV1Marker>
+        + DataProvider<BuddhistDateSymbolsV1Marker>
+        + DataProvider<ChineseCacheV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<CopticDateLengthsV1Marker>
+        + DataProvider<CopticDateSymbolsV1Marker>
+        + DataProvider<DangiCacheV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<EthiopianDateLengthsV1Marker>
+        + DataProvider<EthiopianDateSymbolsV1Marker>
+        + DataProvider<GregorianDateLengthsV1Marker>
+        + DataProvider<GregorianDateSymbolsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<IndianDateLengthsV1Marker>
+        + DataProvider<IndianDateSymbolsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
+        + DataProvider<JapaneseDateLengthsV1Marker>
+        + DataProvider<JapaneseDateSymbolsV1Marker>
+        + DataProvider<JapaneseErasV1Marker>
+        + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+        + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+        + DataProvider<JapaneseExtendedErasV1Marker>
+        + DataProvider<IslamicObservationalCacheV1Marker>
+        + DataProvider<IslamicUmmAlQuraCacheV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
+        + ?Sized
+{
+}
+
 impl DateTimeFormatter {
     /// Construct a new [`DateTimeFormatter`] from compiled data.
     ///
@@ -499,11 +541,52 @@ where {
     /// Takes a [`DateTimeInput`] implementer and returns an instance of a [`FormattedDateTime`]
     /// that contains all information necessary to display a formatted date and operate on it.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter. Use [`Self::format_strict()`] to reject them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, DateTime};
+    /// use icu::datetime::{options::length, DateTimeFormatter};
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let options = length::Bag::from_date_style(length::Date::Medium).into();
+    ///
+    /// let dtf = DateTimeFormatter::try_new(&locale!("en-u-ca-gregory").into(), options)
+    ///     .expect("Failed to create DateTimeFormatter instance.");
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.")
+    ///     .to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+    ///
+    /// // The Buddhist date is formatted in the Gregorian calendar
+    /// assert_writeable_eq!(dtf.format(&datetime).unwrap(), "Sep 1, 2020");
+    ///
+    /// // unless strict formatting is requested
+    /// assert!(dtf.format_strict(&datetime).is_err());
+    /// ```
     #[inline]
     pub fn format<'l, T>(&'l self, value: &T) -> Result<FormattedDateTime<'l>, DateTimeError>
+    where
+        T: DateTimeInput<Calendar = AnyCalendar>,
+    {
+        if let Some(converted) = calendar::convert_datetime_from_any_calendar(&self.1, value) {
+            Ok(self.0.format(&converted))
+        } else {
+            Ok(self.0.format(value))
+        }
+    }
+
+    /// Takes a [`DateTimeInput`] implementer and returns an instance of a [`FormattedDateTime`]
+    /// that contains all information necessary to display a formatted date and operate on it.
+    ///
+    /// Unlike [`Self::format()`], this function will fail if the date passed in uses a different
+    /// calendar than that of the AnyCalendar. It will still automatically convert and format
+    /// dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format_strict<'l, T>(&'l self, value: &T) -> Result<FormattedDateTime<'l>, DateTimeError>
     where
         T: DateTimeInput<Calendar = AnyCalendar>,
     {
@@ -516,9 +599,8 @@ where {
 
     /// Takes a [`DateTimeInput`] implementer and returns it formatted as a string.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter, see [`Self::format()`].
     #[inline]
     pub fn format_to_string(
         &self,
@@ -572,8 +654,8 @@ where {
 #[cfg(test)]
 #[cfg(feature = "compiled_data")]
 mod tests {
-    use icu::calendar::{AnyCalendar, DateTime};
-    use icu::datetime::{options::length, DateTimeFormatter};
+    use icu::calendar::{AnyCalendar, AnyCalendarKind, DateTime};
+    use icu::datetime::{options::length, DateTimeError, DateTimeFormatter};
     use icu::locid::locale;
     use icu_provider::DataLocale;
 
@@ -614,6 +696,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_iso() {
+        let datetime = DateTime::try_new_iso_datetime(2022, 4, 5, 12, 33, 44).unwrap();
+        test_format(
+            &datetime.to_any(),
+            locale!("en-u-ca-japanese").into(),
+            "April 5, 4 Reiwa, 12:33\u{202f}PM",
+        );
+    }
+
+    #[test]
+    fn test_convert_any_calendar() {
+        let buddhist = DateTime::try_new_iso_datetime(2022, 4, 5, 12, 33, 44)
+            .unwrap()
+            .to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+        test_format(
+            &buddhist,
+            locale!("en-u-ca-gregory").into(),
+            "April 5, 2022, 12:33\u{202f}PM",
+        );
+    }
+
+    #[test]
+    fn test_format_strict() {
+        let options = length::Bag::from_date_time_style(length::Date::Long, length::Time::Short);
+        let dtf =
+            DateTimeFormatter::try_new(&locale!("en-u-ca-gregory").into(), options.into()).unwrap();
+
+        let buddhist = DateTime::try_new_iso_datetime(2022, 4, 5, 12, 33, 44)
+            .unwrap()
+            .to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+        let err = dtf.format_strict(&buddhist).unwrap_err();
+        assert_eq!(
+            err,
+            DateTimeError::MismatchedAnyCalendar(
+                AnyCalendarKind::Gregorian,
+                Some(AnyCalendarKind::Buddhist)
+            )
+        );
+        assert_eq!(
+            err.to_string(),
+            "DateTimeFormatter for Gregorian calendar was given a Some(Buddhist) calendar"
+        );
+
+        // ISO dates are still converted
+        let iso = DateTime::try_new_iso_datetime(2022, 4, 5, 12, 33, 44).unwrap();
+        writeable::assert_writeable_eq!(
+            dtf.format_strict(&iso.to_any()).unwrap(),
+            "April 5, 2022, 12:33\u{202f}PM"
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn works_with_default_options() {
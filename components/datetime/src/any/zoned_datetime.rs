@@ -15,13 +15,12 @@ use crate::input::{DateTimeInput, ExtractedDateTimeInput, TimeZoneInput};
 use crate::provider::{self, calendar::*, date_time::PatternSelector};
 use crate::time_zone::TimeZoneFormatterOptions;
 use crate::{DateTimeError, FormattedZonedDateTime};
-use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
+use icu_calendar::any_calendar::AnyCalendar;
 use icu_calendar::provider::{
     ChineseCacheV1Marker, DangiCacheV1Marker, IslamicObservationalCacheV1Marker,
     IslamicUmmAlQuraCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
     WeekDataV1Marker,
 };
-use icu_calendar::{DateTime, Time};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
 use writeable::Writeable;
@@ -129,6 +128,54 @@ size_test!(ZonedDateTimeFormatter, zoned_date_time_formatter_size, 6248);
 #[derive(Debug)]
 pub struct ZonedDateTimeFormatter(raw::ZonedDateTimeFormatter, AnyCalendar);
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for ZonedDateTimeFormatter where
+    P: DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
+        + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+        + DataProvider<provider::time_zones::MetazoneGenericNamesLongV1Marker>
+        + DataProvider<provider::time_zones::MetazoneGenericNamesShortV1Marker>
+        + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
+        + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<BuddhistDateLengthsV1Marker>
+        + DataProvider<BuddhistDateSymbolsV1Marker>
+        + DataProvider<ChineseCacheV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<CopticDateLengthsV1Marker>
+        + DataProvider<CopticDateSymbolsV1Marker>
+        + DataProvider<DangiCacheV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<EthiopianDateLengthsV1Marker>
+        + DataProvider<EthiopianDateSymbolsV1Marker>
+        + DataProvider<GregorianDateLengthsV1Marker>
+        + DataProvider<GregorianDateSymbolsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<IndianDateLengthsV1Marker>
+        + DataProvider<IndianDateSymbolsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
+        + DataProvider<IslamicObservationalCacheV1Marker>
+        + DataProvider<IslamicUmmAlQuraCacheV1Marker>
+        + DataProvider<JapaneseDateLengthsV1Marker>
+        + DataProvider<JapaneseDateSymbolsV1Marker>
+        + DataProvider<JapaneseErasV1Marker>
+        + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+        + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+        + DataProvider<JapaneseExtendedErasV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
+        + ?Sized
+{
+}
+
 impl ZonedDateTimeFormatter {
     /// Constructor that takes a selected [`DataLocale`] and a list of [`DateTimeFormatterOptions`] and uses compiled data.
     /// It collects all data necessary to format zoned datetime values into the given locale.
@@ -502,16 +549,73 @@ impl ZonedDateTimeFormatter {
     /// Takes a [`DateTimeInput`] and a [`TimeZoneInput`] and returns an instance of a [`FormattedZonedDateTime`]
     /// that contains all information necessary to display a formatted date and operate on it.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter. Use [`Self::format_strict()`] to reject them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, DateTime};
+    /// use icu::datetime::{options::length, ZonedDateTimeFormatter};
+    /// use icu::locid::locale;
+    /// use icu::timezone::CustomTimeZone;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let options = length::Bag::from_date_time_style(
+    ///     length::Date::Medium,
+    ///     length::Time::Long,
+    /// );
+    /// let zdtf = ZonedDateTimeFormatter::try_new(
+    ///     &locale!("en-u-ca-gregory").into(),
+    ///     options.into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Failed to create ZonedDateTimeFormatter instance.");
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.")
+    ///     .to_calendar(AnyCalendar::new(AnyCalendarKind::Buddhist));
+    /// let time_zone = CustomTimeZone::utc();
+    ///
+    /// // The Buddhist date is formatted in the Gregorian calendar
+    /// assert_writeable_eq!(
+    ///     zdtf.format(&datetime, &time_zone).unwrap(),
+    ///     "Sep 1, 2020, 12:34:28\u{202f}PM GMT"
+    /// );
+    ///
+    /// // unless strict formatting is requested
+    /// assert!(zdtf.format_strict(&datetime, &time_zone).is_err());
+    /// ```
     #[inline]
     pub fn format<'l>(
         &'l self,
         date: &impl DateTimeInput<Calendar = AnyCalendar>,
         time_zone: &impl TimeZoneInput,
     ) -> Result<FormattedZonedDateTime<'l>, DateTimeError> {
-        if let Some(converted) = self.convert_if_necessary(date)? {
+        if let Some(converted) = calendar::convert_datetime_from_any_calendar(&self.1, date) {
+            // FIXME(#2145) this is very hacky, can be improved after we improve ZonedDateTimeInput
+            let converted = ExtractedDateTimeInput::extract_from(&converted);
+            Ok(self.0.format(&converted, time_zone))
+        } else {
+            Ok(self.0.format(date, time_zone))
+        }
+    }
+
+    /// Takes a [`DateTimeInput`] and a [`TimeZoneInput`] and returns an instance of a [`FormattedZonedDateTime`]
+    /// that contains all information necessary to display a formatted date and operate on it.
+    ///
+    /// Unlike [`Self::format()`], this function will fail if the date passed in uses a different
+    /// calendar than that of the AnyCalendar. It will still automatically convert and format
+    /// dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format_strict<'l>(
+        &'l self,
+        date: &impl DateTimeInput<Calendar = AnyCalendar>,
+        time_zone: &impl TimeZoneInput,
+    ) -> Result<FormattedZonedDateTime<'l>, DateTimeError> {
+        if let Some(converted) = calendar::convert_datetime_if_necessary(&self.1, date)? {
+            // FIXME(#2145) this is very hacky, can be improved after we improve ZonedDateTimeInput
+            let converted = ExtractedDateTimeInput::extract_from(&converted);
             Ok(self.0.format(&converted, time_zone))
         } else {
             Ok(self.0.format(date, time_zone))
@@ -520,9 +624,8 @@ impl ZonedDateTimeFormatter {
 
     /// Takes a [`DateTimeInput`] and a [`TimeZoneInput`] and returns it formatted as a string.
     ///
-    /// This function will fail if the date passed in uses a different calendar than that of the
-    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
-    /// will automatically convert and format dates that are associated with the ISO calendar.
+    /// Dates in a different calendar than that of the AnyCalendar are automatically converted
+    /// to the calendar of the formatter, see [`Self::format()`].
     #[inline]
     pub fn format_to_string(
         &self,
@@ -531,40 +634,6 @@ impl ZonedDateTimeFormatter {
     ) -> Result<String, DateTimeError> {
         Ok(self.format(date, time_zone)?.write_to_string().into_owned())
     }
-
-    /// Converts a date to the correct calendar if necessary
-    ///
-    /// Returns `Err` if the date is not ISO or compatible with the current calendar, returns `Ok(None)`
-    /// if the date is compatible with the current calendar and doesn't need conversion
-    fn convert_if_necessary(
-        &self,
-        value: &impl DateTimeInput<Calendar = AnyCalendar>,
-    ) -> Result<Option<ExtractedDateTimeInput>, DateTimeError> {
-        let this_calendar = self.1.kind();
-        let date_calendar = value.any_calendar_kind();
-        if Some(this_calendar) != date_calendar {
-            if date_calendar != Some(AnyCalendarKind::Iso) {
-                return Err(DateTimeError::MismatchedAnyCalendar(
-                    this_calendar,
-                    date_calendar,
-                ));
-            }
-            let date = value.to_iso();
-            let time = Time::new(
-                value.hour().unwrap_or_default(),
-                value.minute().unwrap_or_default(),
-                value.second().unwrap_or_default(),
-                value.nanosecond().unwrap_or_default(),
-            );
-            let datetime = DateTime::new(date, time).to_any();
-            let converted = self.1.convert_any_datetime(&datetime);
-            // FIXME(#2145) this is very hacky, can be improved after we improve ZonedDateTimeInput
-            let converted = ExtractedDateTimeInput::extract_from(&converted);
-            Ok(Some(converted))
-        } else {
-            Ok(None)
-        }
-    }
 }
 
 #[test]
@@ -901,19 +901,27 @@ pub(crate) fn convert_if_necessary<'a>(
     let this_kind = any_calendar.kind();
     let date_kind = value.any_calendar_kind();
 
-    if Some(this_kind) != date_kind {
-        if date_kind != Some(AnyCalendarKind::Iso) {
-            return Err(crate::MismatchedCalendarError {
-                this_kind,
-                date_kind,
-            });
-        }
-        let date = value.to_iso().to_any();
-        let converted = any_calendar.convert_any_date(&date);
-        Ok(Some(converted))
-    } else {
-        Ok(None)
+    if Some(this_kind) != date_kind && date_kind != Some(AnyCalendarKind::Iso) {
+        return Err(crate::MismatchedCalendarError {
+            this_kind,
+            date_kind,
+        });
+    }
+    Ok(convert_date_from_any_calendar(any_calendar, value))
+}
+
+/// Converts a date in any calendar to the correct calendar if necessary
+///
+/// Returns `None` if the date is already in the current calendar and doesn't need conversion
+pub(crate) fn convert_date_from_any_calendar<'a>(
+    any_calendar: &'a AnyCalendar,
+    value: &impl crate::input::DateInput<Calendar = AnyCalendar>,
+) -> Option<icu_calendar::Date<icu_calendar::Ref<'a, AnyCalendar>>> {
+    if Some(any_calendar.kind()) == value.any_calendar_kind() {
+        return None;
     }
+    let date = value.to_iso().to_any();
+    Some(any_calendar.convert_any_date(&date))
 }
 
 /// Converts a date to the correct calendar if necessary
@@ -930,24 +938,32 @@ pub(crate) fn convert_datetime_if_necessary<'a>(
     let this_kind = any_calendar.kind();
     let date_kind = value.any_calendar_kind();
 
-    if Some(this_kind) != date_kind {
-        if date_kind != Some(AnyCalendarKind::Iso) {
-            return Err(crate::MismatchedCalendarError {
-                this_kind,
-                date_kind,
-            });
-        }
-        let date = value.to_iso();
-        let time = icu_calendar::Time::new(
-            value.hour().unwrap_or_default(),
-            value.minute().unwrap_or_default(),
-            value.second().unwrap_or_default(),
-            value.nanosecond().unwrap_or_default(),
-        );
-        let datetime = icu_calendar::DateTime::new(date, time).to_any();
-        let converted = any_calendar.convert_any_datetime(&datetime);
-        Ok(Some(converted))
-    } else {
-        Ok(None)
+    if Some(this_kind) != date_kind && date_kind != Some(AnyCalendarKind::Iso) {
+        return Err(crate::MismatchedCalendarError {
+            this_kind,
+            date_kind,
+        });
+    }
+    Ok(convert_datetime_from_any_calendar(any_calendar, value))
+}
+
+/// Converts a datetime in any calendar to the correct calendar if necessary
+///
+/// Returns `None` if the datetime is already in the current calendar and doesn't need conversion
+pub(crate) fn convert_datetime_from_any_calendar<'a>(
+    any_calendar: &'a AnyCalendar,
+    value: &impl crate::input::DateTimeInput<Calendar = AnyCalendar>,
+) -> Option<icu_calendar::DateTime<icu_calendar::Ref<'a, AnyCalendar>>> {
+    if Some(any_calendar.kind()) == value.any_calendar_kind() {
+        return None;
     }
+    let date = value.to_iso();
+    let time = icu_calendar::Time::new(
+        value.hour().unwrap_or_default(),
+        value.minute().unwrap_or_default(),
+        value.second().unwrap_or_default(),
+        value.nanosecond().unwrap_or_default(),
+    );
+    let datetime = icu_calendar::DateTime::new(date, time).to_any();
+    Some(any_calendar.convert_any_datetime(&datetime))
 }
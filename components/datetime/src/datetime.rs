@@ -66,6 +66,14 @@ size_test!(TimeFormatter, time_formatter_size, 1200);
 #[derive(Debug)]
 pub struct TimeFormatter(pub(super) raw::TimeFormatter);
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for TimeFormatter where
+    P: DataProvider<TimeLengthsV1Marker>
+        + DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + ?Sized
+{
+}
+
 impl TimeFormatter {
     /// Constructor that takes a selected locale and a list of preferences, then collects all
     /// compiled data necessary to format date and time values into the given locale,
@@ -231,6 +239,16 @@ size_test!(
 #[derive(Debug)]
 pub struct TypedDateFormatter<C>(pub(super) raw::DateFormatter, PhantomData<C>);
 
+impl<C: CldrCalendar, P> icu_provider::constructors::RequiredKeys<P> for TypedDateFormatter<C> where
+    P: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+        + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + ?Sized
+{
+}
+
 impl<C: CldrCalendar> TypedDateFormatter<C> {
     /// Constructor that takes a selected locale and a list of options, then collects all
     /// compiled data necessary to format date and time values into the given locale.
@@ -456,6 +474,18 @@ size_test!(
 #[derive(Debug)]
 pub struct TypedDateTimeFormatter<C>(pub(super) raw::DateTimeFormatter, PhantomData<C>);
 
+impl<C: CldrCalendar, P> icu_provider::constructors::RequiredKeys<P> for TypedDateTimeFormatter<C> where
+    P: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+        + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+        + DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + ?Sized
+{
+}
+
 impl<C: CldrCalendar> TypedDateTimeFormatter<C> {
     /// Constructor that takes a [`TimeFormatter`] and [`TypedDateFormatter`] and combines them into a [`TypedDateTimeFormatter`].
     ///
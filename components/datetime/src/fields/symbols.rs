@@ -267,21 +267,22 @@ impl FieldSymbol {
             Self::Weekday(Weekday::StandAlone) => 15,
             Self::DayPeriod(DayPeriod::AmPm) => 16,
             Self::DayPeriod(DayPeriod::NoonMidnight) => 17,
-            Self::Hour(Hour::H11) => 18,
-            Self::Hour(Hour::H12) => 19,
-            Self::Hour(Hour::H23) => 20,
-            Self::Hour(Hour::H24) => 21,
-            Self::Minute => 22,
-            Self::Second(Second::Second) => 23,
-            Self::Second(Second::FractionalSecond) => 24,
-            Self::Second(Second::Millisecond) => 25,
-            Self::TimeZone(TimeZone::LowerZ) => 26,
-            Self::TimeZone(TimeZone::UpperZ) => 27,
-            Self::TimeZone(TimeZone::UpperO) => 28,
-            Self::TimeZone(TimeZone::LowerV) => 29,
-            Self::TimeZone(TimeZone::UpperV) => 30,
-            Self::TimeZone(TimeZone::LowerX) => 31,
-            Self::TimeZone(TimeZone::UpperX) => 32,
+            Self::DayPeriod(DayPeriod::Flexible) => 18,
+            Self::Hour(Hour::H11) => 19,
+            Self::Hour(Hour::H12) => 20,
+            Self::Hour(Hour::H23) => 21,
+            Self::Hour(Hour::H24) => 22,
+            Self::Minute => 23,
+            Self::Second(Second::Second) => 24,
+            Self::Second(Second::FractionalSecond) => 25,
+            Self::Second(Second::Millisecond) => 26,
+            Self::TimeZone(TimeZone::LowerZ) => 27,
+            Self::TimeZone(TimeZone::UpperZ) => 28,
+            Self::TimeZone(TimeZone::UpperO) => 29,
+            Self::TimeZone(TimeZone::LowerV) => 30,
+            Self::TimeZone(TimeZone::UpperV) => 31,
+            Self::TimeZone(TimeZone::LowerX) => 32,
+            Self::TimeZone(TimeZone::UpperX) => 33,
         }
     }
 }
@@ -635,6 +636,9 @@ field_type!(
         'a' => AmPm = 0,
         /// Field symbol for the am, pm, noon, midnight day period.
         'b' => NoonMidnight = 1,
+        /// Field symbol for flexible day periods, such as "in the morning" or "at night",
+        /// including noon and midnight.
+        'B' => Flexible = 2,
     };
     Text;
     DayPeriodULE
@@ -29,6 +29,7 @@ use icu_decimal::options::FixedDecimalFormatterOptions;
 use icu_decimal::options::GroupingStrategy;
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_decimal::FixedDecimalFormatter;
+use icu_locid::extensions::unicode::{value, Value};
 use icu_provider::{prelude::*, NeverMarker};
 use writeable::TryWriteable;
 use yoke::Yokeable;
@@ -109,6 +110,21 @@ where
 }
 
 /// Helper for type resolution with optional DataProvider arguments
+/// The number of months in a year of the calendar, and whether the month names
+/// are followed by the same number of leap month names.
+fn month_names_shape(calendar: &Value) -> (usize, bool) {
+    if *calendar == value!("chinese")
+        || *calendar == value!("dangi")
+        || *calendar == value!("hebrew")
+    {
+        (12, true)
+    } else if *calendar == value!("coptic") || *calendar == value!("ethiopic") {
+        (13, false)
+    } else {
+        (12, false)
+    }
+}
+
 pub(crate) struct PhantomProvider;
 
 impl<M: KeyedDataMarker> DataProvider<M> for PhantomProvider {
@@ -137,7 +153,7 @@ impl<M: DataMarker> BoundDataProvider<M> for PhantomProvider {
 size_test!(
     TypedDateTimeNames<icu_calendar::Gregorian, DateTimeMarker>,
     typed_date_time_names_size,
-    488
+    576
 );
 
 /// A low-level type that formats datetime patterns with localized symbols.
@@ -224,6 +240,7 @@ pub trait DateTimeNamesMarker {
     type MonthNames: MaybePayload<MonthNamesV1<'static>> + fmt::Debug;
     type WeekdayNames: MaybePayload<LinearNamesV1<'static>> + fmt::Debug;
     type DayPeriodNames: MaybePayload<LinearNamesV1<'static>> + fmt::Debug;
+    type DayPeriodRules: MaybePayload<DayPeriodRulesV1<'static>> + fmt::Debug;
 }
 
 pub trait MaybePayload<Y: for<'a> Yokeable<'a>> {
@@ -307,6 +324,7 @@ impl DateTimeNamesMarker for DateMarker {
     type MonthNames = DataPayload<MonthNamesV1Marker>;
     type WeekdayNames = DataPayload<WeekdayNamesV1Marker>;
     type DayPeriodNames = ();
+    type DayPeriodRules = ();
 }
 
 #[derive(Debug)]
@@ -317,6 +335,7 @@ impl DateTimeNamesMarker for TimeMarker {
     type MonthNames = ();
     type WeekdayNames = ();
     type DayPeriodNames = DataPayload<DayPeriodNamesV1Marker>;
+    type DayPeriodRules = DataPayload<DayPeriodRulesV1Marker>;
 }
 
 #[derive(Debug)]
@@ -327,6 +346,7 @@ impl DateTimeNamesMarker for DateTimeMarker {
     type MonthNames = DataPayload<MonthNamesV1Marker>;
     type WeekdayNames = DataPayload<WeekdayNamesV1Marker>;
     type DayPeriodNames = DataPayload<DayPeriodNamesV1Marker>;
+    type DayPeriodRules = DataPayload<DayPeriodRulesV1Marker>;
 }
 
 impl From<RawDateTimeNames<DateMarker>> for RawDateTimeNames<DateTimeMarker> {
@@ -336,6 +356,8 @@ impl From<RawDateTimeNames<DateMarker>> for RawDateTimeNames<DateTimeMarker> {
             month_symbols: other.month_symbols,
             weekday_symbols: other.weekday_symbols,
             dayperiod_symbols: OptionalNames::None,
+            flexible_dayperiod_symbols: OptionalNames::None,
+            dayperiod_rules: None,
             fixed_decimal_formatter: other.fixed_decimal_formatter,
             week_calculator: other.week_calculator,
             _marker: PhantomData,
@@ -350,6 +372,8 @@ impl From<RawDateTimeNames<TimeMarker>> for RawDateTimeNames<DateTimeMarker> {
             month_symbols: OptionalNames::None,
             weekday_symbols: OptionalNames::None,
             dayperiod_symbols: other.dayperiod_symbols,
+            flexible_dayperiod_symbols: other.flexible_dayperiod_symbols,
+            dayperiod_rules: other.dayperiod_rules,
             fixed_decimal_formatter: other.fixed_decimal_formatter,
             week_calculator: other.week_calculator,
             _marker: PhantomData,
@@ -363,6 +387,8 @@ pub(crate) struct RawDateTimeNames<R: DateTimeNamesMarker> {
     month_symbols: OptionalNames<fields::Month, R::MonthNames>,
     weekday_symbols: OptionalNames<fields::Weekday, R::WeekdayNames>,
     dayperiod_symbols: OptionalNames<(), R::DayPeriodNames>,
+    flexible_dayperiod_symbols: OptionalNames<(), R::DayPeriodNames>,
+    dayperiod_rules: Option<R::DayPeriodRules>,
     // TODO(#4340): Make the FixedDecimalFormatter optional
     fixed_decimal_formatter: Option<FixedDecimalFormatter>,
     week_calculator: Option<WeekCalculator>,
@@ -375,6 +401,8 @@ pub(crate) struct RawDateTimeNamesBorrowed<'l> {
     month_names: OptionalNames<fields::Month, &'l MonthNamesV1<'l>>,
     weekday_names: OptionalNames<fields::Weekday, &'l LinearNamesV1<'l>>,
     dayperiod_names: OptionalNames<(), &'l LinearNamesV1<'l>>,
+    flexible_dayperiod_names: OptionalNames<(), &'l LinearNamesV1<'l>>,
+    dayperiod_rules: Option<&'l DayPeriodRulesV1<'l>>,
     pub(crate) fixed_decimal_formatter: Option<&'l FixedDecimalFormatter>,
     pub(crate) week_calculator: Option<&'l WeekCalculator>,
 }
@@ -600,6 +628,32 @@ impl<C: CldrCalendar, R: DateTimeNamesMarker> TypedDateTimeNames<C, R> {
         self.load_day_period_names(&crate::provider::Baked, field_length)
     }
 
+    /// Loads flexible day period names, such as "in the morning", for the specified length,
+    /// together with the rules for when each day period applies.
+    ///
+    /// These are used by the `B` field. If they are not loaded, `B` falls back to
+    /// the names loaded with [`Self::load_day_period_names`].
+    ///
+    /// Does not support multiple field symbols or lengths. See #4337
+    pub fn load_flexible_day_period_names<P>(
+        &mut self,
+        provider: &P,
+        field_length: FieldLength,
+    ) -> Result<&mut Self, SingleLoadError>
+    where
+        P: DataProvider<FlexibleDayPeriodNamesV1Marker>
+            + DataProvider<DayPeriodRulesV1Marker>
+            + ?Sized,
+    {
+        self.inner.load_flexible_day_period_names(
+            &FlexibleDayPeriodNamesV1Marker::bind(provider),
+            &DayPeriodRulesV1Marker::bind(provider),
+            &self.locale,
+            field_length,
+        )?;
+        Ok(self)
+    }
+
     /// Loads weekday names for the specified symbol and length.
     ///
     /// Does not support multiple field symbols or lengths. See #4337
@@ -672,6 +726,111 @@ impl<C: CldrCalendar, R: DateTimeNamesMarker> TypedDateTimeNames<C, R> {
         self.load_weekday_names(&crate::provider::Baked, field_symbol, field_length)
     }
 
+    /// Sets month names for the specified symbol and length, replacing any loaded names.
+    ///
+    /// The names must be complete for the calendar: 12 or 13 names for solar calendars, or
+    /// 12 names followed by 12 leap month names for lunisolar calendars. Names set here take
+    /// precedence over data: loading month names with the same symbol and length afterwards,
+    /// including when loading for a pattern, keeps these names.
+    ///
+    /// Does not support multiple field symbols or lengths. See #4337
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::calendar::Gregorian;
+    /// use icu::datetime::fields::FieldLength;
+    /// use icu::datetime::neo_pattern::DateTimePattern;
+    /// use icu::datetime::provider::neo::MonthNamesV1;
+    /// use icu::datetime::SingleLoadError;
+    /// use icu::datetime::TypedDateTimeNames;
+    /// use icu::locid::locale;
+    /// use writeable::assert_try_writeable_eq;
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let mut names =
+    ///     TypedDateTimeNames::<Gregorian>::try_new(&locale!("en").into())
+    ///         .unwrap();
+    /// let field_symbol = icu::datetime::fields::Month::Format;
+    ///
+    /// // Gregorian months need 12 names:
+    /// let month_names: Vec<String> = (0..12)
+    ///     .map(|i| format!("Q{} Month {}", i / 3 + 1, i % 3 + 1))
+    ///     .collect();
+    /// assert!(matches!(
+    ///     names.set_month_names(
+    ///         field_symbol,
+    ///         FieldLength::Wide,
+    ///         MonthNamesV1::Linear(VarZeroVec::from(&month_names[..11])),
+    ///     ),
+    ///     Err(SingleLoadError::InvalidNames(_))
+    /// ));
+    /// names
+    ///     .set_month_names(
+    ///         field_symbol,
+    ///         FieldLength::Wide,
+    ///         MonthNamesV1::Linear(VarZeroVec::from(&month_names)),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// // Other names still come from data:
+    /// let pattern: DateTimePattern = "EEEE, d MMMM y".parse().unwrap();
+    /// let date = Date::try_new_gregorian_date(2023, 10, 25).unwrap();
+    /// assert_try_writeable_eq!(
+    ///     names.include_for_pattern(&pattern).unwrap().format_date(&date),
+    ///     "Wednesday, 25 Q4 Month 1 2023"
+    /// );
+    /// ```
+    pub fn set_month_names(
+        &mut self,
+        field_symbol: fields::Month,
+        field_length: FieldLength,
+        names: MonthNamesV1<'static>,
+    ) -> Result<&mut Self, SingleLoadError> {
+        self.inner.set_month_names(
+            field_symbol,
+            field_length,
+            names,
+            month_names_shape(&C::DEFAULT_BCP_47_IDENTIFIER),
+        )?;
+        Ok(self)
+    }
+
+    /// Sets day period names for the specified length, replacing any loaded names.
+    ///
+    /// The names are 'am' and 'pm', optionally followed by 'noon' and 'midnight'. Names set
+    /// here take precedence over data: loading day period names with the same length
+    /// afterwards, including when loading for a pattern, keeps these names.
+    ///
+    /// Does not support multiple field symbols or lengths. See #4337
+    pub fn set_day_period_names(
+        &mut self,
+        field_length: FieldLength,
+        names: LinearNamesV1<'static>,
+    ) -> Result<&mut Self, SingleLoadError> {
+        self.inner.set_day_period_names(field_length, names)?;
+        Ok(self)
+    }
+
+    /// Sets weekday names for the specified symbol and length, replacing any loaded names.
+    ///
+    /// The names must be the 7 weekdays starting with Sunday. Names set here take precedence
+    /// over data: loading weekday names with the same symbol and length afterwards, including
+    /// when loading for a pattern, keeps these names.
+    ///
+    /// Does not support multiple field symbols or lengths. See #4337
+    pub fn set_weekday_names(
+        &mut self,
+        field_symbol: fields::Weekday,
+        field_length: FieldLength,
+        names: LinearNamesV1<'static>,
+    ) -> Result<&mut Self, SingleLoadError> {
+        self.inner
+            .set_weekday_names(field_symbol, field_length, names)?;
+        Ok(self)
+    }
+
     /// Sets the week calculator to use with patterns requiring week numbering.
     ///
     /// # Examples
@@ -756,6 +915,8 @@ impl<C: CldrCalendar, R: DateTimeNamesMarker> TypedDateTimeNames<C, R> {
             + DataProvider<C::MonthNamesV1Marker>
             + DataProvider<WeekdayNamesV1Marker>
             + DataProvider<DayPeriodNamesV1Marker>
+            + DataProvider<FlexibleDayPeriodNamesV1Marker>
+            + DataProvider<DayPeriodRulesV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<WeekDataV2Marker>
             + ?Sized,
@@ -771,6 +932,12 @@ impl<C: CldrCalendar, R: DateTimeNamesMarker> TypedDateTimeNames<C, R> {
             locale,
             pattern.iter_items(),
         )?;
+        self.inner.load_flexible_day_periods_for_pattern(
+            &FlexibleDayPeriodNamesV1Marker::bind(provider),
+            &DayPeriodRulesV1Marker::bind(provider),
+            locale,
+            pattern.iter_items(),
+        )?;
         Ok(DateTimePatternFormatter {
             inner: self.inner.with_pattern(pattern.as_borrowed()),
             _calendar: PhantomData,
@@ -782,6 +949,9 @@ impl<C: CldrCalendar, R: DateTimeNamesMarker> TypedDateTimeNames<C, R> {
     ///
     /// Does not support duplicate textual field symbols. See #4337
     ///
+    /// There is no compiled data for flexible day periods yet, so the `B` field
+    /// is formatted with the names for AM and PM.
+    ///
     /// # Examples
     ///
     /// ```
@@ -850,6 +1020,8 @@ pub enum SingleLoadError {
     UnsupportedField(Field),
     /// The specific type does not support this field
     TypeTooNarrow(Field),
+    /// The names passed to a setter are incomplete for this field or calendar
+    InvalidNames(Field),
     /// An error arising from the [`DataProvider`]
     Data(DataError),
 }
@@ -864,6 +1036,8 @@ pub enum LoadError {
     UnsupportedField(Field),
     /// The specific type does not support this field
     TypeTooNarrow(Field),
+    /// The names passed to a setter are incomplete for this field or calendar
+    InvalidNames(Field),
     /// An error arising from the [`DataProvider`]
     Data(DataError),
     /// MissingNames
@@ -877,6 +1051,7 @@ impl From<SingleLoadError> for LoadError {
             SingleLoadError::UnsupportedField(f) => LoadError::UnsupportedField(f),
             SingleLoadError::TypeTooNarrow(f) => LoadError::TypeTooNarrow(f),
             SingleLoadError::DuplicateField(f) => LoadError::DuplicateField(f),
+            SingleLoadError::InvalidNames(f) => LoadError::InvalidNames(f),
         }
     }
 }
@@ -888,6 +1063,8 @@ impl<R: DateTimeNamesMarker> RawDateTimeNames<R> {
             month_symbols: OptionalNames::None,
             weekday_symbols: OptionalNames::None,
             dayperiod_symbols: OptionalNames::None,
+            flexible_dayperiod_symbols: OptionalNames::None,
+            dayperiod_rules: None,
             fixed_decimal_formatter: None,
             week_calculator: None,
             _marker: PhantomData,
@@ -900,6 +1077,11 @@ impl<R: DateTimeNamesMarker> RawDateTimeNames<R> {
             month_names: self.month_symbols.as_borrowed(),
             weekday_names: self.weekday_symbols.as_borrowed(),
             dayperiod_names: self.dayperiod_symbols.as_borrowed(),
+            flexible_dayperiod_names: self.flexible_dayperiod_symbols.as_borrowed(),
+            dayperiod_rules: self
+                .dayperiod_rules
+                .as_ref()
+                .and_then(MaybePayload::maybe_get),
             fixed_decimal_formatter: self.fixed_decimal_formatter.as_ref(),
             week_calculator: self.week_calculator.as_ref(),
         }
@@ -1047,6 +1229,67 @@ impl<R: DateTimeNamesMarker> RawDateTimeNames<R> {
         Ok(())
     }
 
+    pub(crate) fn load_flexible_day_period_names<P, Q>(
+        &mut self,
+        names_provider: &P,
+        rules_provider: &Q,
+        locale: &DataLocale,
+        field_length: FieldLength,
+    ) -> Result<(), SingleLoadError>
+    where
+        P: BoundDataProvider<FlexibleDayPeriodNamesV1Marker> + ?Sized,
+        Q: BoundDataProvider<DayPeriodRulesV1Marker> + ?Sized,
+    {
+        let field = fields::Field {
+            symbol: FieldSymbol::DayPeriod(fields::DayPeriod::Flexible),
+            length: field_length,
+        };
+        // UTS 35 says that "B..BBB" are all Abbreviated
+        let field_length = field_length.numeric_to_abbr();
+        match self
+            .flexible_dayperiod_symbols
+            .check_with_length((), field_length)
+        {
+            NamePresence::Loaded => return Ok(()),
+            NamePresence::NotLoaded => (),
+            NamePresence::Mismatched => return Err(SingleLoadError::DuplicateField(field)),
+        };
+        if self.dayperiod_rules.is_none() {
+            // The rules are only keyed by language
+            let rules = R::DayPeriodRules::load_from(
+                rules_provider,
+                DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                },
+            )
+            .ok_or(SingleLoadError::TypeTooNarrow(field))?
+            .map_err(SingleLoadError::Data)?;
+            self.dayperiod_rules = Some(rules);
+        }
+        let mut locale = locale.clone();
+        locale.set_aux(AuxiliaryKeys::from_subtag(aux::symbol_subtag_for(
+            aux::Context::Format,
+            match field_length {
+                FieldLength::Abbreviated => aux::Length::Abbr,
+                FieldLength::Narrow => aux::Length::Narrow,
+                FieldLength::Wide => aux::Length::Wide,
+                _ => return Err(SingleLoadError::UnsupportedField(field)),
+            },
+        )));
+        let payload = R::DayPeriodNames::load_from(
+            names_provider,
+            DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            },
+        )
+        .ok_or(SingleLoadError::TypeTooNarrow(field))?
+        .map_err(SingleLoadError::Data)?;
+        self.flexible_dayperiod_symbols = OptionalNames::SingleLength((), field_length, payload);
+        Ok(())
+    }
+
     pub(crate) fn load_weekday_names<P>(
         &mut self,
         provider: &P,
@@ -1107,6 +1350,118 @@ impl<R: DateTimeNamesMarker> RawDateTimeNames<R> {
         Ok(())
     }
 
+    pub(crate) fn set_month_names(
+        &mut self,
+        field_symbol: fields::Month,
+        field_length: FieldLength,
+        names: MonthNamesV1<'static>,
+        (month_count, has_leap): (usize, bool),
+    ) -> Result<(), SingleLoadError> {
+        let field = fields::Field {
+            symbol: FieldSymbol::Month(field_symbol),
+            length: field_length,
+        };
+        if !matches!(
+            field_length,
+            FieldLength::Abbreviated | FieldLength::Narrow | FieldLength::Wide
+        ) {
+            return Err(SingleLoadError::UnsupportedField(field));
+        }
+        let is_complete = match &names {
+            MonthNamesV1::Linear(symbols) => {
+                !has_leap && symbols.len() == month_count && symbols.iter().all(|s| !s.is_empty())
+            }
+            // Leap months without a name in the calendar are empty
+            MonthNamesV1::LeapLinear(symbols) => {
+                has_leap
+                    && symbols.len() == 2 * month_count
+                    && symbols.iter().take(month_count).all(|s| !s.is_empty())
+            }
+            MonthNamesV1::LeapNumeric(_) => false,
+        };
+        if !is_complete {
+            return Err(SingleLoadError::InvalidNames(field));
+        }
+        self.month_symbols = OptionalNames::SingleLength(
+            field_symbol,
+            field_length,
+            R::MonthNames::maybe_from_payload(DataPayload::<MonthNamesV1Marker>::from_owned(names))
+                .ok_or(SingleLoadError::TypeTooNarrow(field))?,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn set_day_period_names(
+        &mut self,
+        field_length: FieldLength,
+        names: LinearNamesV1<'static>,
+    ) -> Result<(), SingleLoadError> {
+        let field = fields::Field {
+            symbol: FieldSymbol::DayPeriod(fields::DayPeriod::NoonMidnight),
+            length: field_length,
+        };
+        // UTS 35 says that "a..aaa" are all Abbreviated
+        let field_length = field_length.numeric_to_abbr();
+        if !matches!(
+            field_length,
+            FieldLength::Abbreviated | FieldLength::Narrow | FieldLength::Wide
+        ) {
+            return Err(SingleLoadError::UnsupportedField(field));
+        }
+        // 'am' and 'pm' are required, 'noon' and 'midnight' may be empty or absent
+        if !(2..=4).contains(&names.symbols.len())
+            || names.symbols.iter().take(2).any(str::is_empty)
+        {
+            return Err(SingleLoadError::InvalidNames(field));
+        }
+        self.dayperiod_symbols = OptionalNames::SingleLength(
+            (),
+            field_length,
+            R::DayPeriodNames::maybe_from_payload(
+                DataPayload::<DayPeriodNamesV1Marker>::from_owned(names),
+            )
+            .ok_or(SingleLoadError::TypeTooNarrow(field))?,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn set_weekday_names(
+        &mut self,
+        field_symbol: fields::Weekday,
+        field_length: FieldLength,
+        names: LinearNamesV1<'static>,
+    ) -> Result<(), SingleLoadError> {
+        let field = fields::Field {
+            symbol: FieldSymbol::Weekday(field_symbol),
+            length: field_length,
+        };
+        // UTS 35 says that "E..EEE" are all Abbreviated
+        // However, this doesn't apply to "e" and "c".
+        let field_length = if matches!(field_symbol, fields::Weekday::Format) {
+            field_length.numeric_to_abbr()
+        } else {
+            field_length
+        };
+        if !matches!(
+            field_length,
+            FieldLength::Abbreviated | FieldLength::Narrow | FieldLength::Wide | FieldLength::Six
+        ) {
+            return Err(SingleLoadError::UnsupportedField(field));
+        }
+        if names.symbols.len() != 7 || names.symbols.iter().any(str::is_empty) {
+            return Err(SingleLoadError::InvalidNames(field));
+        }
+        self.weekday_symbols = OptionalNames::SingleLength(
+            field_symbol,
+            field_length,
+            R::WeekdayNames::maybe_from_payload(DataPayload::<WeekdayNamesV1Marker>::from_owned(
+                names,
+            ))
+            .ok_or(SingleLoadError::TypeTooNarrow(field))?,
+        );
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn set_week_calculator(&mut self, week_calculator: WeekCalculator) {
         self.week_calculator = Some(week_calculator);
@@ -1224,6 +1579,32 @@ impl<R: DateTimeNamesMarker> RawDateTimeNames<R> {
 
         Ok(())
     }
+
+    /// Loads the flexible day period data required for the `B` fields in the given [`PatternItem`]s.
+    ///
+    /// This is separate from [`Self::load_for_pattern`] because the other day period names
+    /// serve as a fallback for `B`.
+    pub(crate) fn load_flexible_day_periods_for_pattern(
+        &mut self,
+        names_provider: &(impl BoundDataProvider<FlexibleDayPeriodNamesV1Marker> + ?Sized),
+        rules_provider: &(impl BoundDataProvider<DayPeriodRulesV1Marker> + ?Sized),
+        locale: &DataLocale,
+        pattern_items: impl Iterator<Item = PatternItem>,
+    ) -> Result<(), LoadError> {
+        for item in pattern_items {
+            if let PatternItem::Field(field) = item {
+                if field.symbol == FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) {
+                    self.load_flexible_day_period_names(
+                        names_provider,
+                        rules_provider,
+                        locale,
+                        field.length,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -1513,13 +1894,34 @@ impl<'data> TimeSymbols for RawDateTimeNamesBorrowed<'data> {
         hour: input::IsoHour,
         is_top_of_hour: bool,
     ) -> Result<&str, GetSymbolForDayPeriodError> {
-        use fields::DayPeriod::NoonMidnight;
+        use fields::DayPeriod::{Flexible, NoonMidnight};
         let field = fields::Field {
             symbol: FieldSymbol::DayPeriod(field_symbol),
             length: field_length,
         };
         // UTS 35 says that "a..aaa" are all Abbreviated
         let field_length = field_length.numeric_to_abbr();
+        if field_symbol == Flexible {
+            if let (Some(names), Some(rules)) = (
+                self.flexible_dayperiod_names
+                    .get_with_length((), field_length),
+                self.dayperiod_rules,
+            ) {
+                // Day periods without a name in this length fall back to 'am' and 'pm'
+                let option_value = rules
+                    .period_for(u8::from(hour), is_top_of_hour)
+                    .and_then(|index| names.flexible(index))
+                    .or_else(|| {
+                        if u8::from(hour) < 12 {
+                            names.am()
+                        } else {
+                            names.pm()
+                        }
+                    });
+                return option_value.ok_or(GetSymbolForDayPeriodError::MissingNames(field));
+            }
+            // Without flexible day period data, fall back to 'am' and 'pm' below
+        }
         let dayperiod_symbols = self
             .dayperiod_names
             .get_with_length((), field_length)
@@ -1540,6 +1942,8 @@ impl<'data> TimeSymbols for RawDateTimeNamesBorrowed<'data> {
 #[cfg(feature = "compiled_data")]
 mod tests {
     use super::*;
+    use icu_calendar::chinese::Chinese;
+    use icu_calendar::coptic::Coptic;
     use icu_calendar::{DateTime, Gregorian};
     use icu_locid::locale;
     use writeable::assert_try_writeable_eq;
@@ -1579,6 +1983,181 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_names_override_data() {
+        use zerovec::VarZeroVec;
+        let mut names: TypedDateTimeNames<Gregorian> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        let weekdays = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"].map(|s| s.to_uppercase());
+        names
+            .set_weekday_names(
+                fields::Weekday::Format,
+                FieldLength::Wide,
+                LinearNamesV1 {
+                    symbols: VarZeroVec::from(&weekdays),
+                },
+            )
+            .unwrap()
+            .set_day_period_names(
+                FieldLength::Abbreviated,
+                LinearNamesV1 {
+                    symbols: VarZeroVec::from(&["morning", "evening"]),
+                },
+            )
+            .unwrap();
+        let pattern: DateTimePattern = "EEEE, MMMM d, h:mm a".parse().unwrap();
+        // Month names are not overridden and are loaded from data
+        let formatter = names.include_for_pattern(&pattern).unwrap();
+        let datetime = DateTime::try_new_gregorian_datetime(2023, 10, 25, 15, 0, 55).unwrap();
+        assert_try_writeable_eq!(formatter.format(&datetime), "WED, October 25, 3:00 evening");
+
+        // Loading the same field again keeps the overrides
+        names
+            .include_weekday_names(fields::Weekday::Format, FieldLength::Wide)
+            .unwrap();
+        assert_try_writeable_eq!(
+            names.with_pattern(&pattern).format(&datetime),
+            "WED, October 25, 3:00 evening"
+        );
+
+        // Setting replaces names loaded from data
+        names
+            .set_month_names(
+                fields::Month::Format,
+                FieldLength::Wide,
+                MonthNamesV1::Linear(VarZeroVec::from(&[
+                    "M1", "M2", "M3", "M4", "M5", "M6", "M7", "M8", "M9", "M10", "M11", "M12",
+                ])),
+            )
+            .unwrap();
+        assert_try_writeable_eq!(
+            names.with_pattern(&pattern).format(&datetime),
+            "WED, M10 25, 3:00 evening"
+        );
+    }
+
+    #[test]
+    fn test_set_names_validation() {
+        use zerovec::VarZeroVec;
+        let twelve = [
+            "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+        ];
+        let wide = fields::Month::Format;
+        let invalid = |field_length| {
+            Err(SingleLoadError::InvalidNames(fields::Field {
+                symbol: FieldSymbol::Month(wide),
+                length: field_length,
+            }))
+        };
+
+        let mut names: TypedDateTimeNames<Gregorian> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        // An empty name
+        let mut incomplete = twelve;
+        incomplete[5] = "";
+        assert_eq!(
+            names
+                .set_month_names(
+                    wide,
+                    FieldLength::Wide,
+                    MonthNamesV1::Linear(VarZeroVec::from(&incomplete))
+                )
+                .map(|_| ()),
+            invalid(FieldLength::Wide)
+        );
+        // Leap months in a solar calendar
+        let leap_linear = MonthNamesV1::LeapLinear(VarZeroVec::from(&[twelve, twelve].concat()));
+        assert_eq!(
+            names
+                .set_month_names(wide, FieldLength::Wide, leap_linear.clone())
+                .map(|_| ()),
+            invalid(FieldLength::Wide)
+        );
+        // Numeric lengths have no names
+        assert!(matches!(
+            names.set_month_names(
+                wide,
+                FieldLength::TwoDigit,
+                MonthNamesV1::Linear(VarZeroVec::from(&twelve))
+            ),
+            Err(SingleLoadError::UnsupportedField(_))
+        ));
+
+        // Coptic has 13 months
+        let mut names: TypedDateTimeNames<Coptic> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        assert_eq!(
+            names
+                .set_month_names(
+                    wide,
+                    FieldLength::Abbreviated,
+                    MonthNamesV1::Linear(VarZeroVec::from(&twelve))
+                )
+                .map(|_| ()),
+            invalid(FieldLength::Abbreviated)
+        );
+        let thirteen = [&twelve[..], &["13"]].concat();
+        names
+            .set_month_names(
+                wide,
+                FieldLength::Abbreviated,
+                MonthNamesV1::Linear(VarZeroVec::from(&thirteen)),
+            )
+            .unwrap();
+
+        // Chinese needs names for the leap months
+        let mut names: TypedDateTimeNames<Chinese> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        assert_eq!(
+            names
+                .set_month_names(
+                    wide,
+                    FieldLength::Wide,
+                    MonthNamesV1::Linear(VarZeroVec::from(&twelve))
+                )
+                .map(|_| ()),
+            invalid(FieldLength::Wide)
+        );
+        names
+            .set_month_names(wide, FieldLength::Wide, leap_linear)
+            .unwrap();
+
+        // Weekdays and day periods
+        let mut names: TypedDateTimeNames<Gregorian> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        assert!(matches!(
+            names.set_weekday_names(
+                fields::Weekday::Format,
+                FieldLength::Wide,
+                LinearNamesV1 {
+                    symbols: VarZeroVec::from(&twelve[..6]),
+                }
+            ),
+            Err(SingleLoadError::InvalidNames(_))
+        ));
+        assert!(matches!(
+            names.set_day_period_names(
+                FieldLength::Wide,
+                LinearNamesV1 {
+                    symbols: VarZeroVec::from(&["am"]),
+                }
+            ),
+            Err(SingleLoadError::InvalidNames(_))
+        ));
+
+        // Time names do not include month names
+        let mut names: TypedDateTimeNames<Gregorian, TimeMarker> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        assert!(matches!(
+            names.set_month_names(
+                wide,
+                FieldLength::Wide,
+                MonthNamesV1::Linear(VarZeroVec::from(&twelve))
+            ),
+            Err(SingleLoadError::TypeTooNarrow(_))
+        ));
+    }
+
     #[test]
     fn test_era_coverage() {
         let locale = locale!("uk").into();
@@ -1826,7 +2405,6 @@ mod tests {
     #[test]
     fn test_dayperiod_coverage() {
         // Thai has different values for different lengths of day periods
-        // TODO(#487): Support flexible day periods, too
         let locale = locale!("th").into();
         #[derive(Debug)]
         struct TestCase {
@@ -1904,4 +2482,15 @@ mod tests {
             assert_try_writeable_eq!(formatted_pattern, expected, Ok(()), "{cas:?}");
         }
     }
+
+    #[test]
+    fn test_flexible_dayperiods_fallback() {
+        // Without flexible day period data, 'B' falls back to AM and PM
+        let mut names: TypedDateTimeNames<Gregorian> =
+            TypedDateTimeNames::try_new(&locale!("en").into()).unwrap();
+        names.include_day_period_names(FieldLength::Wide).unwrap();
+        let pattern: DateTimePattern = "h:mm BBBB".parse().unwrap();
+        let time = icu_calendar::types::Time::try_new(12, 0, 0, 0).unwrap();
+        assert_try_writeable_eq!(names.with_pattern(&pattern).format_time(&time), "12:00 PM");
+    }
 }
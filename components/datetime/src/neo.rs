@@ -119,7 +119,7 @@ macro_rules! gen_any_buffer_constructors_with_external_loader {
 size_test!(
     TypedNeoDateFormatter<icu_calendar::Gregorian>,
     typed_neo_date_formatter_size,
-    456
+    464
 );
 
 /// [`TypedNeoDateFormatter`] can format dates from a calendar selected at compile time.
@@ -271,7 +271,7 @@ impl<C: CldrCalendar> TypedNeoDateFormatter<C> {
     }
 }
 
-size_test!(TypedNeoFormatter<icu_calendar::Gregorian, crate::neo_marker::NeoYearMonthDayMarker>, typed_neo_year_month_day_formatter_size, 536);
+size_test!(TypedNeoFormatter<icu_calendar::Gregorian, crate::neo_marker::NeoYearMonthDayMarker>, typed_neo_year_month_day_formatter_size, 544);
 
 /// [`TypedNeoFormatter`] is a formatter capable of formatting dates and/or times from
 /// a calendar selected at compile time.
@@ -485,7 +485,7 @@ impl<C: CldrCalendar, R: TypedNeoFormatterMarker<C>> TypedNeoFormatter<C, R> {
 size_test!(
     NeoFormatter<crate::neo_marker::NeoYearMonthDayMarker>,
     neo_year_month_day_formatter_size,
-    592
+    600
 );
 
 /// [`NeoFormatter`] is a formatter capable of formatting dates and/or times from
@@ -849,7 +849,7 @@ impl<R: NeoFormatterMarker> NeoFormatter<R> {
     }
 }
 
-size_test!(NeoDateFormatter, neo_date_formatter_size, 512);
+size_test!(NeoDateFormatter, neo_date_formatter_size, 520);
 
 /// [`NeoDateFormatter`] is a formatter capable of formatting dates from any calendar, selected
 /// at runtime. For the difference between this and [`TypedNeoDateFormatter`], please read the
@@ -1165,7 +1165,7 @@ impl<'a> FormattedNeoDate<'a> {
     }
 }
 
-size_test!(NeoTimeFormatter, neo_time_formatter_size, 312);
+size_test!(NeoTimeFormatter, neo_time_formatter_size, 400);
 
 /// [`NeoTimeFormatter`] can format times of day.
 /// It supports both 12-hour and 24-hour formats.
@@ -1338,7 +1338,7 @@ impl<'a> FormattedNeoTime<'a> {
 size_test!(
     TypedNeoDateTimeFormatter<icu_calendar::Gregorian>,
     typed_neo_date_time_formatter_size,
-    576
+    664
 );
 
 /// [`TypedNeoDateTimeFormatter`] can format dates with times of day. The dates must be in
@@ -1757,7 +1757,7 @@ impl<C: CldrCalendar> TypedNeoDateTimeFormatter<C> {
     }
 }
 
-size_test!(NeoDateTimeFormatter, neo_date_time_formatter_size, 632);
+size_test!(NeoDateTimeFormatter, neo_date_time_formatter_size, 720);
 
 /// [`NeoDateTimeFormatter`] is a formatter capable of formatting dates from any calendar, selected
 /// at runtime. For the difference between this and [`TypedNeoDateFormatter`], please read the
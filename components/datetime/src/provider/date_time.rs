@@ -535,7 +535,8 @@ impl<'data> DateSymbols<'data> for provider::calendar::DateSymbolsV1<'data> {
 pub(crate) trait TimeSymbols {
     /// Gets the day period symbol.
     ///
-    /// Internally, 'noon' and 'midnight' should fall back to 'am' and 'pm'.
+    /// Internally, 'noon' and 'midnight' should fall back to 'am' and 'pm', as should
+    /// flexible day periods if their data is not available.
     fn get_symbol_for_day_period(
         &self,
         day_period: fields::DayPeriod,
@@ -8,7 +8,7 @@ use crate::pattern::runtime::{self, PatternULE};
 use alloc::borrow::Cow;
 use icu_provider::prelude::*;
 use zerovec::ule::{AsULE, UnvalidatedStr, ULE};
-use zerovec::{VarZeroVec, ZeroMap};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
 
 #[cfg(feature = "experimental")]
 use crate::neo_skeleton::NeoSkeletonLength;
@@ -374,6 +374,9 @@ size_test!(LinearNamesV1, linear_names_v1_size, 24);
 /// - For weekdays, element 0 is Sunday
 /// - For dayperiods, the elements are in order: AM, PM, (noon), (midnight), where the latter two are optional.
 ///   In the case noon is missing but midnight is present, the noon value can be the empty string. This is unlikely.
+/// - For flexible dayperiods, the elements are in order: AM, PM, noon, midnight, morning1, morning2,
+///   afternoon1, afternoon2, evening1, evening2, night1, night2. Periods not used by the locale
+///   are the empty string. The indices are the ones used by [`DayPeriodRulesV1`].
 /// - For day names element 0 is the first day of the month
 ///
 /// This uses an auxiliary subtag for length. See [`YearNamesV1`] for more information on the scheme.
@@ -387,6 +390,7 @@ size_test!(LinearNamesV1, linear_names_v1_size, 24);
 #[icu_provider::data_struct(
     marker(WeekdayNamesV1Marker, "datetime/symbols/weekdays@1"),
     marker(DayPeriodNamesV1Marker, "datetime/symbols/dayperiods@1"),
+    marker(FlexibleDayPeriodNamesV1Marker, "datetime/symbols/dayperiods/flexible@1"),
 
     // We're not producing or using day symbols yet, but this is where they would go
     marker(ChineseDaySymbolsV1Marker, "datetime/symbols/chinese/days@1"),
@@ -432,6 +436,59 @@ impl<'data> LinearNamesV1<'data> {
     pub(crate) fn midnight(&self) -> Option<&str> {
         self.symbols.get(3)
     }
+    /// Gets the symbol at a [`DayPeriodRulesV1`] index assuming this struct contains
+    /// flexible day period data.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn flexible(&self, index: u8) -> Option<&str> {
+        self.symbols
+            .get(usize::from(index))
+            .and_then(|s| if s.is_empty() { None } else { Some(s) })
+    }
+}
+
+size_test!(DayPeriodRulesV1, day_period_rules_v1_size, 32);
+
+/// The rules assigning each hour of the day to a flexible day period, such as
+/// "in the morning" or "at night".
+///
+/// CLDR only defines day period rules per language, so this data is only keyed by language.
+#[doc = day_period_rules_v1_size!()]
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(DayPeriodRulesV1Marker, "datetime/dayperiods/rules@1"))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::neo),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct DayPeriodRulesV1<'data> {
+    /// For each of the 24 hours of the day, the index of its day period in the
+    /// flexible day period names. See [`LinearNamesV1`] for the order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hours: ZeroVec<'data, u8>,
+    /// Whether 00:00 is called "midnight".
+    pub has_midnight: bool,
+    /// Whether 12:00 is called "noon".
+    pub has_noon: bool,
+}
+
+impl DayPeriodRulesV1<'_> {
+    /// Gets the index of the flexible day period for the given time.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn period_for(&self, hour: u8, is_top_of_hour: bool) -> Option<u8> {
+        match (hour, is_top_of_hour) {
+            (0, true) if self.has_midnight => Some(3),
+            (12, true) if self.has_noon => Some(2),
+            _ => self.hours.get(usize::from(hour)),
+        }
+    }
 }
 
 size_test!(DatePatternV1, date_pattern_v1_size, 32);
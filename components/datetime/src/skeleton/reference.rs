@@ -86,10 +86,9 @@ impl From<&Pattern> for Skeleton {
                     // Only flexible day periods are used in skeletons, ignore all others.
                     FieldSymbol::DayPeriod(fields::DayPeriod::AmPm)
                     | FieldSymbol::DayPeriod(fields::DayPeriod::NoonMidnight) => continue,
-                    // TODO(#487) - Flexible day periods should be included here.
-                    // FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
-                    //     FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
-                    // }
+                    // TODO(#487) - Flexible day periods should be included here once skeleton
+                    // matching supports them.
+                    FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => continue,
 
                     // Only the H12 and H23 symbols are used in skeletons, while the patterns may
                     // contain H11 or H23 depending on the localization.
@@ -133,6 +132,11 @@ impl TryFrom<&str> for Skeleton {
         while let Some(ch) = iter.next() {
             // Convert the byte to a valid field symbol.
             let field_symbol = FieldSymbol::try_from(ch)?;
+            // TODO(#487) - Flexible day periods can be formatted, but are not yet supported
+            // in skeleton matching.
+            if field_symbol == FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) {
+                return Err(SkeletonError::SymbolUnimplemented(ch));
+            }
 
             // Go through the chars to count how often it's repeated.
             let mut field_length: u8 = 1;
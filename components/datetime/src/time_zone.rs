@@ -147,6 +147,11 @@ pub struct TimeZoneFormatter {
     pub(super) fallback_unit: FallbackTimeZoneFormatterUnit,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for TimeZoneFormatter where
+    P: DataProvider<provider::time_zones::TimeZoneFormatsV1Marker> + ?Sized
+{
+}
+
 /// A container contains all data payloads for CustomTimeZone.
 #[derive(Debug)]
 pub(super) struct TimeZoneDataPayloads {
@@ -81,6 +81,26 @@ use crate::{
 #[derive(Debug)]
 pub struct TypedZonedDateTimeFormatter<C>(raw::ZonedDateTimeFormatter, PhantomData<C>);
 
+impl<C: CldrCalendar, P> icu_provider::constructors::RequiredKeys<P>
+    for TypedZonedDateTimeFormatter<C>
+where
+    P: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+        + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+        + DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
+        + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+        + DataProvider<provider::time_zones::MetazoneGenericNamesLongV1Marker>
+        + DataProvider<provider::time_zones::MetazoneGenericNamesShortV1Marker>
+        + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
+        + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + ?Sized,
+{
+}
+
 impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
     /// Constructor that takes a selected locale and a list of [`DateTimeFormatterOptions`].
     /// It collects all data necessary to format zoned datetime values into the given locale.
@@ -869,3 +869,28 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_ethiopian_epagomenal_month() {
+    use icu_calendar::Date;
+    use icu_datetime::{options::length, DateFormatter};
+
+    // 5 Pagumen 2015 Incarnation, the last day of the year
+    let date = Date::try_new_ethiopian_date(EthiopianEraStyle::AmeteMihret, 2015, 13, 5).unwrap();
+    assert_eq!(date.month().code.0, "M13");
+    let amete_alem = date.to_calendar(Ethiopian::new_with_era_style(EthiopianEraStyle::AmeteAlem));
+
+    let ethiopic =
+        DateFormatter::try_new_with_length(&locale!("en-u-ca-ethiopic").into(), length::Date::Long)
+            .unwrap();
+    let result = ethiopic.format_to_string(&date.to_any()).unwrap();
+    assert!(result.starts_with("Pagumen 5, 2015"), "{result}");
+
+    let ethioaa =
+        DateFormatter::try_new_with_length(&locale!("en-u-ca-ethioaa").into(), length::Date::Long)
+            .unwrap();
+    assert_eq!(
+        ethioaa.format_to_string(&amete_alem.to_any()).unwrap(),
+        "Pagumen 5, 7515 ERA0"
+    );
+}
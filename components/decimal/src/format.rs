@@ -79,4 +79,32 @@ mod tests {
         let fd = "12345.67".parse().unwrap();
         assert_writeable_eq!(fmt.format(&fd), "12,345.67");
     }
+
+    #[test]
+    pub fn test_to_bidi_string_rtl() {
+        use fixed_decimal::FixedDecimal;
+
+        for (locale, minus_sign, expected) in [
+            (locale!("ar"), '-', "\u{202D}-1234.5\u{202C}"),
+            (locale!("ar-EG"), '-', "\u{202D}-1234.5\u{202C}"),
+            (locale!("he"), '-', "\u{202D}-1234.5\u{202C}"),
+            (locale!("fa"), '\u{2212}', "\u{202D}\u{2212}1234.5\u{202C}"),
+        ] {
+            let fmt =
+                FixedDecimalFormatter::try_new(&(&locale).into(), Default::default()).unwrap();
+            // The minus sign of the locale, without its bidi controls
+            let mut signs = fmt
+                .symbols
+                .get()
+                .minus_sign_affixes
+                .prefix
+                .chars()
+                .filter(|c| !matches!(c, '\u{200E}' | '\u{200F}' | '\u{061C}'));
+            assert_eq!(signs.next(), Some(minus_sign), "{locale}");
+            assert_eq!(signs.next(), None, "{locale}");
+
+            let dec: FixedDecimal = "-1234.5".parse().unwrap();
+            assert_eq!(dec.to_bidi_string(minus_sign, None), expected, "{locale}");
+        }
+    }
 }
@@ -124,6 +124,11 @@ pub struct FixedDecimalFormatter {
     symbols: DataPayload<provider::DecimalSymbolsV1Marker>,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for FixedDecimalFormatter where
+    P: DataProvider<provider::DecimalSymbolsV1Marker> + ?Sized
+{
+}
+
 impl FixedDecimalFormatter {
     icu_provider::gen_any_buffer_data_constructors!(
         locale: include,
@@ -0,0 +1,114 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fixed_decimal::FixedDecimal;
+use icu_experimental::relativetime::{RelativeTimeFormatter, RelativeTimeFormatterOptions, Tense};
+use icu_locid::{locale, Locale};
+use icu_plurals::PluralCategory;
+use writeable::Writeable;
+
+/// Returns a pattern of `len` bytes with the placeholder in the middle.
+fn pattern_of_length(len: usize) -> String {
+    let text = "x".repeat(len - "{0}".len());
+    let (prefix, suffix) = text.split_at(text.len() / 2);
+    format!("{prefix}{{0}}{suffix}")
+}
+
+fn relativetime(c: &mut Criterion) {
+    let mut group = c.benchmark_group("relativetime/pattern");
+    for len in [10, 50, 100] {
+        let mut formatter = RelativeTimeFormatter::try_new_long_day(
+            &locale!("en").into(),
+            RelativeTimeFormatterOptions::default(),
+        )
+        .unwrap();
+        formatter
+            .set_pattern_override(
+                Tense::Future,
+                PluralCategory::Other,
+                &pattern_of_length(len),
+            )
+            .unwrap();
+        let value = FixedDecimal::from(5);
+        group.bench_function(BenchmarkId::from_parameter(len), |b| {
+            b.iter(|| {
+                formatter
+                    .format(black_box(value.clone()))
+                    .write_to_string()
+                    .into_owned()
+            })
+        });
+    }
+    group.finish();
+
+    // Arabic uses all six plural categories: 0 is zero, 1 is one, 2 is two, 3 is few,
+    // 11 is many, and 100 is other.
+    let formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("ar").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .unwrap();
+    let values = [0, 1, 2, 3, 11, 100].map(FixedDecimal::from);
+    c.bench_function("relativetime/plural_categories", |b| {
+        b.iter(|| {
+            for value in &values {
+                formatter
+                    .format(black_box(value.clone()))
+                    .write_to_string()
+                    .into_owned();
+            }
+        })
+    });
+
+    let formatters = ["en", "ar", "ru", "ja", "fr", "bn"]
+        .into_iter()
+        .map(|locale| {
+            RelativeTimeFormatter::try_new_long_day(
+                &locale.parse::<Locale>().unwrap().into(),
+                RelativeTimeFormatterOptions::default(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    c.bench_function("relativetime/format/1000", |b| {
+        b.iter(|| {
+            for (i, formatter) in (-500..500).zip(formatters.iter().cycle()) {
+                formatter
+                    .format(black_box(FixedDecimal::from(i)))
+                    .write_to_string()
+                    .into_owned();
+            }
+        })
+    });
+
+    // A timeline of offsets, formatted in a batch and one at a time.
+    let formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .unwrap();
+    let values = (-500..500).collect::<Vec<i64>>();
+    let mut group = c.benchmark_group("relativetime/timeline");
+    group.bench_function("format_many", |b| {
+        b.iter(|| formatter.format_many(black_box(&values)))
+    });
+    group.bench_function("format", |b| {
+        b.iter(|| {
+            black_box(&values)
+                .iter()
+                .map(|&value| {
+                    formatter
+                        .format(FixedDecimal::from(value))
+                        .write_to_string()
+                        .into_owned()
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, relativetime,);
+criterion_main!(benches);
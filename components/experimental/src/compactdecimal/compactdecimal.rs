@@ -97,6 +97,15 @@ pub struct CompactDecimalFormatter {
     pub(crate) compact_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for CompactDecimalFormatter where
+    P: DataProvider<ShortCompactDecimalFormatDataV1Marker>
+        + DataProvider<LongCompactDecimalFormatDataV1Marker>
+        + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+        + DataProvider<icu_plurals::provider::CardinalV1Marker>
+        + ?Sized
+{
+}
+
 impl CompactDecimalFormatter {
     /// Constructor that takes a selected locale and a list of preferences,
     /// then collects all compiled data necessary to format numbers in short compact
@@ -36,6 +36,13 @@ pub struct CurrencyFormatter {
     fixed_decimal_formatter: FixedDecimalFormatter,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for CurrencyFormatter where
+    P: DataProvider<super::super::provider::currency::CurrencyEssentialsV1Marker>
+        + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+        + ?Sized
+{
+}
+
 /// A currency code, such as "USD" or "EUR".
 #[derive(Clone, Copy)]
 pub struct CurrencyCode(pub TinyAsciiStr<3>);
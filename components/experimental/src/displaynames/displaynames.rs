@@ -4,6 +4,7 @@
 
 //! This module contains types and implementations for the Displaynames component.
 
+use crate::dimension::currency::formatter::CurrencyCode;
 use crate::displaynames::options::*;
 use crate::displaynames::provider::*;
 use alloc::borrow::Cow;
@@ -12,6 +13,7 @@ use icu_locid::{
     subtags::Language, subtags::Region, subtags::Script, subtags::Variant, LanguageIdentifier,
     Locale,
 };
+use icu_plurals::PluralCategory;
 use icu_provider::prelude::*;
 use zerovec::ule::UnvalidatedStr;
 
@@ -316,6 +318,79 @@ impl LanguageDisplayNames {
     }
 }
 
+/// Lookup of the locale-specific display names and symbols by currency code.
+///
+/// There is no compiled data for this type yet, so it has to be constructed with
+/// [`CurrencyDisplayNames::try_new_unstable`] and data generated by `icu_datagen`.
+#[derive(Default)]
+pub struct CurrencyDisplayNames {
+    currency_data: DataPayload<CurrencyDisplayNamesV1Marker>,
+}
+
+impl CurrencyDisplayNames {
+    /// Creates a new [`CurrencyDisplayNames`] from locale data provided by a [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.</div>
+    pub fn try_new_unstable<D: DataProvider<CurrencyDisplayNamesV1Marker> + ?Sized>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, DataError> {
+        let currency_data = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+
+        Ok(Self { currency_data })
+    }
+
+    /// Returns the display name of a currency, such as "US Dollar".
+    pub fn of(&self, currency_code: CurrencyCode) -> Option<&str> {
+        self.currency_data
+            .get()
+            .names
+            .get(&currency_code.0.to_unvalidated())
+    }
+
+    /// Returns the display name of a currency for an amount with the given plural category,
+    /// such as "US dollars".
+    ///
+    /// Falls back to the name for [`PluralCategory::Other`], and then to the display name
+    /// returned by [`Self::of`].
+    pub fn plural_name(
+        &self,
+        currency_code: CurrencyCode,
+        category: PluralCategory,
+    ) -> Option<&str> {
+        let data = self.currency_data.get();
+        let code = currency_code.0.to_unvalidated();
+        data.plural_names
+            .get_2d(&code, &category)
+            .or_else(|| data.plural_names.get_2d(&code, &PluralCategory::Other))
+            .or_else(|| data.names.get(&code))
+    }
+
+    /// Returns the symbol of a currency, such as "US$".
+    pub fn symbol(&self, currency_code: CurrencyCode) -> Option<&str> {
+        self.currency_data
+            .get()
+            .symbols
+            .get(&currency_code.0.to_unvalidated())
+    }
+
+    /// Returns the narrow symbol of a currency, such as "$", falling back to the symbol.
+    pub fn narrow_symbol(&self, currency_code: CurrencyCode) -> Option<&str> {
+        let data = self.currency_data.get();
+        let code = currency_code.0.to_unvalidated();
+        data.narrow_symbols
+            .get(&code)
+            .or_else(|| data.symbols.get(&code))
+    }
+}
+
 /// Format a locale as a display string.
 ///
 /// # Example
@@ -352,6 +427,16 @@ pub struct LocaleDisplayNamesFormatter {
     // transforms_data: DataPayload<TransformsDisplayNamesV1Marker>,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for LocaleDisplayNamesFormatter where
+    P: DataProvider<LocaleDisplayNamesV1Marker>
+        + DataProvider<LanguageDisplayNamesV1Marker>
+        + DataProvider<ScriptDisplayNamesV1Marker>
+        + DataProvider<RegionDisplayNamesV1Marker>
+        + DataProvider<VariantDisplayNamesV1Marker>
+        + ?Sized
+{
+}
+
 impl LocaleDisplayNamesFormatter {
     icu_provider::gen_any_buffer_data_constructors!(
         locale: include,
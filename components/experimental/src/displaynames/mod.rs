@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Display names for languages and regions.
+//! Display names for languages, regions and currencies.
 
 // TODO: expand documentation
 
@@ -25,6 +25,7 @@ mod displaynames;
 mod options;
 pub mod provider;
 
+pub use displaynames::CurrencyDisplayNames;
 pub use displaynames::LanguageDisplayNames;
 pub use displaynames::LocaleDisplayNamesFormatter;
 pub use displaynames::RegionDisplayNames;
@@ -9,10 +9,11 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use icu_plurals::PluralCategory;
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
 use zerovec::ule::UnvalidatedStr;
-use zerovec::ZeroMap;
+use zerovec::{ZeroMap, ZeroMap2d};
 
 // We use raw TinyAsciiStrs for map keys, as we then don't have to
 // validate them as subtags on deserialization. Map lookup can be
@@ -23,6 +24,7 @@ type UnvalidatedLanguage = UnvalidatedTinyAsciiStr<3>;
 type UnvalidatedScript = UnvalidatedTinyAsciiStr<4>;
 type UnvalidatedLocale = UnvalidatedStr;
 type UnvalidatedVariant = UnvalidatedTinyAsciiStr<8>;
+type UnvalidatedCurrency = UnvalidatedTinyAsciiStr<3>;
 
 #[cfg(feature = "compiled_data")]
 /// Baked data
@@ -137,3 +139,32 @@ pub struct VariantDisplayNamesV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub names: ZeroMap<'data, UnvalidatedVariant, str>,
 }
+
+#[icu_provider::data_struct(CurrencyDisplayNamesV1Marker = "displaynames/currencies@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// CurrencyDisplayNames provides the display names and symbols of currency codes.
+pub struct CurrencyDisplayNamesV1<'data> {
+    /// Mapping for currency code to display name, such as "US Dollar".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// Mapping for currency code and plural category to display name for an amount, such as
+    /// "US dollars".
+    ///
+    /// Names that are the same as the one for [`PluralCategory::Other`] are omitted, as is the
+    /// one for [`PluralCategory::Other`] if it is the same as the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plural_names: ZeroMap2d<'data, UnvalidatedCurrency, PluralCategory, str>,
+    /// Mapping for currency code to symbol, such as "US$".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// Mapping for currency code to narrow symbol, such as "$".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow_symbols: ZeroMap<'data, UnvalidatedCurrency, str>,
+}
@@ -0,0 +1,152 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::GraphemeClusterBreakDataV1Marker;
+use icu_segmenter::GraphemeClusterSegmenter;
+
+use crate::ellipsis::provider::EllipsisPatternsV1Marker;
+use crate::ellipsis::EllipsizerError;
+
+/// Truncates text for display, using the locale's ellipsis.
+///
+/// Text is only cut between grapheme clusters, so that emoji sequences and combining marks
+/// are never broken up. Lengths are measured in grapheme clusters, and include the ellipsis.
+///
+/// There is no compiled data for this type yet, so it has to be constructed from data
+/// generated by `icu_datagen`.
+#[derive(Debug)]
+pub struct Ellipsizer {
+    patterns: DataPayload<EllipsisPatternsV1Marker>,
+    segmenter: GraphemeClusterSegmenter,
+}
+
+impl Ellipsizer {
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(ANY, Self::try_new_unstable)]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, EllipsizerError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale)
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(BUFFER, Self::try_new_unstable)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, EllipsizerError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale)
+    }
+
+    /// Creates a new [`Ellipsizer`] from locale data provided by a [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.</div>
+    pub fn try_new_unstable<P>(provider: &P, locale: &DataLocale) -> Result<Self, EllipsizerError>
+    where
+        P: DataProvider<EllipsisPatternsV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            patterns: provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?,
+            segmenter: GraphemeClusterSegmenter::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Truncates the text to at most `max_graphemes` grapheme clusters by removing text from
+    /// the end, such as "Hello, wor…".
+    ///
+    /// Returns the text unchanged if it fits. If the ellipsis itself is longer than
+    /// `max_graphemes`, only the ellipsis is returned.
+    pub fn truncate_end<'a>(&self, text: &'a str, max_graphemes: usize) -> Cow<'a, str> {
+        let ellipsis = &*self.patterns.get().end;
+        let Some((boundaries, keep)) = self.truncation(text, ellipsis, max_graphemes) else {
+            return Cow::Borrowed(text);
+        };
+        let end = boundaries.get(keep).copied().unwrap_or_default();
+        let mut result = String::with_capacity(end + ellipsis.len());
+        result.push_str(text.get(..end).unwrap_or_default());
+        result.push_str(ellipsis);
+        Cow::Owned(result)
+    }
+
+    /// Truncates the text to at most `max_graphemes` grapheme clusters by removing text from
+    /// the middle, such as "Hello…world".
+    ///
+    /// If an odd number of grapheme clusters is kept, the extra one is kept at the start.
+    /// Returns the text unchanged if it fits. If the ellipsis itself is longer than
+    /// `max_graphemes`, only the ellipsis is returned.
+    pub fn truncate_middle<'a>(&self, text: &'a str, max_graphemes: usize) -> Cow<'a, str> {
+        let ellipsis = &*self.patterns.get().middle;
+        let Some((boundaries, keep)) = self.truncation(text, ellipsis, max_graphemes) else {
+            return Cow::Borrowed(text);
+        };
+        let graphemes = boundaries.len() - 1;
+        let end = boundaries.get((keep + 1) / 2).copied().unwrap_or_default();
+        let start = boundaries
+            .get(graphemes - keep / 2)
+            .copied()
+            .unwrap_or(text.len());
+        let mut result = String::with_capacity(end + ellipsis.len() + text.len() - start);
+        result.push_str(text.get(..end).unwrap_or_default());
+        result.push_str(ellipsis);
+        result.push_str(text.get(start..).unwrap_or_default());
+        Cow::Owned(result)
+    }
+
+    /// Truncates the text to at most `max_graphemes` grapheme clusters by removing text from
+    /// the start, such as "…llo, world".
+    ///
+    /// Returns the text unchanged if it fits. If the ellipsis itself is longer than
+    /// `max_graphemes`, only the ellipsis is returned.
+    pub fn truncate_start<'a>(&self, text: &'a str, max_graphemes: usize) -> Cow<'a, str> {
+        let ellipsis = &*self.patterns.get().start;
+        let Some((boundaries, keep)) = self.truncation(text, ellipsis, max_graphemes) else {
+            return Cow::Borrowed(text);
+        };
+        let graphemes = boundaries.len() - 1;
+        let start = boundaries
+            .get(graphemes - keep)
+            .copied()
+            .unwrap_or(text.len());
+        let mut result = String::with_capacity(ellipsis.len() + text.len() - start);
+        result.push_str(ellipsis);
+        result.push_str(text.get(start..).unwrap_or_default());
+        Cow::Owned(result)
+    }
+
+    /// Returns the grapheme cluster boundaries of the text and the number of grapheme clusters
+    /// to keep next to the ellipsis, or `None` if the text does not need to be truncated.
+    fn truncation(
+        &self,
+        text: &str,
+        ellipsis: &str,
+        max_graphemes: usize,
+    ) -> Option<(Vec<usize>, usize)> {
+        let boundaries: Vec<usize> = self.segmenter.segment_str(text).collect();
+        // There are boundaries at the start and end of the text, or only at 0 for the empty
+        // text, so this is the number of grapheme clusters.
+        let graphemes = boundaries.len().saturating_sub(1);
+        if graphemes <= max_graphemes {
+            return None;
+        }
+        let ellipsis_graphemes = self
+            .segmenter
+            .segment_str(ellipsis)
+            .count()
+            .saturating_sub(1);
+        Some((boundaries, max_graphemes.saturating_sub(ellipsis_graphemes)))
+    }
+}
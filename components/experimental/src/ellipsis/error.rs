@@ -0,0 +1,33 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_provider::DataError;
+use icu_segmenter::SegmenterError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::ellipsis::Error).
+#[derive(Display, Debug)]
+#[non_exhaustive]
+pub enum EllipsizerError {
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`GraphemeClusterSegmenter`](icu_segmenter::GraphemeClusterSegmenter).
+    #[displaydoc("Error loading GraphemeClusterSegmenter: {0}")]
+    Segmenter(SegmenterError),
+}
+
+impl From<DataError> for EllipsizerError {
+    fn from(e: DataError) -> Self {
+        EllipsizerError::Data(e)
+    }
+}
+
+impl From<SegmenterError> for EllipsizerError {
+    fn from(e: SegmenterError) -> Self {
+        EllipsizerError::Segmenter(e)
+    }
+}
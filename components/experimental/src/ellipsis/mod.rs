@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Truncation of text for display, such as "Hello, wor…".
+//!
+//! Text is truncated between grapheme clusters and marked with the locale's ellipsis, as
+//! given by the CLDR ellipsis patterns.
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod ellipsizer;
+mod error;
+pub mod provider;
+
+pub use ellipsizer::Ellipsizer;
+pub use error::EllipsizerError;
+#[doc(no_inline)]
+pub use EllipsizerError as Error;
@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::prelude::*;
+
+/// The ellipses used to mark truncated text, derived from the CLDR ellipsis patterns.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(EllipsisPatternsV1Marker = "ellipsis/patterns@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::ellipsis::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct EllipsisPatternsV1<'data> {
+    /// The ellipsis prepended to text that is truncated at the start, such as `"…"` from the
+    /// CLDR `initial` pattern `"…{0}"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub start: Cow<'data, str>,
+
+    /// The ellipsis between the two parts of text that is truncated in the middle, such as
+    /// `"…"` from the CLDR `medial` pattern `"{0}…{1}"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub middle: Cow<'data, str>,
+
+    /// The ellipsis appended to text that is truncated at the end, such as `"…"` from the
+    /// CLDR `final` pattern `"{0}…"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub end: Cow<'data, str>,
+}
@@ -20,8 +20,11 @@ extern crate alloc;
 pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
+pub mod ellipsis;
 pub mod personnames;
 pub mod relativetime;
+#[cfg(feature = "timezone_picker")]
+pub mod timezone_picker;
 pub mod transliterate;
 pub mod unicodeset_parse;
 pub mod units;
@@ -76,6 +79,8 @@ pub mod provider {
         icu_experimental_data::impl_relativetime_short_week_v1!(Baked);
         icu_experimental_data::impl_relativetime_short_year_v1!(Baked);
         icu_experimental_data::impl_units_info_v1!(Baked);
+        #[cfg(feature = "timezone_picker")]
+        icu_experimental_data::impl_time_zone_offset_period_v1!(Baked);
     };
 
     #[cfg(feature = "datagen")]
@@ -120,6 +125,8 @@ pub mod provider {
         super::relativetime::provider::ShortSecondRelativeTimeFormatDataV1Marker::KEY,
         super::relativetime::provider::ShortWeekRelativeTimeFormatDataV1Marker::KEY,
         super::relativetime::provider::ShortYearRelativeTimeFormatDataV1Marker::KEY,
+        #[cfg(feature = "timezone_picker")]
+        super::timezone_picker::provider::ZoneOffsetPeriodV1Marker::KEY,
         super::units::provider::UnitsInfoV1Marker::KEY,
     ];
 }
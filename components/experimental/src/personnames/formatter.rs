@@ -28,6 +28,16 @@ pub struct PersonNamesFormatter {
     fallbacker: LocaleFallbacker,
 }
 
+impl<P> icu_provider::constructors::RequiredKeys<P> for PersonNamesFormatter where
+    P: DataProvider<icu_properties::provider::ScriptWithExtensionsPropertyV1Marker>
+        + DataProvider<icu_properties::provider::ScriptValueToShortNameV1Marker>
+        + DataProvider<icu_locid_transform::provider::LocaleFallbackLikelySubtagsV1Marker>
+        + DataProvider<icu_locid_transform::provider::LocaleFallbackParentsV1Marker>
+        + DataProvider<icu_locid_transform::provider::CollationFallbackSupplementV1Marker>
+        + ?Sized
+{
+}
+
 impl From<&PersonNamesFormatterOptions> for PersonNamesFormattingAttributesMask {
     fn from(value: &PersonNamesFormatterOptions) -> Self {
         PersonNamesFormattingAttributes::from(value.order).bit_value()
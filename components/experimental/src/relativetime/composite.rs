@@ -0,0 +1,591 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_list::provider::UnitListV1Marker;
+use icu_list::{ListFormatter, ListLength};
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+use crate::relativetime::provider::*;
+use crate::relativetime::{
+    RelativeTimeError, RelativeTimeFormatter, RelativeTimeFormatterOptions, RelativeTimeUnit,
+};
+
+/// The units of the constructors such as [`CompositeRelativeTimeFormatter::try_new_long`],
+/// from the largest.
+const CLOCK_UNITS: &[RelativeTimeUnit] = &[
+    RelativeTimeUnit::Day,
+    RelativeTimeUnit::Hour,
+    RelativeTimeUnit::Minute,
+    RelativeTimeUnit::Second,
+];
+
+/// The units of the constructors such as
+/// [`CompositeRelativeTimeFormatter::try_new_long_calendar`], from the largest.
+const CALENDAR_UNITS: &[RelativeTimeUnit] = &[
+    RelativeTimeUnit::Year,
+    RelativeTimeUnit::Quarter,
+    RelativeTimeUnit::Month,
+];
+
+/// Options for [`CompositeRelativeTimeFormatter`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CompositeRelativeTimeFormatterOptions {
+    /// The maximum number of units in the result, such as 2 for "1 day, 2 hours ago".
+    ///
+    /// Smaller units are truncated. 0 is treated as 1. The default is 2.
+    pub max_units: u8,
+}
+
+impl Default for CompositeRelativeTimeFormatterOptions {
+    fn default() -> Self {
+        Self { max_units: 2 }
+    }
+}
+
+/// The text before and after the placeholder that all unit patterns of a tense share, such
+/// as "in " for the future and " ago" for the past in English.
+#[derive(Debug, Default)]
+struct Frame {
+    prefix: String,
+    suffix: String,
+}
+
+impl Frame {
+    /// Computes the frame of the patterns of one tense of all units.
+    ///
+    /// The prefix is the longest common prefix of the text before the placeholder, and the
+    /// suffix the longest common suffix of the text after it, over all plural forms of all
+    /// units. Patterns without a placeholder are not considered. Then:
+    /// * if the prefix contains whitespace, it is cut after its last whitespace, and if the
+    ///   suffix contains whitespace, it is cut before its first whitespace, so that words that
+    ///   only share their ending, such as Hungarian "nappal" and "órával", are not split;
+    /// * a full stop is never the last character of the prefix or the first of the suffix, as
+    ///   it belongs to an abbreviated unit, such as in "1 hr. ago".
+    fn new<'a>(mappings: impl Iterator<Item = &'a PluralRulesCategoryMapping<'a>>) -> Self {
+        let mut frame: Option<(&str, &str)> = None;
+        for (before, after) in mappings.flat_map(patterns).filter_map(split) {
+            frame = Some(match frame {
+                None => (before, after),
+                Some((prefix, suffix)) => {
+                    (common_prefix(prefix, before), common_suffix(suffix, after))
+                }
+            });
+        }
+        let (mut prefix, mut suffix) = frame.unwrap_or_default();
+        if let Some((index, whitespace)) =
+            prefix.char_indices().rev().find(|(_, c)| c.is_whitespace())
+        {
+            prefix = prefix
+                .get(..index + whitespace.len_utf8())
+                .unwrap_or(prefix);
+        }
+        if let Some(index) = suffix.find(char::is_whitespace) {
+            suffix = suffix.get(index..).unwrap_or(suffix);
+        }
+        Self {
+            prefix: String::from(prefix.trim_end_matches('.')),
+            suffix: String::from(suffix.trim_start_matches('.')),
+        }
+    }
+}
+
+fn patterns<'a>(
+    mapping: &'a PluralRulesCategoryMapping<'a>,
+) -> impl Iterator<Item = &'a SingularSubPattern<'a>> {
+    [
+        &mapping.zero,
+        &mapping.one,
+        &mapping.two,
+        &mapping.few,
+        &mapping.many,
+    ]
+    .into_iter()
+    .flatten()
+    .chain([&mapping.other])
+}
+
+/// Splits a pattern at its placeholder, returning `None` if it has none or if it is malformed.
+fn split<'a>(pattern: &'a SingularSubPattern<'a>) -> Option<(&'a str, &'a str)> {
+    if pattern.index == 255 {
+        return None;
+    }
+    let index = usize::from(pattern.index);
+    Some((pattern.pattern.get(..index)?, pattern.pattern.get(index..)?))
+}
+
+pub(super) fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(cmp::min(a.len(), b.len()), |((i, _), _)| i);
+    a.get(..len).unwrap_or_default()
+}
+
+pub(super) fn common_suffix<'a>(a: &'a str, b: &str) -> &'a str {
+    let start = a
+        .char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|((_, x), y)| x == y)
+        .last()
+        .map_or(a.len(), |((i, _), _)| i);
+    a.get(start..).unwrap_or_default()
+}
+
+/// Formats a duration with several units relative to now, such as "1 hour, 20 minutes ago".
+///
+/// The duration is split into days, hours, minutes and seconds, of which the
+/// [`max_units`](CompositeRelativeTimeFormatterOptions::max_units) largest nonzero ones are
+/// shown. Days are the largest unit, as longer units do not have a fixed length in seconds.
+/// The calendar constructors, such as [`Self::try_new_long_calendar`], instead split a
+/// number of months into years, quarters and months, as in "2 years, 3 quarters ago". See
+/// [`RelativeTimeUnit::decompose`] for the splitting.
+///
+/// The units are joined with the unit list patterns of the locale, and the list is placed in a
+/// frame that says whether the time is in the past or in the future. There is no data for
+/// frames, so it is derived from the relative time patterns of the units: the frame is the
+/// text before and after the placeholder `{0}` that all patterns of the tense have in common,
+/// and the rest of each pattern presents the quantity of its unit. For example, in German the
+/// past patterns `vor {0} Stunden` and `vor {0} Minuten` give the frame `vor {0}`, and "80
+/// minutes ago" is formatted as "vor 1 Stunde, 20 Minuten". A placeholder in the middle of
+/// the pattern works the same way, such as `{0} 時間前` and `{0} 分前` in Japanese, which
+/// give the frame `{0}前`. The quantities use the plural forms of the tense, so they agree with
+/// the frame, such as the German dative in "vor 2 Tagen".
+///
+/// # Example
+///
+/// ```
+/// use icu::experimental::relativetime::{
+///     CompositeRelativeTimeFormatter, CompositeRelativeTimeFormatterOptions,
+/// };
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = CompositeRelativeTimeFormatter::try_new_long(
+///     &locale!("en").into(),
+///     CompositeRelativeTimeFormatterOptions::default(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_writeable_eq!(formatter.format(80 * 60), "in 1 hour, 20 minutes");
+/// assert_writeable_eq!(
+///     formatter.format(-(26 * 60 * 60 + 30)),
+///     "1 day, 2 hours ago"
+/// );
+/// assert_writeable_eq!(formatter.format(-45), "45 seconds ago");
+///
+/// let formatter = CompositeRelativeTimeFormatter::try_new_long_calendar(
+///     &locale!("en").into(),
+///     CompositeRelativeTimeFormatterOptions::default(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_writeable_eq!(formatter.format(-33), "2 years, 3 quarters ago");
+/// ```
+#[derive(Debug)]
+pub struct CompositeRelativeTimeFormatter {
+    /// The formatters for `unit_kinds`
+    units: Vec<RelativeTimeFormatter>,
+    /// Either [`CLOCK_UNITS`] or [`CALENDAR_UNITS`]
+    unit_kinds: &'static [RelativeTimeUnit],
+    list_formatter: ListFormatter,
+    past: Frame,
+    future: Frame,
+    max_units: usize,
+}
+
+impl<P> icu_provider::constructors::RequiredKeys<P> for CompositeRelativeTimeFormatter where
+    P: DataProvider<CardinalV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<UnitListV1Marker>
+        + DataProvider<LongSecondRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongMinuteRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongHourRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongDayRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongMonthRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongQuarterRelativeTimeFormatDataV1Marker>
+        + DataProvider<LongYearRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortSecondRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortMinuteRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortHourRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortDayRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortMonthRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortQuarterRelativeTimeFormatDataV1Marker>
+        + DataProvider<ShortYearRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowSecondRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowMinuteRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowHourRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowDayRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowMonthRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowQuarterRelativeTimeFormatDataV1Marker>
+        + DataProvider<NarrowYearRelativeTimeFormatDataV1Marker>
+        + ?Sized
+{
+}
+
+macro_rules! constructor {
+    (
+        $baked: ident,
+        $unstable: ident,
+        $length: expr,
+        $unit_kinds: expr,
+        [$($unit_baked: ident),+],
+        [$($unit_unstable: ident),+],
+        [$($marker: ty),+]
+    ) => {
+        /// Creates a new [`CompositeRelativeTimeFormatter`] from compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        pub fn $baked(
+            locale: &DataLocale,
+            options: CompositeRelativeTimeFormatterOptions,
+        ) -> Result<Self, RelativeTimeError> {
+            let unit_options = RelativeTimeFormatterOptions::default();
+            Ok(Self::from_parts(
+                alloc::vec![$(RelativeTimeFormatter::$unit_baked(locale, unit_options)?),+],
+                $unit_kinds,
+                ListFormatter::try_new_unit_with_length(locale, $length)?,
+                options,
+            ))
+        }
+
+        #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::$baked)]
+        pub fn $unstable<P>(
+            provider: &P,
+            locale: &DataLocale,
+            options: CompositeRelativeTimeFormatterOptions,
+        ) -> Result<Self, RelativeTimeError>
+        where
+            P: DataProvider<CardinalV1Marker>
+                + DataProvider<DecimalSymbolsV1Marker>
+                + DataProvider<UnitListV1Marker>
+                $(+ DataProvider<$marker>)+
+                + ?Sized,
+        {
+            let unit_options = RelativeTimeFormatterOptions::default();
+            Ok(Self::from_parts(
+                alloc::vec![$(RelativeTimeFormatter::$unit_unstable(provider, locale, unit_options)?),+],
+                $unit_kinds,
+                ListFormatter::try_new_unit_with_length_unstable(provider, locale, $length)?,
+                options,
+            ))
+        }
+    };
+}
+
+impl CompositeRelativeTimeFormatter {
+    constructor!(
+        try_new_long,
+        try_new_long_unstable,
+        ListLength::Wide,
+        CLOCK_UNITS,
+        [
+            try_new_long_day,
+            try_new_long_hour,
+            try_new_long_minute,
+            try_new_long_second
+        ],
+        [
+            try_new_long_day_unstable,
+            try_new_long_hour_unstable,
+            try_new_long_minute_unstable,
+            try_new_long_second_unstable
+        ],
+        [
+            LongDayRelativeTimeFormatDataV1Marker,
+            LongHourRelativeTimeFormatDataV1Marker,
+            LongMinuteRelativeTimeFormatDataV1Marker,
+            LongSecondRelativeTimeFormatDataV1Marker
+        ]
+    );
+    constructor!(
+        try_new_short,
+        try_new_short_unstable,
+        ListLength::Short,
+        CLOCK_UNITS,
+        [
+            try_new_short_day,
+            try_new_short_hour,
+            try_new_short_minute,
+            try_new_short_second
+        ],
+        [
+            try_new_short_day_unstable,
+            try_new_short_hour_unstable,
+            try_new_short_minute_unstable,
+            try_new_short_second_unstable
+        ],
+        [
+            ShortDayRelativeTimeFormatDataV1Marker,
+            ShortHourRelativeTimeFormatDataV1Marker,
+            ShortMinuteRelativeTimeFormatDataV1Marker,
+            ShortSecondRelativeTimeFormatDataV1Marker
+        ]
+    );
+    constructor!(
+        try_new_narrow,
+        try_new_narrow_unstable,
+        ListLength::Narrow,
+        CLOCK_UNITS,
+        [
+            try_new_narrow_day,
+            try_new_narrow_hour,
+            try_new_narrow_minute,
+            try_new_narrow_second
+        ],
+        [
+            try_new_narrow_day_unstable,
+            try_new_narrow_hour_unstable,
+            try_new_narrow_minute_unstable,
+            try_new_narrow_second_unstable
+        ],
+        [
+            NarrowDayRelativeTimeFormatDataV1Marker,
+            NarrowHourRelativeTimeFormatDataV1Marker,
+            NarrowMinuteRelativeTimeFormatDataV1Marker,
+            NarrowSecondRelativeTimeFormatDataV1Marker
+        ]
+    );
+
+    constructor!(
+        try_new_long_calendar,
+        try_new_long_calendar_unstable,
+        ListLength::Wide,
+        CALENDAR_UNITS,
+        [try_new_long_year, try_new_long_quarter, try_new_long_month],
+        [
+            try_new_long_year_unstable,
+            try_new_long_quarter_unstable,
+            try_new_long_month_unstable
+        ],
+        [
+            LongYearRelativeTimeFormatDataV1Marker,
+            LongQuarterRelativeTimeFormatDataV1Marker,
+            LongMonthRelativeTimeFormatDataV1Marker
+        ]
+    );
+    constructor!(
+        try_new_short_calendar,
+        try_new_short_calendar_unstable,
+        ListLength::Short,
+        CALENDAR_UNITS,
+        [
+            try_new_short_year,
+            try_new_short_quarter,
+            try_new_short_month
+        ],
+        [
+            try_new_short_year_unstable,
+            try_new_short_quarter_unstable,
+            try_new_short_month_unstable
+        ],
+        [
+            ShortYearRelativeTimeFormatDataV1Marker,
+            ShortQuarterRelativeTimeFormatDataV1Marker,
+            ShortMonthRelativeTimeFormatDataV1Marker
+        ]
+    );
+    constructor!(
+        try_new_narrow_calendar,
+        try_new_narrow_calendar_unstable,
+        ListLength::Narrow,
+        CALENDAR_UNITS,
+        [
+            try_new_narrow_year,
+            try_new_narrow_quarter,
+            try_new_narrow_month
+        ],
+        [
+            try_new_narrow_year_unstable,
+            try_new_narrow_quarter_unstable,
+            try_new_narrow_month_unstable
+        ],
+        [
+            NarrowYearRelativeTimeFormatDataV1Marker,
+            NarrowQuarterRelativeTimeFormatDataV1Marker,
+            NarrowMonthRelativeTimeFormatDataV1Marker
+        ]
+    );
+
+    fn from_parts(
+        units: Vec<RelativeTimeFormatter>,
+        unit_kinds: &'static [RelativeTimeUnit],
+        list_formatter: ListFormatter,
+        options: CompositeRelativeTimeFormatterOptions,
+    ) -> Self {
+        let past = Frame::new(units.iter().map(|unit| &unit.rt.get().past));
+        let future = Frame::new(units.iter().map(|unit| &unit.rt.get().future));
+        Self {
+            units,
+            unit_kinds,
+            list_formatter,
+            past,
+            future,
+            max_units: cmp::max(options.max_units, 1).into(),
+        }
+    }
+
+    /// Formats a duration relative to now; negative values are in the past.
+    ///
+    /// The duration is a number of seconds, or a number of months for the calendar
+    /// constructors, such as [`Self::try_new_long_calendar`].
+    pub fn format(&self, value: i64) -> FormattedCompositeRelativeTime<'_> {
+        let base = match self.unit_kinds.last() {
+            Some(&smallest) => smallest,
+            None => RelativeTimeUnit::Second,
+        };
+        let mut quantities: Vec<_> = base
+            .decompose(value, self.unit_kinds)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(kind, quantity)| {
+                let index = self.unit_kinds.iter().position(|&k| k == kind)?;
+                Some((
+                    self.units.get(index)?,
+                    FixedDecimal::from(quantity.unsigned_abs()),
+                ))
+            })
+            .take(self.max_units)
+            .collect();
+        if quantities.is_empty() {
+            if let Some(smallest) = self.units.last() {
+                quantities.push((smallest, FixedDecimal::from(0)));
+            }
+        }
+        FormattedCompositeRelativeTime {
+            formatter: self,
+            quantities,
+            is_negative: value < 0,
+        }
+    }
+}
+
+/// The result of [`CompositeRelativeTimeFormatter::format`], which can be written to a string
+/// or buffer with the [`Writeable`] trait.
+#[derive(Debug)]
+pub struct FormattedCompositeRelativeTime<'a> {
+    formatter: &'a CompositeRelativeTimeFormatter,
+    quantities: Vec<(&'a RelativeTimeFormatter, FixedDecimal)>,
+    is_negative: bool,
+}
+
+impl Writeable for FormattedCompositeRelativeTime<'_> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let frame = if self.is_negative {
+            &self.formatter.past
+        } else {
+            &self.formatter.future
+        };
+        sink.write_str(&frame.prefix)?;
+        let last = self.quantities.len().saturating_sub(1);
+        self.formatter
+            .list_formatter
+            .format(
+                self.quantities
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (unit, value))| Quantity {
+                        unit,
+                        value,
+                        frame,
+                        is_negative: self.is_negative,
+                        is_first: index == 0,
+                        is_last: index == last,
+                    }),
+            )
+            .write_to(sink)?;
+        sink.write_str(&frame.suffix)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCompositeRelativeTime<'_>);
+
+/// The quantity of one unit, which is its relative time pattern without the frame.
+///
+/// Whitespace between the quantity and the frame is only kept next to the frame, so that it
+/// does not end up between the items of the list, such as after "ชม." in Thai.
+#[derive(Clone)]
+struct Quantity<'a> {
+    unit: &'a RelativeTimeFormatter,
+    value: &'a FixedDecimal,
+    frame: &'a Frame,
+    is_negative: bool,
+    is_first: bool,
+    is_last: bool,
+}
+
+impl Writeable for Quantity<'_> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let data = self.unit.rt.get();
+        let mapping = if self.is_negative {
+            &data.past
+        } else {
+            &data.future
+        };
+        let pattern = match self.unit.plural_rules.category_for(self.value) {
+            PluralCategory::Zero => &mapping.zero,
+            PluralCategory::One => &mapping.one,
+            PluralCategory::Two => &mapping.two,
+            PluralCategory::Few => &mapping.few,
+            PluralCategory::Many => &mapping.many,
+            PluralCategory::Other => &None,
+        }
+        .as_ref()
+        .unwrap_or(&mapping.other);
+
+        // Patterns without a placeholder, such as Hebrew "אתמול" (yesterday), do not fit
+        // into the frame, so the numeric pattern is used instead
+        let Some((before, after)) = split(pattern).or_else(|| split(&mapping.other)) else {
+            return sink.write_str(self.trim(&pattern.pattern));
+        };
+        sink.write_str(self.trim_start(before))?;
+        self.unit
+            .fixed_decimal_format
+            .format(self.value)
+            .write_to(sink)?;
+        sink.write_str(self.trim_end(after))
+    }
+}
+
+impl Quantity<'_> {
+    fn trim<'a>(&self, text: &'a str) -> &'a str {
+        self.trim_end(self.trim_start(text))
+    }
+
+    /// Removes the prefix of the frame, and the whitespace after it if this is not the
+    /// first quantity.
+    fn trim_start<'a>(&self, text: &'a str) -> &'a str {
+        let text = text.strip_prefix(&*self.frame.prefix).unwrap_or(text);
+        if self.is_first {
+            text
+        } else {
+            text.trim_start()
+        }
+    }
+
+    /// Removes the suffix of the frame, and the whitespace before it if this is not the
+    /// last quantity.
+    fn trim_end<'a>(&self, text: &'a str) -> &'a str {
+        let text = text.strip_suffix(&*self.frame.suffix).unwrap_or(text);
+        if self.is_last {
+            text
+        } else {
+            text.trim_end()
+        }
+    }
+}
@@ -3,9 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use displaydoc::Display;
+#[cfg(feature = "relativetime_namer")]
+use icu_datetime::DateTimeError;
 use icu_decimal::DecimalError;
+use icu_list::ListError;
 use icu_plurals::PluralsError;
 use icu_provider::DataError;
+use tinystr::TinyAsciiStr;
 
 /// A list of error outcomes for various operations in this module.
 ///
@@ -22,6 +26,19 @@ pub enum RelativeTimeError {
     /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
     #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
     Decimal(DecimalError),
+    /// An error originating from [`DateFormatter`](icu_datetime::DateFormatter).
+    #[cfg(feature = "relativetime_namer")]
+    #[displaydoc("Error loading DateFormatter: {0}")]
+    DateTime(DateTimeError),
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("Error loading ListFormatter: {0}")]
+    List(ListError),
+    /// A pattern override has an invalid placeholder.
+    #[displaydoc("Invalid pattern")]
+    InvalidPattern,
+    /// The numbering system option is not the name of a numeric numbering system.
+    #[displaydoc("Unsupported numbering system: {0}")]
+    UnsupportedNumberingSystem(TinyAsciiStr<8>),
 }
 
 impl From<PluralsError> for RelativeTimeError {
@@ -41,3 +58,16 @@ impl From<DecimalError> for RelativeTimeError {
         RelativeTimeError::Decimal(e)
     }
 }
+
+#[cfg(feature = "relativetime_namer")]
+impl From<DateTimeError> for RelativeTimeError {
+    fn from(e: DateTimeError) -> Self {
+        RelativeTimeError::DateTime(e)
+    }
+}
+
+impl From<ListError> for RelativeTimeError {
+    fn from(e: ListError) -> Self {
+        RelativeTimeError::List(e)
+    }
+}
@@ -4,11 +4,15 @@
 
 use alloc::fmt::Write;
 
-use fixed_decimal::FixedDecimal;
+use alloc::string::String;
+use fixed_decimal::{FixedDecimal, Sign, SignDisplay};
+use icu_decimal::FormattedFixedDecimal;
 use writeable::Writeable;
 
 use crate::relativetime::{
-    options::{Numeric, RelativeTimeFormatterOptions},
+    composite::{common_prefix, common_suffix},
+    options::{Numeric, RelativeTimeFormatterOptions, UnitDisplay},
+    provider::SingularSubPattern,
     relativetime::RelativeTimeFormatter,
 };
 
@@ -25,6 +29,7 @@ pub mod parts {
 
 /// An intermediate structure returned by [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
 /// This structure can be consumed via [`Writeable`](Writeable) trait to a string or buffer.
+#[derive(Debug)]
 pub struct FormattedRelativeTime<'a> {
     pub(crate) formatter: &'a RelativeTimeFormatter,
     pub(crate) options: &'a RelativeTimeFormatterOptions,
@@ -32,22 +37,251 @@ pub struct FormattedRelativeTime<'a> {
     pub(crate) is_negative: bool,
 }
 
-impl<'a> Writeable for FormattedRelativeTime<'a> {
-    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
-        if self.options.numeric == Numeric::Auto {
-            let relatives = &self.formatter.rt.get().relatives;
-            if self.value.magnitude_range() == (0..=0) {
-                // Can be cast without overflow as it is a single digit.
-                let i8_value = if self.is_negative {
-                    -(self.value.digit_at(0) as i8)
+/// Where the text of a [`FormattedRelativeTime`] comes from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RelativeTimeForm {
+    /// A name for the offset from the data, such as "yesterday" or "last quarter", used with
+    /// [`Numeric::Auto`].
+    Lexical,
+    /// A past or future pattern with the number, such as "3 days ago" or "in 1 quarter".
+    ///
+    /// A few locales have patterns without a placeholder for some plural forms; these are
+    /// still numeric, as the number is implied by the plural form.
+    Numeric,
+}
+
+impl<'a> FormattedRelativeTime<'a> {
+    /// Returns whether the result is a name for the offset, such as "yesterday", or uses a
+    /// pattern with a number, such as "1 day ago".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::experimental::relativetime::options::Numeric;
+    /// use icu::experimental::relativetime::{
+    ///     RelativeTimeForm, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    /// };
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let relative_time_formatter = RelativeTimeFormatter::try_new_long_day(
+    ///     &locale!("en").into(),
+    ///     RelativeTimeFormatterOptions::from(Numeric::Auto),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let formatted = relative_time_formatter.format(FixedDecimal::from(-1));
+    /// assert_writeable_eq!(formatted, "yesterday");
+    /// assert_eq!(formatted.form(), RelativeTimeForm::Lexical);
+    ///
+    /// let formatted = relative_time_formatter.format(FixedDecimal::from(-3));
+    /// assert_writeable_eq!(formatted, "3 days ago");
+    /// assert_eq!(formatted.form(), RelativeTimeForm::Numeric);
+    /// ```
+    pub fn form(&self) -> RelativeTimeForm {
+        if self.relative().is_some() {
+            RelativeTimeForm::Lexical
+        } else {
+            RelativeTimeForm::Numeric
+        }
+    }
+
+    /// Returns the name for the value if [`Numeric::Auto`] is used and the data has one.
+    fn relative(&self) -> Option<&'a str> {
+        if self.options.numeric != Numeric::Auto
+            || self.value.magnitude_range() != (0..=0)
+            || self.compact_unit().is_some()
+        {
+            return None;
+        }
+        // Can be cast without overflow as it is a single digit.
+        let i8_value = if self.is_negative {
+            -(self.value.digit_at(0) as i8)
+        } else {
+            self.value.digit_at(0) as i8
+        };
+        self.formatter.rt.get().relatives.get(&i8_value)
+    }
+
+    /// Returns the unit for [`UnitDisplay::Compact`], and whether it follows the number, if
+    /// that option is used and the patterns share one.
+    fn compact_unit(&self) -> Option<(&'a str, bool)> {
+        if self.options.unit_display != UnitDisplay::Compact {
+            return None;
+        }
+        let data = self.formatter.rt.get();
+        let (past, past_is_after) = unit_next_to_placeholder(&data.past.other)?;
+        let (future, future_is_after) = unit_next_to_placeholder(&data.future.other)?;
+        if past_is_after != future_is_after {
+            return None;
+        }
+        let unit = if past_is_after {
+            common_prefix(past, future)
+        } else {
+            common_suffix(past, future)
+        };
+        (!unit.trim().is_empty()).then_some((unit, past_is_after))
+    }
+}
+
+/// Returns the word of the pattern next to the placeholder, and whether it follows the
+/// placeholder.
+///
+/// A word that touches the placeholder is preferred over one separated by whitespace, which is
+/// returned with that whitespace, and one after the placeholder over one before it.
+fn unit_next_to_placeholder<'a>(pattern: &'a SingularSubPattern<'a>) -> Option<(&'a str, bool)> {
+    // 255 is used to denote a string without placeholder '{0}'.
+    if pattern.index == 255 {
+        return None;
+    }
+    let index = usize::from(pattern.index);
+    let before = pattern.pattern.get(..index)?;
+    let after = pattern.pattern.get(index..)?;
+    let word_after = |skip_whitespace: bool| {
+        let start = if skip_whitespace {
+            after.len() - after.trim_start().len()
+        } else {
+            0
+        };
+        let end = after
+            .get(start..)?
+            .find(char::is_whitespace)
+            .map_or(after.len(), |i| start + i);
+        Some((after.get(..end)?, true))
+    };
+    let word_before = |skip_whitespace: bool| {
+        let end = if skip_whitespace {
+            before.trim_end().len()
+        } else {
+            before.len()
+        };
+        let start = before
+            .get(..end)?
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        Some((before.get(start..)?, false))
+    };
+    [
+        word_after(false),
+        word_before(false),
+        word_after(true),
+        word_before(true),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|(word, _)| !word.trim().is_empty())
+}
+
+/// Returns whether the text is empty or only contains zero-width characters.
+fn is_invisible(text: &str) -> bool {
+    text.chars().all(|c| {
+        matches!(
+            c,
+            '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+        )
+    })
+}
+
+/// A [`FormattedRelativeTime`] whose number is transformed by a closure, returned by
+/// [`FormattedRelativeTime::map_number`].
+pub struct MappedRelativeTime<'a, F> {
+    formatted: FormattedRelativeTime<'a>,
+    map: F,
+}
+
+impl<'a, F> core::fmt::Debug for MappedRelativeTime<'a, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedRelativeTime")
+            .field("formatted", &self.formatted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> FormattedRelativeTime<'a> {
+    /// Transforms the formatted number with `map` before it is inserted into the pattern, for
+    /// example to wrap it in markup.
+    ///
+    /// `map` is called with the formatted number, including its sign for
+    /// [`UnitDisplay::Compact`], and only sees that text: the text of the pattern around it is
+    /// written unchanged. It is not called for names like "yesterday", which have no number.
+    /// The number is written as a literal part, without the parts of the number formatter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::experimental::relativetime::{
+    ///     RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    /// };
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let relative_time_formatter = RelativeTimeFormatter::try_new_long_day(
+    ///     &locale!("en").into(),
+    ///     RelativeTimeFormatterOptions::default(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let formatted = relative_time_formatter
+    ///     .format(FixedDecimal::from(-1200))
+    ///     .map_number(|number| format!("<span class=\"num\">{number}</span>"));
+    /// assert_writeable_eq!(formatted, "<span class=\"num\">1,200</span> days ago");
+    /// ```
+    pub fn map_number<F>(self, map: F) -> MappedRelativeTime<'a, F>
+    where
+        F: Fn(&str) -> String,
+    {
+        MappedRelativeTime {
+            formatted: self,
+            map,
+        }
+    }
+
+    /// Writes the relative time, transforming the formatted number with `map` if it is given.
+    fn write_parts_with<S: writeable::PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+        map: Option<&dyn Fn(&str) -> String>,
+    ) -> core::fmt::Result {
+        // Writes the number, returning whether nothing visible was written.
+        let write_number = |number: FormattedFixedDecimal, sink: &mut S| match map {
+            Some(map) => {
+                let mapped = map(&number.write_to_string());
+                sink.with_part(parts::LITERAL, |s| s.write_str(&mapped))?;
+                Ok(is_invisible(&mapped))
+            }
+            None => {
+                number.write_to_parts(sink)?;
+                Ok(self.options.trim_empty_substitution && is_invisible(&number.write_to_string()))
+            }
+        };
+
+        if let Some((unit, is_after)) = self.compact_unit() {
+            let value = self
+                .value
+                .clone()
+                .with_sign(if self.is_negative {
+                    Sign::Negative
                 } else {
-                    self.value.digit_at(0) as i8
-                };
-                if let Some(v) = relatives.get(&i8_value) {
-                    sink.with_part(parts::LITERAL, |s| s.write_str(v))?;
-                    return Ok(());
-                }
+                    Sign::Positive
+                })
+                .with_sign_display(SignDisplay::ExceptZero);
+            if !is_after {
+                sink.with_part(parts::LITERAL, |s| s.write_str(unit))?;
             }
+            write_number(self.formatter.fixed_decimal_format.format(&value), sink)?;
+            if is_after {
+                sink.with_part(parts::LITERAL, |s| s.write_str(unit))?;
+            }
+            return Ok(());
+        }
+
+        if let Some(v) = self.relative() {
+            return sink.with_part(parts::LITERAL, |s| s.write_str(v));
         }
 
         let plural_rules_mapping = if self.is_negative {
@@ -65,6 +299,13 @@ impl<'a> Writeable for FormattedRelativeTime<'a> {
             icu_plurals::PluralCategory::Other => &None,
         };
 
+        #[cfg(all(debug_assertions, feature = "log"))]
+        if singular_sub_pattern.is_none() && category != icu_plurals::PluralCategory::Other {
+            log::warn!(
+                "Relative time data has no pattern for plural category {category:?}, using `other`"
+            );
+        }
+
         // Default to using PluralCategory::Other mapping.
         let singular_sub_pattern = singular_sub_pattern
             .as_ref()
@@ -76,20 +317,33 @@ impl<'a> Writeable for FormattedRelativeTime<'a> {
                 s.write_str(&singular_sub_pattern.pattern)
             })?;
         } else {
-            let (prefix, suffix) =
-                if singular_sub_pattern.index as usize <= singular_sub_pattern.pattern.len() {
-                    singular_sub_pattern
-                        .pattern
-                        .split_at(singular_sub_pattern.index as usize)
-                } else {
-                    return Err(core::fmt::Error);
-                };
+            // Malformed data can place the index outside of the pattern or inside of a
+            // character. GIGO: the number is appended to the pattern instead.
+            let index = singular_sub_pattern.index as usize;
+            let (prefix, suffix) = match (
+                singular_sub_pattern.pattern.get(..index),
+                singular_sub_pattern.pattern.get(index..),
+            ) {
+                (Some(prefix), Some(suffix)) => (prefix, suffix),
+                _ => {
+                    debug_assert!(false, "invalid placeholder index {index}");
+                    (&*singular_sub_pattern.pattern, "")
+                }
+            };
 
             sink.with_part(parts::LITERAL, |s| s.write_str(prefix))?;
-            self.formatter
-                .fixed_decimal_format
-                .format(&self.value)
-                .write_to_parts(sink)?;
+            let is_invisible = write_number(
+                self.formatter.fixed_decimal_format.format(&self.value),
+                sink,
+            )?;
+            let suffix = if self.options.trim_empty_substitution
+                && is_invisible
+                && prefix.ends_with(char::is_whitespace)
+            {
+                suffix.strip_prefix(char::is_whitespace).unwrap_or(suffix)
+            } else {
+                suffix
+            };
             sink.with_part(parts::LITERAL, |s| s.write_str(suffix))?;
         }
 
@@ -97,4 +351,31 @@ impl<'a> Writeable for FormattedRelativeTime<'a> {
     }
 }
 
+impl<'a> Writeable for FormattedRelativeTime<'a> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        self.write_parts_with(sink, None)
+    }
+}
+
+impl<'a, F> Writeable for MappedRelativeTime<'a, F>
+where
+    F: Fn(&str) -> String,
+{
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        self.formatted.write_parts_with(sink, Some(&self.map))
+    }
+}
+
 writeable::impl_display_with_writeable!(FormattedRelativeTime<'_>);
+
+/// This trait is implemented for compatibility with [`fmt!`](alloc::fmt).
+/// To create a string, [`Writeable::write_to_string`] is usually more efficient.
+impl<'a, F> core::fmt::Display for MappedRelativeTime<'a, F>
+where
+    F: Fn(&str) -> String,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f)
+    }
+}
@@ -0,0 +1,52 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+/// The major CLDR version that relative time formatting was last tested with.
+///
+/// Keep in sync with `DatagenProvider::LATEST_TESTED_CLDR_TAG`.
+#[cfg(all(feature = "compiled_data", debug_assertions))]
+const LATEST_TESTED_CLDR_MAJOR_VERSION: u16 = 45;
+
+/// The number of CLDR releases that compiled data may lag behind before it is considered stale.
+///
+/// CLDR has two releases per year, so this is about a year.
+#[cfg(all(feature = "compiled_data", debug_assertions))]
+const MAX_CLDR_RELEASES_BEHIND: u16 = 2;
+
+/// Returns the CLDR version of the compiled relative time data, such as `"45.0.0"`, or `None`
+/// if custom data is compiled in, whose version is unknown.
+///
+/// In debug builds, this logs a warning if the data is more than two CLDR releases older than
+/// the release that this crate was tested with. Logging requires the `logging` Cargo feature of
+/// `icu_provider`.
+///
+/// ✨ *Enabled with the `compiled_data` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::relativetime::check_data_freshness;
+///
+/// if let Some(version) = check_data_freshness() {
+///     assert!(version.starts_with(char::is_numeric));
+/// }
+/// ```
+#[cfg(feature = "compiled_data")]
+pub fn check_data_freshness() -> Option<&'static str> {
+    let version = icu_experimental_data::check_data_freshness();
+    #[cfg(debug_assertions)]
+    if let Some(major) = version
+        .and_then(|v| v.split('.').next())
+        .and_then(|major| major.parse::<u16>().ok())
+    {
+        if major.saturating_add(MAX_CLDR_RELEASES_BEHIND) < LATEST_TESTED_CLDR_MAJOR_VERSION {
+            icu_provider::_internal::log::warn!(
+                "Relative time data is from CLDR {major}, which is more than \
+                 {MAX_CLDR_RELEASES_BEHIND} releases older than CLDR \
+                 {LATEST_TESTED_CLDR_MAJOR_VERSION}. Consider updating the data."
+            );
+        }
+    }
+    version
+}
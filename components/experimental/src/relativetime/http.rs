@@ -0,0 +1,181 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::buf::BufferFormat;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use std::io::Read;
+use std::string::{String, ToString};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A [`BufferProvider`] that fetches relative time data from an HTTP server, so that the data
+/// can be updated without a new release of the application.
+///
+/// The URL of each request is built from a pattern with two placeholders:
+/// * `{locale}` is replaced by the requested locale, such as `en` or `sr-Latn`;
+/// * `{unit}` is replaced by the width and unit of the data key, such as `long/second` for
+///   `relativetime/long/second@1`.
+///
+/// The server must respond with the postcard-encoded
+/// [`RelativeTimePatternDataV1`](super::provider::RelativeTimePatternDataV1) for that locale
+/// and unit, and with status 404 if it does not have the locale. Keys that are not relative
+/// time keys result in a [`DataErrorKind::MissingDataKey`] error without a request.
+///
+/// Responses are cached for the given time to live. If a request fails because of a network
+/// error, a cached response is returned even if it is older than that.
+///
+/// This provider does not perform locale fallback; wrap it in a
+/// [`LocaleFallbackProvider`](https://docs.rs/icu_provider_adapters/latest/icu_provider_adapters/fallback/struct.LocaleFallbackProvider.html)
+/// if the server only has data for some locales.
+///
+/// ✨ *Enabled with the `http` Cargo feature.*
+///
+/// # Examples
+///
+/// ```no_run
+/// use icu::experimental::relativetime::provider::LongDayRelativeTimeFormatDataV1Marker;
+/// use icu::experimental::relativetime::HttpRelativeTimeProvider;
+/// use icu::locid::locale;
+/// use icu_provider::prelude::*;
+/// use std::time::Duration;
+///
+/// let provider = HttpRelativeTimeProvider::try_new(
+///     "https://cdn.example.com/icu4x/relativetime/{locale}/{unit}.postcard",
+///     Duration::from_secs(24 * 60 * 60),
+/// )
+/// .expect("the pattern is valid");
+///
+/// // Fetches https://cdn.example.com/icu4x/relativetime/en/long/day.postcard
+/// let payload: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+///     .as_deserializing()
+///     .load(DataRequest {
+///         locale: &locale!("en").into(),
+///         metadata: Default::default(),
+///     })
+///     .expect("the server has data for en")
+///     .take_payload()
+///     .expect("the response has a payload");
+/// ```
+///
+/// The constructors of [`RelativeTimeFormatter`](super::RelativeTimeFormatter) also load
+/// plural rules and decimal symbols, which this provider does not serve. Combine it with a
+/// provider for those keys, for example with a `ForkByKeyProvider`.
+#[derive(Debug)]
+pub struct HttpRelativeTimeProvider {
+    url_pattern: String,
+    ttl: Duration,
+    agent: ureq::Agent,
+    cache: Mutex<HashMap<(DataKey, DataLocale), CacheEntry>>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    payload: DataPayload<BufferMarker>,
+    fetched_at: Instant,
+}
+
+impl HttpRelativeTimeProvider {
+    const KEY_PREFIX: &'static str = "relativetime/";
+
+    /// Creates a provider that fetches data from URLs built from `url_pattern`, caching
+    /// responses for `ttl`.
+    ///
+    /// Returns an error if the pattern does not contain both the `{locale}` and the `{unit}`
+    /// placeholders.
+    pub fn try_new(url_pattern: &str, ttl: Duration) -> Result<Self, DataError> {
+        if !url_pattern.contains("{locale}") || !url_pattern.contains("{unit}") {
+            return Err(DataError::custom("URL pattern needs {locale} and {unit}")
+                .with_display_context(url_pattern));
+        }
+        Ok(Self {
+            url_pattern: url_pattern.to_string(),
+            ttl,
+            agent: ureq::AgentBuilder::new().build(),
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Returns `Ok(None)` if the server does not have the resource.
+    fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>, DataError> {
+        let response = match self.agent.get(url).call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(e) => return Err(DataError::custom("HTTP request failed").with_display_context(&e)),
+        };
+        let mut buffer = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut buffer)
+            .map_err(|e| DataError::from(e).with_display_context(url))?;
+        Ok(Some(buffer))
+    }
+}
+
+impl BufferProvider for HttpRelativeTimeProvider {
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        let Some(unit) = key
+            .path()
+            .get()
+            .strip_prefix(Self::KEY_PREFIX)
+            .and_then(|path| path.split_once('@'))
+            .map(|(unit, _version)| unit)
+        else {
+            return Err(DataErrorKind::MissingDataKey.with_req(key, req));
+        };
+
+        let mut metadata = DataResponseMetadata::default();
+        metadata.buffer_format = Some(BufferFormat::Postcard1);
+
+        let cache_key = (key, req.locale.clone());
+        let cached = self.cache.lock().ok().and_then(|cache| {
+            cache
+                .get(&cache_key)
+                .map(|entry| (entry.payload.clone(), entry.fetched_at.elapsed() < self.ttl))
+        });
+        if let Some((payload, true)) = cached {
+            return Ok(DataResponse {
+                metadata,
+                payload: Some(payload),
+            });
+        }
+
+        let url = self
+            .url_pattern
+            .replace("{locale}", &req.locale.to_string())
+            .replace("{unit}", unit);
+        let payload = match self.fetch(&url) {
+            Ok(Some(buffer)) => DataPayload::from_owned_buffer(buffer.into_boxed_slice()),
+            Ok(None) => return Err(DataErrorKind::MissingLocale.with_req(key, req)),
+            Err(error) => {
+                return match cached {
+                    // Serve stale data while the server is unreachable
+                    Some((payload, _)) => Ok(DataResponse {
+                        metadata,
+                        payload: Some(payload),
+                    }),
+                    None => Err(error.with_req(key, req)),
+                };
+            }
+        };
+
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(
+                cache_key,
+                CacheEntry {
+                    payload: payload.clone(),
+                    fetched_at: Instant::now(),
+                },
+            );
+        }
+        Ok(DataResponse {
+            metadata,
+            payload: Some(payload),
+        })
+    }
+}
@@ -3,18 +3,63 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! Relative time formatting
+//!
+//! # `no_std`
+//!
+//! Formatting only needs `core` and `alloc`, so the formatter can be used without the `std`
+//! Cargo feature, for example in WebAssembly or on embedded targets.
+//!
+//! # Panics
+//!
+//! Neither construction nor formatting panics. The module denies `unwrap`, `expect`, `panic!`
+//! and indexing outside of tests. Malformed data does not cause a panic either:
+//! * a pattern whose placeholder index falls outside of the pattern, or inside of a character,
+//!   is written with the number appended, and formatting still succeeds. This is a debug
+//!   assertion failure;
+//! * a placeholder at or past byte 255 of a pattern is rejected with a
+//!   [`DataError`](icu_provider::DataError) when the pattern is parsed.
 
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
 #![warn(missing_docs)]
 
+mod composite;
 mod error;
 mod format;
+mod freshness;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "relativetime_namer")]
+mod namer;
+mod numbering;
 pub mod options;
 pub mod provider;
 mod relativetime;
 
+pub use composite::{
+    CompositeRelativeTimeFormatter, CompositeRelativeTimeFormatterOptions,
+    FormattedCompositeRelativeTime,
+};
 pub use error::RelativeTimeError;
-pub use format::FormattedRelativeTime;
+pub use format::{FormattedRelativeTime, MappedRelativeTime, RelativeTimeForm};
+#[cfg(feature = "compiled_data")]
+pub use freshness::check_data_freshness;
+#[cfg(feature = "http")]
+pub use http::HttpRelativeTimeProvider;
+#[cfg(feature = "relativetime_namer")]
+pub use namer::{RelativeDateTimeNamer, RelativeDateTimeNamerOptions};
 pub use options::RelativeTimeFormatterOptions;
-pub use relativetime::RelativeTimeFormatter;
+pub use relativetime::{RelativeTimeFormatter, RelativeTimeUnit, Tense};
 #[doc(no_inline)]
 pub use RelativeTimeError as Error;
@@ -0,0 +1,244 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use core::cmp;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::types::IsoWeekday;
+use icu_calendar::{AsCalendar, Date, Iso};
+use icu_datetime::options::length;
+use icu_datetime::provider::calendar::*;
+use icu_datetime::DateFormatter;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+use crate::relativetime::options::Numeric;
+use crate::relativetime::{
+    RelativeTimeError, RelativeTimeForm, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+};
+
+/// Options for [`RelativeDateTimeNamer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RelativeDateTimeNamerOptions {
+    /// The number of days after the reference date for which the name of the weekday is
+    /// used, if the locale has no name for the day.
+    ///
+    /// Dates before the reference date never use the bare name of their weekday, as it would
+    /// read as the coming one.
+    ///
+    /// Values above 6 are treated as 6, as the weekday is ambiguous 7 days from the reference.
+    /// The default is 6.
+    pub cutoff_days: u8,
+    /// The length of the absolute date used outside of the cutoff window. The default is
+    /// [`length::Date::Medium`].
+    pub length: length::Date,
+}
+
+impl Default for RelativeDateTimeNamerOptions {
+    fn default() -> Self {
+        Self {
+            cutoff_days: 6,
+            length: length::Date::Medium,
+        }
+    }
+}
+
+/// Names a date relative to a reference date, the way calendar apps do.
+///
+/// The name is chosen based on the number of days between the dates:
+/// 1. if the locale has a name for the day, such as "yesterday", "today", or "tomorrow", that
+///    name is used (see [`Numeric::Auto`]);
+/// 2. otherwise, if the date is at most [`RelativeDateTimeNamerOptions::cutoff_days`] after
+///    the reference date, the name of its weekday is used, such as "Tuesday";
+/// 3. otherwise, the date is formatted in full, such as "Jan 23, 2024".
+///
+/// ✨ *Enabled with the `relativetime_namer` Cargo feature.*
+///
+/// The dates may use different calendars. The absolute date uses the default calendar of the
+/// locale. Only dates are compared, so date-times in different time zones need to be
+/// converted to the time zone of the user before their dates are passed here.
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::experimental::relativetime::{
+///     RelativeDateTimeNamer, RelativeDateTimeNamerOptions,
+/// };
+/// use icu::locid::locale;
+///
+/// let namer = RelativeDateTimeNamer::try_new(
+///     &locale!("en").into(),
+///     RelativeDateTimeNamerOptions::default(),
+/// )
+/// .expect("locale should be present");
+///
+/// let reference = Date::try_new_iso_date(2024, 1, 15).unwrap();
+/// let name = |day| {
+///     namer
+///         .format(&Date::try_new_iso_date(2024, 1, day).unwrap(), &reference)
+///         .expect("date should be formattable")
+/// };
+///
+/// assert_eq!(name(11), "Jan 11, 2024");
+/// assert_eq!(name(14), "yesterday");
+/// assert_eq!(name(15), "today");
+/// assert_eq!(name(18), "Thursday");
+/// assert_eq!(name(23), "Jan 23, 2024");
+/// ```
+#[derive(Debug)]
+pub struct RelativeDateTimeNamer {
+    day_formatter: RelativeTimeFormatter,
+    weekday_names: DataPayload<GregorianDateSymbolsV1Marker>,
+    date_formatter: DateFormatter,
+    cutoff_days: i64,
+}
+
+impl RelativeDateTimeNamer {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: RelativeDateTimeNamerOptions,
+        error: RelativeTimeError,
+        #[cfg(skip)]
+    );
+
+    /// Creates a new [`RelativeDateTimeNamer`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: RelativeDateTimeNamerOptions,
+    ) -> Result<Self, RelativeTimeError> {
+        Ok(Self {
+            day_formatter: RelativeTimeFormatter::try_new_long_day(
+                locale,
+                RelativeTimeFormatterOptions::from(Numeric::Auto),
+            )?,
+            weekday_names: icu_datetime::provider::Baked
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?,
+            date_formatter: DateFormatter::try_new_with_length(locale, options.length)?,
+            cutoff_days: cmp::min(options.cutoff_days, 6).into(),
+        })
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        options: RelativeDateTimeNamerOptions,
+    ) -> Result<Self, RelativeTimeError>
+    where
+        P: DataProvider<crate::relativetime::provider::LongDayRelativeTimeFormatDataV1Marker>
+            + DataProvider<icu_plurals::provider::CardinalV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<icu_plurals::provider::OrdinalV1Marker>
+            + DataProvider<icu_calendar::provider::WeekDataV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<icu_calendar::provider::ChineseCacheV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<icu_calendar::provider::DangiCacheV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<icu_calendar::provider::IslamicObservationalCacheV1Marker>
+            + DataProvider<icu_calendar::provider::IslamicUmmAlQuraCacheV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<icu_calendar::provider::JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<icu_calendar::provider::JapaneseExtendedErasV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            day_formatter: RelativeTimeFormatter::try_new_long_day_unstable(
+                provider,
+                locale,
+                RelativeTimeFormatterOptions::from(Numeric::Auto),
+            )?,
+            weekday_names: provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?,
+            date_formatter: DateFormatter::try_new_with_length_unstable(
+                provider,
+                locale,
+                options.length,
+            )?,
+            cutoff_days: cmp::min(options.cutoff_days, 6).into(),
+        })
+    }
+
+    /// Names `date` relative to `reference`.
+    ///
+    /// Returns an error if the absolute date cannot be formatted with the loaded data.
+    pub fn format<A: AsCalendar, B: AsCalendar>(
+        &self,
+        date: &Date<A>,
+        reference: &Date<B>,
+    ) -> Result<String, RelativeTimeError> {
+        let date = date.to_iso();
+        let fixed = |date: &Date<Iso>| {
+            calendrical_calculations::iso::fixed_from_iso(
+                date.year().number,
+                date.month().ordinal as u8,
+                date.day_of_month().0 as u8,
+            )
+        };
+        let days = fixed(&date) - fixed(&reference.to_iso());
+
+        let formatted = self.day_formatter.format(FixedDecimal::from(days));
+        if formatted.form() == RelativeTimeForm::Lexical {
+            return Ok(formatted.write_to_string().into_owned());
+        }
+
+        if (1..=self.cutoff_days).contains(&days) {
+            let weekdays = &self.weekday_names.get().weekdays;
+            let names = weekdays
+                .stand_alone
+                .as_ref()
+                .and_then(|stand_alone| stand_alone.wide.as_ref())
+                .unwrap_or(&weekdays.format.wide);
+            // The names start on Sunday
+            let index = match date.day_of_week() {
+                IsoWeekday::Sunday => 0,
+                weekday => weekday as usize,
+            };
+            if let Some(name) = names.0.get(index) {
+                return Ok(String::from(&**name));
+            }
+        }
+
+        Ok(self.date_formatter.format_to_string(&date.to_any())?)
+    }
+}
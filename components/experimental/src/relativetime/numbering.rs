@@ -0,0 +1,150 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Digits of the numeric numbering systems of CLDR, used for the
+//! [`numbering_system`](crate::relativetime::options::RelativeTimeFormatterOptions::numbering_system)
+//! option.
+
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::extensions::unicode::{key, Value};
+use icu_provider::prelude::*;
+use tinystr::{tinystr, TinyAsciiStr};
+
+/// The numeric numbering systems of CLDR whose digits are consecutive code points, sorted by
+/// name, with their digit zero.
+const CONSECUTIVE_DIGITS: [(TinyAsciiStr<8>, char); 68] = [
+    (tinystr!(8, "adlm"), '\u{1E950}'),
+    (tinystr!(8, "ahom"), '\u{11730}'),
+    (tinystr!(8, "arab"), '\u{660}'),
+    (tinystr!(8, "arabext"), '\u{6F0}'),
+    (tinystr!(8, "bali"), '\u{1B50}'),
+    (tinystr!(8, "beng"), '\u{9E6}'),
+    (tinystr!(8, "bhks"), '\u{11C50}'),
+    (tinystr!(8, "brah"), '\u{11066}'),
+    (tinystr!(8, "cakm"), '\u{11136}'),
+    (tinystr!(8, "cham"), '\u{AA50}'),
+    (tinystr!(8, "deva"), '\u{966}'),
+    (tinystr!(8, "diak"), '\u{11950}'),
+    (tinystr!(8, "fullwide"), '\u{FF10}'),
+    (tinystr!(8, "gong"), '\u{11DA0}'),
+    (tinystr!(8, "gonm"), '\u{11D50}'),
+    (tinystr!(8, "gujr"), '\u{AE6}'),
+    (tinystr!(8, "guru"), '\u{A66}'),
+    (tinystr!(8, "hmng"), '\u{16B50}'),
+    (tinystr!(8, "hmnp"), '\u{1E140}'),
+    (tinystr!(8, "java"), '\u{A9D0}'),
+    (tinystr!(8, "kali"), '\u{A900}'),
+    (tinystr!(8, "kawi"), '\u{11F50}'),
+    (tinystr!(8, "khmr"), '\u{17E0}'),
+    (tinystr!(8, "knda"), '\u{CE6}'),
+    (tinystr!(8, "lana"), '\u{1A80}'),
+    (tinystr!(8, "lanatham"), '\u{1A90}'),
+    (tinystr!(8, "laoo"), '\u{ED0}'),
+    (tinystr!(8, "latn"), '\u{30}'),
+    (tinystr!(8, "lepc"), '\u{1C40}'),
+    (tinystr!(8, "limb"), '\u{1946}'),
+    (tinystr!(8, "mathbold"), '\u{1D7CE}'),
+    (tinystr!(8, "mathdbl"), '\u{1D7D8}'),
+    (tinystr!(8, "mathmono"), '\u{1D7F6}'),
+    (tinystr!(8, "mathsanb"), '\u{1D7EC}'),
+    (tinystr!(8, "mathsans"), '\u{1D7E2}'),
+    (tinystr!(8, "mlym"), '\u{D66}'),
+    (tinystr!(8, "modi"), '\u{11650}'),
+    (tinystr!(8, "mong"), '\u{1810}'),
+    (tinystr!(8, "mroo"), '\u{16A60}'),
+    (tinystr!(8, "mtei"), '\u{ABF0}'),
+    (tinystr!(8, "mymr"), '\u{1040}'),
+    (tinystr!(8, "mymrshan"), '\u{1090}'),
+    (tinystr!(8, "mymrtlng"), '\u{A9F0}'),
+    (tinystr!(8, "nagm"), '\u{1E4F0}'),
+    (tinystr!(8, "newa"), '\u{11450}'),
+    (tinystr!(8, "nkoo"), '\u{7C0}'),
+    (tinystr!(8, "olck"), '\u{1C50}'),
+    (tinystr!(8, "orya"), '\u{B66}'),
+    (tinystr!(8, "osma"), '\u{104A0}'),
+    (tinystr!(8, "rohg"), '\u{10D30}'),
+    (tinystr!(8, "saur"), '\u{A8D0}'),
+    (tinystr!(8, "segment"), '\u{1FBF0}'),
+    (tinystr!(8, "shrd"), '\u{111D0}'),
+    (tinystr!(8, "sind"), '\u{112F0}'),
+    (tinystr!(8, "sinh"), '\u{DE6}'),
+    (tinystr!(8, "sora"), '\u{110F0}'),
+    (tinystr!(8, "sund"), '\u{1BB0}'),
+    (tinystr!(8, "takr"), '\u{116C0}'),
+    (tinystr!(8, "talu"), '\u{19D0}'),
+    (tinystr!(8, "tamldec"), '\u{BE6}'),
+    (tinystr!(8, "telu"), '\u{C66}'),
+    (tinystr!(8, "thai"), '\u{E50}'),
+    (tinystr!(8, "tibt"), '\u{F20}'),
+    (tinystr!(8, "tirh"), '\u{114D0}'),
+    (tinystr!(8, "tnsa"), '\u{16AC0}'),
+    (tinystr!(8, "vaii"), '\u{A620}'),
+    (tinystr!(8, "wara"), '\u{118E0}'),
+    (tinystr!(8, "wcho"), '\u{1E2F0}'),
+];
+
+/// The digits of the `hanidec` numbering system, the only numeric numbering system of CLDR whose
+/// digits are not consecutive code points.
+const HANIDEC_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// Returns the digits of the numeric numbering system with the given CLDR name.
+pub(crate) fn digits(name: TinyAsciiStr<8>) -> Option<[char; 10]> {
+    if name == tinystr!(8, "hanidec") {
+        return Some(HANIDEC_DIGITS);
+    }
+    let index = CONSECUTIVE_DIGITS
+        .binary_search_by(|(n, _)| n.as_str().cmp(name.as_str()))
+        .ok()?;
+    let (_, zero) = CONSECUTIVE_DIGITS.get(index)?;
+    let mut digits = [*zero; 10];
+    for (offset, digit) in (0..).zip(digits.iter_mut()) {
+        *digit = char::from_u32(u32::from(*zero) + offset)?;
+    }
+    Some(digits)
+}
+
+/// A provider of decimal symbols that uses the digits of a numbering system.
+///
+/// If the data has symbols for the locale with the numbering system, such as `ar-u-nu-latn`,
+/// those are used; otherwise, the digits of the symbols of the locale are replaced.
+#[derive(Debug)]
+pub(crate) struct NumberingSystemProvider<'a, P: ?Sized> {
+    pub(crate) provider: &'a P,
+    pub(crate) numbering_system: Option<(TinyAsciiStr<8>, [char; 10])>,
+}
+
+impl<P> DataProvider<DecimalSymbolsV1Marker> for NumberingSystemProvider<'_, P>
+where
+    P: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
+        let Some((name, digits)) = self.numbering_system else {
+            return self.provider.load(req);
+        };
+        let mut locale = req.locale.clone();
+        locale.set_unicode_ext(
+            key!("nu"),
+            Value::try_from_single_subtag(name.as_bytes()).map_err(|_| {
+                DataError::custom("Invalid numbering system")
+                    .with_req(DecimalSymbolsV1Marker::KEY, req)
+            })?,
+        );
+        let mut metadata = req.metadata;
+        metadata.silent = true;
+        let mut response = match self.provider.load(DataRequest {
+            locale: &locale,
+            metadata,
+        }) {
+            Err(DataError {
+                kind: DataErrorKind::MissingLocale,
+                ..
+            }) => self.provider.load(req)?,
+            result => result?,
+        };
+        if let Some(payload) = response.payload.as_mut() {
+            payload.with_mut(move |symbols| symbols.digits = digits);
+        }
+        Ok(response)
+    }
+}
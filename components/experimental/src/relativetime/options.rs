@@ -4,16 +4,107 @@
 
 //! Options for configuring [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
 
+use fixed_decimal::{FixedDecimal, RoundingMode};
+use tinystr::TinyAsciiStr;
+
 /// A bag of options for defining how to format time using
 /// [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter).
+///
+/// The width of the unit, which corresponds to the ECMA-402 `style` option and the CLDR
+/// `relativeTimeWidth`, is chosen by the constructor, such as
+/// [`try_new_long_day`](crate::relativetime::RelativeTimeFormatter::try_new_long_day) or
+/// [`try_new_narrow_day`](crate::relativetime::RelativeTimeFormatter::try_new_narrow_day).
+///
+/// New options may be added in the future, so this struct is created from its [`Default`] or
+/// from a single option, and other fields are set afterwards:
+///
+/// ```
+/// use fixed_decimal::RoundingMode;
+/// use icu::experimental::relativetime::options::{Numeric, SubUnitPolicy};
+/// use icu::experimental::relativetime::RelativeTimeFormatterOptions;
+///
+/// let mut options = RelativeTimeFormatterOptions::from(Numeric::Auto);
+/// options.sub_unit = SubUnitPolicy::RoundNearest(RoundingMode::HalfExpand);
+/// ```
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct RelativeTimeFormatterOptions {
     /// Whether to always use numeric formatting for time.
+    ///
+    /// Corresponds to the ECMA-402 `numeric` option: [`Numeric::Auto`] uses the CLDR
+    /// `relative-type-*` names, such as "yesterday", and [`Numeric::Always`] only uses the
+    /// `relativeTime` patterns, such as "1 day ago". The default is [`Numeric::Always`].
     pub numeric: Numeric,
+
+    /// How to present values that are not a whole number of the unit.
+    ///
+    /// CLDR and ECMA-402 have no corresponding option; ECMA-402 formats the value with its
+    /// fraction digits, which is the default [`SubUnitPolicy::ShowFraction`].
+    pub sub_unit: SubUnitPolicy,
+
+    /// Whether to format the time with the patterns, such as "in 3 quarters", or compactly,
+    /// such as "+3q".
+    ///
+    /// CLDR and ECMA-402 have no corresponding option. The default is
+    /// [`UnitDisplay::Pattern`].
+    pub unit_display: UnitDisplay,
+
+    /// The CLDR name of the numbering system for the number, such as `"latn"`, `"arab"` or
+    /// `"deva"`, independently of the locale.
+    ///
+    /// Corresponds to the ECMA-402 `numberingSystem` option. Only numeric numbering systems
+    /// are supported; constructors return [`RelativeTimeError::UnsupportedNumberingSystem`]
+    /// for other names. If the data has no decimal symbols for the locale with the numbering
+    /// system, the digits of the numbering system are used with the symbols of the locale.
+    /// The default is `None`, which uses the numbering system of the locale.
+    ///
+    /// [`RelativeTimeError::UnsupportedNumberingSystem`]: crate::relativetime::RelativeTimeError::UnsupportedNumberingSystem
+    pub numbering_system: Option<TinyAsciiStr<8>>,
+
+    /// Whether to remove one of the two spaces around the placeholder `{0}` when nothing
+    /// visible is written there, such as "через  квартал" from `через {0} квартал`.
+    ///
+    /// This happens when the written number is empty or only contains zero-width characters,
+    /// for example when [`FormattedRelativeTime::map_number`] replaces it with an empty string.
+    /// Only a doubled space at the placeholder is removed; the rest of the pattern is written
+    /// unchanged.
+    ///
+    /// [`FormattedRelativeTime::map_number`]: crate::relativetime::FormattedRelativeTime::map_number
+    ///
+    /// CLDR and ECMA-402 have no corresponding option. The default is `false`.
+    pub trim_empty_substitution: bool,
+}
+
+impl From<Numeric> for RelativeTimeFormatterOptions {
+    fn from(numeric: Numeric) -> Self {
+        Self {
+            numeric,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<SubUnitPolicy> for RelativeTimeFormatterOptions {
+    fn from(sub_unit: SubUnitPolicy) -> Self {
+        Self {
+            sub_unit,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<UnitDisplay> for RelativeTimeFormatterOptions {
+    fn from(unit_display: UnitDisplay) -> Self {
+        Self {
+            unit_display,
+            ..Default::default()
+        }
+    }
 }
 
 /// Configures whether to always use numeric formatting even when special formatting is available.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_enums)] // mirrors the two values of the ECMA-402 `numeric` option
 pub enum Numeric {
     /// Always use numeric formatting.
     #[default]
@@ -22,3 +113,57 @@ pub enum Numeric {
     /// Automatically select special formatting if available else fallback to numeric formatting.
     Auto,
 }
+
+/// Configures how values with a fraction of the unit, such as 1.4 quarters, are presented.
+///
+/// The plural form is chosen from the value after this policy is applied, so `1.4` with
+/// [`SubUnitPolicy::ShowFraction`] uses the plural form for `1.4`, while
+/// [`SubUnitPolicy::Truncate`] uses the one for `1`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SubUnitPolicy {
+    /// Round the value to a whole number with the given [`RoundingMode`].
+    RoundNearest(RoundingMode),
+
+    /// Discard the fraction of the value, rounding towards zero.
+    Truncate,
+
+    /// Format the value as given, including its fraction digits.
+    #[default]
+    ShowFraction,
+}
+
+impl SubUnitPolicy {
+    pub(crate) fn apply(self, mut value: FixedDecimal) -> FixedDecimal {
+        match self {
+            Self::RoundNearest(mode) => value.quantize(&FixedDecimal::from(1), mode),
+            Self::Truncate => value.trunc(0),
+            Self::ShowFraction => {}
+        }
+        value
+    }
+}
+
+/// Configures how the unit and the direction of the time are presented.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum UnitDisplay {
+    /// Use the past and future patterns, such as "in 3 quarters" and "3 quarters ago".
+    #[default]
+    Pattern,
+
+    /// Show the number with a sign for the direction, followed or preceded by the unit, such as
+    /// "+3q" and "-3q", for very small spaces such as badges.
+    ///
+    /// There is no data for this form, so the unit is taken from the patterns of the
+    /// [`RelativeTimeFormatter`](crate::relativetime::RelativeTimeFormatter): it is the text
+    /// directly next to the placeholder `{0}` that the past and future patterns share, up to
+    /// whitespace. With the narrow width, this is usually a single character, such as "q" from
+    /// `in {0}q` and `{0}q ago` in English, but not always, such as "四半期" from `{0}四半期後`
+    /// and `{0}四半期前` in Japanese.
+    ///
+    /// If the patterns share no such text, for example when the direction is a prefix of the
+    /// unit, the value is formatted as with [`UnitDisplay::Pattern`]. Names for the offset,
+    /// such as "yesterday", are only used in that case.
+    Compact,
+}
@@ -127,11 +127,11 @@ impl FromStr for SingularSubPattern<'_> {
     type Err = DataError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (pattern, index) = if let Some(index) = s.find("{0}") {
-            if index >= 255 {
+        let (pattern, index) = if let Some((prefix, suffix)) = s.split_once("{0}") {
+            if prefix.len() >= 255 {
                 return Err(DataError::custom("Placeholder index too large to store."));
             }
-            (format!("{}{}", &s[..index], &s[index + 3..]), index as u8)
+            (format!("{prefix}{suffix}"), prefix.len() as u8)
         } else {
             (s.to_string(), 255u8)
         };
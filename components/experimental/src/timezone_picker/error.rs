@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_collator::CollatorError;
+use icu_datetime::DateTimeError;
+use icu_list::ListError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::timezone_picker::Error).
+#[derive(Display, Debug)]
+#[non_exhaustive]
+pub enum TimeZonePickerError {
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`TimeZoneFormatter`](icu_datetime::time_zone::TimeZoneFormatter).
+    #[displaydoc("Error loading TimeZoneFormatter: {0}")]
+    DateTime(DateTimeError),
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("Error loading ListFormatter: {0}")]
+    List(ListError),
+    /// An error originating from [`Collator`](icu_collator::Collator).
+    #[displaydoc("Error loading Collator: {0}")]
+    Collator(CollatorError),
+}
+
+impl From<DataError> for TimeZonePickerError {
+    fn from(e: DataError) -> Self {
+        TimeZonePickerError::Data(e)
+    }
+}
+
+impl From<DateTimeError> for TimeZonePickerError {
+    fn from(e: DateTimeError) -> Self {
+        TimeZonePickerError::DateTime(e)
+    }
+}
+
+impl From<ListError> for TimeZonePickerError {
+    fn from(e: ListError) -> Self {
+        TimeZonePickerError::List(e)
+    }
+}
+
+impl From<CollatorError> for TimeZonePickerError {
+    fn from(e: CollatorError) -> Self {
+        TimeZonePickerError::Collator(e)
+    }
+}
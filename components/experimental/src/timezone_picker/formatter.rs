@@ -0,0 +1,274 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+use icu_calendar::{DateTime, Iso};
+use icu_collator::{provider::*, Collator, CollatorOptions};
+use icu_datetime::provider::time_zones::{ExemplarCitiesV1Marker, TimeZoneFormatsV1Marker};
+use icu_datetime::time_zone::{TimeZoneFormatter, TimeZoneFormatterOptions};
+use icu_list::{provider::UnitListV1Marker, ListFormatter, ListLength};
+use icu_normalizer::provider::{
+    CanonicalDecompositionDataV1Marker, CanonicalDecompositionTablesV1Marker,
+};
+use icu_pattern::DoublePlaceholderPattern;
+use icu_provider::prelude::*;
+use icu_timezone::{CustomTimeZone, GmtOffset, TimeZoneBcp47Id};
+use writeable::Writeable;
+use zerovec::ule::AsULE;
+
+use crate::timezone_picker::provider::ZoneOffsetPeriodV1Marker;
+use crate::timezone_picker::TimeZonePickerError;
+
+/// A formatter for the labels of a time zone picker.
+///
+/// Time zones are grouped by their GMT offset at a given time, and each group is labeled with
+/// the localized GMT format of the offset and the exemplar cities of its time zones, for example
+/// "GMT+01:00 (Berlin, Paris, Rome)". The two are combined with the locale's time zone
+/// fallback format.
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::experimental::timezone_picker::TimeZonePickerFormatter;
+/// use icu::locid::locale;
+/// use icu::timezone::TimeZoneBcp47Id;
+/// use tinystr::tinystr;
+///
+/// let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into())
+///     .expect("locale should be present");
+///
+/// let time_zones = [
+///     TimeZoneBcp47Id(tinystr!(8, "itrom")),
+///     TimeZoneBcp47Id(tinystr!(8, "deber")),
+///     TimeZoneBcp47Id(tinystr!(8, "frpar")),
+///     TimeZoneBcp47Id(tinystr!(8, "gblon")),
+/// ];
+///
+/// let winter = DateTime::try_new_iso_datetime(2024, 1, 15, 12, 0, 0).unwrap();
+/// let labels = formatter
+///     .format(&time_zones, &winter)
+///     .into_iter()
+///     .map(|entry| entry.label)
+///     .collect::<Vec<_>>();
+/// assert_eq!(labels, ["GMT (London)", "GMT+01:00 (Berlin, Paris, Rome)"]);
+///
+/// let summer = DateTime::try_new_iso_datetime(2024, 7, 15, 12, 0, 0).unwrap();
+/// let labels = formatter
+///     .format(&time_zones, &summer)
+///     .into_iter()
+///     .map(|entry| entry.label)
+///     .collect::<Vec<_>>();
+/// assert_eq!(labels, ["GMT+01:00 (London)", "GMT+02:00 (Berlin, Paris, Rome)"]);
+/// ```
+#[derive(Debug)]
+pub struct TimeZonePickerFormatter {
+    time_zone_formatter: TimeZoneFormatter,
+    list_formatter: ListFormatter,
+    collator: Collator,
+    exemplar_cities: DataPayload<ExemplarCitiesV1Marker>,
+    label_pattern: DoublePlaceholderPattern<String>,
+    offsets: DataPayload<ZoneOffsetPeriodV1Marker>,
+}
+
+impl<P> icu_provider::constructors::RequiredKeys<P> for TimeZonePickerFormatter where
+    P: DataProvider<ExemplarCitiesV1Marker>
+        + DataProvider<TimeZoneFormatsV1Marker>
+        + DataProvider<ZoneOffsetPeriodV1Marker>
+        + DataProvider<UnitListV1Marker>
+        + DataProvider<CollationSpecialPrimariesV1Marker>
+        + DataProvider<CollationDataV1Marker>
+        + DataProvider<CollationDiacriticsV1Marker>
+        + DataProvider<CollationJamoV1Marker>
+        + DataProvider<CollationMetadataV1Marker>
+        + DataProvider<CollationReorderingV1Marker>
+        + DataProvider<CanonicalDecompositionDataV1Marker>
+        + DataProvider<CanonicalDecompositionTablesV1Marker>
+        + ?Sized
+{
+}
+
+/// A group of time zones that share a GMT offset, as returned by
+/// [`TimeZonePickerFormatter::format`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TimeZonePickerEntry {
+    /// The GMT offset shared by the time zones.
+    pub offset: GmtOffset,
+    /// The time zones in this group, ordered by the exemplar city as they appear in the label.
+    /// Time zones without an exemplar city come last.
+    pub time_zones: Vec<TimeZoneBcp47Id>,
+    /// The label, such as "GMT+01:00 (Berlin, Paris, Rome)".
+    pub label: String,
+}
+
+impl TimeZonePickerFormatter {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: TimeZonePickerError,
+        #[cfg(skip)]
+    );
+
+    /// Creates a new [`TimeZonePickerFormatter`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(locale: &DataLocale) -> Result<Self, TimeZonePickerError> {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        Ok(Self {
+            time_zone_formatter: TimeZoneFormatter::try_new(
+                locale,
+                TimeZoneFormatterOptions::default(),
+            )?,
+            list_formatter: ListFormatter::try_new_unit_with_length(locale, ListLength::Short)?,
+            collator: Collator::try_new(locale, CollatorOptions::new())?,
+            exemplar_cities: icu_datetime::provider::Baked.load(req)?.take_payload()?,
+            label_pattern: Self::label_pattern(&icu_datetime::provider::Baked, req)?,
+            offsets: crate::provider::Baked
+                .load(Default::default())?
+                .take_payload()?,
+        })
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+    ) -> Result<Self, TimeZonePickerError>
+    where
+        P: DataProvider<ExemplarCitiesV1Marker>
+            + DataProvider<TimeZoneFormatsV1Marker>
+            + DataProvider<ZoneOffsetPeriodV1Marker>
+            + DataProvider<UnitListV1Marker>
+            + DataProvider<CollationSpecialPrimariesV1Marker>
+            + DataProvider<CollationDataV1Marker>
+            + DataProvider<CollationDiacriticsV1Marker>
+            + DataProvider<CollationJamoV1Marker>
+            + DataProvider<CollationMetadataV1Marker>
+            + DataProvider<CollationReorderingV1Marker>
+            + DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + ?Sized,
+    {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        Ok(Self {
+            time_zone_formatter: TimeZoneFormatter::try_new_unstable(
+                provider,
+                locale,
+                TimeZoneFormatterOptions::default(),
+            )?,
+            list_formatter: ListFormatter::try_new_unit_with_length_unstable(
+                provider,
+                locale,
+                ListLength::Short,
+            )?,
+            collator: Collator::try_new_unstable(provider, locale, CollatorOptions::new())?,
+            exemplar_cities: provider.load(req)?.take_payload()?,
+            label_pattern: Self::label_pattern(provider, req)?,
+            offsets: provider.load(Default::default())?.take_payload()?,
+        })
+    }
+
+    /// Loads the time zone fallback format, such as "{1} ({0})", which combines the GMT format
+    /// ({1}) with the list of exemplar cities ({0}).
+    fn label_pattern<P>(
+        provider: &P,
+        req: DataRequest,
+    ) -> Result<DoublePlaceholderPattern<String>, DataError>
+    where
+        P: DataProvider<TimeZoneFormatsV1Marker> + ?Sized,
+    {
+        let formats = provider.load(req)?.take_payload()?;
+        DoublePlaceholderPattern::from_str(&formats.get().fallback_format).map_err(|_| {
+            DataError::custom("Invalid time zone fallback format")
+                .with_display_context(&formats.get().fallback_format)
+        })
+    }
+
+    /// Groups the time zones by their GMT offset at `time`, which is a UTC date and time, and
+    /// labels each group.
+    ///
+    /// The groups are ordered by offset, from west to east. Within a group, exemplar cities are
+    /// ordered by the locale's collation. Duplicate time zones are only listed once. Time zones
+    /// without an offset at `time`, such as unknown time zones, or any time zone before the years
+    /// covered by [`ZoneOffsetPeriodV1`](crate::timezone_picker::provider::ZoneOffsetPeriodV1), are
+    /// left out.
+    pub fn format(
+        &self,
+        time_zones: &[TimeZoneBcp47Id],
+        time: &DateTime<Iso>,
+    ) -> Vec<TimeZonePickerEntry> {
+        let minutes = time.minutes_since_local_unix_epoch();
+        let mut groups: BTreeMap<i32, (GmtOffset, Vec<TimeZoneBcp47Id>)> = BTreeMap::new();
+        for &time_zone in time_zones {
+            let Some(offset) = self.offset_at(time_zone, minutes) else {
+                continue;
+            };
+            let (_, group) = groups
+                .entry(offset.offset_seconds())
+                .or_insert_with(|| (offset, Vec::new()));
+            if !group.contains(&time_zone) {
+                group.push(time_zone);
+            }
+        }
+
+        groups
+            .into_values()
+            .map(|(offset, time_zones)| {
+                let mut time_zones: Vec<(TimeZoneBcp47Id, Option<&str>)> = time_zones
+                    .into_iter()
+                    .map(|tz| (tz, self.exemplar_cities.get().0.get(&tz)))
+                    .collect();
+                time_zones.sort_by(|(a_tz, a_city), (b_tz, b_city)| match (a_city, b_city) {
+                    (Some(a), Some(b)) => self.collator.compare(a, b),
+                    (a, b) => a.is_none().cmp(&b.is_none()).then(a_tz.cmp(b_tz)),
+                });
+
+                let gmt = self
+                    .time_zone_formatter
+                    .format_to_string(&CustomTimeZone::new_with_offset(offset));
+                let mut cities = time_zones.iter().filter_map(|(_, city)| *city).peekable();
+                let label = if cities.peek().is_some() {
+                    self.label_pattern
+                        .interpolate((self.list_formatter.format(cities), gmt))
+                        .write_to_string()
+                        .into_owned()
+                } else {
+                    gmt
+                };
+
+                TimeZonePickerEntry {
+                    offset,
+                    time_zones: time_zones.into_iter().map(|(tz, _)| tz).collect(),
+                    label,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the offset of the time zone at the UTC time given in minutes since the unix epoch.
+    fn offset_at(&self, time_zone: TimeZoneBcp47Id, minutes: i32) -> Option<GmtOffset> {
+        let mut offset = None;
+        for (&start, seconds) in self.offsets.get().0.get0(&time_zone)?.iter1_copied() {
+            if minutes >= i32::from_unaligned(start) {
+                offset = Some(seconds);
+            } else {
+                break;
+            }
+        }
+        GmtOffset::try_from_offset_seconds(offset?).ok()
+    }
+}
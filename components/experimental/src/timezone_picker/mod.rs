@@ -0,0 +1,22 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Labels for time zone pickers, such as "GMT+01:00 (Berlin, Paris, Rome)".
+//!
+//! Time zones are grouped by their GMT offset at a given time. Each group is labeled with the
+//! localized GMT format of the offset and a list of the exemplar cities of its time zones,
+//! combined with the locale's time zone fallback format.
+//!
+//! ✨ *Enabled with the `timezone_picker` Cargo feature.*
+
+#![warn(missing_docs)]
+
+mod error;
+mod formatter;
+pub mod provider;
+
+pub use error::TimeZonePickerError;
+pub use formatter::{TimeZonePickerEntry, TimeZonePickerFormatter};
+#[doc(no_inline)]
+pub use TimeZonePickerError as Error;
@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::prelude::*;
+use icu_timezone::TimeZoneBcp47Id;
+use zerovec::ZeroMap2d;
+
+/// The GMT offsets that time zones use in a range of years, derived from the time zone database.
+///
+/// The data generated by `icu_datagen` covers the years 2010 through 2035. Later times use
+/// the last offset of each time zone.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    ZoneOffsetPeriodV1Marker,
+    "time_zone/offset_period@1",
+    singleton
+))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::timezone_picker::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneOffsetPeriodV1<'data>(
+    /// The offsets of each time zone. The second level key is a UTC time represented as the
+    /// number of minutes since the unix epoch. It represents when the offset started to be used.
+    /// The value is the offset in seconds.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ZeroMap2d<'data, TimeZoneBcp47Id, i32, i32>,
+);
@@ -50,6 +50,8 @@ pub enum ParseErrorKind {
     /// The provided escape sequence is not a valid Unicode code point or represents too many
     /// code points.
     InvalidEscape,
+    /// The set contains strings, but only code points are allowed in this context.
+    UnexpectedString,
 }
 use zerovec::VarZeroVec;
 use ParseErrorKind as PEK;
@@ -176,6 +178,9 @@ impl ParseError {
             ParseErrorKind::InvalidEscape => {
                 s.push_str("invalid escape sequence");
             }
+            ParseErrorKind::UnexpectedString => {
+                s.push_str("unexpected string");
+            }
         }
 
         s
@@ -1725,6 +1730,63 @@ where
     parse_unstable_with_variables(source, &dummy, provider)
 }
 
+/// Constructs sets from a complete UnicodeSet pattern, such as `[a-z ãç]`.
+///
+/// This provides `try_from_pattern` for the set types of [`icu_collections`], which cannot
+/// depend on this crate. See [`parse`] for the supported syntax. Unlike [`parse`], the whole
+/// source has to be a single UnicodeSet.
+///
+/// The inverse operation is `to_pattern_string`, e.g.
+/// [`CodePointInversionList::to_pattern_string`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::collections::codepointinvlist::CodePointInversionList;
+/// use icu::experimental::unicodeset_parse::{ParseErrorKind, TryFromPattern};
+///
+/// let set = CodePointInversionList::try_from_pattern(r"[a-z\ ãç]").unwrap();
+/// assert!(set.contains('ã'));
+/// assert!(set.contains(' '));
+/// assert_eq!(set.to_pattern_string(), r"[\ a-zãç]");
+///
+/// // Strings cannot be stored in a CodePointInversionList
+/// let err = CodePointInversionList::try_from_pattern("[a{ch}]").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnexpectedString);
+///
+/// // Trailing input is an error
+/// let err = CodePointInversionList::try_from_pattern("[a-c][x-z]").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnexpectedChar('['));
+/// assert_eq!(err.offset(), Some(5));
+/// ```
+#[cfg(feature = "compiled_data")]
+pub trait TryFromPattern: Sized {
+    /// Parses a UnicodeSet pattern that spans the whole `source`.
+    fn try_from_pattern(source: &str) -> Result<Self>;
+}
+
+#[cfg(feature = "compiled_data")]
+impl TryFromPattern for CodePointInversionListAndStringList<'static> {
+    fn try_from_pattern(source: &str) -> Result<Self> {
+        let (set, consumed) = parse(source)?;
+        match source.get(consumed..).and_then(|rest| rest.chars().next()) {
+            None => Ok(set),
+            Some(c) => Err(PEK::UnexpectedChar(c).with_offset(consumed)),
+        }
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl TryFromPattern for CodePointInversionList<'static> {
+    fn try_from_pattern(source: &str) -> Result<Self> {
+        let set = CodePointInversionListAndStringList::try_from_pattern(source)?;
+        if set.has_strings() {
+            return Err(PEK::UnexpectedString.into());
+        }
+        Ok(set.code_points().clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::ops::RangeInclusive;
@@ -2206,4 +2268,67 @@ mod tests {
             assert_eq!(expected_consumed, consumed);
         }
     }
+
+    #[test]
+    fn test_pattern_string_roundtrip() {
+        // A subset of patterns from the ICU4C UnicodeSet tests
+        let cases = [
+            "[]",
+            "[abc]",
+            "[a-z ãç]",
+            r"[\-\[\]\\\^\&\{\}\$:]",
+            r"[\u0000-\u001F\u007F]",
+            r"[\ \u200E\u2028\u2029]",
+            "[^a-z]",
+            "[^]",
+            "[[a-z]-[aeiou]]",
+            "[[a-z]&[aeiou]]",
+            "[[:Lu:]&[A-Z]]",
+            r"[\p{Nd}-[0-9]]",
+            "[a{ch}{ll}{[}]",
+            r"[{\ }{\u0009\-}]",
+            "[\u{10000}-\u{10FFFF}]",
+        ];
+
+        for source in cases {
+            let (set, _) = parse(source).unwrap();
+            let pattern = set.to_pattern_string();
+            let (roundtrip, consumed) = parse(&pattern).unwrap();
+            assert_eq!(consumed, pattern.len(), "{pattern}");
+            assert_eq!(set, roundtrip, "{source} => {pattern}");
+            assert_eq!(
+                CodePointInversionListAndStringList::try_from_pattern(&pattern),
+                Ok(set.clone()),
+                "{source} => {pattern}"
+            );
+            // Code point only sets also roundtrip through the CodePointInversionList pattern
+            if !set.has_strings() {
+                let pattern = set.code_points().to_pattern_string();
+                assert_eq!(
+                    CodePointInversionList::try_from_pattern(&pattern).as_ref(),
+                    Ok(set.code_points()),
+                    "{source} => {pattern}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_from_pattern_errors() {
+        let cases = [
+            ("[abc", PEK::Eof, None),
+            ("[abc] ", PEK::UnexpectedChar(' '), Some(5)),
+            ("[a][b]", PEK::UnexpectedChar('['), Some(3)),
+            ("[\\p{Foo}]", PEK::UnknownProperty, Some(6)),
+            ("[a{bc}]", PEK::UnexpectedString, None),
+        ];
+        for (source, kind, offset) in cases {
+            let err = CodePointInversionList::try_from_pattern(source).unwrap_err();
+            assert_eq!(err.kind(), kind, "{source}");
+            if offset.is_some() {
+                assert_eq!(err.offset(), offset, "{source}");
+            }
+        }
+        assert!(CodePointInversionListAndStringList::try_from_pattern("[a{bc}]").is_ok());
+    }
 }
@@ -0,0 +1,90 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::borrow::Cow;
+
+use icu_experimental::ellipsis::provider::{EllipsisPatternsV1, EllipsisPatternsV1Marker};
+use icu_experimental::ellipsis::Ellipsizer;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::GraphemeClusterBreakDataV1Marker;
+
+/// Provides the English ellipsis patterns, and the compiled grapheme cluster data.
+struct TestProvider;
+
+impl DataProvider<EllipsisPatternsV1Marker> for TestProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<EllipsisPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(EllipsisPatternsV1 {
+                start: "…".into(),
+                middle: "…".into(),
+                end: "…".into(),
+            })),
+        })
+    }
+}
+
+impl DataProvider<GraphemeClusterBreakDataV1Marker> for TestProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<GraphemeClusterBreakDataV1Marker>, DataError> {
+        icu_segmenter::provider::Baked.load(req)
+    }
+}
+
+fn ellipsizer() -> Ellipsizer {
+    Ellipsizer::try_new_unstable(&TestProvider, &Default::default()).unwrap()
+}
+
+#[test]
+fn test_truncate() {
+    let ellipsizer = ellipsizer();
+
+    assert_eq!(ellipsizer.truncate_end("Hello, world", 6), "Hello…");
+    assert_eq!(ellipsizer.truncate_middle("Hello, world", 6), "Hel…ld");
+    assert_eq!(ellipsizer.truncate_start("Hello, world", 6), "…world");
+
+    // Only the ellipsis fits
+    assert_eq!(ellipsizer.truncate_end("Hello, world", 1), "…");
+    assert_eq!(ellipsizer.truncate_end("Hello, world", 0), "…");
+}
+
+#[test]
+fn test_exact_fit_is_borrowed() {
+    let ellipsizer = ellipsizer();
+
+    for truncated in [
+        ellipsizer.truncate_end("Hello", 5),
+        ellipsizer.truncate_middle("Hello", 5),
+        ellipsizer.truncate_start("Hello", 5),
+        ellipsizer.truncate_end("", 0),
+    ] {
+        assert!(matches!(truncated, Cow::Borrowed(_)));
+    }
+
+    // Combining marks do not count as separate graphemes
+    let text = "cafe\u{301}";
+    assert!(matches!(ellipsizer.truncate_end(text, 4), Cow::Borrowed(t) if t == text));
+}
+
+#[test]
+fn test_emoji_sequences() {
+    let ellipsizer = ellipsizer();
+
+    // 👨‍👩‍👧 is a single grapheme cluster of five code points
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let text = format!("ab{family}cd");
+
+    assert_eq!(ellipsizer.truncate_end(&text, 4), format!("ab{family}…"));
+    assert_eq!(ellipsizer.truncate_end(&text, 3), "ab…");
+    assert_eq!(ellipsizer.truncate_start(&text, 4), format!("…{family}cd"));
+    assert_eq!(ellipsizer.truncate_start(&text, 3), "…cd");
+    assert_eq!(ellipsizer.truncate_middle(&text, 4), "ab…d");
+
+    // 🏳️‍🌈 and a flag made of regional indicators
+    let text = "🏳\u{fe0f}\u{200d}🌈🇩🇪🇫🇷";
+    assert_eq!(ellipsizer.truncate_end(text, 2), "🏳\u{fe0f}\u{200d}🌈…");
+    assert_eq!(ellipsizer.truncate_start(text, 2), "…🇫🇷");
+}
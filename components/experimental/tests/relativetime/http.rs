@@ -0,0 +1,230 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu::decimal::provider::DecimalSymbolsV1Marker;
+use icu::plurals::provider::CardinalV1Marker;
+use icu_experimental::relativetime::provider::*;
+use icu_experimental::relativetime::{
+    HttpRelativeTimeProvider, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+};
+use icu_locid::{langid, locale};
+use icu_provider::hello_world::HelloWorldV1Marker;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A minimal HTTP server that serves postcard-encoded relative time data.
+struct MockServer {
+    url_pattern: String,
+    requests: Arc<AtomicUsize>,
+    offline: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    fn start() -> Self {
+        let mut files = HashMap::new();
+        for locale in [langid!("en"), langid!("es")] {
+            let payload: DataPayload<LongDayRelativeTimeFormatDataV1Marker> =
+                icu_experimental::provider::Baked
+                    .load(DataRequest {
+                        locale: &locale.clone().into(),
+                        metadata: Default::default(),
+                    })
+                    .unwrap()
+                    .take_payload()
+                    .unwrap();
+            files.insert(
+                format!("/relativetime/{locale}/long/day.postcard"),
+                postcard::to_allocvec(payload.get()).unwrap(),
+            );
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let offline = Arc::new(AtomicBool::new(false));
+        let server = MockServer {
+            url_pattern: format!(
+                "http://127.0.0.1:{port}/relativetime/{{locale}}/{{unit}}.postcard"
+            ),
+            requests: requests.clone(),
+            offline: offline.clone(),
+        };
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                if offline.load(Ordering::SeqCst) {
+                    // Simulate a network failure by closing the connection
+                    continue;
+                }
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                requests.fetch_add(1, Ordering::SeqCst);
+                let request = String::from_utf8(request).unwrap();
+                let path = request.split(' ').nth(1).unwrap_or_default();
+                let (status, body) = match files.get(path) {
+                    Some(body) => ("200 OK", body.as_slice()),
+                    None => ("404 Not Found", &[][..]),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(body);
+            }
+        });
+
+        server
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    fn set_offline(&self, offline: bool) {
+        self.offline.store(offline, Ordering::SeqCst);
+    }
+}
+
+/// Serves relative time data over HTTP, and the other data of the formatter from compiled data.
+struct WithCompiledData<'a>(&'a HttpRelativeTimeProvider);
+
+impl DataProvider<LongDayRelativeTimeFormatDataV1Marker> for WithCompiledData<'_> {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LongDayRelativeTimeFormatDataV1Marker>, DataError> {
+        self.0.as_deserializing().load(req)
+    }
+}
+
+impl DataProvider<CardinalV1Marker> for WithCompiledData<'_> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CardinalV1Marker>, DataError> {
+        icu::plurals::provider::Baked.load(req)
+    }
+}
+
+impl DataProvider<DecimalSymbolsV1Marker> for WithCompiledData<'_> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
+        icu::decimal::provider::Baked.load(req)
+    }
+}
+
+fn format(
+    provider: &HttpRelativeTimeProvider,
+    locale: &DataLocale,
+    value: i32,
+) -> Result<String, DataError> {
+    let formatter = RelativeTimeFormatter::try_new_long_day_unstable(
+        &WithCompiledData(provider),
+        locale,
+        RelativeTimeFormatterOptions::default(),
+    )
+    .map_err(|e| match e {
+        icu_experimental::relativetime::RelativeTimeError::Data(e) => e,
+        e => panic!("{e:?}"),
+    })?;
+    Ok(formatter.format(FixedDecimal::from(value)).to_string())
+}
+
+#[test]
+fn test_fetch_and_cache() {
+    let server = MockServer::start();
+    let provider =
+        HttpRelativeTimeProvider::try_new(&server.url_pattern, Duration::from_secs(3600)).unwrap();
+
+    assert_eq!(
+        format(&provider, &locale!("en").into(), 2).unwrap(),
+        "in 2 days"
+    );
+    assert_eq!(server.requests(), 1);
+
+    // Cache hit
+    assert_eq!(
+        format(&provider, &locale!("en").into(), -3).unwrap(),
+        "3 days ago"
+    );
+    assert_eq!(server.requests(), 1);
+
+    // Each locale is fetched separately
+    assert_eq!(
+        format(&provider, &locale!("es").into(), 3).unwrap(),
+        "dentro de 3 días"
+    );
+    assert_eq!(server.requests(), 2);
+}
+
+#[test]
+fn test_expired_cache() {
+    let server = MockServer::start();
+    let provider = HttpRelativeTimeProvider::try_new(&server.url_pattern, Duration::ZERO).unwrap();
+
+    format(&provider, &locale!("en").into(), 2).unwrap();
+    format(&provider, &locale!("en").into(), 2).unwrap();
+    assert_eq!(server.requests(), 2);
+}
+
+#[test]
+fn test_network_failure() {
+    let server = MockServer::start();
+    let provider = HttpRelativeTimeProvider::try_new(&server.url_pattern, Duration::ZERO).unwrap();
+
+    format(&provider, &locale!("en").into(), 2).unwrap();
+    assert_eq!(server.requests(), 1);
+
+    server.set_offline(true);
+    // The expired cache entry is used while the server is unreachable
+    assert_eq!(
+        format(&provider, &locale!("en").into(), 2).unwrap(),
+        "in 2 days"
+    );
+    // Without a cache entry, the network error is returned
+    let err = format(&provider, &locale!("es").into(), 2).unwrap_err();
+    assert_eq!(err.kind, DataErrorKind::Custom);
+
+    server.set_offline(false);
+    format(&provider, &locale!("es").into(), 2).unwrap();
+    assert_eq!(server.requests(), 2);
+}
+
+#[test]
+fn test_missing_data() {
+    let server = MockServer::start();
+    let provider =
+        HttpRelativeTimeProvider::try_new(&server.url_pattern, Duration::from_secs(3600)).unwrap();
+
+    let err = format(&provider, &locale!("fr").into(), 2).unwrap_err();
+    assert_eq!(err.kind, DataErrorKind::MissingLocale);
+    assert_eq!(server.requests(), 1);
+
+    // Other keys are not requested from the server
+    let err =
+        DataProvider::<HelloWorldV1Marker>::load(&provider.as_deserializing(), Default::default())
+            .unwrap_err();
+    assert_eq!(err.kind, DataErrorKind::MissingDataKey);
+    assert_eq!(server.requests(), 1);
+}
+
+#[test]
+fn test_invalid_pattern() {
+    assert!(HttpRelativeTimeProvider::try_new(
+        "https://cdn.example.com/{locale}.postcard",
+        Duration::ZERO
+    )
+    .is_err());
+}
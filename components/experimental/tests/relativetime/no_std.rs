@@ -0,0 +1,35 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formats relative times using only `core` and `alloc`, to make sure that the
+//! formatter stays usable without `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Write;
+use fixed_decimal::FixedDecimal;
+use icu_experimental::relativetime::{RelativeTimeFormatter, RelativeTimeFormatterOptions};
+use icu_locid::locale;
+use writeable::Writeable;
+
+#[test]
+fn test_format_without_std() {
+    let formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+
+    assert_eq!(
+        formatter.format(FixedDecimal::from(-3)).write_to_string(),
+        "3 days ago"
+    );
+
+    let mut sink = String::new();
+    write!(sink, "{}", formatter.format(FixedDecimal::from(5))).expect("infallible");
+    assert_eq!(sink, "in 5 days");
+}
@@ -0,0 +1,80 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_experimental::relativetime::provider::*;
+use icu_experimental::relativetime::{RelativeTimeFormatter, RelativeTimeFormatterOptions};
+use icu_locid::{langid, locale};
+use icu_provider::datagen::DataExporter;
+use icu_provider::dynutil::UpcastDataPayload;
+use icu_provider::prelude::*;
+use icu_provider_blob::export::BlobExporter;
+use icu_provider_blob::BlobDataProvider;
+use writeable::assert_writeable_eq;
+
+/// A blob in which the English future pattern for quarters is replaced.
+fn hotfix_blob() -> Box<[u8]> {
+    let mut data: DataPayload<LongQuarterRelativeTimeFormatDataV1Marker> =
+        icu_experimental::provider::Baked
+            .load(DataRequest {
+                locale: &langid!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+    data.with_mut(|data| data.future.other = "in {0} qtrs".parse().unwrap());
+
+    let mut blob = Vec::new();
+    let mut exporter = BlobExporter::new_v2_with_sink(Box::new(&mut blob));
+    exporter
+        .put_payload(
+            LongQuarterRelativeTimeFormatDataV1Marker::KEY,
+            &langid!("en").into(),
+            &UpcastDataPayload::upcast(data),
+        )
+        .unwrap();
+    exporter
+        .flush(LongQuarterRelativeTimeFormatDataV1Marker::KEY)
+        .unwrap();
+    exporter.close().unwrap();
+    drop(exporter);
+    blob.into_boxed_slice()
+}
+
+/// Formats 3 with baked data that the override does not replace.
+fn format_unchanged() -> [String; 4] {
+    let options = RelativeTimeFormatterOptions::default();
+    [
+        RelativeTimeFormatter::try_new_long_quarter(&locale!("en-GB").into(), options),
+        RelativeTimeFormatter::try_new_long_quarter(&locale!("es").into(), options),
+        RelativeTimeFormatter::try_new_long_day(&locale!("en").into(), options),
+        RelativeTimeFormatter::try_new_short_quarter(&locale!("en").into(), options),
+    ]
+    .map(|formatter| formatter.unwrap().format(FixedDecimal::from(3)).to_string())
+}
+
+#[test]
+fn test_override_provider() {
+    let options = RelativeTimeFormatterOptions::default();
+    let en = RelativeTimeFormatter::try_new_long_quarter(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(en.format(FixedDecimal::from(3)), "in 3 quarters");
+    let unchanged = format_unchanged();
+
+    let provider = BlobDataProvider::try_new_from_blob(hotfix_blob()).unwrap();
+    icu_provider::baked::set_override_provider(Box::leak(Box::new(provider))).unwrap();
+
+    // Formatters created after the registration use the override
+    let en = RelativeTimeFormatter::try_new_long_quarter(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(en.format(FixedDecimal::from(3)), "in 3 qtrs");
+    assert_writeable_eq!(en.format(FixedDecimal::from(-3)), "3 quarters ago");
+
+    // Other locales, units and widths still use the baked data
+    assert_eq!(format_unchanged(), unchanged);
+    assert_eq!(unchanged[1], "dentro de 3 trimestres");
+
+    // The override provider can only be registered once
+    let provider = BlobDataProvider::try_new_from_blob(hotfix_blob()).unwrap();
+    assert!(icu_provider::baked::set_override_provider(Box::leak(Box::new(provider))).is_err());
+}
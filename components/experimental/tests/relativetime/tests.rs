@@ -46,9 +46,7 @@ macro_rules! generate_test {
 generate_test!(
     test_long_second_always,
     try_new_long_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 seconds ago"),
         (-2, "2 seconds ago"),
@@ -71,9 +69,7 @@ generate_test!(
 generate_test!(
     test_long_second_auto,
     try_new_long_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 seconds ago"),
         (-2, "2 seconds ago"),
@@ -96,9 +92,7 @@ generate_test!(
 generate_test!(
     test_long_minute_always,
     try_new_long_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 minutes ago"),
         (-2, "2 minutes ago"),
@@ -121,9 +115,7 @@ generate_test!(
 generate_test!(
     test_long_minute_auto,
     try_new_long_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 minutes ago"),
         (-2, "2 minutes ago"),
@@ -146,9 +138,7 @@ generate_test!(
 generate_test!(
     test_long_hour_always,
     try_new_long_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 hours ago"),
         (-2, "2 hours ago"),
@@ -171,9 +161,7 @@ generate_test!(
 generate_test!(
     test_long_hour_auto,
     try_new_long_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 hours ago"),
         (-2, "2 hours ago"),
@@ -196,9 +184,7 @@ generate_test!(
 generate_test!(
     test_long_day_always,
     try_new_long_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 days ago"),
         (-2, "2 days ago"),
@@ -221,9 +207,7 @@ generate_test!(
 generate_test!(
     test_long_day_auto,
     try_new_long_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 days ago"),
         (-2, "2 days ago"),
@@ -246,9 +230,7 @@ generate_test!(
 generate_test!(
     test_long_week_always,
     try_new_long_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 weeks ago"),
         (-2, "2 weeks ago"),
@@ -271,9 +253,7 @@ generate_test!(
 generate_test!(
     test_long_week_auto,
     try_new_long_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 weeks ago"),
         (-2, "2 weeks ago"),
@@ -296,9 +276,7 @@ generate_test!(
 generate_test!(
     test_long_month_always,
     try_new_long_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 months ago"),
         (-2, "2 months ago"),
@@ -321,9 +299,7 @@ generate_test!(
 generate_test!(
     test_long_month_auto,
     try_new_long_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 months ago"),
         (-2, "2 months ago"),
@@ -346,9 +322,7 @@ generate_test!(
 generate_test!(
     test_long_quarter_always,
     try_new_long_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 quarters ago"),
         (-2, "2 quarters ago"),
@@ -371,9 +345,7 @@ generate_test!(
 generate_test!(
     test_long_quarter_auto,
     try_new_long_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 quarters ago"),
         (-2, "2 quarters ago"),
@@ -396,9 +368,7 @@ generate_test!(
 generate_test!(
     test_long_year_always,
     try_new_long_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 years ago"),
         (-2, "2 years ago"),
@@ -421,9 +391,7 @@ generate_test!(
 generate_test!(
     test_long_year_auto,
     try_new_long_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 years ago"),
         (-2, "2 years ago"),
@@ -446,9 +414,7 @@ generate_test!(
 generate_test!(
     test_short_second_always,
     try_new_short_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 sec. ago"),
         (-2, "2 sec. ago"),
@@ -471,9 +437,7 @@ generate_test!(
 generate_test!(
     test_short_second_auto,
     try_new_short_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 sec. ago"),
         (-2, "2 sec. ago"),
@@ -496,9 +460,7 @@ generate_test!(
 generate_test!(
     test_short_minute_always,
     try_new_short_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 min. ago"),
         (-2, "2 min. ago"),
@@ -521,9 +483,7 @@ generate_test!(
 generate_test!(
     test_short_minute_auto,
     try_new_short_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 min. ago"),
         (-2, "2 min. ago"),
@@ -546,9 +506,7 @@ generate_test!(
 generate_test!(
     test_short_hour_always,
     try_new_short_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 hr. ago"),
         (-2, "2 hr. ago"),
@@ -571,9 +529,7 @@ generate_test!(
 generate_test!(
     test_short_hour_auto,
     try_new_short_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 hr. ago"),
         (-2, "2 hr. ago"),
@@ -596,9 +552,7 @@ generate_test!(
 generate_test!(
     test_short_day_always,
     try_new_short_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 days ago"),
         (-2, "2 days ago"),
@@ -621,9 +575,7 @@ generate_test!(
 generate_test!(
     test_short_day_auto,
     try_new_short_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 days ago"),
         (-2, "2 days ago"),
@@ -646,9 +598,7 @@ generate_test!(
 generate_test!(
     test_short_week_always,
     try_new_short_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 wk. ago"),
         (-2, "2 wk. ago"),
@@ -671,9 +621,7 @@ generate_test!(
 generate_test!(
     test_short_week_auto,
     try_new_short_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 wk. ago"),
         (-2, "2 wk. ago"),
@@ -696,9 +644,7 @@ generate_test!(
 generate_test!(
     test_short_month_always,
     try_new_short_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 mo. ago"),
         (-2, "2 mo. ago"),
@@ -721,9 +667,7 @@ generate_test!(
 generate_test!(
     test_short_month_auto,
     try_new_short_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 mo. ago"),
         (-2, "2 mo. ago"),
@@ -746,9 +690,7 @@ generate_test!(
 generate_test!(
     test_short_quarter_always,
     try_new_short_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 qtrs. ago"),
         (-2, "2 qtrs. ago"),
@@ -771,9 +713,7 @@ generate_test!(
 generate_test!(
     test_short_quarter_auto,
     try_new_short_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 qtrs. ago"),
         (-2, "2 qtrs. ago"),
@@ -796,9 +736,7 @@ generate_test!(
 generate_test!(
     test_short_year_always,
     try_new_short_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10 yr. ago"),
         (-2, "2 yr. ago"),
@@ -821,9 +759,7 @@ generate_test!(
 generate_test!(
     test_short_year_auto,
     try_new_short_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10 yr. ago"),
         (-2, "2 yr. ago"),
@@ -846,9 +782,7 @@ generate_test!(
 generate_test!(
     test_narrow_second_always,
     try_new_narrow_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10s ago"),
         (-2, "2s ago"),
@@ -871,9 +805,7 @@ generate_test!(
 generate_test!(
     test_narrow_second_auto,
     try_new_narrow_second,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10s ago"),
         (-2, "2s ago"),
@@ -896,9 +828,7 @@ generate_test!(
 generate_test!(
     test_narrow_minute_always,
     try_new_narrow_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10m ago"),
         (-2, "2m ago"),
@@ -921,9 +851,7 @@ generate_test!(
 generate_test!(
     test_narrow_minute_auto,
     try_new_narrow_minute,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10m ago"),
         (-2, "2m ago"),
@@ -946,9 +874,7 @@ generate_test!(
 generate_test!(
     test_narrow_hour_always,
     try_new_narrow_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10h ago"),
         (-2, "2h ago"),
@@ -971,9 +897,7 @@ generate_test!(
 generate_test!(
     test_narrow_hour_auto,
     try_new_narrow_hour,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10h ago"),
         (-2, "2h ago"),
@@ -996,9 +920,7 @@ generate_test!(
 generate_test!(
     test_narrow_day_always,
     try_new_narrow_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10d ago"),
         (-2, "2d ago"),
@@ -1021,9 +943,7 @@ generate_test!(
 generate_test!(
     test_narrow_day_auto,
     try_new_narrow_day,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10d ago"),
         (-2, "2d ago"),
@@ -1046,9 +966,7 @@ generate_test!(
 generate_test!(
     test_narrow_week_always,
     try_new_narrow_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10w ago"),
         (-2, "2w ago"),
@@ -1071,9 +989,7 @@ generate_test!(
 generate_test!(
     test_narrow_week_auto,
     try_new_narrow_week,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10w ago"),
         (-2, "2w ago"),
@@ -1096,9 +1012,7 @@ generate_test!(
 generate_test!(
     test_narrow_month_always,
     try_new_narrow_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10mo ago"),
         (-2, "2mo ago"),
@@ -1121,9 +1035,7 @@ generate_test!(
 generate_test!(
     test_narrow_month_auto,
     try_new_narrow_month,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10mo ago"),
         (-2, "2mo ago"),
@@ -1146,9 +1058,7 @@ generate_test!(
 generate_test!(
     test_narrow_quarter_always,
     try_new_narrow_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10q ago"),
         (-2, "2q ago"),
@@ -1171,9 +1081,7 @@ generate_test!(
 generate_test!(
     test_narrow_quarter_auto,
     try_new_narrow_quarter,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10q ago"),
         (-2, "2q ago"),
@@ -1196,9 +1104,7 @@ generate_test!(
 generate_test!(
     test_narrow_year_always,
     try_new_narrow_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Always
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Always),
     [
         (-10, "10y ago"),
         (-2, "2y ago"),
@@ -1221,9 +1127,7 @@ generate_test!(
 generate_test!(
     test_narrow_year_auto,
     try_new_narrow_year,
-    RelativeTimeFormatterOptions {
-        numeric: Numeric::Auto
-    },
+    RelativeTimeFormatterOptions::from(Numeric::Auto),
     [
         (-10, "10y ago"),
         (-2, "2y ago"),
@@ -1243,3 +1147,1162 @@ generate_test!(
         (10, "خلال ١٠ سنوات")
     ]
 );
+
+#[test]
+fn test_plural_examples() {
+    use icu_plurals::PluralCategory;
+
+    let examples = |relative_time_formatter: &RelativeTimeFormatter| {
+        relative_time_formatter
+            .plural_examples()
+            .into_iter()
+            .map(|(category, n)| (category, n.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    let relative_time_formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+    assert_eq!(
+        examples(&relative_time_formatter),
+        [
+            (PluralCategory::One, "1".into()),
+            (PluralCategory::Other, "2".into())
+        ]
+    );
+
+    let relative_time_formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("ar").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+    assert_eq!(
+        examples(&relative_time_formatter),
+        [
+            (PluralCategory::Zero, "0".into()),
+            (PluralCategory::One, "1".into()),
+            (PluralCategory::Two, "2".into()),
+            (PluralCategory::Few, "3".into()),
+            (PluralCategory::Many, "11".into()),
+            (PluralCategory::Other, "100".into()),
+        ]
+    );
+
+    // `other` is only selected by decimals in Russian
+    let relative_time_formatter = RelativeTimeFormatter::try_new_long_day(
+        &locale!("ru").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+    assert_eq!(
+        examples(&relative_time_formatter),
+        [
+            (PluralCategory::One, "1".into()),
+            (PluralCategory::Few, "2".into()),
+            (PluralCategory::Many, "5".into()),
+            (PluralCategory::Other, "0.1".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_pcm() {
+    let options = RelativeTimeFormatterOptions::from(Numeric::Always);
+    let locale = &locale!("pcm").into();
+
+    let long_year = RelativeTimeFormatter::try_new_long_year(locale, options).unwrap();
+    let long_quarter = RelativeTimeFormatter::try_new_long_quarter(locale, options).unwrap();
+    let long_month = RelativeTimeFormatter::try_new_long_month(locale, options).unwrap();
+    let long_week = RelativeTimeFormatter::try_new_long_week(locale, options).unwrap();
+    let long_day = RelativeTimeFormatter::try_new_long_day(locale, options).unwrap();
+    let long_hour = RelativeTimeFormatter::try_new_long_hour(locale, options).unwrap();
+    let long_minute = RelativeTimeFormatter::try_new_long_minute(locale, options).unwrap();
+    let long_second = RelativeTimeFormatter::try_new_long_second(locale, options).unwrap();
+
+    // Nigerian Pidgin does not inflect the unit for number, so 1, 2, 5 and 10 all use the
+    // same form.
+    for (formatter, unit) in [
+        (&long_year, "yiẹ"),
+        (&long_quarter, "kwọ́ta"),
+        (&long_month, "mọnt"),
+        (&long_week, "wik"),
+        (&long_day, "dè"),
+        (&long_hour, "áwa"),
+        (&long_minute, "mínit"),
+        (&long_second, "sẹ́kọn"),
+    ] {
+        for value in [1, 2, 5, 10] {
+            assert_writeable_eq!(
+                formatter.format(FixedDecimal::from(-value)),
+                format!("{value} {unit} wé dọ́n pas")
+            );
+        }
+    }
+
+    for (value, expected) in [
+        (1, "fọ 1 yiẹ wé de kọm"),
+        (2, "fọ 2 yiẹ wé de kọm"),
+        (5, "fọ 5 yiẹ wé de kọm"),
+        (10, "fọ 10 yiẹ wé de kọm"),
+    ] {
+        assert_writeable_eq!(long_year.format(FixedDecimal::from(value)), expected);
+    }
+    assert_writeable_eq!(
+        long_quarter.format(FixedDecimal::from(5)),
+        "fọ 5 kwọ́ta wé de kọm"
+    );
+    assert_writeable_eq!(
+        long_month.format(FixedDecimal::from(2)),
+        "Fọ 2mọnt wé de kọm"
+    );
+    assert_writeable_eq!(
+        long_week.format(FixedDecimal::from(10)),
+        "Fọ 10wik wé de kọm"
+    );
+    assert_writeable_eq!(long_day.format(FixedDecimal::from(1)), "Fọ 1dè wé de kọm");
+    assert_writeable_eq!(
+        long_hour.format(FixedDecimal::from(5)),
+        "Fọ 5 áwa wé de kọm"
+    );
+    assert_writeable_eq!(
+        long_minute.format(FixedDecimal::from(2)),
+        "Fọ 2 mínit wé de kọm"
+    );
+    assert_writeable_eq!(long_second.format(FixedDecimal::from(10)), "Fọ 10 Sẹ́kọn");
+
+    // CLDR uses the long patterns for the short width in pcm.
+    for (long, short) in [
+        (
+            &long_year,
+            RelativeTimeFormatter::try_new_short_year(locale, options).unwrap(),
+        ),
+        (
+            &long_month,
+            RelativeTimeFormatter::try_new_short_month(locale, options).unwrap(),
+        ),
+        (
+            &long_week,
+            RelativeTimeFormatter::try_new_short_week(locale, options).unwrap(),
+        ),
+        (
+            &long_day,
+            RelativeTimeFormatter::try_new_short_day(locale, options).unwrap(),
+        ),
+        (
+            &long_hour,
+            RelativeTimeFormatter::try_new_short_hour(locale, options).unwrap(),
+        ),
+        (
+            &long_minute,
+            RelativeTimeFormatter::try_new_short_minute(locale, options).unwrap(),
+        ),
+        (
+            &long_second,
+            RelativeTimeFormatter::try_new_short_second(locale, options).unwrap(),
+        ),
+    ] {
+        for value in [-10, -5, -2, -1, 1, 2, 5, 10] {
+            assert_eq!(
+                writeable::Writeable::write_to_string(&long.format(FixedDecimal::from(value))),
+                writeable::Writeable::write_to_string(&short.format(FixedDecimal::from(value))),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_kgp() {
+    use icu_experimental::relativetime::provider::LongQuarterRelativeTimeFormatDataV1Marker;
+    use icu_plurals::{PluralCategory, PluralRules};
+    use icu_provider::prelude::*;
+
+    let locale = &locale!("kgp").into();
+
+    // CLDR has no plural rules for Kaingang, so every number uses `other`.
+    assert_eq!(
+        PluralRules::try_new_cardinal(locale)
+            .unwrap()
+            .categories()
+            .collect::<Vec<_>>(),
+        [PluralCategory::Other]
+    );
+    let data: DataPayload<LongQuarterRelativeTimeFormatDataV1Marker> =
+        icu_experimental::provider::Baked
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+    for mapping in [&data.get().past, &data.get().future] {
+        assert_eq!(mapping.zero, None);
+        assert_eq!(mapping.one, None);
+        assert_eq!(mapping.two, None);
+        assert_eq!(mapping.few, None);
+        assert_eq!(mapping.many, None);
+    }
+
+    let long_quarter = RelativeTimeFormatter::try_new_long_quarter(
+        locale,
+        RelativeTimeFormatterOptions::from(Numeric::Always),
+    )
+    .unwrap();
+    for value in [0, 1, 2, 5, 10] {
+        assert_writeable_eq!(
+            long_quarter.format(FixedDecimal::from(value)),
+            format!("kysã-tẽgtũ {value} kar kỹ")
+        );
+    }
+    for value in [1, 2, 5, 10] {
+        assert_writeable_eq!(
+            long_quarter.format(FixedDecimal::from(-value)),
+            format!("kysã-tẽgtũ {value} si ser")
+        );
+    }
+
+    let long_quarter = RelativeTimeFormatter::try_new_long_quarter(
+        locale,
+        RelativeTimeFormatterOptions::from(Numeric::Auto),
+    )
+    .unwrap();
+    for (value, expected) in [
+        (-2, "kysã-tẽgtũ 2 si ser"),
+        (-1, "kysã tẽgtũ ẽg no"),
+        (0, "kysã tẽgtũ tag"),
+        (1, "kysã tẽgtũ ũn kã"),
+        (2, "kysã-tẽgtũ 2 kar kỹ"),
+        (5, "kysã-tẽgtũ 5 kar kỹ"),
+        (10, "kysã-tẽgtũ 10 kar kỹ"),
+    ] {
+        assert_writeable_eq!(long_quarter.format(FixedDecimal::from(value)), expected);
+    }
+}
+
+#[test]
+fn test_pattern_overrides() {
+    use icu_experimental::relativetime::{RelativeTimeError, Tense};
+    use icu_plurals::PluralCategory;
+
+    let mut relative_time_formatter = RelativeTimeFormatter::try_new_long_quarter(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::from(Numeric::Auto),
+    )
+    .expect("locale should be present");
+
+    relative_time_formatter.set_relative_override(0, "current quarter");
+    relative_time_formatter
+        .set_pattern_override(Tense::Past, PluralCategory::One, "a quarter ago")
+        .unwrap();
+    relative_time_formatter
+        .set_pattern_override(
+            Tense::Future,
+            PluralCategory::Other,
+            "{0} quarters from now",
+        )
+        .unwrap();
+
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(0)),
+        "current quarter"
+    );
+    // Unchanged data is still used
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(-1)),
+        "last quarter"
+    );
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(-2)),
+        "2 quarters ago"
+    );
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(2)),
+        "2 quarters from now"
+    );
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(1)),
+        "next quarter"
+    );
+
+    let mut relative_time_formatter = RelativeTimeFormatter::try_new_long_quarter(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+    relative_time_formatter
+        .set_pattern_override(Tense::Past, PluralCategory::One, "a quarter ago")
+        .unwrap();
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(-1)),
+        "a quarter ago"
+    );
+
+    // Categories without data fall back to `other` unless overridden
+    relative_time_formatter
+        .set_pattern_override(
+            Tense::Future,
+            PluralCategory::Two,
+            "in a couple of quarters",
+        )
+        .unwrap();
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(2)),
+        "in 2 quarters"
+    );
+
+    for pattern in [
+        "in {0} {0} quarters",
+        "in {1} quarters",
+        "in {0 quarters",
+        "}{0}",
+    ] {
+        assert_eq!(
+            relative_time_formatter.set_pattern_override(
+                Tense::Future,
+                PluralCategory::Other,
+                pattern
+            ),
+            Err(RelativeTimeError::InvalidPattern),
+            "{pattern}"
+        );
+    }
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from(3)),
+        "in 3 quarters"
+    );
+}
+
+#[test]
+fn test_format_length_hint() {
+    let cases = [
+        (
+            RelativeTimeFormatter::try_new_long_hour(
+                &locale!("en").into(),
+                RelativeTimeFormatterOptions::default(),
+            )
+            .unwrap(),
+            [-1, 5, -120, 999],
+        ),
+        (
+            RelativeTimeFormatter::try_new_narrow_year(
+                &locale!("bn").into(),
+                RelativeTimeFormatterOptions::default(),
+            )
+            .unwrap(),
+            [3, -15, 100, -999],
+        ),
+        (
+            RelativeTimeFormatter::try_new_long_quarter(
+                &locale!("en").into(),
+                RelativeTimeFormatterOptions::from(Numeric::Auto),
+            )
+            .unwrap(),
+            [-1, 0, 1, 999],
+        ),
+    ];
+    for (relative_time_formatter, values) in cases {
+        let hint = relative_time_formatter.format_length_hint();
+        for value in values {
+            let formatted = relative_time_formatter
+                .format(FixedDecimal::from(value))
+                .to_string();
+            assert!(formatted.len() <= hint, "{formatted} > {hint}");
+        }
+    }
+}
+
+#[test]
+fn test_format_all_widths() {
+    use icu_experimental::relativetime::RelativeTimeUnit;
+
+    assert_eq!(
+        RelativeTimeFormatter::format_all_widths(
+            &locale!("en").into(),
+            RelativeTimeUnit::Second,
+            FixedDecimal::from(5),
+        )
+        .unwrap(),
+        ["in 5 seconds", "in 5 sec.", "in 5s"]
+    );
+
+    // The short width falls back to the long patterns in pcm.
+    let [long, short, _] = RelativeTimeFormatter::format_all_widths(
+        &locale!("pcm").into(),
+        RelativeTimeUnit::Year,
+        FixedDecimal::from(-2),
+    )
+    .unwrap();
+    assert_eq!(long, "2 yiẹ wé dọ́n pas");
+    assert_eq!(short, long);
+}
+
+#[test]
+fn test_resolved_locale() {
+    for (requested, resolved) in [
+        (locale!("en"), "en"),
+        (locale!("en-US"), "en"),
+        (locale!("sr-Latn-BA"), "sr-Latn-BA"),
+        (locale!("sr-Latn-ME"), "sr-Latn"),
+        (locale!("xx"), "und"),
+    ] {
+        let relative_time_formatter = RelativeTimeFormatter::try_new_short_month(
+            &requested.into(),
+            RelativeTimeFormatterOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            relative_time_formatter.resolved_locale().to_string(),
+            resolved
+        );
+    }
+}
+
+#[test]
+fn test_check_data_freshness() {
+    use icu_experimental::relativetime::check_data_freshness;
+
+    // The compiled data in this repository is generated from a tagged CLDR release
+    assert_eq!(
+        check_data_freshness(),
+        Some(icu_experimental_data::CLDR_VERSION)
+    );
+}
+
+#[test]
+fn test_measure_unit_conversions() {
+    use icu_experimental::relativetime::RelativeTimeUnit;
+    use icu_experimental::units::converter_factory::ConverterFactory;
+
+    let factory = ConverterFactory::new();
+    let parser = factory.parser();
+
+    for unit in [
+        RelativeTimeUnit::Second,
+        RelativeTimeUnit::Minute,
+        RelativeTimeUnit::Hour,
+        RelativeTimeUnit::Day,
+        RelativeTimeUnit::Week,
+        RelativeTimeUnit::Month,
+        RelativeTimeUnit::Quarter,
+        RelativeTimeUnit::Year,
+    ] {
+        let measure_unit = unit.to_measure_unit(&parser).unwrap();
+        assert_eq!(
+            RelativeTimeUnit::try_from_measure_unit(&measure_unit, &parser),
+            Some(unit)
+        );
+        let parsed = parser
+            .try_from_bytes(unit.unit_identifier().as_bytes())
+            .unwrap();
+        assert_eq!(
+            RelativeTimeUnit::try_from_measure_unit(&parsed, &parser),
+            Some(unit)
+        );
+    }
+
+    for identifier in ["kilosecond", "square-second", "meter-per-second", "century"] {
+        let measure_unit = parser.try_from_bytes(identifier.as_bytes()).unwrap();
+        assert_eq!(
+            RelativeTimeUnit::try_from_measure_unit(&measure_unit, &parser),
+            None,
+            "{identifier}"
+        );
+    }
+}
+
+#[test]
+fn test_format_into() {
+    let relative_time_formatter = RelativeTimeFormatter::try_new_short_week(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::from(Numeric::Auto),
+    )
+    .unwrap();
+
+    let mut s = String::with_capacity(64);
+    let ptr = s.as_ptr();
+    s.push_str("schedule: ");
+    for value in [-1, 0, 1, 5] {
+        relative_time_formatter.format_into(FixedDecimal::from(value), &mut s);
+        s.push(';');
+    }
+    assert_eq!(s, "schedule: last wk.;this wk.;next wk.;in 5 wk.;");
+    // The buffer was only appended to, without reallocating
+    assert_eq!(s.as_ptr(), ptr);
+
+    for value in [-1, 0, 1, 5] {
+        let mut s = String::new();
+        relative_time_formatter.format_into(FixedDecimal::from(value), &mut s);
+        assert_eq!(
+            s,
+            relative_time_formatter
+                .format(FixedDecimal::from(value))
+                .to_string()
+        );
+    }
+}
+
+#[test]
+fn test_sub_unit_policy() {
+    use core::str::FromStr;
+    use fixed_decimal::RoundingMode;
+    use icu_experimental::relativetime::options::SubUnitPolicy;
+
+    let cases = [
+        (
+            SubUnitPolicy::RoundNearest(RoundingMode::HalfEven),
+            "in 1 quarter",
+        ),
+        (
+            SubUnitPolicy::RoundNearest(RoundingMode::Ceil),
+            "in 2 quarters",
+        ),
+        (SubUnitPolicy::Truncate, "in 1 quarter"),
+        (SubUnitPolicy::ShowFraction, "in 1.4 quarters"),
+    ];
+    for (sub_unit, expected) in cases {
+        let relative_time_formatter = RelativeTimeFormatter::try_new_long_quarter(
+            &locale!("en").into(),
+            RelativeTimeFormatterOptions::from(sub_unit),
+        )
+        .unwrap();
+        assert_writeable_eq!(
+            relative_time_formatter.format(FixedDecimal::from_str("1.4").unwrap()),
+            expected,
+            "{sub_unit:?}"
+        );
+    }
+
+    // Rounding applies to the signed value, before the special forms are selected
+    let mut options = RelativeTimeFormatterOptions::from(Numeric::Auto);
+    options.sub_unit = SubUnitPolicy::RoundNearest(RoundingMode::Floor);
+    let relative_time_formatter =
+        RelativeTimeFormatter::try_new_long_quarter(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from_str("-1.4").unwrap()),
+        "2 quarters ago"
+    );
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from_str("0.6").unwrap()),
+        "this quarter"
+    );
+    assert_writeable_eq!(
+        relative_time_formatter.format(FixedDecimal::from_str("1.0").unwrap()),
+        "next quarter"
+    );
+}
+
+#[test]
+#[cfg(feature = "relativetime_namer")]
+fn test_relative_date_time_namer() {
+    use icu::calendar::{japanese::Japanese, Date, Gregorian};
+    use icu_experimental::relativetime::{RelativeDateTimeNamer, RelativeDateTimeNamerOptions};
+
+    // 2024-01-15 is a Monday
+    let reference = Date::try_new_iso_date(2024, 1, 15).unwrap();
+    let expected_en = [
+        "Jan 7, 2024",
+        "Jan 8, 2024",
+        "Jan 9, 2024",
+        "Jan 10, 2024",
+        "Jan 11, 2024",
+        "Jan 12, 2024",
+        "Jan 13, 2024",
+        "yesterday",
+        "today",
+        "tomorrow",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+        "Jan 22, 2024",
+        "Jan 23, 2024",
+    ];
+    let expected_ja = [
+        "2024/01/07",
+        "2024/01/08",
+        "2024/01/09",
+        "2024/01/10",
+        "2024/01/11",
+        "2024/01/12",
+        "一昨日",
+        "昨日",
+        "今日",
+        "明日",
+        "明後日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+        "2024/01/22",
+        "2024/01/23",
+    ];
+    for (locale, expected) in [(locale!("en"), expected_en), (locale!("ja"), expected_ja)] {
+        let namer =
+            RelativeDateTimeNamer::try_new(&locale.into(), RelativeDateTimeNamerOptions::default())
+                .unwrap();
+        for (offset, expected) in (-8i32..=8).zip(expected) {
+            let date = Date::try_new_iso_date(2024, 1, (15 + offset) as u8).unwrap();
+            assert_eq!(
+                namer.format(&date, &reference).unwrap(),
+                expected,
+                "{offset}"
+            );
+            // The calendars of the dates do not matter
+            assert_eq!(
+                namer
+                    .format(
+                        &date.to_calendar(Japanese::new()),
+                        &reference.to_calendar(Gregorian)
+                    )
+                    .unwrap(),
+                expected,
+                "{offset}"
+            );
+        }
+    }
+
+    // A cutoff of 7 days would name the weekday of the reference date
+    let mut options = RelativeDateTimeNamerOptions::default();
+    options.cutoff_days = 7;
+    let namer = RelativeDateTimeNamer::try_new(&locale!("en").into(), options).unwrap();
+    let date = Date::try_new_iso_date(2024, 1, 22).unwrap();
+    assert_eq!(namer.format(&date, &reference).unwrap(), "Jan 22, 2024");
+
+    options.cutoff_days = 1;
+    let namer = RelativeDateTimeNamer::try_new(&locale!("en").into(), options).unwrap();
+    let date = Date::try_new_iso_date(2024, 1, 17).unwrap();
+    assert_eq!(namer.format(&date, &reference).unwrap(), "Jan 17, 2024");
+
+    // Across years
+    let namer = RelativeDateTimeNamer::try_new(
+        &locale!("en").into(),
+        RelativeDateTimeNamerOptions::default(),
+    )
+    .unwrap();
+    let reference = Date::try_new_iso_date(2023, 12, 31).unwrap();
+    let date = Date::try_new_iso_date(2024, 1, 1).unwrap();
+    assert_eq!(namer.format(&date, &reference).unwrap(), "tomorrow");
+}
+
+#[test]
+fn test_form() {
+    use core::str::FromStr;
+    use icu_experimental::relativetime::options::SubUnitPolicy;
+    use icu_experimental::relativetime::RelativeTimeForm;
+
+    let locale = &locale!("en").into();
+    let auto = RelativeTimeFormatter::try_new_long_quarter(
+        locale,
+        RelativeTimeFormatterOptions::from(Numeric::Auto),
+    )
+    .unwrap();
+    let always = RelativeTimeFormatter::try_new_long_quarter(
+        locale,
+        RelativeTimeFormatterOptions::from(Numeric::Always),
+    )
+    .unwrap();
+
+    for (value, auto_form, auto_expected, always_expected) in [
+        (
+            -10,
+            RelativeTimeForm::Numeric,
+            "10 quarters ago",
+            "10 quarters ago",
+        ),
+        (
+            -2,
+            RelativeTimeForm::Numeric,
+            "2 quarters ago",
+            "2 quarters ago",
+        ),
+        (
+            -1,
+            RelativeTimeForm::Lexical,
+            "last quarter",
+            "1 quarter ago",
+        ),
+        (
+            0,
+            RelativeTimeForm::Lexical,
+            "this quarter",
+            "in 0 quarters",
+        ),
+        (1, RelativeTimeForm::Lexical, "next quarter", "in 1 quarter"),
+        (
+            2,
+            RelativeTimeForm::Numeric,
+            "in 2 quarters",
+            "in 2 quarters",
+        ),
+    ] {
+        let formatted = auto.format(FixedDecimal::from(value));
+        assert_writeable_eq!(formatted, auto_expected);
+        assert_eq!(formatted.form(), auto_form, "{value}");
+
+        let formatted = always.format(FixedDecimal::from(value));
+        assert_writeable_eq!(formatted, always_expected);
+        assert_eq!(formatted.form(), RelativeTimeForm::Numeric, "{value}");
+    }
+
+    // Fractions are never lexical unless they are rounded away
+    let formatted = auto.format(FixedDecimal::from_str("1.0").unwrap());
+    assert_writeable_eq!(formatted, "in 1.0 quarters");
+    assert_eq!(formatted.form(), RelativeTimeForm::Numeric);
+
+    let mut options = RelativeTimeFormatterOptions::from(Numeric::Auto);
+    options.sub_unit = SubUnitPolicy::Truncate;
+    let truncating = RelativeTimeFormatter::try_new_long_quarter(locale, options).unwrap();
+    let formatted = truncating.format(FixedDecimal::from_str("1.4").unwrap());
+    assert_writeable_eq!(formatted, "next quarter");
+    assert_eq!(formatted.form(), RelativeTimeForm::Lexical);
+
+    // Overridden names are lexical
+    let mut auto = auto;
+    auto.set_relative_override(2, "the quarter after next");
+    let formatted = auto.format(FixedDecimal::from(2));
+    assert_writeable_eq!(formatted, "the quarter after next");
+    assert_eq!(formatted.form(), RelativeTimeForm::Lexical);
+}
+
+#[test]
+fn test_composite() {
+    use icu_experimental::relativetime::{
+        CompositeRelativeTimeFormatter, CompositeRelativeTimeFormatterOptions,
+    };
+
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let seconds = [80 * MINUTE, -(DAY + 2 * HOUR), 45, -1, 0];
+    for (locale, long, narrow) in [
+        (
+            locale!("en"),
+            [
+                "in 1 hour, 20 minutes",
+                "1 day, 2 hours ago",
+                "in 45 seconds",
+                "1 second ago",
+                "in 0 seconds",
+            ],
+            ["in 1h 20m", "1d 2h ago", "in 45s", "1s ago", "in 0s"],
+        ),
+        (
+            locale!("de"),
+            [
+                "in 1 Stunde, 20 Minuten",
+                "vor 1 Tag, 2 Stunden",
+                "in 45 Sekunden",
+                "vor 1 Sekunde",
+                "in 0 Sekunden",
+            ],
+            [
+                "in 1 Std., 20 m",
+                "vor 1 Tag, 2 Std.",
+                "in 45 s",
+                "vor 1 s",
+                "in 0 s",
+            ],
+        ),
+        (
+            locale!("ja"),
+            [
+                "1 時間 20 分後",
+                "1 日 2 時間前",
+                "45 秒後",
+                "1 秒前",
+                "0 秒後",
+            ],
+            ["1時間20分後", "1日2時間前", "45秒後", "1秒前", "0秒後"],
+        ),
+        (
+            locale!("he"),
+            [
+                "בעוד 1 שעות, 20 דקות",
+                "לפני יום 1, 2 שעות",
+                "בעוד 45 שניות",
+                "לפני 1 שניות",
+                "בעוד 0 שניות",
+            ],
+            [
+                "בעוד 1 שע׳ 20 דק׳",
+                "לפני 1 ימים 2 שע׳",
+                "בעוד 45 שנ׳",
+                "לפני 1 שנ׳",
+                "בעוד 0 שנ׳",
+            ],
+        ),
+        (
+            locale!("th"),
+            [
+                "ในอีก 1 ชั่วโมง และ 20 นาที",
+                "1 วัน และ 2 ชั่วโมงที่ผ่านมา",
+                "ในอีก 45 วินาที",
+                "1 วินาทีที่ผ่านมา",
+                "ในอีก 0 วินาที",
+            ],
+            [
+                "ใน 1 ชม. 20 นาที",
+                "1 วัน 2 ชม. ที่แล้ว",
+                "ใน 45 วินาที",
+                "1 วินาทีที่แล้ว",
+                "ใน 0 วินาที",
+            ],
+        ),
+    ] {
+        let locale = &locale.into();
+        let options = CompositeRelativeTimeFormatterOptions::default();
+        let formatter = CompositeRelativeTimeFormatter::try_new_long(locale, options).unwrap();
+        for (seconds, expected) in seconds.into_iter().zip(long) {
+            assert_writeable_eq!(formatter.format(seconds), expected, "{locale} {seconds}");
+        }
+        let formatter = CompositeRelativeTimeFormatter::try_new_narrow(locale, options).unwrap();
+        for (seconds, expected) in seconds.into_iter().zip(narrow) {
+            assert_writeable_eq!(formatter.format(seconds), expected, "{locale} {seconds}");
+        }
+    }
+
+    // Only the largest nonzero units are shown, and smaller units are truncated
+    let mut options = CompositeRelativeTimeFormatterOptions::default();
+    let formatter =
+        CompositeRelativeTimeFormatter::try_new_long(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(
+        formatter.format(DAY + 5 * MINUTE + 59),
+        "in 1 day, 5 minutes"
+    );
+
+    options.max_units = 3;
+    let formatter =
+        CompositeRelativeTimeFormatter::try_new_long(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(
+        formatter.format(-(DAY + 5 * MINUTE + 59)),
+        "1 day, 5 minutes, 59 seconds ago"
+    );
+
+    options.max_units = 0;
+    let formatter =
+        CompositeRelativeTimeFormatter::try_new_short(&locale!("en").into(), options).unwrap();
+    assert_writeable_eq!(formatter.format(-(3 * HOUR + 59 * MINUTE)), "3 hr. ago");
+    assert_writeable_eq!(formatter.format(i64::MIN), "106,751,991,167,300 days ago");
+
+    // Forms without a placeholder, such as "אתמול" (yesterday), are not put into the frame
+    let formatter = CompositeRelativeTimeFormatter::try_new_short(
+        &locale!("he").into(),
+        CompositeRelativeTimeFormatterOptions::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(formatter.format(-DAY), "לפני 1 ימים");
+    assert_writeable_eq!(formatter.format(DAY + HOUR), "בעוד 1 ימים, 1 שע׳");
+
+    // The space before the frame is not kept between the units
+    let formatter = CompositeRelativeTimeFormatter::try_new_short(
+        &locale!("th").into(),
+        CompositeRelativeTimeFormatterOptions::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(formatter.format(-(HOUR + MINUTE)), "1 ชม. 1 นาทีที่แล้ว");
+    assert_writeable_eq!(formatter.format(-HOUR), "1 ชม. ที่แล้ว");
+}
+
+#[test]
+fn test_composite_calendar() {
+    use icu_experimental::relativetime::{
+        CompositeRelativeTimeFormatter, CompositeRelativeTimeFormatterOptions, RelativeTimeUnit,
+    };
+
+    // 2 years and 3 quarters are 33 months
+    assert_eq!(
+        RelativeTimeUnit::Month.decompose(
+            33,
+            &[
+                RelativeTimeUnit::Month,
+                RelativeTimeUnit::Year,
+                RelativeTimeUnit::Quarter
+            ]
+        ),
+        Some(vec![
+            (RelativeTimeUnit::Year, 2),
+            (RelativeTimeUnit::Quarter, 3)
+        ])
+    );
+    assert_eq!(
+        RelativeTimeUnit::Quarter.decompose(-11, &[RelativeTimeUnit::Year]),
+        Some(vec![(RelativeTimeUnit::Year, -2)])
+    );
+    assert_eq!(
+        RelativeTimeUnit::Month.decompose(2, &[RelativeTimeUnit::Year]),
+        Some(vec![])
+    );
+    assert_eq!(
+        RelativeTimeUnit::Week.decompose(3, &[RelativeTimeUnit::Day, RelativeTimeUnit::Month]),
+        None
+    );
+    assert_eq!(
+        RelativeTimeUnit::Week.decompose(i64::MAX, &[RelativeTimeUnit::Second]),
+        Some(vec![(RelativeTimeUnit::Second, i64::MAX)])
+    );
+
+    let mut options = CompositeRelativeTimeFormatterOptions::default();
+    let formatter =
+        CompositeRelativeTimeFormatter::try_new_long_calendar(&locale!("en").into(), options)
+            .unwrap();
+    assert_writeable_eq!(formatter.format(-33), "2 years, 3 quarters ago");
+    assert_writeable_eq!(formatter.format(33), "in 2 years, 3 quarters");
+    assert_writeable_eq!(formatter.format(14), "in 1 year, 2 months");
+    assert_writeable_eq!(formatter.format(0), "in 0 months");
+
+    options.max_units = 3;
+    let formatter =
+        CompositeRelativeTimeFormatter::try_new_short_calendar(&locale!("en").into(), options)
+            .unwrap();
+    assert_writeable_eq!(formatter.format(-35), "2 yr., 3 qtrs., 2 mo. ago");
+}
+
+#[test]
+fn test_compact() {
+    use icu_experimental::relativetime::options::UnitDisplay;
+    use icu_experimental::relativetime::{RelativeTimeForm, Tense};
+    use icu_plurals::PluralCategory;
+
+    let options = RelativeTimeFormatterOptions::from(UnitDisplay::Compact);
+
+    let en = RelativeTimeFormatter::try_new_narrow_quarter(&locale!("en").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(en.format(FixedDecimal::from(-3)), "-3q");
+    assert_writeable_eq!(en.format(FixedDecimal::from(3)), "+3q");
+    assert_writeable_eq!(en.format(FixedDecimal::from(0)), "0q");
+    assert_writeable_eq!(en.format(FixedDecimal::from(-1000)), "-1,000q");
+    assert_eq!(
+        en.format(FixedDecimal::from(-3)).form(),
+        RelativeTimeForm::Numeric
+    );
+
+    // The unit of the Japanese narrow data is not a single character
+    let ja = RelativeTimeFormatter::try_new_narrow_quarter(&locale!("ja").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(ja.format(FixedDecimal::from(-3)), "-3四半期");
+    assert_writeable_eq!(ja.format(FixedDecimal::from(2)), "+2四半期");
+
+    // Compact formatting takes precedence over names
+    let mut auto = options;
+    auto.numeric = Numeric::Auto;
+    let en_auto = RelativeTimeFormatter::try_new_narrow_quarter(&locale!("en").into(), auto)
+        .expect("locale should be present");
+    assert_writeable_eq!(en_auto.format(FixedDecimal::from(-1)), "-1q");
+
+    // A unit separated from the number keeps its whitespace
+    let en_long = RelativeTimeFormatter::try_new_long_quarter(&locale!("en").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(en_long.format(FixedDecimal::from(-3)), "-3 quarters");
+
+    // Without a shared unit, the patterns are used
+    let mut en_auto = en_auto;
+    en_auto
+        .set_pattern_override(Tense::Future, PluralCategory::Other, "{0}q later")
+        .unwrap();
+    en_auto
+        .set_pattern_override(Tense::Past, PluralCategory::Other, "Q{0} before")
+        .unwrap();
+    assert_writeable_eq!(en_auto.format(FixedDecimal::from(3)), "3q later");
+    assert_writeable_eq!(en_auto.format(FixedDecimal::from(-1)), "last qtr.");
+    assert_eq!(
+        en_auto.format(FixedDecimal::from(-1)).form(),
+        RelativeTimeForm::Lexical
+    );
+}
+
+#[test]
+fn test_numbering_system() {
+    use icu_experimental::relativetime::RelativeTimeError;
+    use tinystr::tinystr;
+
+    let mut options = RelativeTimeFormatterOptions::default();
+    let ar = RelativeTimeFormatter::try_new_long_day(&locale!("ar").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(ar.format(FixedDecimal::from(3)), "خلال ٣ أيام");
+
+    options.numbering_system = Some(tinystr!(8, "latn"));
+    let ar_latn = RelativeTimeFormatter::try_new_long_day(&locale!("ar").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(ar_latn.format(FixedDecimal::from(3)), "خلال 3 أيام");
+
+    options.numbering_system = Some(tinystr!(8, "arab"));
+    let ar_arab = RelativeTimeFormatter::try_new_long_day(&locale!("ar").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(ar_arab.format(FixedDecimal::from(3)), "خلال ٣ أيام");
+
+    // A numbering system without symbols for the locale uses its digits
+    options.numbering_system = Some(tinystr!(8, "deva"));
+    let en_deva = RelativeTimeFormatter::try_new_long_day(&locale!("en").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(en_deva.format(FixedDecimal::from(-1234)), "१,२३४ days ago");
+
+    for name in [
+        tinystr!(8, "xxxx"),
+        tinystr!(8, "roman"),
+        tinystr!(8, "Latn"),
+    ] {
+        options.numbering_system = Some(name);
+        assert!(matches!(
+            RelativeTimeFormatter::try_new_long_day(&locale!("en").into(), options),
+            Err(RelativeTimeError::UnsupportedNumberingSystem(n)) if n == name
+        ));
+    }
+}
+
+#[test]
+fn test_trim_empty_substitution() {
+    use icu_decimal::provider::DecimalSymbolsV1Marker;
+    use icu_experimental::relativetime::provider::LongQuarterRelativeTimeFormatDataV1Marker;
+    use icu_plurals::provider::CardinalV1Marker;
+    use icu_provider::prelude::*;
+
+    /// Baked data with zero-width digits, so that the number at the placeholder is invisible.
+    struct ZeroWidthDigits;
+
+    impl DataProvider<DecimalSymbolsV1Marker> for ZeroWidthDigits {
+        fn load(
+            &self,
+            req: DataRequest,
+        ) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
+            let mut response: DataResponse<DecimalSymbolsV1Marker> =
+                icu_decimal::provider::Baked.load(req)?;
+            if let Some(payload) = response.payload.as_mut() {
+                payload.with_mut(|symbols| symbols.digits = ['\u{200B}'; 10]);
+            }
+            Ok(response)
+        }
+    }
+
+    impl DataProvider<CardinalV1Marker> for ZeroWidthDigits {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<CardinalV1Marker>, DataError> {
+            icu_plurals::provider::Baked.load(req)
+        }
+    }
+
+    impl DataProvider<LongQuarterRelativeTimeFormatDataV1Marker> for ZeroWidthDigits {
+        fn load(
+            &self,
+            req: DataRequest,
+        ) -> Result<DataResponse<LongQuarterRelativeTimeFormatDataV1Marker>, DataError> {
+            icu_experimental::provider::Baked.load(req)
+        }
+    }
+
+    let mut options = RelativeTimeFormatterOptions::default();
+    let ru = RelativeTimeFormatter::try_new_long_quarter_unstable(
+        &ZeroWidthDigits,
+        &locale!("ru").into(),
+        options,
+    )
+    .expect("locale should be present");
+    assert_writeable_eq!(ru.format(FixedDecimal::from(1)), "через \u{200B} квартал");
+    assert_writeable_eq!(ru.format(FixedDecimal::from(-1)), "\u{200B} квартал назад");
+
+    options.trim_empty_substitution = true;
+    let ru = RelativeTimeFormatter::try_new_long_quarter_unstable(
+        &ZeroWidthDigits,
+        &locale!("ru").into(),
+        options,
+    )
+    .expect("locale should be present");
+    assert_writeable_eq!(ru.format(FixedDecimal::from(1)), "через \u{200B}квартал");
+    // There is no doubled space at the start of the pattern
+    assert_writeable_eq!(ru.format(FixedDecimal::from(-1)), "\u{200B} квартал назад");
+
+    // Visible numbers keep both spaces
+    let ru = RelativeTimeFormatter::try_new_long_quarter(&locale!("ru").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(ru.format(FixedDecimal::from(1)), "через 1 квартал");
+    assert_writeable_eq!(ru.format(FixedDecimal::from(-3)), "3 квартала назад");
+}
+
+#[test]
+fn test_trim_empty_substitution_map_number() {
+    let mut options = RelativeTimeFormatterOptions::default();
+    let ru = RelativeTimeFormatter::try_new_long_quarter(&locale!("ru").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(
+        ru.format(FixedDecimal::from(3))
+            .map_number(|_| String::new()),
+        "через  квартала"
+    );
+
+    options.trim_empty_substitution = true;
+    let ru = RelativeTimeFormatter::try_new_long_quarter(&locale!("ru").into(), options)
+        .expect("locale should be present");
+    assert_writeable_eq!(
+        ru.format(FixedDecimal::from(3))
+            .map_number(|_| String::new()),
+        "через квартала"
+    );
+    assert_writeable_eq!(
+        ru.format(FixedDecimal::from(-3))
+            .map_number(|_| String::new()),
+        " квартала назад"
+    );
+    // The number that is written is checked, not the formatted one
+    assert_writeable_eq!(
+        ru.format(FixedDecimal::from(3))
+            .map_number(|number| format!("[{number}]")),
+        "через [3] квартала"
+    );
+}
+
+#[test]
+fn test_map_number() {
+    let wrap = |number: &str| format!("<span class=\"num\">{number}</span>");
+
+    let en = RelativeTimeFormatter::try_new_long_day(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::from(Numeric::Auto),
+    )
+    .expect("locale should be present");
+    assert_writeable_eq!(
+        en.format(FixedDecimal::from(3)).map_number(wrap),
+        "in <span class=\"num\">3</span> days"
+    );
+    assert_writeable_eq!(
+        en.format(FixedDecimal::from(-1200)).map_number(wrap),
+        "<span class=\"num\">1,200</span> days ago"
+    );
+    assert_eq!(
+        en.format(FixedDecimal::from(-2))
+            .map_number(wrap)
+            .to_string(),
+        "<span class=\"num\">2</span> days ago"
+    );
+    // Names have no number to map
+    assert_writeable_eq!(
+        en.format(FixedDecimal::from(-1)).map_number(wrap),
+        "yesterday"
+    );
+
+    // The closure only replaces the number, even if it returns pattern syntax
+    assert_writeable_eq!(
+        en.format(FixedDecimal::from(5))
+            .map_number(|number| format!("{{0}}{number}{{0}}")),
+        "in {0}5{0} days"
+    );
+    assert_writeable_eq!(
+        en.format(FixedDecimal::from(5))
+            .map_number(|_| String::new()),
+        "in  days"
+    );
+
+    // The sign of compact formatting is part of the number
+    let en_compact = RelativeTimeFormatter::try_new_narrow_quarter(
+        &locale!("en").into(),
+        RelativeTimeFormatterOptions::from(
+            icu_experimental::relativetime::options::UnitDisplay::Compact,
+        ),
+    )
+    .expect("locale should be present");
+    assert_writeable_eq!(
+        en_compact.format(FixedDecimal::from(-3)).map_number(wrap),
+        "<span class=\"num\">-3</span>q"
+    );
+}
@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::{DateTime, Iso};
+use icu_experimental::timezone_picker::TimeZonePickerFormatter;
+use icu_locid::locale;
+use icu_timezone::TimeZoneBcp47Id;
+use tinystr::tinystr;
+
+fn zones() -> Vec<TimeZoneBcp47Id> {
+    [
+        tinystr!(8, "itrom"),
+        tinystr!(8, "usnyc"),
+        tinystr!(8, "gblon"),
+        tinystr!(8, "deber"),
+        tinystr!(8, "uslax"),
+        tinystr!(8, "utc"),
+        tinystr!(8, "frpar"),
+        tinystr!(8, "ptlis"),
+        tinystr!(8, "inccu"),
+        tinystr!(8, "frpar"),
+    ]
+    .into_iter()
+    .map(TimeZoneBcp47Id)
+    .collect()
+}
+
+fn winter() -> DateTime<Iso> {
+    DateTime::try_new_iso_datetime(2024, 1, 15, 12, 0, 0).unwrap()
+}
+
+fn summer() -> DateTime<Iso> {
+    DateTime::try_new_iso_datetime(2024, 7, 15, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_en() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into()).unwrap();
+    let entries = formatter.format(&zones(), &winter());
+
+    assert_eq!(
+        entries.iter().map(|e| e.label.as_str()).collect::<Vec<_>>(),
+        [
+            "GMT-08:00 (Los Angeles)",
+            "GMT-05:00 (New York)",
+            "GMT (Lisbon, London)",
+            "GMT+01:00 (Berlin, Paris, Rome)",
+            "GMT+05:30 (Kolkata)",
+        ]
+    );
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| e.offset.offset_seconds())
+            .collect::<Vec<_>>(),
+        [-8 * 3600, -5 * 3600, 0, 3600, 19800]
+    );
+    assert_eq!(
+        entries[2].time_zones,
+        [
+            TimeZoneBcp47Id(tinystr!(8, "ptlis")),
+            TimeZoneBcp47Id(tinystr!(8, "gblon")),
+            TimeZoneBcp47Id(tinystr!(8, "utc")),
+        ]
+    );
+    assert_eq!(
+        entries[3].time_zones,
+        [
+            TimeZoneBcp47Id(tinystr!(8, "deber")),
+            TimeZoneBcp47Id(tinystr!(8, "frpar")),
+            TimeZoneBcp47Id(tinystr!(8, "itrom")),
+        ]
+    );
+}
+
+#[test]
+fn test_en_summer() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into()).unwrap();
+    let entries = formatter.format(&zones(), &summer());
+
+    assert_eq!(
+        entries.iter().map(|e| e.label.as_str()).collect::<Vec<_>>(),
+        [
+            "GMT-07:00 (Los Angeles)",
+            "GMT-04:00 (New York)",
+            "GMT",
+            "GMT+01:00 (Lisbon, London)",
+            "GMT+02:00 (Berlin, Paris, Rome)",
+            "GMT+05:30 (Kolkata)",
+        ]
+    );
+}
+
+#[test]
+fn test_time_zone_transition() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into()).unwrap();
+    let berlin = [TimeZoneBcp47Id(tinystr!(8, "deber"))];
+
+    // Central European Summer Time starts at 2024-03-31T01:00Z
+    let before = DateTime::try_new_iso_datetime(2024, 3, 31, 0, 59, 0).unwrap();
+    let after = DateTime::try_new_iso_datetime(2024, 3, 31, 1, 0, 0).unwrap();
+
+    assert_eq!(
+        formatter.format(&berlin, &before)[0].label,
+        "GMT+01:00 (Berlin)"
+    );
+    assert_eq!(
+        formatter.format(&berlin, &after)[0].label,
+        "GMT+02:00 (Berlin)"
+    );
+}
+
+#[test]
+fn test_unknown_time_zone() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into()).unwrap();
+    let entries = formatter.format(
+        &[
+            TimeZoneBcp47Id(tinystr!(8, "unk")),
+            TimeZoneBcp47Id(tinystr!(8, "deber")),
+        ],
+        &winter(),
+    );
+
+    assert_eq!(
+        entries.iter().map(|e| e.label.as_str()).collect::<Vec<_>>(),
+        ["GMT+01:00 (Berlin)"]
+    );
+}
+
+#[test]
+fn test_de() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("de").into()).unwrap();
+    let entries = formatter.format(&zones(), &winter());
+
+    assert_eq!(
+        entries.iter().map(|e| e.label.as_str()).collect::<Vec<_>>(),
+        [
+            "GMT-08:00 (Los Angeles)",
+            "GMT-05:00 (New York)",
+            "GMT (Lissabon, London)",
+            "GMT+01:00 (Berlin, Paris und Rom)",
+            "GMT+05:30 (Kalkutta)",
+        ]
+    );
+}
+
+#[test]
+fn test_no_exemplar_city() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("en").into()).unwrap();
+    let entries = formatter.format(&[TimeZoneBcp47Id(tinystr!(8, "utc"))], &winter());
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].label, "GMT");
+}
+
+#[test]
+fn test_ja() {
+    let formatter = TimeZonePickerFormatter::try_new(&locale!("ja").into()).unwrap();
+    let entries = formatter.format(&zones(), &winter());
+
+    assert_eq!(
+        entries.iter().map(|e| e.label.as_str()).collect::<Vec<_>>(),
+        [
+            "GMT-08:00（ロサンゼルス）",
+            "GMT-05:00（ニューヨーク）",
+            "GMT（リスボン ロンドン）",
+            "GMT+01:00（パリ ベルリン ローマ）",
+            "GMT+05:30（コルカタ）",
+        ]
+    );
+}
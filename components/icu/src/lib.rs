@@ -105,6 +105,8 @@
 //! - `logging`: Enables logging through the `log` crate.
 //! - `serde`: Activates `serde` implementations for core library types, such as [`Locale`], as well
 //!    as `*_with_buffer_provider` constructors for explicit data management.
+//! - `baked_override`: Lets compiled data consult a provider registered at runtime with
+//!    `icu_provider::baked::set_override_provider`. Implies `serde`.
 //!
 //! The following Cargo features are only available on the individual crates, but not on this meta-crate:
 //!
@@ -119,3 +119,29 @@ pub enum ListLength {
     // *Important*: When adding a variant here, make sure the code in
     // ListFormatterPatterns::{start, middle, end, pair} stays panic-free!
 }
+
+/// Represents how a [`ListFormatter`] handles items whose direction differs from the direction
+/// of the formatting locale, such as Hebrew names in an English list.
+///
+/// Without special handling, the Unicode Bidirectional Algorithm can reorder such items together
+/// with the surrounding separators, which scrambles the list visually. The direction of an item
+/// is that of its first strong character, as given by the `Bidi_Class` property.
+///
+/// See [`ListFormatter::try_with_bidi_isolation_unstable()`].
+///
+/// ✨ *Enabled with the `bidi` Cargo feature.*
+#[cfg(feature = "bidi")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[non_exhaustive]
+pub enum ListBidiIsolation {
+    /// Items are written unchanged.
+    #[default]
+    None,
+    /// Items are wrapped in FIRST STRONG ISOLATE (U+2068) and POP DIRECTIONAL ISOLATE (U+2069).
+    Isolates,
+    /// Items are surrounded by a mark with the direction of the locale, LEFT-TO-RIGHT MARK
+    /// (U+200E) or RIGHT-TO-LEFT MARK (U+200F).
+    ///
+    /// Marks are supported by more renderers than isolates, but isolate items less reliably.
+    Marks,
+}
@@ -3,9 +3,15 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::provider::{AndListV1Marker, ErasedListV1Marker, OrListV1Marker, UnitListV1Marker};
+#[cfg(feature = "bidi")]
+use crate::ListBidiIsolation;
 use crate::ListError;
 use crate::ListLength;
 use core::fmt::{self, Write};
+#[cfg(feature = "bidi")]
+use icu_locid_transform::Direction;
+#[cfg(feature = "bidi")]
+use icu_properties::{maps::CodePointMapData, provider::BidiClassV1Marker, BidiClass};
 use icu_provider::prelude::*;
 use writeable::*;
 
@@ -18,6 +24,25 @@ extern crate writeable;
 pub struct ListFormatter {
     data: DataPayload<ErasedListV1Marker>,
     length: ListLength,
+    #[cfg(feature = "bidi")]
+    bidi: Option<BidiIsolator>,
+}
+
+impl<P> icu_provider::constructors::RequiredKeys<P> for ListFormatter where
+    P: DataProvider<AndListV1Marker>
+        + DataProvider<OrListV1Marker>
+        + DataProvider<UnitListV1Marker>
+        + ?Sized
+{
+}
+
+/// The state needed for [`ListBidiIsolation`].
+#[cfg(feature = "bidi")]
+#[derive(Debug)]
+struct BidiIsolator {
+    isolation: ListBidiIsolation,
+    direction: Direction,
+    bidi_class: CodePointMapData<BidiClass>,
 }
 
 macro_rules! constructor {
@@ -55,7 +80,12 @@ macro_rules! constructor {
                     metadata: Default::default(),
                 })?
                 .take_payload()?.cast();
-            Ok(Self { data, length })
+            Ok(Self {
+                data,
+                length,
+                #[cfg(feature = "bidi")]
+                bidi: None,
+            })
         }
     };
 }
@@ -86,6 +116,70 @@ impl ListFormatter {
         "unit"
     );
 
+    /// Sets how items are handled whose direction differs from `direction`, the direction of the
+    /// formatting locale, using compiled data. See [`ListBidiIsolation`].
+    ///
+    /// The direction of a locale can be obtained from
+    /// [`LocaleDirectionality`](icu_locid_transform::LocaleDirectionality).
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `bidi` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::list::*;
+    /// use icu::locid::locale;
+    /// use icu::locid_transform::Direction;
+    /// use writeable::*;
+    ///
+    /// let formatter = ListFormatter::try_new_and_with_length(
+    ///     &locale!("en").into(),
+    ///     ListLength::Wide,
+    /// )
+    /// .unwrap()
+    /// .with_bidi_isolation(Direction::LeftToRight, ListBidiIsolation::Isolates);
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(["Alice", "בוב", "Carol"].iter()),
+    ///     "Alice, \u{2068}בוב\u{2069}, and Carol",
+    /// );
+    /// ```
+    #[cfg(all(feature = "compiled_data", feature = "bidi"))]
+    pub fn with_bidi_isolation(self, direction: Direction, isolation: ListBidiIsolation) -> Self {
+        let bidi_class = icu_properties::maps::bidi_class().static_to_owned();
+        self.with_bidi_class(direction, isolation, bidi_class)
+    }
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::with_bidi_isolation)]
+    #[cfg(feature = "bidi")]
+    pub fn try_with_bidi_isolation_unstable(
+        self,
+        provider: &(impl DataProvider<BidiClassV1Marker> + ?Sized),
+        direction: Direction,
+        isolation: ListBidiIsolation,
+    ) -> Result<Self, ListError> {
+        let bidi_class =
+            CodePointMapData::from_data(provider.load(Default::default())?.take_payload()?);
+        Ok(self.with_bidi_class(direction, isolation, bidi_class))
+    }
+
+    #[cfg(feature = "bidi")]
+    fn with_bidi_class(
+        mut self,
+        direction: Direction,
+        isolation: ListBidiIsolation,
+        bidi_class: CodePointMapData<BidiClass>,
+    ) -> Self {
+        self.bidi = (isolation != ListBidiIsolation::None).then_some(BidiIsolator {
+            isolation,
+            direction,
+            bidi_class,
+        });
+        self
+    }
+
     /// Returns a [`Writeable`] composed of the input [`Writeable`]s and the language-dependent
     /// formatting.
     ///
@@ -137,6 +231,67 @@ impl ListFormatter {
     ) -> alloc::string::String {
         self.format(values).write_to_string().into_owned()
     }
+
+    /// Writes an item, applying the [`ListBidiIsolation`] if one is set.
+    fn write_item<W: Writeable + ?Sized, S: PartsWrite + ?Sized>(
+        &self,
+        item: &W,
+        sink: &mut S,
+    ) -> fmt::Result {
+        #[cfg(feature = "bidi")]
+        if let Some(bidi) = self.bidi.as_ref() {
+            return bidi.write_item(item, sink);
+        }
+        item.write_to_parts(sink)
+    }
+}
+
+#[cfg(feature = "bidi")]
+impl BidiIsolator {
+    /// Writes an item, surrounded by control characters if its direction differs from the
+    /// direction of the locale.
+    fn write_item<W: Writeable + ?Sized, S: PartsWrite + ?Sized>(
+        &self,
+        item: &W,
+        sink: &mut S,
+    ) -> fmt::Result {
+        match self.first_strong_direction(&item.write_to_string()) {
+            Some(direction) if direction != self.direction => (),
+            _ => return item.write_to_parts(sink),
+        }
+        let (before, after) = match self.isolation {
+            ListBidiIsolation::None => return item.write_to_parts(sink),
+            ListBidiIsolation::Isolates => ('\u{2068}', '\u{2069}'),
+            ListBidiIsolation::Marks if self.direction == Direction::RightToLeft => {
+                ('\u{200F}', '\u{200F}')
+            }
+            ListBidiIsolation::Marks => ('\u{200E}', '\u{200E}'),
+        };
+        sink.write_char(before)?;
+        item.write_to_parts(sink)?;
+        sink.write_char(after)
+    }
+
+    /// Returns the direction of the first strong character of the text, ignoring characters
+    /// inside of isolates, as in rule P2 of the Unicode Bidirectional Algorithm.
+    fn first_strong_direction(&self, text: &str) -> Option<Direction> {
+        let bidi_class = self.bidi_class.as_borrowed();
+        let mut isolates = 0usize;
+        for c in text.chars() {
+            match bidi_class.get(c) {
+                BidiClass::LeftToRight if isolates == 0 => return Some(Direction::LeftToRight),
+                BidiClass::RightToLeft | BidiClass::ArabicLetter if isolates == 0 => {
+                    return Some(Direction::RightToLeft)
+                }
+                BidiClass::LeftToRightIsolate
+                | BidiClass::RightToLeftIsolate
+                | BidiClass::FirstStrongIsolate => isolates += 1,
+                BidiClass::PopDirectionalIsolate => isolates = isolates.saturating_sub(1),
+                _ => (),
+            }
+        }
+        None
+    }
 }
 
 /// The [`Part`]s used by [`ListFormatter`].
@@ -182,7 +337,7 @@ impl<'a, W: Writeable + 'a, I: Iterator<Item = W> + Clone + 'a> Writeable
         }
         macro_rules! value {
             ($val:expr) => {
-                sink.with_part(parts::ELEMENT, |e| $val.write_to_parts(e))
+                sink.with_part(parts::ELEMENT, |e| self.formatter.write_item(&$val, e))
             };
         }
 
@@ -262,6 +417,13 @@ impl<'a, W: Writeable + 'a, I: Iterator<Item = W> + Clone + 'a> Writeable
                 w.writeable_length_hint()
             })
             .sum::<LengthHint>();
+        #[cfg(feature = "bidi")]
+        let item_length = if self.formatter.bidi.is_some() {
+            // Two three-byte control characters per item
+            item_length + LengthHint::between(0, 6 * count)
+        } else {
+            item_length
+        };
         item_length
             + self
                 .formatter
@@ -288,6 +450,8 @@ mod tests {
         ListFormatter {
             data: DataPayload::from_owned(crate::patterns::test::test_patterns()),
             length,
+            #[cfg(feature = "bidi")]
+            bidi: None,
         }
     }
 
@@ -432,4 +596,79 @@ mod tests {
             (["x", "Ibiza"], "x ו‑Ibiza"),
         );
     }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn test_bidi_isolation() {
+        use icu_locid_transform::Direction;
+
+        let en = || {
+            ListFormatter::try_new_and_with_length(
+                &icu::locid::locale!("en").into(),
+                ListLength::Wide,
+            )
+            .unwrap()
+        };
+        let he = || {
+            ListFormatter::try_new_and_with_length(
+                &icu::locid::locale!("he").into(),
+                ListLength::Wide,
+            )
+            .unwrap()
+        };
+        let items = ["Alice", "בוב", "Carol"];
+
+        // Off by default
+        assert_eq!(en().format_to_string(items.iter()), "Alice, בוב, and Carol");
+        assert_eq!(
+            en().with_bidi_isolation(Direction::LeftToRight, ListBidiIsolation::None)
+                .format_to_string(items.iter()),
+            "Alice, בוב, and Carol"
+        );
+
+        let formatter =
+            en().with_bidi_isolation(Direction::LeftToRight, ListBidiIsolation::Isolates);
+        assert_writeable_parts_eq!(
+            formatter.format(items.iter()),
+            "Alice, \u{2068}בוב\u{2069}, and Carol",
+            [
+                (0, 5, parts::ELEMENT),
+                (5, 7, parts::LITERAL),
+                (7, 19, parts::ELEMENT),
+                (19, 25, parts::LITERAL),
+                (25, 30, parts::ELEMENT),
+            ]
+        );
+        assert_eq!(
+            en().with_bidi_isolation(Direction::LeftToRight, ListBidiIsolation::Marks)
+                .format_to_string(items.iter()),
+            "Alice, \u{200E}בוב\u{200E}, and Carol"
+        );
+
+        // Items without strong characters, or with the direction of the locale, are unchanged
+        assert_writeable_eq!(formatter.format(["1", "2", "Bob"].iter()), "1, 2, and Bob");
+        // The first strong character decides, ignoring isolated text
+        assert_writeable_eq!(
+            formatter.format(["42 בוב", "\u{2066}Bob\u{2069} בוב"].iter()),
+            "\u{2068}42 בוב\u{2069} and \u{2068}\u{2066}Bob\u{2069} בוב\u{2069}"
+        );
+
+        let items = ["אליס", "Bob", "קרול"];
+        assert_eq!(
+            he().with_bidi_isolation(Direction::RightToLeft, ListBidiIsolation::Isolates)
+                .format_to_string(items.iter()),
+            "אליס, \u{2068}Bob\u{2069} וקרול"
+        );
+        assert_eq!(
+            he().with_bidi_isolation(Direction::RightToLeft, ListBidiIsolation::Marks)
+                .format_to_string(items.iter()),
+            "אליס, \u{200F}Bob\u{200F} וקרול"
+        );
+        // The Hebrew conjunction still depends on the item, not on the control characters
+        assert_eq!(
+            he().with_bidi_isolation(Direction::RightToLeft, ListBidiIsolation::Isolates)
+                .format_to_string(["אליס", "Bob"].iter()),
+            "אליס ו\u{2011}\u{2068}Bob\u{2069}"
+        );
+    }
 }
@@ -253,11 +253,11 @@ macro_rules! impl_tinystr_subtag {
                         &self,
                         formatter: &mut core::fmt::Formatter<'_>,
                     ) -> core::fmt::Result {
-                        write!(formatter, "a valid BCP-47 {}", stringify!($name))
+                        write!(formatter, "a canonical BCP-47 {}", stringify!($name))
                     }
 
                     fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
-                        s.parse().map_err(serde::de::Error::custom)
+                        crate::serde::parse_canonical(s, stringify!($name))
                     }
                 }
 
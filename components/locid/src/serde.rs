@@ -2,9 +2,54 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::LanguageIdentifier;
+use crate::ParserError;
+use crate::{LanguageIdentifier, Locale};
 use alloc::string::ToString;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use writeable::Writeable;
+
+/// Parses `s`, requiring it to be in canonical form so that deserializing accepts exactly
+/// what serializing produces. The errors contain the input and, if it only differs in
+/// form, the canonical spelling.
+pub(crate) fn parse_canonical<T, E>(s: &str, name: &str) -> Result<T, E>
+where
+    T: FromStr<Err = ParserError> + Writeable,
+    E: serde::de::Error,
+{
+    let parsed = s
+        .parse::<T>()
+        .map_err(|e| E::custom(format_args!("invalid {name} {s:?}: {e}")))?;
+    let canonical = parsed.write_to_string();
+    if canonical != s {
+        return Err(E::custom(format_args!(
+            "{name} {s:?} is not in canonical form, expected {canonical:?}"
+        )));
+    }
+    Ok(parsed)
+}
+
+struct CanonicalVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T> serde::de::Visitor<'de> for CanonicalVisitor<T>
+where
+    T: FromStr<Err = ParserError> + Writeable,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a canonical {}", self.0)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        parse_canonical(s, self.0)
+    }
+}
 
 impl Serialize for LanguageIdentifier {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -20,32 +65,33 @@ impl<'de> Deserialize<'de> for LanguageIdentifier {
     where
         D: Deserializer<'de>,
     {
-        struct LanguageIdentifierVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for LanguageIdentifierVisitor {
-            type Value = LanguageIdentifier;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(formatter, "a valid Unicode Language Identifier")
-            }
+        deserializer
+            .deserialize_string(CanonicalVisitor("Unicode Language Identifier", PhantomData))
+    }
+}
 
-            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                s.parse::<LanguageIdentifier>()
-                    .map_err(serde::de::Error::custom)
-            }
-        }
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
 
-        deserializer.deserialize_string(LanguageIdentifierVisitor)
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(CanonicalVisitor("Unicode Locale Identifier", PhantomData))
     }
 }
 
 #[test]
 fn json() {
-    use crate::langid;
-    use crate::subtags::{Language, Region, Script};
+    use crate::subtags::{Language, Region, Script, Variant};
+    use crate::{langid, locale};
 
     assert_eq!(
         serde_json::to_string(&langid!("en-US")).unwrap(),
@@ -86,6 +132,96 @@ fn json() {
         "US".parse::<Region>().unwrap()
     );
     assert!(serde_json::from_str::<Region>(r#""2Xs""#).is_err());
+
+    assert_eq!(
+        serde_json::to_string(&"macos".parse::<Variant>().unwrap()).unwrap(),
+        r#""macos""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Variant>(r#""macos""#).unwrap(),
+        "macos".parse::<Variant>().unwrap()
+    );
+    assert!(serde_json::from_str::<Variant>(r#""2Xs""#).is_err());
+
+    assert_eq!(
+        serde_json::to_string(&locale!("en-US-u-ca-buddhist")).unwrap(),
+        r#""en-US-u-ca-buddhist""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Locale>(r#""en-US-u-ca-buddhist""#).unwrap(),
+        locale!("en-US-u-ca-buddhist")
+    );
+    assert!(serde_json::from_str::<Locale>(r#""2Xs""#).is_err());
+}
+
+#[test]
+fn json_strict() {
+    use crate::subtags::{Language, Region};
+
+    let error = |json| serde_json::from_str::<LanguageIdentifier>(json).unwrap_err();
+    assert_eq!(
+        error(r#""EN_us""#).to_string(),
+        r#"Unicode Language Identifier "EN_us" is not in canonical form, expected "en-US" at line 1 column 7"#
+    );
+    assert_eq!(
+        error(r#""en-2Xs""#).to_string(),
+        r#"invalid Unicode Language Identifier "en-2Xs": Invalid subtag at line 1 column 8"#
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Locale>(r#""en-u-CA-buddhist""#)
+            .unwrap_err()
+            .to_string(),
+        r#"Unicode Locale Identifier "en-u-CA-buddhist" is not in canonical form, expected "en-u-ca-buddhist" at line 1 column 18"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Language>(r#""EN""#)
+            .unwrap_err()
+            .to_string(),
+        r#"Language "EN" is not in canonical form, expected "en" at line 1 column 4"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Region>(r#""USA""#)
+            .unwrap_err()
+            .to_string(),
+        r#"invalid Region "USA": Invalid subtag at line 1 column 5"#
+    );
+}
+
+#[test]
+fn toml() {
+    use crate::subtags::{language, region, Language, Region};
+    use crate::{langid, locale};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        language: Language,
+        region: Option<Region>,
+        locale: Locale,
+        fallbacks: alloc::vec::Vec<LanguageIdentifier>,
+    }
+
+    let config = Config {
+        language: language!("sr"),
+        region: Some(region!("RS")),
+        locale: locale!("sr-Latn-RS-u-nu-latn"),
+        fallbacks: alloc::vec![langid!("sr-Cyrl"), langid!("und")],
+    };
+    let toml = "language = \"sr\"\n\
+        region = \"RS\"\n\
+        locale = \"sr-Latn-RS-u-nu-latn\"\n\
+        fallbacks = [\"sr-Cyrl\", \"und\"]\n";
+
+    assert_eq!(toml::to_string(&config).unwrap(), toml);
+    assert_eq!(toml::from_str::<Config>(toml).unwrap(), config);
+
+    let error = toml::from_str::<Config>("language = \"sr\"\nlocale = \"sr_Latn\"\nfallbacks = []")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(r#""sr_Latn" is not in canonical form, expected "sr-Latn""#),
+        "{error}"
+    );
 }
 
 #[test]
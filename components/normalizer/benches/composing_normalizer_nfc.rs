@@ -127,6 +127,18 @@ fn normalizer_bench_data() -> [BenchDataContent; 15] {
     })
 }
 
+/// About 1 MB of English text, which is entirely ASCII.
+fn ascii_document() -> String {
+    const PARAGRAPH: &str =
+        "No one would have believed in the last years of the nineteenth century \
+        that this world was being watched keenly and closely by intelligences greater than \
+        man's and yet as mortal as his own; that as men busied themselves about their various \
+        concerns they were scrutinised and studied, perhaps almost as narrowly as a man with a \
+        microscope might scrutinise the transient creatures that swarm and multiply in a drop \
+        of water.\n";
+    PARAGRAPH.repeat(1_000_000 / PARAGRAPH.len() + 1)
+}
+
 fn function_under_bench(normalizer: &ComposingNormalizer, text: &str) {
     normalizer.normalize(text);
 }
@@ -226,5 +238,13 @@ pub fn criterion_benchmark(criterion: &mut Criterion) {
             },
         );
     }
+
+    let ascii = ascii_document();
+    group.bench_function(BenchmarkId::from_parameter("ascii_1mb"), |bencher| {
+        bencher.iter(|| function_under_bench(&normalizer_under_bench, black_box(&ascii)))
+    });
+    group.bench_function(BenchmarkId::from_parameter("ascii_1mb_utf_8"), |bencher| {
+        bencher.iter(|| normalizer_under_bench.normalize_utf8(black_box(ascii.as_bytes())))
+    });
     group.finish();
 }
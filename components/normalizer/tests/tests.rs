@@ -63,6 +63,19 @@ fn test_nfc_basic() {
     assert_eq!(normalizer.normalize("\u{0345}"), "\u{0345}"); // Iota subscript
 }
 
+#[test]
+fn test_nfc_long_ascii() {
+    // Runs of ASCII are skipped in blocks, so marks after them must still compose
+    let normalizer: ComposingNormalizer = ComposingNormalizer::new_nfc();
+    for len in [1, 15, 16, 17, 31, 32, 33, 64] {
+        let run = "a".repeat(len);
+        let input = format!("{run}\u{0308}{run}e\u{0323}\u{0302}{run}");
+        let expected = format!("{}ä{run}ệ{run}", &run[1..]);
+        assert_eq!(normalizer.normalize(&input), expected);
+        assert_eq!(normalizer.normalize_utf8(input.as_bytes()), expected);
+    }
+}
+
 #[test]
 fn test_nfkc_basic() {
     let normalizer: ComposingNormalizer = ComposingNormalizer::new_nfkc();
@@ -20,10 +20,12 @@ use fixed_decimal::{CompactDecimal, FixedDecimal};
 /// - Strings representing an arbitrary-precision decimal
 /// - [`FixedDecimal`]
 ///
-/// This crate does not support selection from a floating-point number, because floats are not
-/// capable of carrying trailing zeros, which are required for proper plural rule selection. For
-/// example, in English, "1 star" has a different plural form than "1.0 stars", but this
-/// distinction cannot be represented using a float. Clients should use [`FixedDecimal`] instead.
+/// Floating-point numbers are not capable of carrying trailing zeros, which are required for
+/// proper plural rule selection. For example, in English, "1 star" has a different plural form
+/// than "1.0 stars", but this distinction cannot be represented using a float. Clients should
+/// use [`FixedDecimal`] or a string instead, or convert the float with
+/// [`PluralOperands::from_f64_with_digits`], which chooses the visible fraction digits the same
+/// way as a formatter with a maximum number of fraction digits.
 ///
 /// # Examples
 ///
@@ -139,6 +141,10 @@ fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
 impl FromStr for PluralOperands {
     type Err = OperandsError;
 
+    /// Parses a decimal string, such as `"-1.50"` or `"1.2e3"`, into [`PluralOperands`].
+    ///
+    /// The conversion is exact: all fraction digits of the string are visible, including
+    /// trailing zeros, so `"1.00"` has two visible fraction digits while `"1"` has none.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         if input.is_empty() {
             return Err(OperandsError::Empty);
@@ -272,6 +278,60 @@ impl PluralOperands {
     }
 }
 
+impl PluralOperands {
+    /// Converts an [`f64`] to [`PluralOperands`], keeping at most `max_fraction_digits`
+    /// fraction digits.
+    ///
+    /// The float is first converted to its shortest decimal representation, which is then
+    /// rounded half-even at `max_fraction_digits`. Trailing zeros are not visible, as in
+    /// ECMA-402 formatting with a minimum of 0 fraction digits: `1.0` and `1.001` with 2
+    /// digits both have no visible fraction digits. To keep trailing zeros, convert the
+    /// number with [`FromStr`] or from a [`FixedDecimal`] instead.
+    ///
+    /// Returns an error if the float is not finite.
+    ///
+    /// ✨ *Enabled with the `ryu` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::RawPluralOperands;
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// assert_eq!(
+    ///     PluralOperands::from_f64_with_digits(1.256, 2),
+    ///     Ok(PluralOperands::from(RawPluralOperands {
+    ///         i: 1,
+    ///         v: 2,
+    ///         w: 2,
+    ///         f: 26,
+    ///         t: 26,
+    ///         c: 0,
+    ///     }))
+    /// );
+    /// assert_eq!(
+    ///     PluralOperands::from_f64_with_digits(2.5, 0),
+    ///     Ok(PluralOperands::from(2_u64))
+    /// );
+    /// assert_eq!(
+    ///     PluralOperands::from_f64_with_digits(1.0, 2),
+    ///     Ok(PluralOperands::from(1_u64))
+    /// );
+    /// assert!(PluralOperands::from_f64_with_digits(f64::NAN, 2).is_err());
+    /// ```
+    #[cfg(feature = "ryu")]
+    pub fn from_f64_with_digits(
+        value: f64,
+        max_fraction_digits: u8,
+    ) -> Result<Self, OperandsError> {
+        let mut dec = FixedDecimal::try_from_f64(value, fixed_decimal::FloatPrecision::Floating)
+            .map_err(|_| OperandsError::Invalid)?;
+        dec.half_even(-i16::from(max_fraction_digits));
+        dec.trim_end();
+        Ok(Self::from(&dec))
+    }
+}
+
 impl From<&FixedDecimal> for PluralOperands {
     /// Converts a [`fixed_decimal::FixedDecimal`] to [`PluralOperands`]. Retains at most 18
     /// digits each from the integer and fraction parts.
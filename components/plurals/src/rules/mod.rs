@@ -153,6 +153,8 @@
 //! [`Sample`]: super::rules::reference::ast::Samples
 //! [`AST`]: super::rules::reference::ast
 
+#[cfg(feature = "experimental")]
+pub mod raw;
 #[doc(hidden)]
 pub mod reference;
 // Need to expose it for `icu_datagen` use, but we don't
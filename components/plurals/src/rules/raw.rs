@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Parsing and evaluating a single plural rule at runtime, without locale
+//! data
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! <a href="https://github.com/unicode-org/icu4x/issues/1091">#1091</a>
+//! </div>
+
+use super::reference::{ast, parser, resolver};
+use crate::PluralOperands;
+use core::ops::Range;
+use core::str::FromStr;
+use displaydoc::Display;
+
+pub use super::reference::ParserError;
+
+/// 🚧 \[Experimental\] A plural rule in the [UTS #35 syntax], such as
+/// `n % 10 = 2..4 and n % 100 != 12..14`, which can be tested against [`PluralOperands`].
+///
+/// The rule may be followed by `@integer` and `@decimal` samples, as in CLDR data; they are
+/// parsed but not used by [`PluralRule::matches`]. An empty rule matches all operands, like
+/// the rule for [`PluralCategory::Other`](crate::PluralCategory::Other).
+///
+/// This is intended for tooling. [`PluralRules`](crate::PluralRules) uses a more compact
+/// representation of the rules in its data.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/1091">#1091</a>
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::raw::PluralRule;
+/// use icu::plurals::PluralOperands;
+///
+/// let rule = PluralRule::parse("n % 10 = 2..4 and n % 100 != 12..14")
+///     .expect("the rule is valid");
+///
+/// assert!(rule.matches(&PluralOperands::from(3_u32)));
+/// assert!(rule.matches(&PluralOperands::from(22_u32)));
+/// assert!(!rule.matches(&PluralOperands::from(12_u32)));
+/// assert!(!rule.matches(&PluralOperands::from(5_u32)));
+/// ```
+///
+/// [UTS #35 syntax]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluralRule(ast::Rule);
+
+impl PluralRule {
+    /// Parses a plural rule, returning the byte range of `rule` at which it is invalid on
+    /// failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::rules::raw::PluralRule;
+    ///
+    /// let rule = "i = 1 and v = x";
+    /// let error = PluralRule::parse(rule).expect_err("x is not a value");
+    ///
+    /// assert_eq!(error.span, 14..15);
+    /// assert_eq!(&rule[error.span], "x");
+    /// ```
+    pub fn parse(rule: &str) -> Result<Self, PluralRuleError> {
+        parser::parse_with_span(rule.as_bytes())
+            .map(Self)
+            .map_err(|(kind, span)| PluralRuleError { kind, span })
+    }
+
+    /// Returns whether the condition of the rule is true for `operands`.
+    pub fn matches(&self, operands: &PluralOperands) -> bool {
+        resolver::test_condition(&self.0.condition, operands)
+    }
+
+    /// Returns the syntax tree of the rule.
+    pub fn ast(&self) -> &ast::Rule {
+        &self.0
+    }
+}
+
+impl FromStr for PluralRule {
+    type Err = PluralRuleError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        Self::parse(rule)
+    }
+}
+
+/// 🚧 \[Experimental\] An error returned by [`PluralRule::parse`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/1091">#1091</a>
+/// </div>
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[displaydoc("{kind} at bytes {span:?}")]
+#[allow(clippy::exhaustive_structs)] // experimental
+pub struct PluralRuleError {
+    /// What the parser expected.
+    pub kind: ParserError,
+    /// The byte range of the rule at which parsing failed. This is an empty range at the
+    /// end of the rule if it ended early.
+    pub span: Range<usize>,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PluralRuleError {}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::ast;
+use core::ops::Range;
 use displaydoc::Display;
 
 #[derive(Debug, PartialEq)]
@@ -54,6 +55,7 @@ impl std::error::Error for LexerError {}
 pub struct Lexer<'l> {
     chars: &'l [u8],
     ptr: usize,
+    token_start: usize,
 }
 
 impl<'l> Lexer<'l> {
@@ -70,9 +72,16 @@ impl<'l> Lexer<'l> {
         Self {
             chars: input,
             ptr: 0,
+            token_start: 0,
         }
     }
 
+    /// Returns the byte range of the input covered by the last token, or an empty range at
+    /// the end of the input once the tokens are exhausted.
+    pub(crate) fn token_span(&self) -> Range<usize> {
+        self.token_start..self.ptr.min(self.chars.len())
+    }
+
     fn bump(&mut self) -> Option<&u8> {
         let ret = self.chars.get(self.ptr);
         self.ptr += 1;
@@ -98,6 +107,7 @@ impl<'l> Lexer<'l> {
 
     fn advance_token(&mut self) -> Result<Option<Token>, LexerError> {
         loop {
+            self.token_start = self.ptr.min(self.chars.len());
             if let Some(c) = self.bump() {
                 let token = match c {
                     b' ' => continue,
@@ -11,6 +11,6 @@ pub(crate) mod resolver;
 pub(crate) mod serializer;
 
 pub use lexer::Lexer;
-pub use parser::{parse, parse_condition};
+pub use parser::{parse, parse_condition, ParserError};
 pub use resolver::test_condition;
 pub use serializer::serialize;
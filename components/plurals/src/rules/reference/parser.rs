@@ -8,7 +8,7 @@ use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::iter::Peekable;
+use core::ops::Range;
 use displaydoc::Display;
 
 #[derive(Display, Debug, PartialEq, Eq, Copy, Clone)]
@@ -29,6 +29,8 @@ pub enum ParserError {
     ExpectedSampleType,
     #[displaydoc("Value too large")]
     ValueTooLarge,
+    #[displaydoc("unexpected token")]
+    UnexpectedToken,
 }
 
 #[cfg(feature = "std")]
@@ -94,14 +96,33 @@ pub fn parse_condition(input: &[u8]) -> Result<ast::Condition, ParserError> {
     parser.parse_condition()
 }
 
+/// Parses a complete Rule like [`parse`], returning the byte range of the input at which
+/// parsing failed alongside the error.
+///
+/// Unlike [`parse`], input remaining after the Rule is an error.
+#[cfg(feature = "experimental")]
+pub(crate) fn parse_with_span(input: &[u8]) -> Result<ast::Rule, (ParserError, Range<usize>)> {
+    let mut parser = Parser::new(input);
+    let rule = parser.get_rule().and_then(|rule| match parser.peek() {
+        Some(_) => Err(ParserError::UnexpectedToken),
+        None => Ok(rule),
+    });
+    rule.map_err(|error| (error, parser.span))
+}
+
 struct Parser<'p> {
-    lexer: Peekable<Lexer<'p>>,
+    lexer: Lexer<'p>,
+    peeked: Option<Option<Token>>,
+    /// The byte range of the last token taken from the lexer.
+    span: Range<usize>,
 }
 
 impl<'p> Parser<'p> {
     fn new(input: &'p [u8]) -> Self {
         Self {
-            lexer: Lexer::new(input).peekable(),
+            lexer: Lexer::new(input),
+            peeked: None,
+            span: 0..0,
         }
     }
 
@@ -159,7 +180,7 @@ impl<'p> Parser<'p> {
 
     fn get_relation(&mut self) -> Result<Option<ast::Relation>, ParserError> {
         if let Some(expression) = self.get_expression()? {
-            let operator = match self.lexer.next() {
+            let operator = match self.next() {
                 Some(Token::Operator(op)) => op,
                 _ => return Err(ParserError::ExpectedOperator),
             };
@@ -175,13 +196,13 @@ impl<'p> Parser<'p> {
     }
 
     fn get_expression(&mut self) -> Result<Option<ast::Expression>, ParserError> {
-        let operand = match self.lexer.peek() {
+        let operand = match self.peek() {
             Some(Token::E) => ast::Operand::E,
             Some(Token::Operand(op)) => *op,
             Some(Token::At) | None => return Ok(None),
             _ => return Err(ParserError::ExpectedOperand),
         };
-        self.lexer.next();
+        self.next();
         let modulus = if self.take_if(Token::Modulo) {
             Some(self.get_value()?)
         } else {
@@ -201,9 +222,35 @@ impl<'p> Parser<'p> {
         Ok(ast::RangeList(range_list))
     }
 
+    fn peek(&mut self) -> Option<&Token> {
+        let Self {
+            lexer,
+            peeked,
+            span,
+        } = self;
+        peeked
+            .get_or_insert_with(|| {
+                let token = lexer.next();
+                *span = lexer.token_span();
+                token
+            })
+            .as_ref()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        match self.peeked.take() {
+            Some(token) => token,
+            None => {
+                let token = self.lexer.next();
+                self.span = self.lexer.token_span();
+                token
+            }
+        }
+    }
+
     fn take_if(&mut self, token: Token) -> bool {
-        if self.lexer.peek() == Some(&token) {
-            self.lexer.next();
+        if self.peek() == Some(&token) {
+            self.next();
             true
         } else {
             false
@@ -221,7 +268,7 @@ impl<'p> Parser<'p> {
     }
 
     fn get_value(&mut self) -> Result<ast::Value, ParserError> {
-        match self.lexer.next() {
+        match self.next() {
             Some(Token::Number(v)) => Ok(ast::Value(v as u64)),
             Some(Token::Zero) => Ok(ast::Value(0)),
             _ => Err(ParserError::ExpectedValue),
@@ -233,7 +280,7 @@ impl<'p> Parser<'p> {
         let mut decimal = None;
 
         while self.take_if(Token::At) {
-            match self.lexer.next() {
+            match self.next() {
                 Some(Token::Integer) => integer = Some(self.get_sample_list()?),
                 Some(Token::Decimal) => decimal = Some(self.get_sample_list()?),
                 _ => return Err(ParserError::ExpectedSampleType),
@@ -279,7 +326,7 @@ impl<'p> Parser<'p> {
     fn get_decimal_value(&mut self) -> Result<ast::DecimalValue, ParserError> {
         let mut s = String::new();
         loop {
-            match self.lexer.peek() {
+            match self.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
                     s.push_str(&v.to_string());
@@ -288,12 +335,12 @@ impl<'p> Parser<'p> {
                     break;
                 }
             }
-            self.lexer.next();
+            self.next();
         }
         if self.take_if(Token::Dot) {
             s.push('.');
             loop {
-                match self.lexer.peek() {
+                match self.peek() {
                     Some(Token::Zero) => s.push('0'),
                     Some(Token::Number(v)) => {
                         s.push_str(&v.to_string());
@@ -302,13 +349,20 @@ impl<'p> Parser<'p> {
                         break;
                     }
                 }
-                self.lexer.next();
+                self.next();
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
-            match self.lexer.peek() {
+        // The exponent is written with `c` in newer CLDR data, and with `e` before that.
+        let exponent = match self.peek() {
+            Some(Token::E) => Some('e'),
+            Some(Token::Operand(ast::Operand::C)) => Some('c'),
+            _ => None,
+        };
+        if let Some(exponent) = exponent {
+            self.next();
+            s.push(exponent);
+            match self.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
                     s.push_str(&v.to_string());
@@ -317,7 +371,7 @@ impl<'p> Parser<'p> {
                     return Err(ParserError::ExpectedValue);
                 }
             }
-            self.lexer.next();
+            self.next();
         }
         if s.is_empty() {
             Err(ParserError::ExpectedValue)
@@ -71,3 +71,51 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+#[cfg(all(feature = "ryu", feature = "compiled_data"))]
+fn test_from_f64_with_digits() {
+    use icu_locid::locale;
+    use icu_plurals::{PluralCategory, PluralRules};
+
+    let en = PluralRules::try_new_cardinal(&locale!("en").into()).expect("locale should exist");
+
+    // Visible trailing zeros select a different category than the same float
+    assert_eq!(
+        en.category_for("1.00".parse::<PluralOperands>().unwrap()),
+        PluralCategory::Other
+    );
+    assert_eq!(
+        en.category_for(PluralOperands::from_f64_with_digits(1.0, 0).unwrap()),
+        PluralCategory::One
+    );
+    assert_eq!(
+        en.category_for(PluralOperands::from_f64_with_digits(1.0, 2).unwrap()),
+        PluralCategory::One
+    );
+    assert_eq!(
+        en.category_for(PluralOperands::from_f64_with_digits(1.5, 1).unwrap()),
+        PluralCategory::Other
+    );
+
+    // Rounding is half-even at the given number of digits
+    assert_eq!(
+        PluralOperands::from_f64_with_digits(0.5, 0),
+        Ok(PluralOperands::from(0_u64))
+    );
+    assert_eq!(
+        PluralOperands::from_f64_with_digits(1.5, 0),
+        Ok(PluralOperands::from(2_u64))
+    );
+    assert_eq!(PluralOperands::from_f64_with_digits(1.25, 1), "1.2".parse());
+    assert_eq!(
+        PluralOperands::from_f64_with_digits(-1.35, 1),
+        "1.4".parse()
+    );
+    assert_eq!(
+        en.category_for(PluralOperands::from_f64_with_digits(0.96, 0).unwrap()),
+        PluralCategory::One
+    );
+
+    assert!(PluralOperands::from_f64_with_digits(f64::INFINITY, 0).is_err());
+}
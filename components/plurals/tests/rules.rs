@@ -55,3 +55,59 @@ fn test_parsing_operands() {
         }
     }
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_raw_plural_rule() {
+    use icu_plurals::rules::raw::{ParserError, PluralRule};
+
+    // Russian "few", with samples
+    let rule = PluralRule::parse(
+        "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 @integer 2~4, 22~24, 32~34, 42~44, …",
+    )
+    .expect("valid rule");
+    for (n, expected) in [
+        (2, true),
+        (4, true),
+        (5, false),
+        (12, false),
+        (14, false),
+        (22, true),
+    ] {
+        assert_eq!(
+            rule.matches(&PluralOperands::from(n as u32)),
+            expected,
+            "{n}"
+        );
+    }
+    assert!(!rule.matches(&"2.0".parse().unwrap()));
+
+    // French "many" for compact numbers
+    let rule: PluralRule = "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5"
+        .parse()
+        .expect("valid rule");
+    assert!(rule.matches(&PluralOperands::from(1_000_000_u32)));
+    assert!(!rule.matches(&PluralOperands::from(1_000_u32)));
+
+    // Samples of compact numbers
+    let rule = PluralRule::parse("e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 @integer 1000000, 1c6, 2c6, … @decimal 1.0000001c6, 1.1c6, …")
+        .expect("valid rule");
+    assert!(rule.ast().samples.is_some());
+
+    // An empty rule matches everything
+    let rule = PluralRule::parse(" @integer 0, 2~16").expect("valid rule");
+    assert!(rule.matches(&PluralOperands::from(7_u32)));
+
+    for (rule, kind, span) in [
+        ("n = ", ParserError::ExpectedValue, 4..4),
+        ("n == 1", ParserError::ExpectedValue, 3..4),
+        ("n = 1 or", ParserError::ExpectedAndCondition, 8..8),
+        ("n % = 1", ParserError::ExpectedValue, 4..5),
+        ("q = 1", ParserError::ExpectedOperand, 0..1),
+        ("n = 1 @integer 1 n", ParserError::UnexpectedToken, 17..18),
+        ("n = 1 @foo", ParserError::ExpectedSampleType, 7..8),
+    ] {
+        let error = PluralRule::parse(rule).unwrap_err();
+        assert_eq!((error.kind, error.span), (kind, span), "{rule}");
+    }
+}
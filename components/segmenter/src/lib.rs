@@ -141,6 +141,7 @@ pub use crate::word::WordBreakIterator;
 pub use crate::word::WordSegmenter;
 
 // Options structs and enums
+pub use crate::line::LineBreakClass;
 pub use crate::line::LineBreakOptions;
 pub use crate::line::LineBreakStrictness;
 pub use crate::line::LineBreakWordOption;
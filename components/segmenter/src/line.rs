@@ -10,6 +10,7 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::char;
+use core::ops::RangeInclusive;
 use core::str::CharIndices;
 use icu_provider::prelude::*;
 use utf8_iter::Utf8CharIndices;
@@ -168,6 +169,163 @@ pub enum LineBreakWordOption {
     KeepAll,
 }
 
+/// A Line_Break class of the Unicode Line Breaking Algorithm, used to override the class of
+/// code points with [`LineBreakOptions::override_property()`].
+///
+/// See <https://www.unicode.org/reports/tr14/#Table1> for the meaning of each class.
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LineBreakClass {
+    /// Ambiguous (Alphabetic or Ideographic) (AI)
+    Ambiguous,
+    /// Alphabetic (AL)
+    Alphabetic,
+    /// Break Opportunity Before and After (B2)
+    BreakBoth,
+    /// Break After (BA)
+    BreakAfter,
+    /// Break Before (BB)
+    BreakBefore,
+    /// Mandatory Break (BK)
+    MandatoryBreak,
+    /// Contingent Break Opportunity (CB)
+    ContingentBreak,
+    /// Conditional Japanese Starter (CJ)
+    ConditionalJapaneseStarter,
+    /// Close Punctuation (CL)
+    ClosePunctuation,
+    /// Combining Mark (CM)
+    CombiningMark,
+    /// Close Parenthesis (CP)
+    CloseParenthesis,
+    /// Carriage Return (CR)
+    CarriageReturn,
+    /// Emoji Base (EB)
+    EBase,
+    /// Emoji Modifier (EM)
+    EModifier,
+    /// Exclamation/Interrogation (EX)
+    Exclamation,
+    /// Non-breaking ("Glue") (GL)
+    Glue,
+    /// Hangul LV Syllable (H2)
+    H2,
+    /// Hangul LVT Syllable (H3)
+    H3,
+    /// Hebrew Letter (HL)
+    HebrewLetter,
+    /// Hyphen (HY)
+    Hyphen,
+    /// Ideographic (ID)
+    Ideographic,
+    /// Inseparable (IN)
+    Inseparable,
+    /// Infix Numeric Separator (IS)
+    InfixNumeric,
+    /// Hangul L Jamo (JL)
+    JL,
+    /// Hangul T Jamo (JT)
+    JT,
+    /// Hangul V Jamo (JV)
+    JV,
+    /// Line Feed (LF)
+    LineFeed,
+    /// Next Line (NL)
+    NextLine,
+    /// Nonstarter (NS)
+    Nonstarter,
+    /// Numeric (NU)
+    Numeric,
+    /// Open Punctuation (OP)
+    OpenPunctuation,
+    /// Postfix Numeric (PO)
+    PostfixNumeric,
+    /// Prefix Numeric (PR)
+    PrefixNumeric,
+    /// Quotation (QU)
+    Quotation,
+    /// Regional Indicator (RI)
+    RegionalIndicator,
+    /// Complex Context Dependent (South East Asian) (SA)
+    ComplexContext,
+    /// Surrogate (SG)
+    Surrogate,
+    /// Space (SP)
+    Space,
+    /// Symbols Allowing Break After (SY)
+    BreakSymbols,
+    /// Word Joiner (WJ)
+    WordJoiner,
+    /// Unknown (XX)
+    Unknown,
+    /// Zero Width Space (ZW)
+    ZWSpace,
+    /// Zero Width Joiner (ZWJ)
+    ZWJ,
+}
+
+impl LineBreakClass {
+    /// Returns the class used by the line break rules of the segmenter data.
+    fn to_property(self) -> u8 {
+        match self {
+            Self::Ambiguous => AI,
+            Self::Alphabetic => AL,
+            Self::BreakBoth => B2,
+            Self::BreakAfter => BA,
+            Self::BreakBefore => BB,
+            Self::MandatoryBreak => BK,
+            Self::ContingentBreak => CB,
+            Self::ConditionalJapaneseStarter => CJ,
+            Self::ClosePunctuation => CL,
+            Self::CombiningMark => CM,
+            Self::CloseParenthesis => CP,
+            Self::CarriageReturn => CR,
+            Self::EBase => EB,
+            Self::EModifier => EM,
+            Self::Exclamation => EX,
+            Self::Glue => GL,
+            Self::H2 => H2,
+            Self::H3 => H3,
+            Self::HebrewLetter => HL,
+            Self::Hyphen => HY,
+            Self::Ideographic => ID,
+            Self::Inseparable => IN,
+            Self::InfixNumeric => IS,
+            Self::JL => JL,
+            Self::JT => JT,
+            Self::JV => JV,
+            Self::LineFeed => LF,
+            Self::NextLine => NL,
+            Self::Nonstarter => NS,
+            Self::Numeric => NU,
+            Self::OpenPunctuation => OP_OP30,
+            Self::PostfixNumeric => PO,
+            Self::PrefixNumeric => PR,
+            Self::Quotation => QU,
+            Self::RegionalIndicator => RI,
+            Self::ComplexContext => SA,
+            Self::Surrogate => SG,
+            Self::Space => SP,
+            Self::BreakSymbols => SY,
+            Self::WordJoiner => WJ,
+            Self::Unknown => XX,
+            Self::ZWSpace => ZW,
+            Self::ZWJ => ZWJ,
+        }
+    }
+}
+
+/// The maximum number of ranges in [`LineBreakOptions::override_property()`].
+const MAX_PROPERTY_OVERRIDES: usize = 16;
+
+/// A range of code points whose Line_Break class is overridden.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+struct PropertyOverride {
+    start: u32,
+    end: u32,
+    property: u8,
+}
+
 /// Options to tailor line-breaking behavior.
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -185,6 +343,11 @@ pub struct LineBreakOptions {
     ///
     /// This option has no effect in Latin-1 mode.
     pub ja_zh: bool,
+
+    /// Ranges of code points whose Line_Break class is overridden, in the order they were
+    /// added. Only the first `property_override_count` entries are used.
+    property_overrides: [PropertyOverride; MAX_PROPERTY_OVERRIDES],
+    property_override_count: u8,
 }
 
 impl Default for LineBreakOptions {
@@ -193,10 +356,75 @@ impl Default for LineBreakOptions {
             strictness: LineBreakStrictness::Strict,
             word_option: LineBreakWordOption::Normal,
             ja_zh: false,
+            property_overrides: Default::default(),
+            property_override_count: 0,
         }
     }
 }
 
+impl LineBreakOptions {
+    /// Overrides the Line_Break class of a range of code points, such as private use code
+    /// points that a font renders as icons.
+    ///
+    /// Overrides are consulted before the data, and later overrides take precedence over
+    /// earlier ones for overlapping ranges. At most 16 ranges can be overridden, to keep
+    /// lookups fast; `false` is returned, and the range is ignored, if that limit is reached.
+    ///
+    /// # Examples
+    ///
+    /// Break around private use icons like around ideographs:
+    ///
+    /// ```
+    /// use icu::segmenter::{LineBreakClass, LineBreakOptions, LineSegmenter};
+    ///
+    /// let mut options = LineBreakOptions::default();
+    /// assert!(options
+    ///     .override_property('\u{E000}'..='\u{F8FF}', LineBreakClass::Ideographic));
+    /// let segmenter = LineSegmenter::new_auto_with_options(options);
+    ///
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_str("ab\u{E001}\u{E002}cd").collect();
+    /// assert_eq!(&breakpoints, &[0, 2, 5, 8, 10]);
+    ///
+    /// // By default, private use code points are alphabetic
+    /// let breakpoints: Vec<usize> = LineSegmenter::new_auto()
+    ///     .segment_str("ab\u{E001}\u{E002}cd")
+    ///     .collect();
+    /// assert_eq!(&breakpoints, &[0, 10]);
+    /// ```
+    #[must_use]
+    pub fn override_property(
+        &mut self,
+        range: RangeInclusive<char>,
+        class: LineBreakClass,
+    ) -> bool {
+        let Some(slot) = self
+            .property_overrides
+            .get_mut(self.property_override_count as usize)
+        else {
+            return false;
+        };
+        *slot = PropertyOverride {
+            start: *range.start() as u32,
+            end: *range.end() as u32,
+            property: class.to_property(),
+        };
+        self.property_override_count += 1;
+        true
+    }
+
+    /// Returns the overridden Line_Break class of a code point, if any.
+    #[inline]
+    fn overridden_property(&self, codepoint: u32) -> Option<u8> {
+        self.property_overrides
+            .get(..self.property_override_count as usize)?
+            .iter()
+            .rev()
+            .find(|o| (o.start..=o.end).contains(&codepoint))
+            .map(|o| o.property)
+    }
+}
+
 /// Line break iterator for an `str` (a UTF-8 string).
 ///
 /// For examples of use, see [`LineSegmenter`].
@@ -710,15 +938,16 @@ impl RuleBreakDataV1<'_> {
     fn get_linebreak_property_utf32_with_rule(
         &self,
         codepoint: u32,
-        strictness: LineBreakStrictness,
-        word_option: LineBreakWordOption,
+        options: &LineBreakOptions,
     ) -> u8 {
         // Note: Default value is 0 == UNKNOWN
-        let prop = self.property_table.get32(codepoint);
+        let prop = options
+            .overridden_property(codepoint)
+            .unwrap_or_else(|| self.property_table.get32(codepoint));
 
-        if word_option == LineBreakWordOption::BreakAll
-            || strictness == LineBreakStrictness::Loose
-            || strictness == LineBreakStrictness::Normal
+        if options.word_option == LineBreakWordOption::BreakAll
+            || options.strictness == LineBreakStrictness::Loose
+            || options.strictness == LineBreakStrictness::Normal
         {
             return match prop {
                 CJ => ID, // All CJ's General_Category is Other_Letter (Lo).
@@ -739,12 +968,9 @@ impl RuleBreakDataV1<'_> {
     }
 
     #[inline]
-    fn use_complex_breaking_utf32(&self, codepoint: u32) -> bool {
-        let line_break_property = self.get_linebreak_property_utf32_with_rule(
-            codepoint,
-            LineBreakStrictness::Strict,
-            LineBreakWordOption::Normal,
-        );
+    fn use_complex_breaking_utf32(&self, codepoint: u32, options: &LineBreakOptions) -> bool {
+        // The rules only resolve CJ, so they do not affect SA
+        let line_break_property = self.get_linebreak_property_utf32_with_rule(codepoint, options);
 
         line_break_property == SA
     }
@@ -1076,16 +1302,16 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf8 {
     type CharType = char;
 
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: char) -> u8 {
-        iterator.data.get_linebreak_property_utf32_with_rule(
-            c as u32,
-            iterator.options.strictness,
-            iterator.options.word_option,
-        )
+        iterator
+            .data
+            .get_linebreak_property_utf32_with_rule(c as u32, iterator.options)
     }
 
     #[inline]
     fn use_complex_breaking(iterator: &LineBreakIterator<Self>, c: char) -> bool {
-        iterator.data.use_complex_breaking_utf32(c as u32)
+        iterator
+            .data
+            .use_complex_breaking_utf32(c as u32, iterator.options)
     }
 
     fn get_current_position_character_len(iterator: &LineBreakIterator<Self>) -> usize {
@@ -1108,16 +1334,16 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypePotentiallyIllFormedUtf8 {
     type CharType = char;
 
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: char) -> u8 {
-        iterator.data.get_linebreak_property_utf32_with_rule(
-            c as u32,
-            iterator.options.strictness,
-            iterator.options.word_option,
-        )
+        iterator
+            .data
+            .get_linebreak_property_utf32_with_rule(c as u32, iterator.options)
     }
 
     #[inline]
     fn use_complex_breaking(iterator: &LineBreakIterator<Self>, c: char) -> bool {
-        iterator.data.use_complex_breaking_utf32(c as u32)
+        iterator
+            .data
+            .use_complex_breaking_utf32(c as u32, iterator.options)
     }
 
     fn get_current_position_character_len(iterator: &LineBreakIterator<Self>) -> usize {
@@ -1195,7 +1421,10 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeLatin1 {
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: u8) -> u8 {
         // No CJ on Latin1
         // Note: Default value is 0 == UNKNOWN
-        iterator.data.property_table.get32(c as u32)
+        iterator
+            .options
+            .overridden_property(c as u32)
+            .unwrap_or_else(|| iterator.data.property_table.get32(c as u32))
     }
 
     #[inline]
@@ -1223,16 +1452,16 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf16 {
     type CharType = u32;
 
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: u32) -> u8 {
-        iterator.data.get_linebreak_property_utf32_with_rule(
-            c,
-            iterator.options.strictness,
-            iterator.options.word_option,
-        )
+        iterator
+            .data
+            .get_linebreak_property_utf32_with_rule(c, iterator.options)
     }
 
     #[inline]
     fn use_complex_breaking(iterator: &LineBreakIterator<Self>, c: u32) -> bool {
-        iterator.data.use_complex_breaking_utf32(c)
+        iterator
+            .data
+            .use_complex_breaking_utf32(c, iterator.options)
     }
 
     fn get_current_position_character_len(iterator: &LineBreakIterator<Self>) -> usize {
@@ -1316,11 +1545,9 @@ mod tests {
         .expect("Data should be present!");
 
         let get_linebreak_property = |codepoint| {
-            payload.get().get_linebreak_property_utf32_with_rule(
-                codepoint as u32,
-                LineBreakStrictness::Strict,
-                LineBreakWordOption::Normal,
-            )
+            payload
+                .get()
+                .get_linebreak_property_utf32_with_rule(codepoint as u32, &Default::default())
         };
 
         assert_eq!(get_linebreak_property('\u{0020}'), SP);
@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::LineBreakClass;
+use icu_segmenter::LineBreakOptions;
+use icu_segmenter::LineSegmenter;
+
+fn breaks(s: &str, options: LineBreakOptions) -> (Vec<usize>, Vec<usize>) {
+    let segmenter = LineSegmenter::new_dictionary_with_options(options);
+    let utf16: Vec<u16> = s.encode_utf16().collect();
+    (
+        segmenter.segment_str(s).collect(),
+        segmenter.segment_utf16(&utf16).collect(),
+    )
+}
+
+#[test]
+fn private_use_as_ideographic() {
+    let mut options = LineBreakOptions::default();
+    assert!(options.override_property('\u{E000}'..='\u{F8FF}', LineBreakClass::Ideographic));
+
+    // Private use icons break like the CJK ideographs of the same encoded lengths
+    for (icons, ideographs) in [
+        ("ab\u{E001}\u{E002}cd", "ab\u{4E00}\u{4E01}cd"),
+        ("\u{E001}, \u{E002}。", "\u{4E00}, \u{4E01}。"),
+        ("(\u{E001})\u{E002}\u{E003}", "(\u{4E00})\u{4E01}\u{4E02}"),
+        ("icon \u{E001} text", "icon \u{4E00} text"),
+    ] {
+        assert_eq!(
+            breaks(icons, options),
+            breaks(ideographs, LineBreakOptions::default()),
+            "{icons}"
+        );
+    }
+    assert_eq!(breaks("ab\u{E001}\u{E002}cd", options).0, [0, 2, 5, 8, 10]);
+
+    // Without the override, private use code points are alphabetic
+    assert_eq!(
+        breaks("ab\u{E001}\u{E002}cd", LineBreakOptions::default()).0,
+        [0, 10]
+    );
+    // Code points outside of the range are unaffected
+    assert_eq!(
+        breaks("ab\u{F900}cd", options),
+        breaks("ab\u{F900}cd", Default::default())
+    );
+}
+
+#[test]
+fn later_overrides_take_precedence() {
+    let mut options = LineBreakOptions::default();
+    assert!(options.override_property('\u{E000}'..='\u{F8FF}', LineBreakClass::Ideographic));
+    assert!(options.override_property('\u{E002}'..='\u{E002}', LineBreakClass::Glue));
+
+    // No break on either side of the glue
+    assert_eq!(
+        breaks("\u{E001}\u{E002}\u{E003}\u{E004}", options).0,
+        [0, 9, 12]
+    );
+}
+
+#[test]
+fn latin1() {
+    let mut options = LineBreakOptions::default();
+    assert!(options.override_property('x'..='x', LineBreakClass::BreakAfter));
+    let segmenter = LineSegmenter::new_dictionary_with_options(options);
+
+    let breakpoints: Vec<usize> = segmenter.segment_latin1(b"axbxc").collect();
+    assert_eq!(breakpoints, [0, 2, 4, 5]);
+    let breakpoints: Vec<usize> = segmenter.segment_str("axbxc").collect();
+    assert_eq!(breakpoints, [0, 2, 4, 5]);
+}
+
+#[test]
+fn override_limit() {
+    let mut options = LineBreakOptions::default();
+    for c in 'a'..='p' {
+        assert!(options.override_property(c..=c, LineBreakClass::Ideographic));
+    }
+    // The 17th override is rejected
+    assert!(!options.override_property('q'..='q', LineBreakClass::Ideographic));
+
+    assert_eq!(breaks("ab qr", options).0, [0, 1, 3, 5]);
+}
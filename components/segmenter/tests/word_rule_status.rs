@@ -113,6 +113,36 @@ fn rule_status_th() {
     assert!(!iter.is_word_like(), "None is false");
 }
 
+// The same expectations as the word segmenter test of the JS bindings in ffi/npm/test/segmenter.mjs
+#[test]
+fn rule_status_mixed_th_en_utf16() {
+    let segmenter = WordSegmenter::new_auto();
+    let utf16: Vec<u16> = "ภาษาไทยง่ายนิดเดียว Thai is easy.".encode_utf16().collect();
+    let mut iter = segmenter.segment_utf16(&utf16);
+
+    let mut breakpoints = Vec::new();
+    while let Some(breakpoint) = iter.next() {
+        breakpoints.push((breakpoint, iter.word_type(), iter.is_word_like()));
+    }
+    assert_eq!(
+        breakpoints,
+        [
+            (0, WordType::None, false),
+            (4, WordType::Letter, true),
+            (7, WordType::Letter, true),
+            (11, WordType::Letter, true),
+            (19, WordType::None, false),
+            (20, WordType::None, false),
+            (24, WordType::Letter, true),
+            (25, WordType::None, false),
+            (27, WordType::Letter, true),
+            (28, WordType::None, false),
+            (32, WordType::Letter, true),
+            (33, WordType::None, false),
+        ]
+    );
+}
+
 /* The rule status functions are no longer public to non word break iterators.
 #[test]
 fn rule_status_no_word() {
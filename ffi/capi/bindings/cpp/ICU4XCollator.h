@@ -19,6 +19,7 @@ typedef struct ICU4XCollator ICU4XCollator;
 #include "ICU4XCollatorOptionsV1.h"
 #include "diplomat_result_box_ICU4XCollator_ICU4XError.h"
 #include "ICU4XOrdering.h"
+#include "diplomat_result_void_ICU4XError.h"
 #include "ICU4XCollatorResolvedOptionsV1.h"
 #ifdef __cplusplus
 namespace capi {
@@ -33,6 +34,10 @@ ICU4XOrdering ICU4XCollator_compare_valid_utf8(const ICU4XCollator* self, const
 
 ICU4XOrdering ICU4XCollator_compare_utf16(const ICU4XCollator* self, const char16_t* left_data, size_t left_len, const char16_t* right_data, size_t right_len);
 
+diplomat_result_void_ICU4XError ICU4XCollator_write_sort_key(const ICU4XCollator* self, const char* s_data, size_t s_len, DiplomatWriteable* write);
+
+diplomat_result_void_ICU4XError ICU4XCollator_write_sort_key_utf16(const ICU4XCollator* self, const char16_t* s_data, size_t s_len, DiplomatWriteable* write);
+
 ICU4XCollatorResolvedOptionsV1 ICU4XCollator_resolved_options(const ICU4XCollator* self);
 void ICU4XCollator_destroy(ICU4XCollator* self);
 
@@ -70,6 +70,52 @@ class ICU4XCollator {
    */
   ICU4XOrdering compare_utf16(const std::u16string_view left, const std::u16string_view right) const;
 
+  /**
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+   * per byte. Comparing two sort keys as strings gives the same result as comparing
+   * the strings they were computed from with this collator.
+   * 
+   * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+   * to the WHATWG Encoding Standard.
+   * 
+   * See the [Rust documentation for `write_sort_key_utf8`](https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf8) for more information.
+   */
+  template<typename W> diplomat::result<std::monostate, ICU4XError> write_sort_key_to_writeable(const std::string_view s, W& write) const;
+
+  /**
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+   * per byte. Comparing two sort keys as strings gives the same result as comparing
+   * the strings they were computed from with this collator.
+   * 
+   * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+   * to the WHATWG Encoding Standard.
+   * 
+   * See the [Rust documentation for `write_sort_key_utf8`](https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf8) for more information.
+   */
+  diplomat::result<std::string, ICU4XError> write_sort_key(const std::string_view s) const;
+
+  /**
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+   * per byte. Comparing two sort keys as strings gives the same result as comparing
+   * the strings they were computed from with this collator.
+   * 
+   * Returns `ICU4XError::InvalidUtf16Error` if `s` contains unpaired surrogates.
+   * 
+   * See the [Rust documentation for `write_sort_key_utf16`](https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf16) for more information.
+   */
+  template<typename W> diplomat::result<std::monostate, ICU4XError> write_sort_key_utf16_to_writeable(const std::u16string_view s, W& write) const;
+
+  /**
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+   * per byte. Comparing two sort keys as strings gives the same result as comparing
+   * the strings they were computed from with this collator.
+   * 
+   * Returns `ICU4XError::InvalidUtf16Error` if `s` contains unpaired surrogates.
+   * 
+   * See the [Rust documentation for `write_sort_key_utf16`](https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf16) for more information.
+   */
+  diplomat::result<std::string, ICU4XError> write_sort_key_utf16(const std::u16string_view s) const;
+
   /**
    * The resolved options showing how the default options, the requested options,
    * and the options from locale data were combined. None of the struct fields
@@ -113,6 +159,52 @@ inline ICU4XOrdering ICU4XCollator::compare_valid_utf8(const std::string_view le
 inline ICU4XOrdering ICU4XCollator::compare_utf16(const std::u16string_view left, const std::u16string_view right) const {
   return static_cast<ICU4XOrdering>(capi::ICU4XCollator_compare_utf16(this->inner.get(), left.data(), left.size(), right.data(), right.size()));
 }
+template<typename W> inline diplomat::result<std::monostate, ICU4XError> ICU4XCollator::write_sort_key_to_writeable(const std::string_view s, W& write) const {
+  capi::DiplomatWriteable write_writer = diplomat::WriteableTrait<W>::Construct(write);
+  auto diplomat_result_raw_out_value = capi::ICU4XCollator_write_sort_key(this->inner.get(), s.data(), s.size(), &write_writer);
+  diplomat::result<std::monostate, ICU4XError> diplomat_result_out_value;
+  if (diplomat_result_raw_out_value.is_ok) {
+    diplomat_result_out_value = diplomat::Ok<std::monostate>(std::monostate());
+  } else {
+    diplomat_result_out_value = diplomat::Err<ICU4XError>(static_cast<ICU4XError>(diplomat_result_raw_out_value.err));
+  }
+  return diplomat_result_out_value;
+}
+inline diplomat::result<std::string, ICU4XError> ICU4XCollator::write_sort_key(const std::string_view s) const {
+  std::string diplomat_writeable_string;
+  capi::DiplomatWriteable diplomat_writeable_out = diplomat::WriteableFromString(diplomat_writeable_string);
+  auto diplomat_result_raw_out_value = capi::ICU4XCollator_write_sort_key(this->inner.get(), s.data(), s.size(), &diplomat_writeable_out);
+  diplomat::result<std::monostate, ICU4XError> diplomat_result_out_value;
+  if (diplomat_result_raw_out_value.is_ok) {
+    diplomat_result_out_value = diplomat::Ok<std::monostate>(std::monostate());
+  } else {
+    diplomat_result_out_value = diplomat::Err<ICU4XError>(static_cast<ICU4XError>(diplomat_result_raw_out_value.err));
+  }
+  return diplomat_result_out_value.replace_ok(std::move(diplomat_writeable_string));
+}
+template<typename W> inline diplomat::result<std::monostate, ICU4XError> ICU4XCollator::write_sort_key_utf16_to_writeable(const std::u16string_view s, W& write) const {
+  capi::DiplomatWriteable write_writer = diplomat::WriteableTrait<W>::Construct(write);
+  auto diplomat_result_raw_out_value = capi::ICU4XCollator_write_sort_key_utf16(this->inner.get(), s.data(), s.size(), &write_writer);
+  diplomat::result<std::monostate, ICU4XError> diplomat_result_out_value;
+  if (diplomat_result_raw_out_value.is_ok) {
+    diplomat_result_out_value = diplomat::Ok<std::monostate>(std::monostate());
+  } else {
+    diplomat_result_out_value = diplomat::Err<ICU4XError>(static_cast<ICU4XError>(diplomat_result_raw_out_value.err));
+  }
+  return diplomat_result_out_value;
+}
+inline diplomat::result<std::string, ICU4XError> ICU4XCollator::write_sort_key_utf16(const std::u16string_view s) const {
+  std::string diplomat_writeable_string;
+  capi::DiplomatWriteable diplomat_writeable_out = diplomat::WriteableFromString(diplomat_writeable_string);
+  auto diplomat_result_raw_out_value = capi::ICU4XCollator_write_sort_key_utf16(this->inner.get(), s.data(), s.size(), &diplomat_writeable_out);
+  diplomat::result<std::monostate, ICU4XError> diplomat_result_out_value;
+  if (diplomat_result_raw_out_value.is_ok) {
+    diplomat_result_out_value = diplomat::Ok<std::monostate>(std::monostate());
+  } else {
+    diplomat_result_out_value = diplomat::Err<ICU4XError>(static_cast<ICU4XError>(diplomat_result_raw_out_value.err));
+  }
+  return diplomat_result_out_value.replace_ok(std::move(diplomat_writeable_string));
+}
 inline ICU4XCollatorResolvedOptionsV1 ICU4XCollator::resolved_options() const {
   capi::ICU4XCollatorResolvedOptionsV1 diplomat_raw_struct_out_value = capi::ICU4XCollator_resolved_options(this->inner.get());
   return ICU4XCollatorResolvedOptionsV1{ .strength = std::move(static_cast<ICU4XCollatorStrength>(diplomat_raw_struct_out_value.strength)), .alternate_handling = std::move(static_cast<ICU4XCollatorAlternateHandling>(diplomat_raw_struct_out_value.alternate_handling)), .case_first = std::move(static_cast<ICU4XCollatorCaseFirst>(diplomat_raw_struct_out_value.case_first)), .max_variable = std::move(static_cast<ICU4XCollatorMaxVariable>(diplomat_raw_struct_out_value.max_variable)), .case_level = std::move(static_cast<ICU4XCollatorCaseLevel>(diplomat_raw_struct_out_value.case_level)), .numeric = std::move(static_cast<ICU4XCollatorNumeric>(diplomat_raw_struct_out_value.numeric)), .backward_second_level = std::move(static_cast<ICU4XCollatorBackwardSecondLevel>(diplomat_raw_struct_out_value.backward_second_level)) };
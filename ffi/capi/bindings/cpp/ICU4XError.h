@@ -14,6 +14,7 @@ typedef enum ICU4XError {
   ICU4XError_UnknownError = 0,
   ICU4XError_WriteableError = 1,
   ICU4XError_OutOfBoundsError = 2,
+  ICU4XError_InvalidUtf16Error = 3,
   ICU4XError_DataMissingDataKeyError = 256,
   ICU4XError_DataMissingVariantError = 257,
   ICU4XError_DataMissingLocaleError = 258,
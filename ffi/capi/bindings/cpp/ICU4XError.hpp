@@ -39,6 +39,11 @@ enum struct ICU4XError {
    * Some input was out of bounds
    */
   OutOfBoundsError = 2,
+
+  /**
+   * Some input was not well-formed UTF-16
+   */
+  InvalidUtf16Error = 3,
   DataMissingDataKeyError = 256,
   DataMissingVariantError = 257,
   DataMissingLocaleError = 258,
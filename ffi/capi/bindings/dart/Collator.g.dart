@@ -52,6 +52,27 @@ final class Collator implements ffi.Finalizable {
     return result;
   }
 
+  /// Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+  /// per byte. Comparing two sort keys as strings gives the same result as comparing
+  /// the strings they were computed from with this collator.
+  ///
+  /// Returns `Error::InvalidUtf16Error` if `s` contains unpaired surrogates.
+  ///
+  /// See the [Rust documentation for `write_sort_key_utf16`](https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf16) for more information.
+  ///
+  /// Throws [Error] on failure.
+  String writeSortKey(String s) {
+    final temp = ffi2.Arena();
+    final sView = s.utf16View;
+    final writeable = _Writeable();
+    final result = _ICU4XCollator_write_sort_key_utf16(_ffi, sView.allocIn(temp), sView.length, writeable._ffi);
+    temp.releaseAll();
+    if (!result.isOk) {
+      throw Error.values.firstWhere((v) => v._ffi == result.union.err);
+    }
+    return writeable.finalize();
+  }
+
   /// The resolved options showing how the default options, the requested options,
   /// and the options from locale data were combined. None of the struct fields
   /// will have `Auto` as the value.
@@ -78,6 +99,11 @@ external _ResultOpaqueInt32 _ICU4XCollator_create_v1(ffi.Pointer<ffi.Opaque> pro
 // ignore: non_constant_identifier_names
 external int _ICU4XCollator_compare_utf16_(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Uint16> leftData, int leftLength, ffi.Pointer<ffi.Uint16> rightData, int rightLength);
 
+@meta.ResourceIdentifier('ICU4XCollator_write_sort_key_utf16')
+@ffi.Native<_ResultVoidInt32 Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Uint16>, ffi.Size, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'ICU4XCollator_write_sort_key_utf16')
+// ignore: non_constant_identifier_names
+external _ResultVoidInt32 _ICU4XCollator_write_sort_key_utf16(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Uint16> sData, int sLength, ffi.Pointer<ffi.Opaque> writeable);
+
 @meta.ResourceIdentifier('ICU4XCollator_resolved_options')
 @ffi.Native<_ResolvedCollatorOptionsFfi Function(ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'ICU4XCollator_resolved_options')
 // ignore: non_constant_identifier_names
@@ -20,6 +20,9 @@ enum Error {
   /// Some input was out of bounds
   outOfBoundsError,
 
+  /// Some input was not well-formed UTF-16
+  invalidUtf16Error,
+
   dataMissingDataKeyError,
 
   dataMissingVariantError,
@@ -137,6 +140,8 @@ enum Error {
         return 1;
       case outOfBoundsError:
         return 2;
+      case invalidUtf16Error:
+        return 3;
       case dataMissingDataKeyError:
         return 256;
       case dataMissingVariantError:
@@ -49,6 +49,28 @@ export class ICU4XCollator {
    */
   compare_utf16(left: string, right: string): ICU4XOrdering;
 
+  /**
+
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits per byte. Comparing two sort keys as strings gives the same result as comparing the strings they were computed from with this collator.
+
+   * Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according to the WHATWG Encoding Standard.
+
+   * See the {@link https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf8 Rust documentation for `write_sort_key_utf8`} for more information.
+   * @throws {@link FFIError}<{@link ICU4XError}>
+   */
+  write_sort_key(s: string): string | never;
+
+  /**
+
+   * Write the sort key of a string to `write`, as two lowercase hexadecimal digits per byte. Comparing two sort keys as strings gives the same result as comparing the strings they were computed from with this collator.
+
+   * Returns `ICU4XError::InvalidUtf16Error` if `s` contains unpaired surrogates.
+
+   * See the {@link https://docs.rs/icu/latest/icu/collator/struct.Collator.html#method.write_sort_key_utf16 Rust documentation for `write_sort_key_utf16`} for more information.
+   * @throws {@link FFIError}<{@link ICU4XError}>
+   */
+  write_sort_key_utf16(s: string): string | never;
+
   /**
 
    * The resolved options showing how the default options, the requested options, and the options from locale data were combined. None of the struct fields will have `Auto` as the value.
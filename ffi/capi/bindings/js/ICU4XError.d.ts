@@ -23,6 +23,11 @@ export enum ICU4XError {
    * Some input was out of bounds
    */
   OutOfBoundsError = 'OutOfBoundsError',
+  /**
+
+   * Some input was not well-formed UTF-16
+   */
+  InvalidUtf16Error = 'InvalidUtf16Error',
   /**
    */
   DataMissingDataKeyError = 'DataMissingDataKeyError',
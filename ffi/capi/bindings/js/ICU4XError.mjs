@@ -5,6 +5,7 @@ export const ICU4XError_js_to_rust = {
   "UnknownError": 0,
   "WriteableError": 1,
   "OutOfBoundsError": 2,
+  "InvalidUtf16Error": 3,
   "DataMissingDataKeyError": 256,
   "DataMissingVariantError": 257,
   "DataMissingLocaleError": 258,
@@ -64,6 +65,7 @@ export const ICU4XError_rust_to_js = {
   [0]: "UnknownError",
   [1]: "WriteableError",
   [2]: "OutOfBoundsError",
+  [3]: "InvalidUtf16Error",
   [256]: "DataMissingDataKeyError",
   [257]: "DataMissingVariantError",
   [258]: "DataMissingLocaleError",
@@ -123,6 +125,7 @@ export const ICU4XError = {
   "UnknownError": "UnknownError",
   "WriteableError": "WriteableError",
   "OutOfBoundsError": "OutOfBoundsError",
+  "InvalidUtf16Error": "InvalidUtf16Error",
   "DataMissingDataKeyError": "DataMissingDataKeyError",
   "DataMissingVariantError": "DataMissingVariantError",
   "DataMissingLocaleError": "DataMissingLocaleError",
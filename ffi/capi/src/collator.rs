@@ -12,6 +12,8 @@ pub mod ffi {
         provider::ffi::ICU4XDataProvider,
     };
 
+    use super::HexSink;
+
     #[diplomat::opaque]
     #[diplomat::rust_link(icu::collator::Collator, Struct)]
     pub struct ICU4XCollator(pub Collator);
@@ -171,6 +173,45 @@ pub mod ffi {
             self.0.compare_utf16(left, right)
         }
 
+        /// Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+        /// per byte. Comparing two sort keys as strings gives the same result as comparing
+        /// the strings they were computed from with this collator.
+        ///
+        /// Ill-formed input is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+        /// to the WHATWG Encoding Standard.
+        #[diplomat::rust_link(icu::collator::Collator::write_sort_key_utf8, FnInStruct)]
+        #[diplomat::rust_link(icu::collator::Collator::write_sort_key, FnInStruct, hidden)]
+        #[diplomat::attr(dart, disable)]
+        pub fn write_sort_key(
+            &self,
+            s: &DiplomatStr,
+            write: &mut diplomat_runtime::DiplomatWriteable,
+        ) -> Result<(), ICU4XError> {
+            let mut sink = HexSink::new(write);
+            self.0.write_sort_key_utf8(s, &mut sink);
+            sink.finish()
+        }
+
+        /// Write the sort key of a string to `write`, as two lowercase hexadecimal digits
+        /// per byte. Comparing two sort keys as strings gives the same result as comparing
+        /// the strings they were computed from with this collator.
+        ///
+        /// Returns `ICU4XError::InvalidUtf16Error` if `s` contains unpaired surrogates.
+        #[diplomat::rust_link(icu::collator::Collator::write_sort_key_utf16, FnInStruct)]
+        #[diplomat::attr(dart, rename = "write_sort_key")]
+        pub fn write_sort_key_utf16(
+            &self,
+            s: &DiplomatStr16,
+            write: &mut diplomat_runtime::DiplomatWriteable,
+        ) -> Result<(), ICU4XError> {
+            if char::decode_utf16(s.iter().copied()).any(|c| c.is_err()) {
+                return Err(ICU4XError::InvalidUtf16Error);
+            }
+            let mut sink = HexSink::new(write);
+            self.0.write_sort_key_utf16(s, &mut sink);
+            sink.finish()
+        }
+
         /// The resolved options showing how the default options, the requested options,
         /// and the options from locale data were combined. None of the struct fields
         /// will have `Auto` as the value.
@@ -182,11 +223,45 @@ pub mod ffi {
     }
 }
 
+use core::fmt::{self, Write};
+use diplomat_runtime::DiplomatWriteable;
 use icu_collator::{
     AlternateHandling, BackwardSecondLevel, CaseFirst, CaseLevel, CollatorOptions, MaxVariable,
     Numeric, ResolvedCollatorOptions, Strength,
 };
 
+/// Adapts a [`DiplomatWriteable`] into a sink for sort key bytes, writing each byte
+/// as two lowercase hexadecimal digits. This keeps the order of the keys and never
+/// writes a NUL.
+struct HexSink<'a> {
+    write: &'a mut DiplomatWriteable,
+    result: fmt::Result,
+}
+
+impl<'a> HexSink<'a> {
+    fn new(write: &'a mut DiplomatWriteable) -> Self {
+        Self {
+            write,
+            result: Ok(()),
+        }
+    }
+
+    fn finish(self) -> Result<(), ffi::ICU4XError> {
+        Ok(self.result?)
+    }
+}
+
+impl Extend<u8> for HexSink<'_> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            if self.result.is_err() {
+                return;
+            }
+            self.result = write!(self.write, "{byte:02x}");
+        }
+    }
+}
+
 impl From<ffi::ICU4XCollatorStrength> for Option<Strength> {
     fn from(strength: ffi::ICU4XCollatorStrength) -> Option<Strength> {
         match strength {
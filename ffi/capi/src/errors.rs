@@ -74,6 +74,8 @@ pub mod ffi {
         WriteableError = 0x01,
         /// Some input was out of bounds
         OutOfBoundsError = 0x02,
+        /// Some input was not well-formed UTF-16
+        InvalidUtf16Error = 0x03,
 
         // general data errors
         // See DataError
@@ -0,0 +1,74 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import test from 'ava';
+
+import { ICU4XCollator, ICU4XDataProvider, ICU4XLocale } from "icu4x"
+
+// The same lists are sorted by test_sort_small_list in components/collator/tests/tests.rs
+const WORDS = ["nube", "mañana", "luz", "manna"];
+const NUMBERED = ["a10", "a2", "a1"];
+
+function options(overrides) {
+  return {
+    strength: "Auto",
+    alternate_handling: "Auto",
+    case_first: "Auto",
+    max_variable: "Auto",
+    case_level: "Auto",
+    numeric: "Auto",
+    backward_second_level: "Auto",
+    ...overrides,
+  };
+}
+
+function createCollator(locale, overrides = {}) {
+  const provider = ICU4XDataProvider.create_compiled();
+  return ICU4XCollator.create_v1(provider, ICU4XLocale.create_from_string(locale), options(overrides));
+}
+
+function order(ordering) {
+  return { Less: -1, Equal: 0, Greater: 1 }[ordering];
+}
+
+test("sort a list of words with compare_utf16", t => {
+  const en = createCollator("en");
+  t.deepEqual([...WORDS].sort((a, b) => order(en.compare_utf16(a, b))), ["luz", "mañana", "manna", "nube"]);
+
+  const es = createCollator("es");
+  t.deepEqual([...WORDS].sort((a, b) => order(es.compare_utf16(a, b))), ["luz", "manna", "mañana", "nube"]);
+});
+
+test("sort numbered words with and without numeric collation", t => {
+  const collator = createCollator("und");
+  t.deepEqual([...NUMBERED].sort((a, b) => order(collator.compare(a, b))), ["a1", "a10", "a2"]);
+
+  const numeric = createCollator("und", { numeric: "On" });
+  t.deepEqual([...NUMBERED].sort((a, b) => order(numeric.compare(a, b))), ["a1", "a2", "a10"]);
+  t.is(numeric.resolved_options().numeric, "On");
+});
+
+test("compare with strength and case level options", t => {
+  t.is(createCollator("en", { strength: "Primary" }).compare_utf16("as", "às"), "Equal");
+  t.is(createCollator("en", { strength: "Secondary" }).compare_utf16("as", "às"), "Less");
+  t.is(createCollator("und", { strength: "Primary", case_level: "On" }).compare_utf16("aA", "Aa"), "Less");
+});
+
+test("compare unpaired surrogates as U+FFFD", t => {
+  const collator = createCollator("und", { strength: "Quaternary" });
+  t.is(collator.compare_utf16("\uD801", "\uD802"), "Equal");
+});
+
+test("sort a list of words by sort key", t => {
+  const es = createCollator("es");
+  const keys = new Map(WORDS.map(word => [word, es.write_sort_key_utf16(word)]));
+  t.deepEqual([...WORDS].sort((a, b) => keys.get(a) < keys.get(b) ? -1 : keys.get(a) > keys.get(b) ? 1 : 0), ["luz", "manna", "mañana", "nube"]);
+  t.is(es.write_sort_key("mañana"), keys.get("mañana"));
+});
+
+test("reject unpaired surrogates when writing a sort key", t => {
+  const collator = createCollator("und");
+  const error = t.throws(() => collator.write_sort_key_utf16("a\uD801"));
+  t.is(error.error_value, "InvalidUtf16Error");
+});
@@ -0,0 +1,69 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import test from 'ava';
+
+import {
+  ICU4XDataProvider,
+  ICU4XGraphemeClusterSegmenter,
+  ICU4XLineSegmenter,
+  ICU4XSentenceSegmenter,
+  ICU4XWordSegmenter,
+} from "icu4x"
+
+const MIXED = "ภาษาไทยง่ายนิดเดียว Thai is easy.";
+
+function collectBreakpoints(iterator) {
+  const breakpoints = [];
+  for (let breakpoint = iterator.next(); breakpoint !== -1; breakpoint = iterator.next()) {
+    breakpoints.push(breakpoint);
+  }
+  return breakpoints;
+}
+
+test("segment a mixed Thai and English sentence into words", t => {
+  const provider = ICU4XDataProvider.create_compiled();
+  const segmenter = ICU4XWordSegmenter.create_auto(provider);
+
+  const iterator = segmenter.segment_utf16(MIXED);
+  const words = [];
+  for (let breakpoint = iterator.next(); breakpoint !== -1; breakpoint = iterator.next()) {
+    words.push([breakpoint, iterator.word_type(), iterator.is_word_like()]);
+  }
+
+  t.deepEqual(words.map(([breakpoint]) => breakpoint), [0, 4, 7, 11, 19, 20, 24, 25, 27, 28, 32, 33]);
+  t.deepEqual(words.slice(6), [
+    [24, "Letter", true],
+    [25, "None", false],
+    [27, "Letter", true],
+    [28, "None", false],
+    [32, "Letter", true],
+    [33, "None", false],
+  ]);
+});
+
+test("segment a mixed Thai and English sentence into lines", t => {
+  const provider = ICU4XDataProvider.create_compiled();
+  const segmenter = ICU4XLineSegmenter.create_auto(provider);
+
+  t.deepEqual(collectBreakpoints(segmenter.segment_utf16(MIXED)), [0, 4, 7, 11, 19, 20, 25, 28, 33]);
+});
+
+test("segment a mixed Thai and English sentence into grapheme clusters", t => {
+  const provider = ICU4XDataProvider.create_compiled();
+  const segmenter = ICU4XGraphemeClusterSegmenter.create(provider);
+
+  const breakpoints = collectBreakpoints(segmenter.segment_utf16(MIXED));
+  // Thai combining marks do not start a new grapheme cluster
+  t.deepEqual(breakpoints.slice(0, 11), [0, 1, 2, 3, 4, 5, 6, 7, 9, 10, 11]);
+  t.is(breakpoints.length, 31);
+  t.is(breakpoints[breakpoints.length - 1], MIXED.length);
+});
+
+test("segment a mixed Thai and English sentence into sentences", t => {
+  const provider = ICU4XDataProvider.create_compiled();
+  const segmenter = ICU4XSentenceSegmenter.create(provider);
+
+  t.deepEqual(collectBreakpoints(segmenter.segment_utf16(MIXED)), [0, 33]);
+});
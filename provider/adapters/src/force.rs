@@ -0,0 +1,245 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A data provider wrapper that loads every request in a single fixed locale.
+//!
+//! This is useful for testing: by forcing all requests to `und`, every formatter returns the
+//! root data regardless of the locale it was constructed with, which makes hard-coded or
+//! untranslated strings stand out. It can also be used to route all requests to a pseudo-locale.
+
+use icu_provider::prelude::*;
+
+/// A data provider wrapper that rewrites the locale of every [`DataRequest`] to a fixed
+/// [`DataLocale`] before delegating to the inner provider.
+///
+/// The originally requested locale is recorded in
+/// [`DataResponseMetadata::requested_locale`](icu_provider::DataResponseMetadata::requested_locale),
+/// and [`DataResponseMetadata::locale`](icu_provider::DataResponseMetadata::locale) is set to the
+/// locale that was actually loaded.
+///
+/// If the [`DataKey`] has an extension key in its fallback configuration (for example, `-u-nu`
+/// for decimal symbols) and the forced locale does not specify a value for it, the value from
+/// the original request is retained. Singleton keys are passed through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::force::ForceLocaleProvider;
+///
+/// let provider = ForceLocaleProvider::new_with_locale(
+///     HelloWorldProvider,
+///     langid!("en").into(),
+/// );
+///
+/// let response: DataResponse<HelloWorldV1Marker> = provider
+///     .load(DataRequest {
+///         locale: &langid!("de").into(),
+///         metadata: Default::default(),
+///     })
+///     .expect("Loading should succeed");
+///
+/// assert_eq!(response.metadata.locale, Some(langid!("en").into()));
+/// assert_eq!(response.metadata.requested_locale, Some(langid!("de").into()));
+/// assert_eq!(response.payload.unwrap().get().message, "Hello World");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ForceLocaleProvider<P> {
+    inner: P,
+    locale: DataLocale,
+}
+
+impl<P> ForceLocaleProvider<P> {
+    /// Wrap a provider such that all requests load root (`und`) data.
+    pub fn new(provider: P) -> Self {
+        Self::new_with_locale(provider, DataLocale::default())
+    }
+
+    /// Wrap a provider such that all requests load data for the given locale.
+    pub fn new_with_locale(provider: P, locale: DataLocale) -> Self {
+        Self {
+            inner: provider,
+            locale,
+        }
+    }
+
+    /// Returns the locale that all requests are rewritten to.
+    pub fn locale(&self) -> &DataLocale {
+        &self.locale
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner provider.
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Run the data load with the rewritten locale.
+    /// Internal function; external clients should use one of the trait impls below.
+    ///
+    /// Function arguments:
+    ///
+    /// - F1 should perform a data load for a single DataRequest and return the result of it
+    /// - F2 should map from the provider-specific response type to DataResponseMetadata
+    fn run_forced<F1, F2, R>(
+        &self,
+        key: DataKey,
+        base_req: DataRequest,
+        f1: F1,
+        mut f2: F2,
+    ) -> Result<R, DataError>
+    where
+        F1: FnOnce(DataRequest) -> Result<R, DataError>,
+        F2: FnMut(&mut R) -> &mut DataResponseMetadata,
+    {
+        if key.metadata().singleton {
+            return f1(base_req);
+        }
+        let mut locale = self.locale.clone();
+        if let Some(extension_key) = key.fallback_config().extension_key {
+            if !locale.contains_unicode_ext(&extension_key) {
+                if let Some(value) = base_req.locale.get_unicode_ext(&extension_key) {
+                    locale.set_unicode_ext(extension_key, value);
+                }
+            }
+        }
+        f1(DataRequest {
+            locale: &locale,
+            metadata: base_req.metadata,
+        })
+        .map(|mut res| {
+            let metadata = f2(&mut res);
+            if metadata.locale.is_none() {
+                metadata.locale = Some(locale);
+            }
+            metadata.requested_locale = Some(base_req.locale.clone());
+            res
+        })
+        // Log the original request rather than the rewritten request
+        .map_err(|e| e.with_req(key, base_req))
+    }
+}
+
+impl<P> AnyProvider for ForceLocaleProvider<P>
+where
+    P: AnyProvider,
+{
+    fn load_any(&self, key: DataKey, base_req: DataRequest) -> Result<AnyResponse, DataError> {
+        self.run_forced(
+            key,
+            base_req,
+            |req| self.inner.load_any(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P> BufferProvider for ForceLocaleProvider<P>
+where
+    P: BufferProvider,
+{
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        base_req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        self.run_forced(
+            key,
+            base_req,
+            |req| self.inner.load_buffer(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P, M> DynamicDataProvider<M> for ForceLocaleProvider<P>
+where
+    P: DynamicDataProvider<M>,
+    M: DataMarker,
+{
+    fn load_data(&self, key: DataKey, base_req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.run_forced(
+            key,
+            base_req,
+            |req| self.inner.load_data(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P, M> DataProvider<M> for ForceLocaleProvider<P>
+where
+    P: DataProvider<M>,
+    M: KeyedDataMarker,
+{
+    fn load(&self, base_req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.run_forced(
+            M::KEY,
+            base_req,
+            |req| self.inner.load(req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_decimal::provider::{Baked, DecimalSymbolsV1Marker};
+    use icu_locid::locale;
+
+    #[test]
+    fn test_und_for_all_locales() {
+        let provider = ForceLocaleProvider::new(Baked);
+
+        let response: DataResponse<DecimalSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-GB").into(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+        let und: DataResponse<DecimalSymbolsV1Marker> = Baked
+            .load(DataRequest {
+                locale: &Default::default(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+
+        assert_eq!(response.metadata.locale, Some(Default::default()));
+        assert_eq!(
+            response.metadata.requested_locale,
+            Some(locale!("en-GB").into())
+        );
+        assert_eq!(response.payload.unwrap().get(), und.payload.unwrap().get());
+    }
+
+    #[test]
+    fn test_extension_key_retained() {
+        let provider = ForceLocaleProvider::new_with_locale(Baked, locale!("th").into());
+
+        let response: DataResponse<DecimalSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-u-nu-thai").into(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            response.metadata.locale,
+            Some(locale!("th-u-nu-thai").into())
+        );
+        assert_eq!(response.payload.unwrap().get().digits[1], '๑');
+    }
+}
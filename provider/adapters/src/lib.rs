@@ -8,6 +8,8 @@
 //! - Use the [`either`] module to choose between multiple provider types at runtime.
 //! - Use the [`filter`] module to programmatically reject certain data requests.
 //! - Use the [`fallback`] module to automatically resolve arbitrary locales for data loading.
+//! - Use the [`force`] module to load every request in a single fixed locale, such as `und`.
+//! - Use the [`rewrite`] module to map legacy or internal locale codes to the locales of the data.
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -32,5 +34,7 @@ pub mod either;
 pub mod empty;
 pub mod fallback;
 pub mod filter;
+pub mod force;
 pub mod fork;
 mod helpers;
+pub mod rewrite;
@@ -0,0 +1,283 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A data provider wrapper that rewrites requested locales with a user-supplied mapping.
+//!
+//! This is useful for products that receive legacy or internal locale codes, such as `iw` for
+//! Hebrew or `no-bok` for Norwegian Bokmål, and need them mapped to the locales of the data.
+
+use core::fmt;
+use icu_provider::prelude::*;
+
+/// A data provider wrapper that maps the locale of every [`DataRequest`] with a closure before
+/// delegating to the inner provider.
+///
+/// If the closure returns `Some`, the request is loaded with the returned locale, the
+/// originally requested locale is recorded in
+/// [`DataResponseMetadata::requested_locale`](icu_provider::DataResponseMetadata::requested_locale),
+/// and [`DataResponseMetadata::locale`](icu_provider::DataResponseMetadata::locale) is set to the
+/// returned locale unless the inner provider resolved a different one. If it returns `None`, the
+/// request is passed through unchanged. Singleton keys are always passed through unchanged.
+///
+/// The mapping sees the locale as requested, so to apply it before locale fallback, wrap the
+/// provider that performs fallback, such as a
+/// [`LocaleFallbackProvider`](crate::fallback::LocaleFallbackProvider) or baked data.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::{langid, subtags::language};
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::rewrite::LocaleRewriteProvider;
+///
+/// // "mo" is a legacy code for Romanian
+/// let provider = LocaleRewriteProvider::new(HelloWorldProvider, |locale: &DataLocale| {
+///     (locale.language() == language!("mo")).then(|| {
+///         let mut locale = locale.clone();
+///         locale.set_language(language!("ro"));
+///         locale
+///     })
+/// });
+///
+/// let response: DataResponse<HelloWorldV1Marker> = provider
+///     .load(DataRequest {
+///         locale: &langid!("mo").into(),
+///         metadata: Default::default(),
+///     })
+///     .expect("Loading should succeed");
+///
+/// assert_eq!(response.metadata.locale, Some(langid!("ro").into()));
+/// assert_eq!(response.metadata.requested_locale, Some(langid!("mo").into()));
+/// assert_eq!(response.payload.unwrap().get().message, "Salut, lume");
+/// ```
+#[derive(Clone)]
+pub struct LocaleRewriteProvider<P, F> {
+    inner: P,
+    rewrite: F,
+}
+
+impl<P: fmt::Debug, F> fmt::Debug for LocaleRewriteProvider<P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocaleRewriteProvider")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P, F> LocaleRewriteProvider<P, F>
+where
+    F: Fn(&DataLocale) -> Option<DataLocale>,
+{
+    /// Wrap a provider such that requested locales are mapped with `rewrite` before loading.
+    pub fn new(provider: P, rewrite: F) -> Self {
+        Self {
+            inner: provider,
+            rewrite,
+        }
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner provider.
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Run the data load with the rewritten locale.
+    /// Internal function; external clients should use one of the trait impls below.
+    ///
+    /// Function arguments:
+    ///
+    /// - F1 should perform a data load for a single DataRequest and return the result of it
+    /// - F2 should map from the provider-specific response type to DataResponseMetadata
+    fn run_rewritten<F1, F2, R>(
+        &self,
+        key: DataKey,
+        base_req: DataRequest,
+        f1: F1,
+        mut f2: F2,
+    ) -> Result<R, DataError>
+    where
+        F1: FnOnce(DataRequest) -> Result<R, DataError>,
+        F2: FnMut(&mut R) -> &mut DataResponseMetadata,
+    {
+        if key.metadata().singleton {
+            return f1(base_req);
+        }
+        let Some(locale) = (self.rewrite)(base_req.locale) else {
+            return f1(base_req);
+        };
+        f1(DataRequest {
+            locale: &locale,
+            metadata: base_req.metadata,
+        })
+        .map(|mut res| {
+            let metadata = f2(&mut res);
+            if metadata.locale.is_none() {
+                metadata.locale = Some(locale);
+            }
+            metadata.requested_locale = Some(base_req.locale.clone());
+            res
+        })
+        // Log the original request rather than the rewritten request
+        .map_err(|e| e.with_req(key, base_req))
+    }
+}
+
+impl<P, F> AnyProvider for LocaleRewriteProvider<P, F>
+where
+    P: AnyProvider,
+    F: Fn(&DataLocale) -> Option<DataLocale>,
+{
+    fn load_any(&self, key: DataKey, base_req: DataRequest) -> Result<AnyResponse, DataError> {
+        self.run_rewritten(
+            key,
+            base_req,
+            |req| self.inner.load_any(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P, F> BufferProvider for LocaleRewriteProvider<P, F>
+where
+    P: BufferProvider,
+    F: Fn(&DataLocale) -> Option<DataLocale>,
+{
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        base_req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        self.run_rewritten(
+            key,
+            base_req,
+            |req| self.inner.load_buffer(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P, F, M> DynamicDataProvider<M> for LocaleRewriteProvider<P, F>
+where
+    P: DynamicDataProvider<M>,
+    F: Fn(&DataLocale) -> Option<DataLocale>,
+    M: DataMarker,
+{
+    fn load_data(&self, key: DataKey, base_req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.run_rewritten(
+            key,
+            base_req,
+            |req| self.inner.load_data(key, req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+impl<P, F, M> DataProvider<M> for LocaleRewriteProvider<P, F>
+where
+    P: DataProvider<M>,
+    F: Fn(&DataLocale) -> Option<DataLocale>,
+    M: KeyedDataMarker,
+{
+    fn load(&self, base_req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.run_rewritten(
+            M::KEY,
+            base_req,
+            |req| self.inner.load(req),
+            |res| &mut res.metadata,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_decimal::provider::{Baked, DecimalSymbolsV1Marker};
+    use icu_decimal::FixedDecimalFormatter;
+    use icu_locid::{locale, subtags::language};
+    use writeable::assert_writeable_eq;
+
+    fn legacy_codes(locale: &DataLocale) -> Option<DataLocale> {
+        (locale.language() == language!("iw")).then(|| {
+            let mut locale = locale.clone();
+            locale.set_language(language!("he"));
+            locale
+        })
+    }
+
+    #[test]
+    fn test_legacy_code_rewritten() {
+        let provider = LocaleRewriteProvider::new(Baked, legacy_codes);
+
+        let response: DataResponse<DecimalSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("iw-IL").into(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+        let he: DataResponse<DecimalSymbolsV1Marker> = Baked
+            .load(DataRequest {
+                locale: &locale!("he-IL").into(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+
+        // The locale resolved by the fallback of the baked data is kept
+        assert_eq!(response.metadata.locale, Some(locale!("he").into()));
+        assert_eq!(response.metadata.locale, he.metadata.locale);
+        assert_eq!(
+            response.metadata.requested_locale,
+            Some(locale!("iw-IL").into())
+        );
+        assert_eq!(response.payload.unwrap().get(), he.payload.unwrap().get());
+
+        // Other locales are passed through
+        let response: DataResponse<DecimalSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap();
+        assert_eq!(response.metadata.requested_locale, None);
+    }
+
+    #[test]
+    fn test_formatter_with_legacy_code() {
+        let provider = LocaleRewriteProvider::new(Baked, legacy_codes);
+
+        let formatter = FixedDecimalFormatter::try_new_unstable(
+            &provider,
+            &locale!("iw-IL").into(),
+            Default::default(),
+        )
+        .unwrap();
+        let he =
+            FixedDecimalFormatter::try_new(&locale!("he-IL").into(), Default::default()).unwrap();
+        // Without the rewrite, the legacy code falls back to root data
+        let und = FixedDecimalFormatter::try_new_unstable(
+            &Baked,
+            &locale!("iw-IL").into(),
+            Default::default(),
+        )
+        .unwrap();
+
+        let value = (-1234).into();
+        assert_writeable_eq!(formatter.format(&value), "\u{200e}-1,234");
+        assert_eq!(
+            formatter.format_to_string(&value),
+            he.format_to_string(&value)
+        );
+        assert_writeable_eq!(und.format(&value), "-1,234");
+    }
+}
@@ -4,8 +4,8 @@
 
 //! Data for the `icu_casemap` crate
 //!
-//! This data was generated with CLDR version 45.0.0, ICU version icu4x/2024-05-16/75.x, and
-//! LSTM segmenter version v0.1.0.
+//! This data was generated with CLDR version 45.0.0, ICU version icu4x/2024-05-16/75.x,
+//! LSTM segmenter version v0.1.0, and tzdata version 2025.2.
 
 #![no_std]
 // The source is not readable and is massive as HTML.
@@ -15,3 +15,17 @@
 include!(concat!(core::env!("ICU4X_DATA_DIR"), "/macros.rs"));
 #[cfg(not(icu4x_custom_data))]
 include!("../data/macros.rs");
+
+/// The CLDR version that the data in this crate was generated from.
+pub const CLDR_VERSION: &str = "45.0.0";
+
+/// Returns the CLDR version of the data, or `None` if custom data is used.
+///
+/// Custom data is built from `ICU4X_DATA_DIR`, so its CLDR version is not known to this crate.
+pub const fn check_data_freshness() -> Option<&'static str> {
+    if cfg!(icu4x_custom_data) {
+        None
+    } else {
+        Some(CLDR_VERSION)
+    }
+}
@@ -264,6 +264,13 @@ pub use __impl_relativetime_short_year_v1 as impl_relativetime_short_year_v1;
 #[doc(inline)]
 pub use __impliterable_relativetime_short_year_v1 as impliterable_relativetime_short_year_v1;
 #[macro_use]
+#[path = "macros/time_zone_offset_period_v1.rs.data"]
+mod time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impl_time_zone_offset_period_v1 as impl_time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impliterable_time_zone_offset_period_v1 as impliterable_time_zone_offset_period_v1;
+#[macro_use]
 #[path = "macros/units_info_v1.rs.data"]
 mod units_info_v1;
 #[doc(inline)]
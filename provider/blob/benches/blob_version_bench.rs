@@ -18,12 +18,30 @@ fn blob_version_bench(c: &mut Criterion) {
         b.iter(|| BlobDataProvider::try_new_from_static_blob(black_box(BLOB_V1)).unwrap());
     });
     c.bench_function("provider/construct/v2", |b| {
-        b.iter(|| BlobDataProvider::try_new_from_static_blob(black_box(BLOB_V1)).unwrap());
+        b.iter(|| BlobDataProvider::try_new_from_static_blob(black_box(BLOB_V2)).unwrap());
     });
 
     let hello_world_provider = HelloWorldProvider;
     let locales = hello_world_provider.supported_locales().unwrap();
 
+    // Startup cost of an application that loads a single key
+    for (name, blob) in [("v1", BLOB_V1), ("v2", BLOB_V2)] {
+        c.bench_function(&format!("provider/construct_and_read_first/{name}"), |b| {
+            b.iter(|| {
+                BlobDataProvider::try_new_from_static_blob(black_box(blob))
+                    .unwrap()
+                    .load_buffer(
+                        HelloWorldV1Marker::KEY,
+                        DataRequest {
+                            locale: &icu_locid::langid!("en").into(),
+                            metadata: Default::default(),
+                        },
+                    )
+                    .unwrap()
+            });
+        });
+    }
+
     c.bench_function("provider/read/v1", |b| {
         let provider = BlobDataProvider::try_new_from_static_blob(black_box(BLOB_V1)).unwrap();
         b.iter(|| {
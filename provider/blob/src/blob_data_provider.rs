@@ -112,10 +112,29 @@ impl BlobDataProvider {
         })
     }
 
+    /// Returns the version of the data in this blob, as set with
+    /// `DataExporter::set_data_version` when the blob was exported.
+    ///
+    /// Returns `None` if the blob was not exported with [`BlobExporter::new_v3_with_sink()`].
+    pub fn data_version(&self) -> Option<&str> {
+        self.data.get().data_version()
+    }
+
+    /// Returns the checksum of the data in this blob, which is also reported in the
+    /// [`DataResponseMetadata`] of every response.
+    ///
+    /// Returns `None` if the blob was not exported with [`BlobExporter::new_v3_with_sink()`].
+    pub fn checksum(&self) -> Option<u64> {
+        self.data.get().checksum()
+    }
+
     /// For testing purposes only: checks if it is using the V2Bigger format
     #[doc(hidden)]
     pub fn internal_is_using_v2_bigger_format(&self) -> bool {
-        matches!(self.data.get(), BlobSchema::V002Bigger(..))
+        matches!(
+            self.data.get(),
+            BlobSchema::V002Bigger(..) | BlobSchema::V003Bigger(..)
+        )
     }
 }
 
@@ -127,6 +146,7 @@ impl BufferProvider for BlobDataProvider {
     ) -> Result<DataResponse<BufferMarker>, DataError> {
         let mut metadata = DataResponseMetadata::default();
         metadata.buffer_format = Some(BufferFormat::Postcard1);
+        metadata.checksum = self.checksum();
         Ok(DataResponse {
             metadata,
             payload: Some(DataPayload::from_yoked_buffer(
@@ -22,6 +22,10 @@ pub(crate) enum BlobSchema<'data> {
     V002(BlobSchemaV2<'data, Index16>),
     #[serde(borrow)]
     V002Bigger(BlobSchemaV2<'data, Index32>),
+    #[serde(borrow)]
+    V003(BlobSchemaV3<'data, Index16>),
+    #[serde(borrow)]
+    V003Bigger(BlobSchemaV3<'data, Index32>),
 }
 
 impl<'data> BlobSchema<'data> {
@@ -39,6 +43,8 @@ impl<'data> BlobSchema<'data> {
             BlobSchema::V001(s) => s.load(key, req),
             BlobSchema::V002(s) => s.load(key, req),
             BlobSchema::V002Bigger(s) => s.load(key, req),
+            BlobSchema::V003(s) => s.data.load(key, req),
+            BlobSchema::V003Bigger(s) => s.data.load(key, req),
         }
     }
 
@@ -48,6 +54,24 @@ impl<'data> BlobSchema<'data> {
             BlobSchema::V001(s) => s.list_locales(key),
             BlobSchema::V002(s) => s.list_locales(key),
             BlobSchema::V002Bigger(s) => s.list_locales(key),
+            BlobSchema::V003(s) => s.data.list_locales(key),
+            BlobSchema::V003Bigger(s) => s.data.list_locales(key),
+        }
+    }
+
+    pub fn data_version(&self) -> Option<&'data str> {
+        match self {
+            BlobSchema::V003(s) => Some(s.data_version),
+            BlobSchema::V003Bigger(s) => Some(s.data_version),
+            _ => None,
+        }
+    }
+
+    pub fn checksum(&self) -> Option<u64> {
+        match self {
+            BlobSchema::V003(s) => Some(s.checksum),
+            BlobSchema::V003Bigger(s) => Some(s.checksum),
+            _ => None,
         }
     }
 
@@ -57,6 +81,8 @@ impl<'data> BlobSchema<'data> {
             BlobSchema::V001(s) => s.check_invariants(),
             BlobSchema::V002(s) => s.check_invariants(),
             BlobSchema::V002Bigger(s) => s.check_invariants(),
+            BlobSchema::V003(s) => s.data.check_invariants(),
+            BlobSchema::V003Bigger(s) => s.data.check_invariants(),
         }
     }
 }
@@ -157,6 +183,10 @@ pub(crate) struct BlobSchemaV2<'data, LocaleVecFormat: VarZeroVecFormat> {
     #[serde(borrow)]
     pub keys: &'data ZeroSlice<DataKeyHash>,
     /// Map from locale to buffer index.
+    ///
+    /// The trie of a key is only read when that key is loaded, in place and without
+    /// allocating. Constructing the provider only validates the offsets of this slice.
+    ///
     /// Weak invariant: the `usize` values are valid indices into `self.buffers`
     /// Weak invariant: there is at least one value for every integer in 0..self.buffers.len()
     /// Weak invariant: keys and locales are the same length
@@ -249,6 +279,25 @@ impl<'data, LocaleVecFormat: VarZeroVecFormat> BlobSchemaV2<'data, LocaleVecForm
     }
 }
 
+/// Version 3 of the ICU4X data blob schema.
+///
+/// This is version 2 together with the version of the data and a checksum of it. The exporter
+/// only produces it if a data version is set.
+#[derive(Clone, Copy, Debug, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+#[serde(bound = "")] // Override the autogenerated `LocaleVecFormat: Serialize/Deserialize` bound
+pub(crate) struct BlobSchemaV3<'data, LocaleVecFormat: VarZeroVecFormat> {
+    /// The version of the data, as set by datagen.
+    #[serde(borrow)]
+    pub data_version: &'data str,
+    /// A hash of the postcard serialization of `data`.
+    pub checksum: u64,
+    /// The data itself.
+    #[serde(borrow)]
+    pub data: BlobSchemaV2<'data, LocaleVecFormat>,
+}
+
 /// This type lets us use a u32-index-format VarZeroVec with the ZeroMap2dBorrowed.
 ///
 /// Eventually we will have a FormatSelector type that lets us do `ZeroMap<FormatSelector<K, Index32>, V>`
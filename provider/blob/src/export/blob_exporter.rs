@@ -9,12 +9,16 @@ use crate::blob_schema::*;
 use icu_provider::datagen::*;
 use icu_provider::prelude::*;
 use std::collections::{BTreeMap, BTreeSet, HashMap};
+#[allow(deprecated)]
+// We're using SipHash, which is deprecated, but we want a stable hasher
+// (we're fine with it not being cryptographically secure since we're just using it to detect changes)
+use std::hash::{Hasher, SipHasher};
 use std::sync::Mutex;
 use writeable::Writeable;
 use zerotrie::ZeroTrieSimpleAscii;
 use zerovec::ule::VarULE;
-use zerovec::vecs::Index32;
 use zerovec::vecs::VarZeroVecOwned;
+use zerovec::vecs::{Index32, VarZeroVecFormat};
 use zerovec::VarZeroVec;
 use zerovec::ZeroMap2d;
 use zerovec::ZeroVec;
@@ -24,10 +28,14 @@ use postcard::ser_flavors::{AllocVec, Flavor};
 enum VersionConfig {
     V001,
     V002,
+    V003,
 }
 
 /// A data exporter that writes data to a single-file blob.
 /// See the module-level docs for an example.
+///
+/// Payloads are stored once for each distinct serialization, across all keys and locales, so
+/// keys that share data for a locale do not increase the size of the blob.
 pub struct BlobExporter<'w> {
     /// Map of key hash -> locale byte string -> blob ID
     #[allow(clippy::type_complexity)]
@@ -38,6 +46,7 @@ pub struct BlobExporter<'w> {
     unique_resources: Mutex<HashMap<Vec<u8>, usize>>,
     sink: Box<dyn std::io::Write + Sync + 'w>,
     version: VersionConfig,
+    data_version: Option<String>,
 }
 
 impl core::fmt::Debug for BlobExporter<'_> {
@@ -46,6 +55,7 @@ impl core::fmt::Debug for BlobExporter<'_> {
             .field("resources", &self.resources)
             .field("unique_resources", &self.unique_resources)
             .field("all_keys", &self.all_keys)
+            .field("data_version", &self.data_version)
             .field("sink", &"<sink>")
             .finish()
     }
@@ -63,6 +73,7 @@ impl<'w> BlobExporter<'w> {
             all_keys: Default::default(),
             sink,
             version: VersionConfig::V001,
+            data_version: None,
         }
     }
 
@@ -78,11 +89,41 @@ impl<'w> BlobExporter<'w> {
             all_keys: Default::default(),
             sink,
             version: VersionConfig::V002,
+            data_version: None,
+        }
+    }
+
+    /// Creates a version 3 [`BlobExporter`] that writes to the given I/O stream.
+    ///
+    /// Version 3 has the same layout as version 2, and additionally records the version passed
+    /// to [`DataExporter::set_data_version()`] together with a checksum of the data. Blobs of this
+    /// version cannot be read by ICU4X 1.5 and below, so only use it if all readers of the blob
+    /// are newer. If no data version is set, an empty version is recorded.
+    pub fn new_v3_with_sink(sink: Box<dyn std::io::Write + Sync + 'w>) -> Self {
+        Self {
+            resources: Default::default(),
+            unique_resources: Default::default(),
+            all_keys: Default::default(),
+            sink,
+            version: VersionConfig::V003,
+            data_version: None,
         }
     }
 }
 
 impl DataExporter for BlobExporter<'_> {
+    fn set_data_version(&mut self, version: &str) -> Result<(), DataError> {
+        match self.version {
+            VersionConfig::V001 | VersionConfig::V002 => {
+                log::warn!(
+                    "Only version 3 blobs can record the data version {version:?}, ignoring it"
+                );
+            }
+            VersionConfig::V003 => self.data_version = Some(version.into()),
+        }
+        Ok(())
+    }
+
     fn put_payload(
         &self,
         key: DataKey,
@@ -121,7 +162,7 @@ impl DataExporter for BlobExporter<'_> {
     fn close(&mut self) -> Result<(), DataError> {
         match self.version {
             VersionConfig::V001 => self.close_v1(),
-            VersionConfig::V002 => self.close_v2(),
+            VersionConfig::V002 | VersionConfig::V003 => self.close_v2(),
         }
     }
 }
@@ -235,11 +276,18 @@ impl BlobExporter<'_> {
             if let Ok(locales_vzv) =
                 VarZeroVecOwned::<[u8]>::try_from_elements(locales_vec.as_slice())
             {
-                let blob = BlobSchema::V002(BlobSchemaV2 {
+                let data = BlobSchemaV2 {
                     keys: &keys,
                     locales: &locales_vzv,
                     buffers: &vzv,
-                });
+                };
+                let blob = match self.version {
+                    VersionConfig::V003 => BlobSchema::V003(with_checksum(
+                        self.data_version.as_deref().unwrap_or_default(),
+                        data,
+                    )?),
+                    _ => BlobSchema::V002(data),
+                };
                 log::info!("Serializing blob to output stream...");
 
                 let output = postcard::to_allocvec(&blob)?;
@@ -249,11 +297,18 @@ impl BlobExporter<'_> {
                 let locales_vzv =
                     VarZeroVecOwned::<[u8], Index32>::try_from_elements(locales_vec.as_slice())
                         .expect("Locales vector does not fit in Index32 buffer!");
-                let blob = BlobSchema::V002Bigger(BlobSchemaV2 {
+                let data = BlobSchemaV2 {
                     keys: &keys,
                     locales: &locales_vzv,
                     buffers: &vzv,
-                });
+                };
+                let blob = match self.version {
+                    VersionConfig::V003 => BlobSchema::V003Bigger(with_checksum(
+                        self.data_version.as_deref().unwrap_or_default(),
+                        data,
+                    )?),
+                    _ => BlobSchema::V002Bigger(data),
+                };
                 log::info!("Serializing blob to output stream...");
 
                 let output = postcard::to_allocvec(&blob)?;
@@ -264,3 +319,18 @@ impl BlobExporter<'_> {
         Ok(())
     }
 }
+
+/// Adds the data version and the checksum of the serialized data.
+fn with_checksum<'a, LocaleVecFormat: VarZeroVecFormat>(
+    data_version: &'a str,
+    data: BlobSchemaV2<'a, LocaleVecFormat>,
+) -> Result<BlobSchemaV3<'a, LocaleVecFormat>, DataError> {
+    #[allow(deprecated)]
+    let mut hasher = SipHasher::new();
+    hasher.write(&postcard::to_allocvec(&data)?);
+    Ok(BlobSchemaV3 {
+        data_version,
+        checksum: hasher.finish(),
+        data,
+    })
+}
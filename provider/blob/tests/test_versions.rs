@@ -4,7 +4,8 @@
 
 use icu_datagen::prelude::*;
 use icu_locid::LanguageIdentifier;
-use icu_provider::datagen::IterableDataProvider;
+use icu_provider::datagen::{DataExporter, IterableDataProvider};
+use icu_provider::dynutil::UpcastDataPayload;
 use icu_provider::hello_world::*;
 use icu_provider::prelude::*;
 use icu_provider_blob::export::*;
@@ -70,6 +71,57 @@ fn test_v2() {
     check_hello_world(blob_provider.as_deserializing());
 }
 
+// Version 2 blobs are unchanged by a data version, which is only recorded in version 3 blobs.
+#[test]
+fn test_v2_with_data_version() {
+    let mut blob: Vec<u8> = Vec::new();
+    let exporter = BlobExporter::new_v2_with_sink(Box::new(&mut blob));
+    DatagenDriver::new()
+        .with_keys([HelloWorldV1Marker::KEY])
+        .with_locales_and_fallback([LocaleFamily::FULL], Default::default())
+        .with_data_version("1.0.0")
+        .export(&HelloWorldProvider, exporter)
+        .unwrap();
+    assert_eq!(BLOB_V2, blob.as_slice());
+
+    let blob_provider = BlobDataProvider::try_new_from_blob(blob.into_boxed_slice()).unwrap();
+    assert_eq!(blob_provider.data_version(), None);
+    assert_eq!(blob_provider.checksum(), None);
+}
+
+#[test]
+fn test_v3() {
+    let mut blob: Vec<u8> = Vec::new();
+    let exporter = BlobExporter::new_v3_with_sink(Box::new(&mut blob));
+    DatagenDriver::new()
+        .with_keys([HelloWorldV1Marker::KEY])
+        .with_locales_and_fallback([LocaleFamily::FULL], Default::default())
+        .with_data_version("1.0.0")
+        .export(&HelloWorldProvider, exporter)
+        .unwrap();
+
+    let blob_provider = BlobDataProvider::try_new_from_blob(blob.into_boxed_slice()).unwrap();
+    assert_eq!(blob_provider.data_version(), Some("1.0.0"));
+    assert!(blob_provider.checksum().is_some());
+    check_hello_world(blob_provider.as_deserializing());
+}
+
+// Loads the same key from several threads at once on a fresh provider, which must not race.
+#[test]
+fn test_concurrent_first_access() {
+    fn assert_sync<T: Sync>(_: &T) {}
+
+    for blob in [BLOB_V1, BLOB_V2] {
+        let blob_provider = BlobDataProvider::try_new_from_static_blob(blob).unwrap();
+        assert_sync(&blob_provider);
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| check_hello_world(blob_provider.as_deserializing()));
+            }
+        });
+    }
+}
+
 // This tests that the V2Bigger format works by attempting to export something with 26^4 = 456976 data entries
 #[test]
 fn test_v2_bigger() {
@@ -163,3 +215,104 @@ impl IterableDataProvider<HelloWorldV1Marker> for ManyLocalesProvider {
 }
 
 icu_provider::make_exportable_provider!(ManyLocalesProvider, [HelloWorldV1Marker,]);
+
+struct OtherHelloWorldV1Marker;
+
+impl DataMarker for OtherHelloWorldV1Marker {
+    type Yokeable = HelloWorldV1<'static>;
+}
+
+impl KeyedDataMarker for OtherHelloWorldV1Marker {
+    const KEY: DataKey = icu_provider::data_key!("hello/other@1");
+}
+
+/// Exports the hello world data, and optionally the same messages with `suffix` under a
+/// second key.
+fn export_two_keys(mut exporter: BlobExporter, other_suffix: Option<&str>) -> usize {
+    let mut other_bytes = 0;
+    for locale in HelloWorldProvider.supported_locales().unwrap() {
+        let payload: DataPayload<HelloWorldV1Marker> = HelloWorldProvider
+            .load(DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        exporter
+            .put_payload(
+                HelloWorldV1Marker::KEY,
+                &locale,
+                &UpcastDataPayload::upcast(payload.clone()),
+            )
+            .unwrap();
+        if let Some(suffix) = other_suffix {
+            let payload: DataPayload<HelloWorldV1Marker> = DataPayload::from_owned(HelloWorldV1 {
+                message: format!("{}{suffix}", payload.get().message).into(),
+            });
+            other_bytes += postcard::to_allocvec(payload.get()).unwrap().len();
+            exporter
+                .put_payload(
+                    OtherHelloWorldV1Marker::KEY,
+                    &locale,
+                    &UpcastDataPayload::upcast(payload),
+                )
+                .unwrap();
+        }
+    }
+    exporter.flush(HelloWorldV1Marker::KEY).unwrap();
+    if other_suffix.is_some() {
+        exporter.flush(OtherHelloWorldV1Marker::KEY).unwrap();
+    }
+    exporter.close().unwrap();
+    other_bytes
+}
+
+// Identical payloads are stored once, even if they belong to different keys.
+#[test]
+fn test_payloads_shared_across_keys() {
+    #[allow(clippy::type_complexity)]
+    let exporters: [(&str, fn(&mut Vec<u8>) -> BlobExporter); 3] = [
+        ("v1", |blob| BlobExporter::new_with_sink(Box::new(blob))),
+        ("v2", |blob| BlobExporter::new_v2_with_sink(Box::new(blob))),
+        ("v3", |blob| {
+            let mut exporter = BlobExporter::new_v3_with_sink(Box::new(blob));
+            exporter.set_data_version("1.0.0").unwrap();
+            exporter
+        }),
+    ];
+    for (version, new_exporter) in exporters {
+        let mut shared = Vec::new();
+        export_two_keys(new_exporter(&mut shared), Some(""));
+        let mut distinct = Vec::new();
+        let distinct_bytes = export_two_keys(new_exporter(&mut distinct), Some("!"));
+        assert!(
+            distinct.len() - shared.len() >= distinct_bytes,
+            "{version}: {} bytes shared, {} bytes distinct",
+            shared.len(),
+            distinct.len()
+        );
+
+        let blob_provider = BlobDataProvider::try_new_from_blob(shared.into()).unwrap();
+        for locale in HelloWorldProvider.supported_locales().unwrap() {
+            let req = DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            };
+            let hello: DataPayload<HelloWorldV1Marker> = blob_provider
+                .as_deserializing()
+                .load(req)
+                .unwrap()
+                .take_payload()
+                .unwrap();
+            let other: DataPayload<OtherHelloWorldV1Marker> = blob_provider
+                .as_deserializing()
+                .load(req)
+                .unwrap()
+                .take_payload()
+                .unwrap();
+            assert_eq!(hello.get(), other.get(), "{version}: {locale}");
+        }
+        check_hello_world(blob_provider.as_deserializing());
+    }
+}
@@ -494,11 +494,83 @@ mod test {
 
         let err = any_payload.downcast::<WrongMarker>().unwrap_err();
         assert_eq!(
-            "ICU4X data error: Mismatched types: tried to downcast with icu_provider::any::test::test_debug::WrongMarker, but actual type is different: icu_provider::hello_world::HelloWorldV1Marker",
+            "ICU4X data error: Mismatched types: tried to downcast with icu_provider::any::test::test_debug::WrongMarker, but actual type is icu_provider::hello_world::HelloWorldV1Marker",
             format!("{err}")
         );
     }
 
+    #[test]
+    fn test_mismatched_types_message() {
+        // A hand-rolled provider that serves the wrong type for the hello world key
+        struct WrongProvider;
+        impl AnyProvider for WrongProvider {
+            fn load_any(&self, _: DataKey, _: DataRequest) -> Result<AnyResponse, DataError> {
+                Ok(AnyResponse {
+                    metadata: Default::default(),
+                    payload: Some(AnyPayload::from_static_ref(&0u8)),
+                })
+            }
+        }
+
+        let err = DataProvider::<HelloWorldV1Marker>::load(
+            &WrongProvider.as_downcasting(),
+            Default::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.kind,
+            DataErrorKind::MismatchedType(core::any::type_name::<HelloWorldV1Marker>())
+        );
+        assert_eq!(err.key, Some(HelloWorldV1Marker::KEY));
+        assert_eq!(
+            format!("{err}"),
+            "ICU4X data error: Mismatched types: tried to downcast with icu_provider::hello_world::HelloWorldV1Marker, but actual type is u8 (key: core/helloworld@1)"
+        );
+
+        // A marker with the same key and data struct, but a different type
+        struct WrongMarker;
+        impl DataMarker for WrongMarker {
+            type Yokeable = HelloWorldV1<'static>;
+        }
+        impl KeyedDataMarker for WrongMarker {
+            const KEY: DataKey = HelloWorldV1Marker::KEY;
+        }
+
+        // Payloads wrapped from a DataPayload report the marker type
+        let err = DataProvider::<WrongMarker>::load(
+            &HelloWorldProvider.as_any_provider().as_downcasting(),
+            DataRequest {
+                locale: &icu_locid::langid!("en").into(),
+                metadata: Default::default(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.kind,
+            DataErrorKind::MismatchedType(core::any::type_name::<WrongMarker>())
+        );
+        assert_eq!(
+            err.str_context,
+            Some(core::any::type_name::<HelloWorldV1Marker>())
+        );
+
+        // DataPayload::dynamic_cast reports the same types
+        let payload = DataPayload::<HelloWorldV1Marker>::from_owned(Default::default());
+        let err = payload.clone().dynamic_cast::<WrongMarker>().unwrap_err();
+        assert_eq!(
+            err.kind,
+            DataErrorKind::MismatchedType(core::any::type_name::<WrongMarker>())
+        );
+        assert_eq!(
+            err.str_context,
+            Some(core::any::type_name::<HelloWorldV1Marker>())
+        );
+        assert_eq!(
+            payload.clone().dynamic_cast::<HelloWorldV1Marker>(),
+            Ok(payload)
+        );
+    }
+
     #[test]
     fn test_non_owned_any_marker() {
         // This test demonstrates a code path that can trigger the InvalidState error kind.
@@ -0,0 +1,120 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Replacing baked data at runtime.
+//!
+//! ✨ *Enabled with the `baked_override` Cargo feature.*
+//!
+//! Baked data is compiled into the binary, so fixing a single bad pattern normally requires
+//! shipping a new build. With this feature, the `DataProvider` implementations of baked data,
+//! including the compiled data used by the constructors without a provider argument, first
+//! consult the [`BufferProvider`] registered with [`set_override_provider`], and only use the
+//! baked data if it has no data for the key or the locale.
+//!
+//! Baked data needs to deserialize the data of the override provider, so with this feature, baked
+//! data only compiles if its data structs implement `Deserialize`, which for ICU4X components
+//! requires their `serde` Cargo feature. Otherwise compilation fails because the data marker
+//! does not implement [`OverridableDataMarker`]. The `baked_override` Cargo feature of the `icu`
+//! crate enables this feature together with `serde` on all components. The override provider
+//! also needs the deserialization Cargo feature for its buffer format, such as
+//! `deserialize_postcard_1` for a blob. Without the `baked_override` feature, baked data does
+//! not check for an override at all.
+//!
+//! This applies in the same way to data baked with `icu_datagen` outside of the components: the
+//! generated code consults the override provider as well, so the crate that includes it needs
+//! the `serde` Cargo features of the components whose data it contains.
+//!
+//! This lives in `icu_provider` rather than in a crate for baked data because the generated
+//! code of every baked data crate already depends on `icu_provider`, and the override provider
+//! has to be a single process-wide registration that all of them consult.
+//!
+//! The override provider is queried with the requested locale, before the baked data performs
+//! any fallback, so it should contain data for each locale it overrides.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use icu_provider::baked;
+//! use icu_provider_blob::BlobDataProvider;
+//!
+//! let blob = std::fs::read("hotfix.postcard").expect("file exists");
+//! let provider = BlobDataProvider::try_new_from_blob(blob.into_boxed_slice())
+//!     .expect("valid blob");
+//!
+//! baked::set_override_provider(Box::leak(Box::new(provider)))
+//!     .expect("no override provider registered yet");
+//! ```
+
+use crate::prelude::*;
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use yoke::trait_hack::YokeTraitHack;
+use yoke::Yokeable;
+
+static OVERRIDE_PROVIDER: OnceCell<&'static (dyn BufferProvider + Sync)> = OnceCell::new();
+
+/// Registers a provider whose data takes precedence over baked data.
+///
+/// The provider can only be registered once, usually at startup, and only affects data loaded
+/// afterwards. Returns an error if a provider has already been registered.
+pub fn set_override_provider(
+    provider: &'static (dyn BufferProvider + Sync),
+) -> Result<(), DataError> {
+    OVERRIDE_PROVIDER
+        .set(provider)
+        .map_err(|_| DataError::custom("An override provider is already registered"))
+}
+
+/// A data marker whose data can be loaded from the override provider.
+///
+/// This is implemented for all markers whose data structs implement `Deserialize`. With the
+/// `baked_override` Cargo feature, baked data fails to compile for markers that do not
+/// implement it; for ICU4X components, enable their `serde` Cargo feature.
+pub trait OverridableDataMarker: KeyedDataMarker + Sized {
+    /// Loads data from the override provider, returning `None` if no provider is registered or
+    /// it has no data for the key or the locale.
+    ///
+    /// Not public API.
+    #[doc(hidden)] // macro
+    fn load_override(req: DataRequest) -> Option<Result<DataResponse<Self>, DataError>>;
+}
+
+impl<M> OverridableDataMarker for M
+where
+    M: KeyedDataMarker,
+    // Actual bound:
+    //     for<'de> <M::Yokeable as Yokeable<'de>>::Output: Deserialize<'de>,
+    // Necessary workaround bound (see `yoke::trait_hack` docs):
+    for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: Deserialize<'de>,
+{
+    fn load_override(req: DataRequest) -> Option<Result<DataResponse<M>, DataError>> {
+        let provider = OVERRIDE_PROVIDER.get()?;
+        let mut metadata = req.metadata;
+        metadata.silent = true;
+        match provider.as_deserializing().load(DataRequest {
+            locale: req.locale,
+            metadata,
+        }) {
+            Err(DataError {
+                kind: DataErrorKind::MissingDataKey | DataErrorKind::MissingLocale,
+                ..
+            }) => None,
+            result => Some(result),
+        }
+    }
+}
+
+/// Consults the override provider in the `load` implementations of baked data.
+///
+/// Not public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __baked_override {
+    ($marker:ty, $req:expr) => {
+        if let Some(result) = <$marker as $crate::baked::OverridableDataMarker>::load_override($req)
+        {
+            return result;
+        }
+    };
+}
@@ -371,3 +371,102 @@ macro_rules! gen_any_buffer_data_constructors {
         }
     };
 }
+
+/// Implemented by components for every provider type that implements [`DataProvider`] for all
+/// keys that the constructors of the component load.
+///
+/// This is used by [`assert_keys_present!`](crate::assert_keys_present) and has no methods.
+///
+/// [`DataProvider`]: crate::DataProvider
+pub trait RequiredKeys<P: ?Sized> {}
+
+/// Asserts at compile time that a provider type implements [`DataProvider`] for every key
+/// that the listed components require.
+///
+/// A missing key otherwise results in an error deep inside the generic bounds of a
+/// constructor. This macro fails with an error naming the provider and the missing marker,
+/// such as ``the trait bound `MyProvider: DataProvider<HelloWorldV1Marker>` is not
+/// satisfied``.
+///
+/// The components need to implement [`RequiredKeys`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::hello_world::*;
+///
+/// icu_provider::assert_keys_present!(HelloWorldProvider, [HelloWorldFormatter]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// use icu_provider::hello_world::*;
+///
+/// struct EmptyProvider;
+///
+/// icu_provider::assert_keys_present!(EmptyProvider, [HelloWorldFormatter]);
+/// ```
+///
+/// A provider that implements only some of the keys fails as well. Here, the error is
+/// ``the trait bound `PartialProvider: DataProvider<UnitListV1Marker>` is not satisfied``:
+///
+/// ```compile_fail,E0277
+/// # use icu_provider::prelude::*;
+/// # macro_rules! impl_missing_data {
+/// #     ($provider:ty, [$($marker:ty),+]) => {
+/// #         $(impl DataProvider<$marker> for $provider {
+/// #             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+/// #                 Err(DataErrorKind::MissingDataKey.with_req(<$marker>::KEY, req))
+/// #             }
+/// #         })+
+/// #     };
+/// # }
+/// use icu::list::provider::*;
+/// use icu::list::ListFormatter;
+///
+/// struct PartialProvider;
+/// impl_missing_data!(PartialProvider, [AndListV1Marker, OrListV1Marker]);
+///
+/// icu_provider::assert_keys_present!(PartialProvider, [ListFormatter]);
+/// ```
+///
+/// Adding the missing key makes it compile:
+///
+/// ```
+/// # use icu_provider::prelude::*;
+/// # macro_rules! impl_missing_data {
+/// #     ($provider:ty, [$($marker:ty),+]) => {
+/// #         $(impl DataProvider<$marker> for $provider {
+/// #             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+/// #                 Err(DataErrorKind::MissingDataKey.with_req(<$marker>::KEY, req))
+/// #             }
+/// #         })+
+/// #     };
+/// # }
+/// use icu::list::provider::*;
+/// use icu::list::ListFormatter;
+///
+/// struct PartialProvider;
+/// impl_missing_data!(PartialProvider, [AndListV1Marker, OrListV1Marker]);
+/// impl_missing_data!(PartialProvider, [UnitListV1Marker]);
+///
+/// icu_provider::assert_keys_present!(PartialProvider, [ListFormatter]);
+/// ```
+///
+/// [`DataProvider`]: crate::DataProvider
+#[macro_export]
+macro_rules! assert_keys_present {
+    ($provider:ty, [$($component:ty),+ $(,)?]) => {
+        const _: () = {
+            fn assert_keys_present<C, P>()
+            where
+                C: $crate::constructors::RequiredKeys<P> + ?Sized,
+                P: ?Sized,
+            {
+            }
+            #[allow(dead_code)]
+            fn check() {
+                $(assert_keys_present::<$component, $provider>();)+
+            }
+        };
+    };
+}
@@ -40,6 +40,15 @@ pub trait DataExporter: Sync {
         payload: &DataPayload<ExportMarker>,
     ) -> Result<(), DataError>;
 
+    /// Sets the version of the data that is being exported, such as the CLDR version it was
+    /// generated from.
+    ///
+    /// This function is called before any payloads are put. Exporters that support it record the
+    /// version together with a checksum of the exported data; the default implementation ignores it.
+    fn set_data_version(&mut self, _version: &str) -> Result<(), DataError> {
+        Ok(())
+    }
+
     /// Function called for singleton keys.
     /// Takes non-mut self as it can be called concurrently.
     fn flush_singleton(
@@ -97,6 +106,10 @@ impl DataExporter for Box<dyn DataExporter> {
         (**self).put_payload(key, locale, payload)
     }
 
+    fn set_data_version(&mut self, version: &str) -> Result<(), DataError> {
+        (**self).set_data_version(version)
+    }
+
     fn flush_singleton(
         &self,
         key: DataKey,
@@ -212,6 +225,12 @@ impl DataExporter for MultiExporter {
             .try_for_each(|e| e.put_payload(key, locale, payload))
     }
 
+    fn set_data_version(&mut self, version: &str) -> Result<(), DataError> {
+        self.0
+            .iter_mut()
+            .try_for_each(|e| e.set_data_version(version))
+    }
+
     fn flush_singleton(
         &self,
         key: DataKey,
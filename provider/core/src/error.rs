@@ -36,7 +36,7 @@ pub enum DataErrorKind {
     FilteredResource,
 
     /// The generic type parameter does not match the TypeId. The expected type name is stored
-    /// as context when this error is returned.
+    /// in the variant, and the actual type name, if known, is stored as context.
     #[displaydoc("Mismatched types: tried to downcast with {0}, but actual type is different")]
     MismatchedType(&'static str),
 
@@ -114,6 +114,19 @@ pub struct DataError {
 impl fmt::Display for DataError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ICU4X data error")?;
+        if let (DataErrorKind::MismatchedType(expected), Some(actual)) =
+            (self.kind, self.str_context)
+        {
+            // Name both types in one sentence, before the key
+            write!(
+                f,
+                ": Mismatched types: tried to downcast with {expected}, but actual type is {actual}"
+            )?;
+            if let Some(key) = self.key {
+                write!(f, " (key: {key})")?;
+            }
+            return Ok(());
+        }
         if self.kind != DataErrorKind::Custom {
             write!(f, ": {}", self.kind)?;
         }
@@ -251,6 +251,11 @@ pub struct HelloWorldFormatter {
     data: DataPayload<HelloWorldV1Marker>,
 }
 
+impl<P> crate::constructors::RequiredKeys<P> for HelloWorldFormatter where
+    P: DataProvider<HelloWorldV1Marker> + ?Sized
+{
+}
+
 /// A formatted hello world message. Implements [`Writeable`].
 ///
 /// For an example, see [`HelloWorldFormatter`].
@@ -142,6 +142,8 @@ mod request;
 mod response;
 
 pub mod any;
+#[cfg(feature = "baked_override")]
+pub mod baked;
 pub mod buf;
 pub mod constructors;
 #[cfg(feature = "datagen")]
@@ -255,6 +257,16 @@ pub mod prelude {
     pub use zerofrom;
 }
 
+/// Does nothing without the `baked_override` Cargo feature.
+///
+/// Not public API.
+#[cfg(not(feature = "baked_override"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __baked_override {
+    ($marker:ty, $req:expr) => {};
+}
+
 // Additional crate re-exports for compatibility
 #[doc(hidden)]
 pub use fallback::LocaleFallbackPriority as FallbackPriority;
@@ -246,6 +246,54 @@ impl FromStr for DataLocale {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for DataLocale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.write_to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DataLocale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct DataLocaleVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DataLocaleVisitor {
+            type Value = DataLocale;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a canonical DataLocale")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let locale = s
+                    .parse::<Locale>()
+                    .map_err(|e| E::custom(format_args!("invalid DataLocale {s:?}: {e}")))?;
+                let data_locale = DataLocale::from(locale);
+                // Also rejects extensions that a DataLocale cannot represent
+                if data_locale.strict_cmp(s.as_bytes()) != Ordering::Equal {
+                    return Err(E::custom(format_args!(
+                        "DataLocale {s:?} is not in canonical form, expected {:?}",
+                        data_locale.write_to_string()
+                    )));
+                }
+                Ok(data_locale)
+            }
+        }
+
+        deserializer.deserialize_string(DataLocaleVisitor)
+    }
+}
+
 impl DataLocale {
     /// Compare this [`DataLocale`] with BCP-47 bytes.
     ///
@@ -1092,3 +1140,36 @@ fn test_data_locale_from_string() {
         writeable::assert_writeable_eq!(data_locale, cas.input);
     }
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_data_locale_serde() {
+    for input in ["und", "en-ZA", "sr-Latn-RS-u-ca-buddhist-nu-latn"] {
+        let locale = input.parse::<DataLocale>().unwrap();
+        let json = serde_json::to_string(&locale).unwrap();
+        assert_eq!(json, format!("{input:?}"));
+        assert_eq!(serde_json::from_str::<DataLocale>(&json).unwrap(), locale);
+    }
+
+    for (input, expected) in [
+        (
+            r#""EN_us""#,
+            r#"DataLocale "EN_us" is not in canonical form, expected "en-US" at line 1 column 7"#,
+        ),
+        (
+            r#""en-t-de""#,
+            r#"DataLocale "en-t-de" is not in canonical form, expected "en" at line 1 column 9"#,
+        ),
+        (
+            r#""en-2Xs""#,
+            r#"invalid DataLocale "en-2Xs": Invalid subtag at line 1 column 8"#,
+        ),
+    ] {
+        assert_eq!(
+            serde_json::from_str::<DataLocale>(input)
+                .unwrap_err()
+                .to_string(),
+            expected
+        );
+    }
+}
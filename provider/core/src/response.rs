@@ -28,6 +28,15 @@ pub struct DataResponseMetadata {
     pub locale: Option<DataLocale>,
     /// The format of the buffer for buffer-backed data, if known (for example, JSON).
     pub buffer_format: Option<crate::buf::BufferFormat>,
+    /// The locale originally requested by the caller, if an adapter rewrote the request
+    /// to a different locale before loading.
+    pub requested_locale: Option<DataLocale>,
+    /// A checksum of the data the response was loaded from, if the provider records one.
+    ///
+    /// Data exporters compute the checksum when the data is generated with a data version
+    /// (see `DataExporter::set_data_version`), so that a client can detect that two responses
+    /// come from different builds of the data.
+    pub checksum: Option<u64>,
 }
 
 /// A container for data payloads returned from a data provider.
@@ -478,6 +487,10 @@ where
     /// - [`DataPayload::try_map_project()`] to bubble up an error
     /// - [`DataPayload::try_map_project_cloned()`] to do both of the above
     ///
+    /// The projected payload borrows from the same cart as `self`, so it keeps the whole buffer
+    /// alive. To extract a small part of a large payload for a long-lived cache, use
+    /// [`DataPayload::map_project_cloned_to_owned()`] instead.
+    ///
     /// # Examples
     ///
     /// Map from `HelloWorldV1` to a `Cow<str>` containing just the message:
@@ -694,6 +707,81 @@ where
         })))
     }
 
+    /// Version of [`DataPayload::map_project_cloned()`] whose result does not borrow from the
+    /// cart of `self`.
+    ///
+    /// The function has to return fully owned data, for example by converting borrowed
+    /// [`Cow`](alloc::borrow::Cow)s into owned ones. The resulting payload does not keep the
+    /// buffer that `self` was loaded from alive, so it can be used to keep a small part of a
+    /// large payload in a long-lived cache, such as the entry for a single region of a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// struct HelloWorldV1MessageMarker;
+    /// impl DataMarker for HelloWorldV1MessageMarker {
+    ///     type Yokeable = Cow<'static, str>;
+    /// }
+    ///
+    /// let p1: DataPayload<HelloWorldV1Marker> = DataPayload::from_owned(HelloWorldV1 {
+    ///     message: Cow::Borrowed("Hello World"),
+    /// });
+    ///
+    /// let p2: DataPayload<HelloWorldV1MessageMarker> =
+    ///     p1.map_project_cloned_to_owned(|obj| Cow::Owned(obj.message.to_string()));
+    ///
+    /// // p1, and the buffer that it borrows from, can now be dropped.
+    /// drop(p1);
+    /// assert_eq!("Hello World", p2.get());
+    /// ```
+    pub fn map_project_cloned_to_owned<M2, F>(&self, f: F) -> DataPayload<M2>
+    where
+        M2: DataMarker,
+        F: for<'a> FnOnce(&<M::Yokeable as Yokeable<'a>>::Output) -> M2::Yokeable,
+    {
+        DataPayload::from_owned(f(self.get()))
+    }
+
+    /// Converts this [`DataPayload`] into one that does not borrow from a cart, using `f` to
+    /// create an owned copy of the data.
+    ///
+    /// `f` is only called if the data is borrowed from a buffer, such as a payload loaded from
+    /// a blob; payloads created from owned or static data are returned as is.
+    ///
+    /// This is useful for long-lived caches, which should not keep the buffer of a data
+    /// provider alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let payload = DataPayload::<HelloWorldV1Marker>::from_static_str("Hello World");
+    ///
+    /// let payload = payload.into_owned(|obj| HelloWorldV1 {
+    ///     message: Cow::Owned(obj.message.to_string()),
+    /// });
+    ///
+    /// assert_eq!("Hello World", payload.get().message);
+    /// ```
+    pub fn into_owned<F>(self, f: F) -> Self
+    where
+        F: for<'a> FnOnce(&<M::Yokeable as Yokeable<'a>>::Output) -> M::Yokeable,
+    {
+        match &self.0 {
+            DataPayloadInner::Yoke(yoke) if !yoke.backing_cart().is_none() => {
+                self.map_project_cloned_to_owned(f)
+            }
+            _ => self,
+        }
+    }
+
     /// Convert between two [`DataMarker`] types that are compatible with each other
     /// with compile-time type checking.
     ///
@@ -729,6 +817,53 @@ where
         })
     }
 
+    /// Convert a [`DataPayload`] to one of the same type with runtime type checking.
+    ///
+    /// Primarily useful to convert from a generic to a concrete marker type.
+    ///
+    /// If the `M2` type argument does not match the true marker type, a `DataError` is returned
+    /// that names both types.
+    ///
+    /// For compile-time static casting, use [`DataPayload::cast()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::prelude::*;
+    ///
+    /// fn message<M: DataMarker>(payload: DataPayload<M>) -> Option<String> {
+    ///     let payload = payload.dynamic_cast::<HelloWorldV1Marker>().ok()?;
+    ///     Some(payload.get().message.to_string())
+    /// }
+    ///
+    /// let payload = DataPayload::<HelloWorldV1Marker>::from_owned(Default::default());
+    /// assert_eq!(message(payload.clone()).as_deref(), Some("(und) Hello World"));
+    ///
+    /// let err = payload.dynamic_cast::<BufferMarker>().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "ICU4X data error: Mismatched types: tried to downcast with \
+    ///      icu_provider::buf::BufferMarker, but actual type is \
+    ///      icu_provider::hello_world::HelloWorldV1Marker"
+    /// );
+    /// ```
+    #[inline]
+    pub fn dynamic_cast<M2>(self) -> Result<DataPayload<M2>, DataError>
+    where
+        M2: DataMarker,
+    {
+        let mut option_self = Some(self);
+        let mut option_out = Option::<DataPayload<M2>>::None;
+        let option_out_any: &mut dyn core::any::Any = &mut option_out;
+        if let Some(option_out) = option_out_any.downcast_mut::<Option<DataPayload<M>>>() {
+            core::mem::swap(option_out, &mut option_self);
+        }
+        option_out.ok_or_else(|| {
+            DataError::for_type::<M2>().with_str_context(core::any::type_name::<M>())
+        })
+    }
+
     /// Convert a mutable reference of a [`DataPayload`] to another mutable reference
     /// of the same type with runtime type checking.
     ///
@@ -995,6 +1130,43 @@ where
     }
 }
 
+#[test]
+fn test_map_project_cloned_to_owned_drops_cart() {
+    use crate::hello_world::*;
+    use alloc::borrow::Cow;
+
+    let cart: CartInner = SelectedRc::new(Box::from(&b"Hello World"[..]));
+    let weak = SelectedRc::downgrade(&cart);
+    let p1 = DataPayload::<HelloWorldV1Marker>(DataPayloadInner::Yoke(
+        Yoke::attach_to_cart(cart, |b| HelloWorldV1 {
+            message: Cow::Borrowed(core::str::from_utf8(b).unwrap()),
+        })
+        .wrap_cart_in_option()
+        .convert_cart_into_option_pointer(),
+    ));
+
+    // Projecting keeps the cart alive
+    struct HelloWorldV1MessageMarker;
+    impl DataMarker for HelloWorldV1MessageMarker {
+        type Yokeable = Cow<'static, str>;
+    }
+    let projected: DataPayload<HelloWorldV1MessageMarker> =
+        p1.map_project_cloned(|obj, _| obj.message.clone());
+    assert_eq!(weak.strong_count(), 2);
+    drop(projected);
+
+    let p2: DataPayload<HelloWorldV1MessageMarker> =
+        p1.map_project_cloned_to_owned(|obj| Cow::Owned(obj.message.to_string()));
+    let p3 = p1.clone().into_owned(|obj| HelloWorldV1 {
+        message: Cow::Owned(obj.message.to_string()),
+    });
+    drop(p1);
+
+    assert_eq!(weak.strong_count(), 0);
+    assert_eq!("Hello World", p2.get());
+    assert_eq!("Hello World", p3.get().message);
+}
+
 #[test]
 fn test_debug() {
     use crate::hello_world::*;
@@ -1005,5 +1177,5 @@ fn test_debug() {
             message: Cow::Borrowed("foo"),
         })),
     };
-    assert_eq!("DataResponse { metadata: DataResponseMetadata { locale: None, buffer_format: None }, payload: Some(HelloWorldV1 { message: \"foo\" }) }", format!("{resp:?}"));
+    assert_eq!("DataResponse { metadata: DataResponseMetadata { locale: None, buffer_format: None, requested_locale: None, checksum: None }, payload: Some(HelloWorldV1 { message: \"foo\" }) }", format!("{resp:?}"));
 }
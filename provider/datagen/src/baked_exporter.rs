@@ -94,6 +94,15 @@
 //!
 //! assert_eq!(formatter.format_to_string(), "Hello World");
 //! ```
+//!
+//! ## Runtime overrides
+//!
+//! With the `baked_override` Cargo feature of `icu_provider`, the generated `DataProvider`
+//! implementations first consult the provider registered with
+//! `icu_provider::baked::set_override_provider`, so that individual data can be replaced
+//! without a new build. The data structs then need to be deserializable, so the generated
+//! code only compiles if the components whose data it contains are built with their `serde`
+//! Cargo feature.
 
 use databake::*;
 use icu_provider::datagen::*;
@@ -408,6 +417,7 @@ impl DataExporter for BakedExporter {
                     &self,
                     req: icu_provider::DataRequest,
                 ) -> Result<icu_provider::DataResponse<#marker>, icu_provider::DataError> {
+                    icu_provider::__baked_override!(#marker, req);
                     if req.locale.is_empty() {
                         Ok(icu_provider::DataResponse {
                             payload: Some(icu_provider::DataPayload::from_static_ref(Self::#singleton_ident)),
@@ -615,6 +625,7 @@ impl BakedExporter {
                         &self,
                         req: icu_provider::DataRequest,
                     ) -> Result<icu_provider::DataResponse<#marker>, icu_provider::DataError> {
+                        icu_provider::__baked_override!(#marker, req);
                         #load_body
                     }
                 }
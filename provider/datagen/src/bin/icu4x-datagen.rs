@@ -57,6 +57,20 @@ struct Cli {
     )]
     fingerprint: bool,
 
+    #[arg(long)]
+    #[arg(
+        help = "--format=dir, --format=blob2 only: record the CLDR and ICU4X versions and a checksum of the data. \
+                With --format=blob2, this exports a version 3 blob, which cannot be read by ICU4X 1.5 and below."
+    )]
+    data_version: bool,
+
+    #[arg(long, value_enum, default_value_t = FileDeduplication::None)]
+    #[arg(
+        help = "--format=dir only: how to store files that are identical to another file of the same key. \
+                Redirect files are understood by FsDataProvider, but not by other readers of the data."
+    )]
+    file_deduplication: FileDeduplication,
+
     #[arg(short = 't', long, value_name = "TAG", default_value = "latest")]
     #[arg(
         help = "Download CLDR JSON data from this GitHub tag (https://github.com/unicode-org/cldr-json/tags)\n\
@@ -112,6 +126,25 @@ struct Cli {
     #[cfg(feature = "provider")]
     segmenter_lstm_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download the compiled time zone database from this version of the tzdata package (https://pypi.org/project/tzdata/#history)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--tzdb-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default).\n\
+                  Only required for experimental time zone keys."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    tzdb_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory of compiled time zone database files, such as /usr/share/zoneinfo.\n\
+                  Only required for experimental time zone keys."
+    )]
+    #[cfg(feature = "provider")]
+    tzdb_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -126,6 +159,29 @@ struct Cli {
     #[cfg(feature = "provider")]
     collation_han_database: CollationHanDatabase,
 
+    #[arg(long, value_name = "PERCENT")]
+    #[arg(
+        help = "Generate the pseudo-locales en-XA (accented, expanded by PERCENT) and ar-XB (bidi) from en data.\n\
+                  Currently supported for relative time and list patterns."
+    )]
+    #[cfg(feature = "provider")]
+    pseudolocales: Option<u16>,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a JSON file of cardinal plural rules that override CLDR's.\n\
+                  Maps locales to plural categories to rules in TR35 syntax, such as {\"en\": {\"two\": \"n = 2\"}}."
+    )]
+    #[cfg(feature = "provider")]
+    cardinal_rules_overrides: Option<PathBuf>,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a JSON file of ordinal plural rules that override CLDR's, in the same format as --cardinal-rules-overrides."
+    )]
+    #[cfg(feature = "provider")]
+    ordinal_rules_overrides: Option<PathBuf>,
+
     #[arg(long, value_enum, num_args = 1..)]
     #[arg(
         help = "Which less-common collation tables to include. 'search-all' includes all search tables."
@@ -307,6 +363,15 @@ enum Deduplication {
     None,
 }
 
+// Mirrors icu_provider_fs::export::DeduplicationOption
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+enum FileDeduplication {
+    None,
+    Hardlink,
+    Symlink,
+    Redirect,
+}
+
 // Mirrors crate::RuntimeFallbackLocation
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 enum RuntimeFallbackLocation {
@@ -346,6 +411,25 @@ fn main() -> eyre::Result<()> {
         None
     };
 
+    // The version of the CLDR data is only known if it is downloaded for a tag
+    #[cfg(feature = "provider")]
+    let data_version = match (&cli.cldr_root, cli.cldr_tag.as_str()) {
+        (Some(_), _) => None,
+        (None, "latest") => Some(DatagenProvider::LATEST_TESTED_CLDR_TAG),
+        (None, tag) => Some(tag),
+    }
+    .map(|tag| format!("CLDR {tag}, ICU4X {}", env!("CARGO_PKG_VERSION")));
+    #[cfg(not(feature = "provider"))]
+    let data_version = None::<String>;
+    #[cfg(feature = "blob_input")]
+    let data_version = data_version.filter(|_| cli.input_blob.is_none());
+    if cli.data_version && data_version.is_none() {
+        log::warn!(
+            "The CLDR version is only known for downloaded data, no data version is recorded"
+        );
+    }
+    let data_version = data_version.filter(|_| cli.data_version);
+
     let provider: Box<dyn ExportableProvider> = match () {
         #[cfg(feature = "blob_input")]
         () if cli.input_blob.is_some() => Box::new(ReexportableBlobDataProvider(
@@ -370,6 +454,18 @@ fn main() -> eyre::Result<()> {
                 p = p.with_fast_tries();
             }
 
+            if let Some(expansion_percent) = cli.pseudolocales {
+                p = p.with_pseudolocales(expansion_percent);
+            }
+
+            if let Some(path) = cli.cardinal_rules_overrides {
+                p = p.with_plural_rules_overrides(icu_plurals::PluralRuleType::Cardinal, path)?;
+            }
+
+            if let Some(path) = cli.ordinal_rules_overrides {
+                p = p.with_plural_rules_overrides(icu_plurals::PluralRuleType::Ordinal, path)?;
+            }
+
             p = match (cli.cldr_root, cli.cldr_tag.as_str()) {
                 (Some(path), _) => p.with_cldr(path)?,
                 #[cfg(feature = "networking")]
@@ -416,6 +512,20 @@ fn main() -> eyre::Result<()> {
                 }
             };
 
+            p = match (cli.tzdb_root, cli.tzdb_tag.as_str()) {
+                (Some(path), _) => p.with_tzdb(path)?,
+                #[cfg(feature = "networking")]
+                (_, "latest") => p.with_tzdb_for_tag(DatagenProvider::LATEST_TESTED_TZDB_TAG),
+                #[cfg(feature = "networking")]
+                (_, tag) => p.with_tzdb_for_tag(tag),
+                #[cfg(not(feature = "networking"))]
+                (None, _) => {
+                    eyre::bail!(
+                        "Downloading data from tags requires the `networking` Cargo feature"
+                    )
+                }
+            };
+
             if cli.locales.as_slice() == ["recommended"] {
                 preprocessed_locales = Some(PreprocessedLocales::LanguageIdentifiers(
                     p.locales_for_coverage_levels([
@@ -593,6 +703,10 @@ fn main() -> eyre::Result<()> {
         driver.with_segmenter_models(cli.segmenter_models.clone())
     };
 
+    if let Some(data_version) = data_version {
+        driver = driver.with_data_version(data_version);
+    }
+
     if cli.format == Format::DeprecatedDefault {
         log::warn!(
             "Defaulting to --format=dir. This will become a required parameter in the future."
@@ -625,6 +739,12 @@ fn main() -> eyre::Result<()> {
                     {
                         options.fingerprint = cli.fingerprint;
                     }
+                    options.deduplication = match cli.file_deduplication {
+                        FileDeduplication::None => DeduplicationOption::None,
+                        FileDeduplication::Hardlink => DeduplicationOption::Hardlink,
+                        FileDeduplication::Symlink => DeduplicationOption::Symlink,
+                        FileDeduplication::Redirect => DeduplicationOption::Redirect,
+                    };
                     options
                 },
             )?
@@ -648,6 +768,8 @@ fn main() -> eyre::Result<()> {
             };
             if cli.format == Format::Blob {
                 icu_provider_blob::export::BlobExporter::new_with_sink(sink)
+            } else if cli.data_version {
+                icu_provider_blob::export::BlobExporter::new_v3_with_sink(sink)
             } else {
                 icu_provider_blob::export::BlobExporter::new_v2_with_sink(sink)
             }
@@ -407,6 +407,7 @@ pub struct DatagenDriver {
     legacy_fallback_mode: FallbackMode,
     additional_collations: HashSet<String>,
     segmenter_models: Vec<String>,
+    data_version: Option<String>,
 }
 
 impl DatagenDriver {
@@ -422,6 +423,7 @@ impl DatagenDriver {
             legacy_locales: None,
             additional_collations: HashSet::new(),
             segmenter_models: Vec::new(),
+            data_version: None,
         }
         .with_recommended_segmenter_models()
     }
@@ -591,6 +593,21 @@ impl DatagenDriver {
         }
     }
 
+    /// Sets the version of the generated data, such as the CLDR version and the ICU4X version.
+    ///
+    /// The version is passed to the exporter with [`DataExporter::set_data_version`]. Filesystem
+    /// exporters and version 3 blob exporters record it together with a checksum of the data,
+    /// which can be read back from the providers and is reported in every [`DataResponseMetadata`].
+    /// Other exporters ignore it.
+    ///
+    /// [`DataResponseMetadata`]: icu_provider::DataResponseMetadata
+    pub fn with_data_version(self, data_version: impl Into<String>) -> Self {
+        Self {
+            data_version: Some(data_version.into()),
+            ..self
+        }
+    }
+
     /// Exports data from the given provider to the given exporter.
     ///
     /// See
@@ -620,6 +637,7 @@ impl DatagenDriver {
             legacy_fallback_mode,
             additional_collations,
             segmenter_models,
+            data_version,
         } = self;
 
         let Some(keys) = keys else {
@@ -628,6 +646,10 @@ impl DatagenDriver {
             ));
         };
 
+        if let Some(data_version) = data_version {
+            sink.set_data_version(&data_version)?;
+        }
+
         let map_legacy_locales_to_locales_with_expansion =
             |legacy_locales: Option<Vec<LanguageIdentifier>>| match legacy_locales {
                 Some(v) => v
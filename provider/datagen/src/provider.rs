@@ -7,7 +7,7 @@
 use elsa::sync::FrozenMap;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
-use source::{AbstractFs, SerdeCache};
+use source::{AbstractFs, SerdeCache, TzdbCache};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -32,6 +32,7 @@ mod tests;
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_tzdb_error`](Self::is_missing_tzdb_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct DatagenProvider {
@@ -70,11 +71,16 @@ impl DatagenProvider {
     /// The latest segmentation LSTM model tag that has been verified to work with this version of `icu_datagen`.
     pub const LATEST_TESTED_SEGMENTER_LSTM_TAG: &'static str = "v0.1.0";
 
+    /// The latest version of the [`tzdata`](https://pypi.org/project/tzdata/) package, which contains the
+    /// compiled time zone database, that has been verified to work with this version of `icu_datagen`.
+    pub const LATEST_TESTED_TZDB_TAG: &'static str = "2025.2";
+
     /// A provider using the latest data that has been verified to work with this version of `icu_datagen`.
     ///
     /// See [`LATEST_TESTED_CLDR_TAG`](Self::LATEST_TESTED_CLDR_TAG),
     /// [`LATEST_TESTED_ICUEXPORT_TAG`](Self::LATEST_TESTED_ICUEXPORT_TAG),
-    /// [`LATEST_TESTED_SEGMENTER_LSTM_TAG`](Self::LATEST_TESTED_SEGMENTER_LSTM_TAG).
+    /// [`LATEST_TESTED_SEGMENTER_LSTM_TAG`](Self::LATEST_TESTED_SEGMENTER_LSTM_TAG),
+    /// [`LATEST_TESTED_TZDB_TAG`](Self::LATEST_TESTED_TZDB_TAG).
     ///
     /// ✨ *Enabled with the `networking` Cargo feature.*
     #[cfg(feature = "networking")]
@@ -88,6 +94,7 @@ impl DatagenProvider {
                     .with_cldr_for_tag(Self::LATEST_TESTED_CLDR_TAG)
                     .with_icuexport_for_tag(Self::LATEST_TESTED_ICUEXPORT_TAG)
                     .with_segmenter_lstm_for_tag(Self::LATEST_TESTED_SEGMENTER_LSTM_TAG)
+                    .with_tzdb_for_tag(Self::LATEST_TESTED_TZDB_TAG)
             })
             .clone()
     }
@@ -96,15 +103,20 @@ impl DatagenProvider {
     /// will return errors.
     ///
     /// Use [`with_cldr`](Self::with_cldr), [`with_icuexport`](Self::with_icuexport),
-    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm) to set data sources.
+    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm), [`with_tzdb`](Self::with_tzdb)
+    /// to set data sources.
     pub fn new_custom() -> Self {
         Self {
             source: SourceData {
                 cldr_paths: None,
                 icuexport_paths: None,
                 segmenter_lstm_paths: None,
+                tzdb_paths: None,
                 trie_type: Default::default(),
                 collation_han_database: Default::default(),
+                pseudolocale_expansion: None,
+                cardinal_rules_overrides: None,
+                ordinal_rules_overrides: None,
                 #[cfg(feature = "legacy_api")]
                 icuexport_dictionary_fallback: None,
                 #[cfg(feature = "legacy_api")]
@@ -152,6 +164,20 @@ impl DatagenProvider {
         })
     }
 
+    /// Adds time zone database source data to the provider. The path should point to a local
+    /// directory or ZIP file of compiled TZif files, such as `/usr/share/zoneinfo`.
+    pub fn with_tzdb(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            source: SourceData {
+                tzdb_paths: Some(Arc::new(TzdbCache::new(
+                    SerdeCache::new(AbstractFs::new(root)?),
+                    "",
+                ))),
+                ..self.source
+            },
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -208,6 +234,27 @@ impl DatagenProvider {
         }
     }
 
+    /// Adds time zone database source data to the provider. The data will be downloaded from PyPI
+    /// using the given version of the `tzdata` package (see [release history](https://pypi.org/project/tzdata/#history)).
+    ///
+    /// Also see: [`LATEST_TESTED_TZDB_TAG`](Self::LATEST_TESTED_TZDB_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_tzdb_for_tag(self, tag: &str) -> Self {
+        Self {
+            source: SourceData {
+                tzdb_paths: Some(Arc::new(TzdbCache::new(
+                    SerdeCache::new(AbstractFs::new_from_url(format!(
+                        "https://files.pythonhosted.org/packages/py2.py3/t/tzdata/tzdata-{tag}-py2.py3-none-any.whl"
+                    ))),
+                    "tzdata/zoneinfo/",
+                ))),
+                ..self.source
+            },
+        }
+    }
+
     const MISSING_CLDR_ERROR: DataError = DataErrorKind::MissingSourceData.with_str_context("cldr");
 
     const MISSING_ICUEXPORT_ERROR: DataError =
@@ -216,6 +263,8 @@ impl DatagenProvider {
     const MISSING_SEGMENTER_LSTM_ERROR: DataError =
         DataErrorKind::MissingSourceData.with_str_context("segmenter");
 
+    const MISSING_TZDB_ERROR: DataError = DataErrorKind::MissingSourceData.with_str_context("tzdb");
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.key = None;
@@ -234,6 +283,12 @@ impl DatagenProvider {
         e == Self::MISSING_SEGMENTER_LSTM_ERROR
     }
 
+    /// Identifies errors that are due to missing time zone database data.
+    pub fn is_missing_tzdb_error(mut e: DataError) -> bool {
+        e.key = None;
+        e == Self::MISSING_TZDB_ERROR
+    }
+
     fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.source
             .cldr_paths
@@ -255,6 +310,14 @@ impl DatagenProvider {
             .ok_or(Self::MISSING_SEGMENTER_LSTM_ERROR)
     }
 
+    #[cfg(feature = "experimental_components")]
+    fn tzdb(&self) -> Result<&TzdbCache, DataError> {
+        self.source
+            .tzdb_paths
+            .as_deref()
+            .ok_or(Self::MISSING_TZDB_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -275,6 +338,67 @@ impl DatagenProvider {
         }
     }
 
+    /// Set this to generate the pseudo-locales `en-XA` and `ar-XB` for keys that support them.
+    ///
+    /// The pseudo-locales are derived from the `en` data. `en-XA` uses accented letters and
+    /// strings that are `expansion_percent` percent longer than the original, and `ar-XB`
+    /// wraps all text in right-to-left overrides. Placeholders are retained.
+    ///
+    /// Pseudo-locales are currently supported for relative time and list patterns.
+    pub fn with_pseudolocales(self, expansion_percent: u16) -> Self {
+        Self {
+            source: SourceData {
+                pseudolocale_expansion: Some(expansion_percent),
+                // The supported locales change, so the cache cannot be shared
+                supported_locales_cache: Default::default(),
+                ..self.source
+            },
+        }
+    }
+
+    /// Overrides plural rules with rules from a JSON file, for example to use rules for a
+    /// locale before they are available in CLDR.
+    ///
+    /// The file maps locales to plural categories to rules in the
+    /// [TR35 syntax](https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax),
+    /// which are merged over the CLDR rules of the given type:
+    ///
+    /// ```json
+    /// {
+    ///   "en": { "two": "n = 2" }
+    /// }
+    /// ```
+    ///
+    /// The `other` category is implicit. It may be listed, but must not have a condition.
+    pub fn with_plural_rules_overrides(
+        self,
+        rule_type: icu_plurals::PluralRuleType,
+        path: PathBuf,
+    ) -> Result<Self, DataError> {
+        let overrides = Some(Arc::new(Self::parse_plural_rules_overrides(
+            &std::fs::read(&path).map_err(|e| DataError::from(e).with_path_context(&path))?,
+        )?));
+        Ok(Self {
+            source: match rule_type {
+                icu_plurals::PluralRuleType::Cardinal => SourceData {
+                    cardinal_rules_overrides: overrides,
+                    // The supported locales change, so the cache cannot be shared
+                    supported_locales_cache: Default::default(),
+                    ..self.source
+                },
+                icu_plurals::PluralRuleType::Ordinal => SourceData {
+                    ordinal_rules_overrides: overrides,
+                    supported_locales_cache: Default::default(),
+                    ..self.source
+                },
+                _ => {
+                    return Err(DataError::custom("Unsupported plural rule type")
+                        .with_debug_context(&rule_type))
+                }
+            },
+        })
+    }
+
     fn trie_type(&self) -> TrieType {
         self.source.trie_type
     }
@@ -414,8 +538,12 @@ pub struct SourceData {
     cldr_paths: Option<Arc<CldrCache>>,
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_lstm_paths: Option<Arc<SerdeCache>>,
+    tzdb_paths: Option<Arc<TzdbCache>>,
     trie_type: TrieType,
     collation_han_database: CollationHanDatabase,
+    pseudolocale_expansion: Option<u16>,
+    cardinal_rules_overrides: Option<Arc<transform::cldr::cldr_serde::plurals::Rules>>,
+    ordinal_rules_overrides: Option<Arc<transform::cldr::cldr_serde::plurals::Rules>>,
     #[cfg(feature = "legacy_api")]
     // populated if constructed through `SourceData` constructor only
     icuexport_dictionary_fallback: Option<Arc<SerdeCache>>,
@@ -41,13 +41,13 @@ impl SerdeCache {
         }
     }
 
-    fn read_and_parse<S>(
+    pub fn read_and_parse<S>(
         &self,
         path: &str,
         parser: fn(&[u8]) -> Result<S, DataError>,
     ) -> Result<&S, DataError>
     where
-        for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
+        S: 'static + Send + Sync,
     {
         match self.cache.get(path) {
             Some(x) => x,
@@ -92,6 +92,39 @@ impl SerdeCache {
     }
 }
 
+/// A [`SerdeCache`] over compiled time zone database files, which are located
+/// in the directory `root` of the source.
+#[derive(Debug)]
+pub(super) struct TzdbCache {
+    serde_cache: SerdeCache,
+    root: &'static str,
+}
+
+impl TzdbCache {
+    pub fn new(serde_cache: SerdeCache, root: &'static str) -> Self {
+        Self { serde_cache, root }
+    }
+
+    #[allow(dead_code)] // experimental
+    pub fn read_and_parse<S>(
+        &self,
+        time_zone: &str,
+        parser: fn(&[u8]) -> Result<S, DataError>,
+    ) -> Result<&S, DataError>
+    where
+        S: 'static + Send + Sync,
+    {
+        self.serde_cache
+            .read_and_parse(&format!("{}{time_zone}", self.root), parser)
+    }
+
+    #[allow(dead_code)] // experimental
+    pub fn file_exists(&self, time_zone: &str) -> Result<bool, DataError> {
+        self.serde_cache
+            .file_exists(&format!("{}{time_zone}", self.root))
+    }
+}
+
 pub(super) struct ZipData {
     archive: ZipArchive<Cursor<Vec<u8>>>,
     file_list: HashSet<String>,
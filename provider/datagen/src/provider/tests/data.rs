@@ -6,7 +6,7 @@
 
 #![allow(deprecated)]
 
-use crate::provider::source::{AbstractFs, SerdeCache};
+use crate::provider::source::{AbstractFs, SerdeCache, TzdbCache};
 use crate::provider::transform::cldr::source::CldrCache;
 use crate::provider::{DatagenProvider, SourceData};
 use std::sync::Arc;
@@ -245,6 +245,7 @@ impl DatagenProvider {
                             ("cldr-core/supplemental/calendarData.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()),
                             ("cldr-core/supplemental/currencyData.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()),
                             ("cldr-core/supplemental/units.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()),
+                            ("cldr-core/supplemental/dayPeriods.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/dayPeriods.json").as_slice()),
                             ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()),
                             ("cldr-core/supplemental/metaZones.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()),
                             ("cldr-core/supplemental/numberingSystems.json", include_bytes!("../../../tests/data/cldr/cldr-core/supplemental/numberingSystems.json").as_slice()),
@@ -679,6 +680,13 @@ impl DatagenProvider {
                             ("Thai_graphclust_model4_heavy/weights.json", include_bytes!("../../../tests/data/lstm/Thai_graphclust_model4_heavy/weights.json").as_slice())
                         ].into_iter().collect(),
                     )))),
+                    tzdb_paths: Some(Arc::new(TzdbCache::new(SerdeCache::new(AbstractFs::Memory(
+                        [
+                            ("tzdata/zoneinfo/Asia/Kolkata", include_bytes!("../../../tests/data/tzdb/tzdata/zoneinfo/Asia/Kolkata").as_slice()),
+                            ("tzdata/zoneinfo/Australia/Sydney", include_bytes!("../../../tests/data/tzdb/tzdata/zoneinfo/Australia/Sydney").as_slice()),
+                            ("tzdata/zoneinfo/Europe/Berlin", include_bytes!("../../../tests/data/tzdb/tzdata/zoneinfo/Europe/Berlin").as_slice())
+                        ].into_iter().collect(),
+                    )), "tzdata/zoneinfo/"))),
                     ..DatagenProvider::new_custom().source
                 },
             })
@@ -230,6 +230,8 @@ macro_rules! registry(
             icu::datetime::provider::calendar::DateSkeletonPatternsV1Marker = "datetime/skeletons@1",
             icu::datetime::provider::neo::WeekdayNamesV1Marker = "datetime/symbols/weekdays@1",
             icu::datetime::provider::neo::DayPeriodNamesV1Marker = "datetime/symbols/dayperiods@1",
+            icu::datetime::provider::neo::FlexibleDayPeriodNamesV1Marker = "datetime/symbols/dayperiods/flexible@1",
+            icu::datetime::provider::neo::DayPeriodRulesV1Marker = "datetime/dayperiods/rules@1",
             icu::datetime::provider::neo::TimePatternV1Marker = "datetime/patterns/time@1",
             icu::datetime::provider::neo::DateTimePatternV1Marker = "datetime/patterns/datetime@1",
             icu::datetime::provider::neo::BuddhistYearNamesV1Marker = "datetime/symbols/buddhist/years@1",
@@ -289,11 +291,13 @@ macro_rules! registry(
             icu::experimental::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker = "compactdecimal/short@1",
             icu::experimental::dimension::provider::currency::CurrencyEssentialsV1Marker = "currency/essentials@1",
             icu::experimental::dimension::provider::percent::PercentEssentialsV1Marker = "percent/essentials@1",
+            icu::experimental::displaynames::provider::CurrencyDisplayNamesV1Marker = "displaynames/currencies@1",
             icu::experimental::displaynames::provider::RegionDisplayNamesV1Marker = "displaynames/regions@1",
             icu::experimental::displaynames::provider::LanguageDisplayNamesV1Marker = "displaynames/languages@1",
             icu::experimental::displaynames::provider::LocaleDisplayNamesV1Marker = "displaynames/locales@1",
             icu::experimental::displaynames::provider::ScriptDisplayNamesV1Marker = "displaynames/scripts@1",
             icu::experimental::displaynames::provider::VariantDisplayNamesV1Marker = "displaynames/variants@1",
+            icu::experimental::ellipsis::provider::EllipsisPatternsV1Marker = "ellipsis/patterns@1",
             icu::experimental::relativetime::provider::LongSecondRelativeTimeFormatDataV1Marker = "relativetime/long/second@1",
             icu::experimental::relativetime::provider::ShortSecondRelativeTimeFormatDataV1Marker = "relativetime/short/second@1",
             icu::experimental::relativetime::provider::NarrowSecondRelativeTimeFormatDataV1Marker = "relativetime/narrow/second@1",
@@ -320,6 +324,7 @@ macro_rules! registry(
             icu::experimental::relativetime::provider::NarrowYearRelativeTimeFormatDataV1Marker = "relativetime/narrow/year@1",
             icu::experimental::personnames::provider::PersonNamesFormatV1Marker = "personnames/personnames@1",
             icu::experimental::transliterate::provider::TransliteratorRulesV1Marker = "transliterator/rules@1",
+            icu::experimental::timezone_picker::provider::ZoneOffsetPeriodV1Marker = "time_zone/offset_period@1",
             icu::experimental::units::provider::UnitsInfoV1Marker = "units/info@1",
             icu::plurals::provider::PluralRangesV1Marker = "plurals/ranges@1",
         );
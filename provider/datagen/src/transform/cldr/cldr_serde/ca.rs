@@ -122,6 +122,14 @@ pub(in crate::provider) struct DayPeriodSymbols {
     pub(in crate::provider) pm: Cow<'static, str>,
     pub(in crate::provider) noon: Option<Cow<'static, str>>,
     pub(in crate::provider) midnight: Option<Cow<'static, str>>,
+    pub(in crate::provider) morning1: Option<Cow<'static, str>>,
+    pub(in crate::provider) morning2: Option<Cow<'static, str>>,
+    pub(in crate::provider) afternoon1: Option<Cow<'static, str>>,
+    pub(in crate::provider) afternoon2: Option<Cow<'static, str>>,
+    pub(in crate::provider) evening1: Option<Cow<'static, str>>,
+    pub(in crate::provider) evening2: Option<Cow<'static, str>>,
+    pub(in crate::provider) night1: Option<Cow<'static, str>>,
+    pub(in crate::provider) night2: Option<Cow<'static, str>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize, Clone)]
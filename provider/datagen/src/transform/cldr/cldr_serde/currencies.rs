@@ -18,6 +18,27 @@ pub(in crate::provider) struct CurrencyPatterns {
 
     #[serde(rename = "symbol-alt-narrow")]
     pub(in crate::provider) narrow: Option<String>,
+
+    #[serde(rename = "displayName")]
+    pub(in crate::provider) display_name: Option<String>,
+
+    #[serde(rename = "displayName-count-zero")]
+    pub(in crate::provider) display_name_zero: Option<String>,
+
+    #[serde(rename = "displayName-count-one")]
+    pub(in crate::provider) display_name_one: Option<String>,
+
+    #[serde(rename = "displayName-count-two")]
+    pub(in crate::provider) display_name_two: Option<String>,
+
+    #[serde(rename = "displayName-count-few")]
+    pub(in crate::provider) display_name_few: Option<String>,
+
+    #[serde(rename = "displayName-count-many")]
+    pub(in crate::provider) display_name_many: Option<String>,
+
+    #[serde(rename = "displayName-count-other")]
+    pub(in crate::provider) display_name_other: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dayPeriods.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/dayPeriods.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// The time of day at which a day period applies, e.g. `{ "_from": "06:00", "_before": "12:00" }`
+/// or `{ "_at": "12:00" }`. Times are formatted as "HH:mm".
+#[derive(PartialEq, Debug, Deserialize)]
+pub(in crate::provider) struct DayPeriodRule {
+    #[serde(rename = "_at")]
+    pub(in crate::provider) at: Option<String>,
+    #[serde(rename = "_from")]
+    pub(in crate::provider) from: Option<String>,
+    #[serde(rename = "_before")]
+    pub(in crate::provider) before: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(in crate::provider) struct Supplemental {
+    #[serde(rename = "dayPeriodRuleSet")]
+    pub(in crate::provider) day_period_rule_set:
+        HashMap<LanguageIdentifier, BTreeMap<String, DayPeriodRule>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(in crate::provider) struct Resource {
+    pub(in crate::provider) supplemental: Supplemental,
+}
@@ -17,6 +17,15 @@ pub(in crate::provider) struct LocaleExemplarCharacters {
     pub(in crate::provider) index: Option<String>,
     pub(in crate::provider) numbers: Option<String>,
     pub(in crate::provider) punctuation: Option<String>,
+    pub(in crate::provider) ellipsis: Option<Ellipsis>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(in crate::provider) struct Ellipsis {
+    pub(in crate::provider) initial: String,
+    pub(in crate::provider) medial: String,
+    #[serde(rename = "final")]
+    pub(in crate::provider) final_: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
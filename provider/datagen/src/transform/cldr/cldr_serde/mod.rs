@@ -15,6 +15,7 @@ pub(in crate::provider) mod currencies;
 pub(in crate::provider) mod currency_data;
 #[cfg(feature = "experimental_components")]
 pub(in crate::provider) mod date_fields;
+pub(in crate::provider) mod day_periods;
 pub(in crate::provider) mod directionality;
 #[cfg(feature = "experimental_components")]
 pub(in crate::provider) mod displaynames;
@@ -11,7 +11,7 @@ use icu_locid::LanguageIdentifier;
 use serde::Deserialize;
 use std::collections::HashMap;
 
-#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Deserialize)]
+#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Clone, Default, Deserialize)]
 pub(in crate::provider) struct LocalePluralRules {
     #[serde(rename = "pluralRule-count-zero")]
     pub(in crate::provider) zero: Option<String>,
@@ -0,0 +1,229 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::DatagenProvider;
+use crate::provider::IterableDataProviderInternal;
+use icu_datetime::provider::neo::{DayPeriodRulesV1, DayPeriodRulesV1Marker};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+impl DatagenProvider {
+    fn day_period_rule_set(
+        &self,
+    ) -> Result<
+        &HashMap<LanguageIdentifier, BTreeMap<String, cldr_serde::day_periods::DayPeriodRule>>,
+        DataError,
+    > {
+        Ok(&self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::day_periods::Resource>("supplemental/dayPeriods.json")?
+            .supplemental
+            .day_period_rule_set)
+    }
+}
+
+impl IterableDataProviderInternal<DayPeriodRulesV1Marker> for DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .day_period_rule_set()?
+            .keys()
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl DataProvider<DayPeriodRulesV1Marker> for DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DayPeriodRulesV1Marker>, DataError> {
+        self.check_req::<DayPeriodRulesV1Marker>(req)?;
+        let rules = self
+            .day_period_rule_set()?
+            .get(&req.locale.get_langid())
+            .ok_or(DataErrorKind::MissingLocale.into_error())?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                convert(rules).map_err(|e| e.with_display_context(&req.locale))?,
+            )),
+        })
+    }
+}
+
+/// Parses a "HH:mm" time, which CLDR only uses on full hours for day periods.
+fn parse_hour(time: &str) -> Result<u8, DataError> {
+    match time.split_once(':') {
+        Some((hour, "00")) => hour.parse().ok().filter(|&h| h <= 24),
+        _ => None,
+    }
+    .ok_or_else(|| DataError::custom("Unsupported day period time").with_display_context(time))
+}
+
+fn convert(
+    rules: &BTreeMap<String, cldr_serde::day_periods::DayPeriodRule>,
+) -> Result<DayPeriodRulesV1<'static>, DataError> {
+    let mut hours: [Option<u8>; 24] = [None; 24];
+    let mut has_midnight = false;
+    let mut has_noon = false;
+    for (period, rule) in rules {
+        // The order is specified on LinearNamesV1
+        let index = match period.as_str() {
+            "am" => 0,
+            "pm" => 1,
+            "noon" => 2,
+            "midnight" => 3,
+            "morning1" => 4,
+            "morning2" => 5,
+            "afternoon1" => 6,
+            "afternoon2" => 7,
+            "evening1" => 8,
+            "evening2" => 9,
+            "night1" => 10,
+            "night2" => 11,
+            _ => return Err(DataError::custom("Unknown day period").with_display_context(period)),
+        };
+        match (&rule.at, &rule.from, &rule.before) {
+            (Some(at), None, None) => match (index, parse_hour(at)?) {
+                (2, 12) => has_noon = true,
+                (3, 0) => has_midnight = true,
+                _ => {
+                    return Err(DataError::custom("Unsupported day period time")
+                        .with_display_context(period))
+                }
+            },
+            (None, Some(from), Some(before)) => {
+                let from = parse_hour(from)?;
+                let before = parse_hour(before)?;
+                // Ranges that cross midnight wrap around
+                let len = match (before + 24 - from) % 24 {
+                    0 => 24,
+                    len => len,
+                };
+                for hour in (from..from + len).map(|h| usize::from(h % 24)) {
+                    if hours[hour].replace(index).is_some() {
+                        return Err(DataError::custom("Overlapping day periods")
+                            .with_display_context(period));
+                    }
+                }
+            }
+            _ => {
+                return Err(
+                    DataError::custom("Invalid day period rule").with_display_context(period)
+                )
+            }
+        }
+    }
+    let hours = hours
+        .into_iter()
+        .map(|index| index.ok_or(DataError::custom("Day periods do not cover the whole day")))
+        .collect::<Result<Vec<u8>, _>>()?;
+    Ok(DayPeriodRulesV1 {
+        hours: hours.into_iter().collect(),
+        has_midnight,
+        has_noon,
+    })
+}
+
+#[test]
+fn test_flexible_day_period_formatting() {
+    use icu_calendar::Gregorian;
+    use icu_datetime::neo_pattern::DateTimePattern;
+    use icu_datetime::TypedDateTimeNames;
+    use writeable::assert_try_writeable_eq;
+
+    let provider = DatagenProvider::new_testing();
+
+    #[derive(Debug)]
+    struct TestCase {
+        locale: &'static str,
+        hour: u8,
+        minute: u8,
+        expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            locale: "en",
+            hour: 12,
+            minute: 0,
+            expected: "12:00 noon",
+        },
+        TestCase {
+            locale: "en",
+            hour: 0,
+            minute: 0,
+            expected: "12:00 midnight",
+        },
+        TestCase {
+            locale: "en",
+            hour: 9,
+            minute: 30,
+            expected: "9:30 in the morning",
+        },
+        TestCase {
+            locale: "en",
+            hour: 12,
+            minute: 1,
+            expected: "12:01 in the afternoon",
+        },
+        TestCase {
+            locale: "en",
+            hour: 0,
+            minute: 30,
+            expected: "12:30 at night",
+        },
+        // Spanish has noon, but no midnight
+        TestCase {
+            locale: "es",
+            hour: 0,
+            minute: 0,
+            expected: "12:00 de la madrugada",
+        },
+        TestCase {
+            locale: "es",
+            hour: 12,
+            minute: 0,
+            expected: "12:00 del mediodía",
+        },
+        // Arabic has neither noon nor midnight
+        TestCase {
+            locale: "ar",
+            hour: 12,
+            minute: 0,
+            expected: "١٢:٠٠ ظهرًا",
+        },
+        TestCase {
+            locale: "ar",
+            hour: 0,
+            minute: 0,
+            expected: "١٢:٠٠ ليلاً",
+        },
+        // The root locale only has AM and PM
+        TestCase {
+            locale: "und",
+            hour: 12,
+            minute: 0,
+            expected: "12:00 PM",
+        },
+    ];
+    let pattern: DateTimePattern = "h:mm BBBB".parse().unwrap();
+    for cas in cases {
+        let TestCase {
+            locale,
+            hour,
+            minute,
+            expected,
+        } = cas;
+        let locale = locale.parse::<icu_locid::Locale>().unwrap().into();
+        let mut names: TypedDateTimeNames<Gregorian> =
+            TypedDateTimeNames::try_new_unstable(&provider, &locale).unwrap();
+        let time = icu_calendar::types::Time::try_new(hour, minute, 0, 0).unwrap();
+        let formatted_pattern = names
+            .load_for_pattern(&provider, &pattern)
+            .unwrap()
+            .format_time(&time);
+
+        assert_try_writeable_eq!(formatted_pattern, expected, Ok(()), "{cas:?}");
+    }
+}
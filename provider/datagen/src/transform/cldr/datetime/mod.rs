@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::transform::cldr::pseudo::PseudoLocale;
 use crate::provider::DatagenProvider;
 use crate::provider::IterableDataProviderInternal;
 use either::Either;
@@ -12,9 +13,11 @@ use icu_locid::extensions::unicode::{key, value};
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 use once_cell::sync::OnceCell;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+mod day_period_rules;
 mod neo;
 mod neo_skeleton;
 mod patterns;
@@ -198,13 +201,105 @@ impl DatagenProvider {
     }
 }
 
+/// Applies a pseudo-locale transform to all symbols and patterns in the data.
+fn pseudolocalize(data: &mut cldr_serde::ca::Dates, pseudolocale: PseudoLocale) {
+    fn contexts<Symbols>(
+        contexts: &mut cldr_serde::ca::Contexts<Symbols>,
+        mut f: impl FnMut(&mut Symbols),
+    ) {
+        f(&mut contexts.format.abbreviated);
+        f(&mut contexts.format.narrow);
+        contexts.format.short.as_mut().map(&mut f);
+        f(&mut contexts.format.wide);
+        if let Some(stand_alone) = contexts.stand_alone.as_mut() {
+            stand_alone.abbreviated.as_mut().map(&mut f);
+            stand_alone.narrow.as_mut().map(&mut f);
+            stand_alone.short.as_mut().map(&mut f);
+            stand_alone.wide.as_mut().map(&mut f);
+        }
+        if let Some(numeric) = contexts.numeric.as_mut() {
+            f(&mut numeric.all);
+        }
+    }
+    let transform = |s: &mut String| *s = pseudolocale.transform(s);
+    let transform_cow = |s: &mut Cow<str>| *s = Cow::Owned(pseudolocale.transform(s));
+    let transform_pattern = |p: &mut cldr_serde::ca::LengthPattern| match p {
+        cldr_serde::ca::LengthPattern::Plain(pattern)
+        | cldr_serde::ca::LengthPattern::WithNumberingSystems { pattern, .. } => {
+            *pattern = pseudolocale.transform_datetime_pattern(pattern)
+        }
+    };
+
+    contexts(&mut data.months, |m| m.0.values_mut().for_each(transform));
+    if let Some(month_patterns) = data.month_patterns.as_mut() {
+        contexts(month_patterns, |m| transform(&mut m.leap));
+    }
+    contexts(&mut data.days, |d| {
+        [
+            &mut d.sun, &mut d.mon, &mut d.tue, &mut d.wed, &mut d.thu, &mut d.fri, &mut d.sat,
+        ]
+        .into_iter()
+        .for_each(transform)
+    });
+    if let Some(eras) = data.eras.as_mut() {
+        eras.names
+            .values_mut()
+            .chain(eras.abbr.values_mut())
+            .chain(eras.narrow.values_mut())
+            .for_each(transform);
+    }
+    if let Some(years) = data
+        .cyclic_name_sets
+        .as_mut()
+        .and_then(|c| c.years.as_mut())
+    {
+        contexts(years, |y| y.values_mut().for_each(transform));
+    }
+    contexts(&mut data.day_periods, |d| {
+        transform_cow(&mut d.am);
+        transform_cow(&mut d.pm);
+        [
+            &mut d.noon,
+            &mut d.midnight,
+            &mut d.morning1,
+            &mut d.morning2,
+            &mut d.afternoon1,
+            &mut d.afternoon2,
+            &mut d.evening1,
+            &mut d.evening2,
+            &mut d.night1,
+            &mut d.night2,
+        ]
+        .into_iter()
+        .flatten()
+        .for_each(transform_cow)
+    });
+    for patterns in [&mut data.date_formats, &mut data.time_formats] {
+        transform_pattern(&mut patterns.full);
+        transform_pattern(&mut patterns.long);
+        transform_pattern(&mut patterns.medium);
+        transform_pattern(&mut patterns.short);
+    }
+    transform_pattern(&mut data.datetime_formats.full);
+    transform_pattern(&mut data.datetime_formats.long);
+    transform_pattern(&mut data.datetime_formats.medium);
+    transform_pattern(&mut data.datetime_formats.short);
+    for pattern in data.datetime_formats.available_formats.0.values_mut() {
+        *pattern = pseudolocale.transform_datetime_pattern(pattern);
+    }
+}
+
 macro_rules! impl_data_provider {
     ($marker:ident, $expr:expr, $calendar:expr) => {
         impl DataProvider<$marker> for DatagenProvider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
                 self.check_req::<$marker>(req)?;
 
-                let langid = req.locale.get_langid();
+                let pseudolocale = self.pseudolocale(req.locale);
+                let langid = match pseudolocale {
+                    Some(pseudolocale) => pseudolocale.base_langid(),
+                    None => req.locale.get_langid(),
+                };
 
                 let calendar = if DateSkeletonPatternsV1Marker::KEY == $marker::KEY {
                     req.locale
@@ -214,7 +309,10 @@ macro_rules! impl_data_provider {
                     value!($calendar)
                 };
 
-                let data = self.get_datetime_resources(&langid, Either::Left(&calendar))?;
+                let mut data = self.get_datetime_resources(&langid, Either::Left(&calendar))?;
+                if let Some(pseudolocale) = pseudolocale {
+                    pseudolocalize(&mut data, pseudolocale);
+                }
 
                 #[allow(clippy::redundant_closure_call)]
                 Ok(DataResponse {
@@ -258,7 +356,7 @@ macro_rules! impl_data_provider {
                     });
                 }
 
-                Ok(r)
+                Ok(self.add_pseudolocales(r))
             }
         }
     };
@@ -508,6 +606,111 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_pseudolocales() {
+        use icu_calendar::types::MonthCode;
+        use tinystr::tinystr;
+        let provider = DatagenProvider::new_testing().with_pseudolocales(40);
+
+        let en_xa_symbols: DataPayload<GregorianDateSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en-XA").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            "Ĵáñûáŕý on",
+            en_xa_symbols
+                .get()
+                .months
+                .format
+                .wide
+                .get(MonthCode(tinystr!(4, "M01")))
+                .unwrap()
+        );
+
+        let en_xa_lengths: DataPayload<GregorianDateLengthsV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en-XA").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        // Field symbols are retained
+        assert_eq!("EEEE, MMMM d, y", en_xa_lengths.get().date.full.to_string());
+        // Placeholders are retained
+        assert_eq!(
+            "{1}, {0}",
+            en_xa_lengths.get().length_combinations.full.to_string()
+        );
+
+        let ar_xb_symbols: DataPayload<TimeSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("ar-XB").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            "\u{202E}AM\u{202C}",
+            ar_xb_symbols.get().day_periods.format.abbreviated.am
+        );
+
+        assert!(
+            IterableDataProviderInternal::<GregorianDateSymbolsV1Marker>::supported_locales_impl(
+                &provider
+            )
+            .unwrap()
+            .contains(&langid!("en-XA").into())
+        );
+    }
+
+    #[test]
+    fn test_ethiopian_symbols() {
+        use icu_calendar::types::MonthCode;
+        use tinystr::tinystr;
+        use zerovec::ule::UnvalidatedStr;
+        let provider = DatagenProvider::new_testing();
+
+        let en_dates: DataPayload<EthiopianDateSymbolsV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        // The epagomenal month
+        assert_eq!(
+            "Pagumen",
+            en_dates
+                .get()
+                .months
+                .format
+                .wide
+                .get(MonthCode(tinystr!(4, "M13")))
+                .unwrap()
+        );
+
+        // The era of the Amete Alem calendar comes from the ethioaa data
+        let eras = &en_dates.get().eras;
+        for era in ["incar", "pre-incar", "mundi"] {
+            assert!(
+                eras.abbr.get(UnvalidatedStr::from_str(era)).is_some(),
+                "{era}"
+            );
+        }
+        assert_eq!(
+            eras.names.get(UnvalidatedStr::from_str("mundi")),
+            Some("ERA0")
+        );
+    }
+
     #[test]
     fn unalias_contexts() {
         let provider = DatagenProvider::new_testing();
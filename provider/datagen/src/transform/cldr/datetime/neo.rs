@@ -253,6 +253,38 @@ fn dayperiods_convert(
     })
 }
 
+fn flexible_dayperiods_convert(
+    _datagen: &DatagenProvider,
+    _langid: &LanguageIdentifier,
+    data: &ca::Dates,
+    _calendar: &Value,
+    context: Context,
+    length: Length,
+) -> Result<LinearNamesV1<'static>, DataError> {
+    let day_periods = data.day_periods.get_symbols(context, length);
+
+    // The order is specified on LinearNamesV1
+    let periods = [
+        Some(&day_periods.am),
+        Some(&day_periods.pm),
+        day_periods.noon.as_ref(),
+        day_periods.midnight.as_ref(),
+        day_periods.morning1.as_ref(),
+        day_periods.morning2.as_ref(),
+        day_periods.afternoon1.as_ref(),
+        day_periods.afternoon2.as_ref(),
+        day_periods.evening1.as_ref(),
+        day_periods.evening2.as_ref(),
+        day_periods.night1.as_ref(),
+        day_periods.night2.as_ref(),
+    ]
+    .map(|period| period.map(|p| &**p).unwrap_or_default());
+
+    Ok(LinearNamesV1 {
+        symbols: (&periods).into(),
+    })
+}
+
 fn eras_convert(
     datagen: &DatagenProvider,
     langid: &LanguageIdentifier,
@@ -763,6 +795,12 @@ impl_symbols_datagen!(
     NORMAL_KEY_LENGTHS,
     dayperiods_convert
 );
+impl_symbols_datagen!(
+    FlexibleDayPeriodNamesV1Marker,
+    "gregory",
+    NORMAL_KEY_LENGTHS,
+    flexible_dayperiods_convert
+);
 
 // Years
 impl_symbols_datagen!(
@@ -0,0 +1,272 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::DatagenProvider;
+use crate::provider::IterableDataProviderInternal;
+use icu_experimental::displaynames::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+
+impl DataProvider<CurrencyDisplayNamesV1Marker> for DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CurrencyDisplayNamesV1Marker>, DataError> {
+        self.check_req::<CurrencyDisplayNamesV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::currencies::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "currencies.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyDisplayNamesV1::from(data))),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<CurrencyDisplayNamesV1Marker> for DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .filter(|langid| {
+                // The directory might exist without currencies.json
+                self.cldr()
+                    .unwrap()
+                    .numbers()
+                    .file_exists(langid, "currencies.json")
+                    .unwrap_or_default()
+            })
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl From<&cldr_serde::currencies::Resource> for CurrencyDisplayNamesV1<'static> {
+    fn from(other: &cldr_serde::currencies::Resource) -> Self {
+        let mut names = BTreeMap::new();
+        let mut plural_names = BTreeMap::new();
+        let mut symbols = BTreeMap::new();
+        let mut narrow_symbols = BTreeMap::new();
+        for (&code, currency) in other.main.value.numbers.currencies.iter() {
+            if let Some(name) = &currency.display_name {
+                names.insert(code, name.as_str());
+            }
+            if let Some(symbol) = &currency.short {
+                symbols.insert(code, symbol.as_str());
+            }
+            if let Some(symbol) = &currency.narrow {
+                narrow_symbols.insert(code, symbol.as_str());
+            }
+
+            // Only store the plural names that differ from their fallback, which is the name
+            // for `other`, and for `other` the display name.
+            let other_name = currency
+                .display_name_other
+                .as_deref()
+                .or(currency.display_name.as_deref());
+            if let Some(other_name) = other_name {
+                if currency.display_name.as_deref() != Some(other_name) {
+                    plural_names.insert((code, PluralCategory::Other), other_name);
+                }
+            }
+            for (category, name) in [
+                (PluralCategory::Zero, &currency.display_name_zero),
+                (PluralCategory::One, &currency.display_name_one),
+                (PluralCategory::Two, &currency.display_name_two),
+                (PluralCategory::Few, &currency.display_name_few),
+                (PluralCategory::Many, &currency.display_name_many),
+            ] {
+                if let Some(name) = name.as_deref() {
+                    if Some(name) != other_name {
+                        plural_names.insert((code, category), name);
+                    }
+                }
+            }
+        }
+        Self {
+            names: names.into_iter().collect(),
+            plural_names: plural_names
+                .into_iter()
+                .map(|((code, category), name)| (code, category, name))
+                .collect(),
+            symbols: symbols.into_iter().collect(),
+            narrow_symbols: narrow_symbols.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::langid;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic_currency_display_names() {
+        let provider = DatagenProvider::new_testing();
+
+        let data: DataPayload<CurrencyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let usd = tinystr!(3, "USD").to_unvalidated();
+        assert_eq!(data.get().names.get(&usd).unwrap(), "US Dollar");
+        assert_eq!(
+            data.get()
+                .plural_names
+                .get_2d(&usd, &PluralCategory::One)
+                .unwrap(),
+            "US dollar"
+        );
+        assert_eq!(
+            data.get()
+                .plural_names
+                .get_2d(&usd, &PluralCategory::Other)
+                .unwrap(),
+            "US dollars"
+        );
+        assert_eq!(data.get().symbols.get(&usd).unwrap(), "$");
+        assert_eq!(data.get().narrow_symbols.get(&usd).unwrap(), "$");
+    }
+
+    #[test]
+    fn test_plural_names_are_deduplicated() {
+        let provider = DatagenProvider::new_testing();
+
+        let data: DataPayload<CurrencyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("ru").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        // "доллар США", "доллара США", "долларов США", "доллара США" for one, few, many, other
+        let usd = tinystr!(3, "USD").to_unvalidated();
+        let plural_names = &data.get().plural_names;
+        assert_eq!(
+            plural_names.get_2d(&usd, &PluralCategory::One).unwrap(),
+            "доллар США"
+        );
+        assert_eq!(plural_names.get_2d(&usd, &PluralCategory::Few), None);
+        assert_eq!(
+            plural_names.get_2d(&usd, &PluralCategory::Many).unwrap(),
+            "долларов США"
+        );
+        assert_eq!(
+            plural_names.get_2d(&usd, &PluralCategory::Other).unwrap(),
+            "доллара США"
+        );
+    }
+
+    #[test]
+    fn test_currency_display_names() {
+        use icu_experimental::dimension::currency::formatter::CurrencyCode;
+        use icu_experimental::displaynames::CurrencyDisplayNames;
+
+        let provider = DatagenProvider::new_testing();
+
+        let usd = CurrencyCode(tinystr!(3, "USD"));
+        let eur = CurrencyCode(tinystr!(3, "EUR"));
+        let jpy = CurrencyCode(tinystr!(3, "JPY"));
+
+        let en = CurrencyDisplayNames::try_new_unstable(&provider, &langid!("en").into()).unwrap();
+        assert_eq!(en.of(usd), Some("US Dollar"));
+        assert_eq!(en.plural_name(usd, PluralCategory::One), Some("US dollar"));
+        assert_eq!(
+            en.plural_name(usd, PluralCategory::Other),
+            Some("US dollars")
+        );
+        assert_eq!(en.of(eur), Some("Euro"));
+        assert_eq!(en.plural_name(eur, PluralCategory::One), Some("euro"));
+        assert_eq!(en.plural_name(eur, PluralCategory::Other), Some("euros"));
+        assert_eq!(en.of(jpy), Some("Japanese Yen"));
+        assert_eq!(
+            en.plural_name(jpy, PluralCategory::One),
+            Some("Japanese yen")
+        );
+        assert_eq!(
+            en.plural_name(jpy, PluralCategory::Other),
+            Some("Japanese yen")
+        );
+        // Categories that English does not use fall back to `other`
+        assert_eq!(en.plural_name(usd, PluralCategory::Few), Some("US dollars"));
+        assert_eq!(en.symbol(usd), Some("$"));
+        assert_eq!(en.narrow_symbol(usd), Some("$"));
+        assert_eq!(en.symbol(eur), Some("€"));
+        assert_eq!(en.symbol(jpy), Some("¥"));
+
+        // Unknown currencies have no names
+        let xxy = CurrencyCode(tinystr!(3, "XXY"));
+        assert_eq!(en.of(xxy), None);
+        assert_eq!(en.plural_name(xxy, PluralCategory::One), None);
+        assert_eq!(en.symbol(xxy), None);
+
+        let ru = CurrencyDisplayNames::try_new_unstable(&provider, &langid!("ru").into()).unwrap();
+        assert_eq!(ru.of(usd), Some("доллар США"));
+        assert_eq!(ru.plural_name(usd, PluralCategory::One), Some("доллар США"));
+        assert_eq!(
+            ru.plural_name(usd, PluralCategory::Few),
+            Some("доллара США")
+        );
+        assert_eq!(
+            ru.plural_name(usd, PluralCategory::Many),
+            Some("долларов США")
+        );
+        assert_eq!(
+            ru.plural_name(jpy, PluralCategory::Many),
+            Some("японских иен")
+        );
+        assert_eq!(
+            ru.plural_name(jpy, PluralCategory::Other),
+            Some("японской иены")
+        );
+        // No plural names in the data, so the display name is used
+        assert_eq!(ru.plural_name(eur, PluralCategory::Many), Some("евро"));
+
+        let ar = CurrencyDisplayNames::try_new_unstable(&provider, &langid!("ar").into()).unwrap();
+        assert_eq!(ar.of(usd), Some("دولار أمريكي"));
+        assert_eq!(
+            ar.plural_name(usd, PluralCategory::Few),
+            Some("دولار أمريكي")
+        );
+        assert_eq!(ar.plural_name(eur, PluralCategory::Many), Some("يورو"));
+        assert_eq!(ar.plural_name(jpy, PluralCategory::Two), Some("ين ياباني"));
+        assert_eq!(ar.symbol(usd), Some("US$"));
+        assert_eq!(ar.symbol(jpy), Some("JP¥"));
+
+        let egp = CurrencyCode(tinystr!(3, "EGP"));
+        assert_eq!(
+            ar.plural_name(egp, PluralCategory::Two),
+            Some("جنيهان مصريان")
+        );
+        assert_eq!(
+            ar.plural_name(egp, PluralCategory::Few),
+            Some("جنيهات مصرية")
+        );
+        assert_eq!(
+            ar.plural_name(egp, PluralCategory::Many),
+            Some("جنيهًا مصريًا")
+        );
+        assert_eq!(
+            ar.plural_name(egp, PluralCategory::Other),
+            Some("جنيه مصري")
+        );
+        assert_eq!(ar.symbol(egp), Some("ج.م.\u{200f}"));
+        assert_eq!(ar.narrow_symbol(egp), Some("E£"));
+    }
+}
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub(in crate::provider) mod currency;
 pub(in crate::provider) mod language;
 pub(in crate::provider) mod region;
 pub(in crate::provider) mod script;
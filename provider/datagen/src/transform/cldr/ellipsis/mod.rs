@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::HashSet;
+
+use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::DatagenProvider;
+use crate::provider::IterableDataProviderInternal;
+use icu_experimental::ellipsis::provider::*;
+use icu_provider::prelude::*;
+
+impl DataProvider<EllipsisPatternsV1Marker> for DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<EllipsisPatternsV1Marker>, DataError> {
+        self.check_req::<EllipsisPatternsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::exemplar_chars::Resource = self
+            .cldr()?
+            .misc()
+            .read_and_parse(&langid, "characters.json")?;
+
+        let ellipsis = resource
+            .main
+            .value
+            .characters
+            .ellipsis
+            .as_ref()
+            .ok_or_else(|| DataError::custom("Missing ellipsis patterns"))?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(EllipsisPatternsV1 {
+                start: parse_pattern(&ellipsis.initial, "", "{0}")?.into(),
+                middle: parse_pattern(&ellipsis.medial, "{0}", "{1}")?.into(),
+                end: parse_pattern(&ellipsis.final_, "{0}", "")?.into(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<EllipsisPatternsV1Marker> for DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .misc()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Returns the ellipsis of a pattern that starts with `prefix` and ends with `suffix`,
+/// such as "…" for the pattern "{0}…{1}".
+fn parse_pattern(pattern: &str, prefix: &str, suffix: &str) -> Result<String, DataError> {
+    pattern
+        .strip_prefix(prefix)
+        .and_then(|p| p.strip_suffix(suffix))
+        .filter(|ellipsis| !ellipsis.is_empty() && !ellipsis.contains(['{', '}']))
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| DataError::custom("Invalid ellipsis pattern").with_display_context(pattern))
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = DatagenProvider::new_testing();
+
+    let en: DataPayload<EllipsisPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        en.get(),
+        &EllipsisPatternsV1 {
+            start: "…".into(),
+            middle: "…".into(),
+            end: "…".into(),
+        }
+    );
+
+    let fr: DataPayload<EllipsisPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        fr.get(),
+        &EllipsisPatternsV1 {
+            start: "… ".into(),
+            middle: "… ".into(),
+            end: "…".into(),
+        }
+    );
+}
+
+#[test]
+fn test_ellipsizer() {
+    use icu_experimental::ellipsis::Ellipsizer;
+    use icu_locid::langid;
+
+    let provider = DatagenProvider::new_testing();
+
+    let ellipsizer = Ellipsizer::try_new_unstable(&provider, &langid!("fr").into()).unwrap();
+    assert_eq!(ellipsizer.truncate_end("Bonjour", 5), "Bonj…");
+    assert_eq!(ellipsizer.truncate_start("Bonjour", 5), "… our");
+    assert_eq!(ellipsizer.truncate_middle("Bonjour", 5), "Bo… r");
+}
+
+#[test]
+fn test_parse_pattern() {
+    assert_eq!(parse_pattern("{0} …", "{0}", "").unwrap(), " …");
+    assert!(parse_pattern("{0}…", "", "{0}").is_err());
+    assert!(parse_pattern("{0}{1}", "{0}", "{1}").is_err());
+}
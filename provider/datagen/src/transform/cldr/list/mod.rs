@@ -16,7 +16,11 @@ fn load<M: KeyedDataMarker<Yokeable = ListFormatterPatternsV1<'static>>>(
     selff: &DatagenProvider,
     req: DataRequest,
 ) -> Result<DataResponse<M>, DataError> {
-    let langid = req.locale.get_langid();
+    let pseudolocale = selff.pseudolocale(req.locale);
+    let langid = match pseudolocale {
+        Some(pseudolocale) => pseudolocale.base_langid(),
+        None => req.locale.get_langid(),
+    };
 
     let resource: &cldr_serde::list_patterns::Resource = selff
         .cldr()?
@@ -35,7 +39,7 @@ fn load<M: KeyedDataMarker<Yokeable = ListFormatterPatternsV1<'static>>>(
         return Err(DataError::custom("Unknown key for ListFormatterPatternsV1"));
     };
 
-    let mut patterns = ListFormatterPatternsV1::try_new([
+    let patterns = [
         &wide.start,
         &wide.middle,
         &wide.end,
@@ -48,7 +52,13 @@ fn load<M: KeyedDataMarker<Yokeable = ListFormatterPatternsV1<'static>>>(
         &narrow.middle,
         &narrow.end,
         &narrow.pair,
-    ])?;
+    ]
+    .map(|pattern| match pseudolocale {
+        Some(pseudolocale) => Cow::Owned(pseudolocale.transform(pattern)),
+        None => Cow::Borrowed(pattern.as_str()),
+    });
+
+    let mut patterns = ListFormatterPatternsV1::try_new(core::array::from_fn(|i| &*patterns[i]))?;
 
     if langid.language == language!("es") {
         if M::KEY == AndListV1Marker::KEY || M::KEY == UnitListV1Marker::KEY {
@@ -129,12 +139,13 @@ macro_rules! implement {
 
         impl IterableDataProviderInternal<$marker> for DatagenProvider {
             fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
-                Ok(self
-                    .cldr()?
-                    .misc()
-                    .list_langs()?
-                    .map(DataLocale::from)
-                    .collect())
+                Ok(self.add_pseudolocales(
+                    self.cldr()?
+                        .misc()
+                        .list_langs()?
+                        .map(DataLocale::from)
+                        .collect(),
+                ))
             }
         }
     };
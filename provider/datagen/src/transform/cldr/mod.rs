@@ -13,6 +13,8 @@ pub(in crate::provider) mod datetime;
 pub(in crate::provider) mod decimal;
 #[cfg(feature = "experimental_components")]
 pub(in crate::provider) mod displaynames;
+#[cfg(feature = "experimental_components")]
+pub(in crate::provider) mod ellipsis;
 pub(in crate::provider) mod fallback;
 pub(in crate::provider) mod list;
 pub(in crate::provider) mod locale_canonicalizer;
@@ -21,6 +23,7 @@ pub(in crate::provider) mod percent;
 #[cfg(feature = "experimental_components")]
 pub(in crate::provider) mod personnames;
 pub(in crate::provider) mod plurals;
+pub(in crate::provider) mod pseudo;
 #[cfg(feature = "experimental_components")]
 pub(in crate::provider) mod relativetime;
 pub(in crate::provider) mod source;
@@ -3,11 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use crate::provider::transform::cldr::cldr_serde;
 use crate::provider::DatagenProvider;
 use crate::provider::IterableDataProviderInternal;
+use icu_locid::LanguageIdentifier;
 use icu_plurals::rules::runtime::ast::Rule;
 use icu_plurals::{provider::*, PluralCategory};
 use icu_provider::prelude::*;
@@ -35,6 +37,88 @@ impl DatagenProvider {
         .ok_or(DataError::custom("Unknown key for PluralRulesV1"))
     }
 
+    fn get_rules_overrides_for(&self, key: DataKey) -> Option<&cldr_serde::plurals::Rules> {
+        if key == CardinalV1Marker::KEY {
+            self.source.cardinal_rules_overrides.as_deref()
+        } else if key == OrdinalV1Marker::KEY {
+            self.source.ordinal_rules_overrides.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rules for the locale, with any overrides merged over the CLDR rules.
+    fn get_locale_rules_for(
+        &self,
+        key: DataKey,
+        langid: &LanguageIdentifier,
+    ) -> Result<cldr_serde::plurals::LocalePluralRules, DataError> {
+        let cldr = self.get_rules_for(key)?.0.get(langid);
+        let Some(overrides) = self
+            .get_rules_overrides_for(key)
+            .and_then(|o| o.0.get(langid))
+        else {
+            return cldr
+                .cloned()
+                .ok_or(DataErrorKind::MissingLocale.into_error());
+        };
+        log::info!("Using plural rule overrides for {langid} ({key})");
+        let cldr = cldr.cloned().unwrap_or_default();
+        Ok(cldr_serde::plurals::LocalePluralRules {
+            zero: overrides.zero.clone().or(cldr.zero),
+            one: overrides.one.clone().or(cldr.one),
+            two: overrides.two.clone().or(cldr.two),
+            few: overrides.few.clone().or(cldr.few),
+            many: overrides.many.clone().or(cldr.many),
+        })
+    }
+
+    /// Parses a JSON file of plural rule overrides, mapping locales to plural categories to
+    /// rule strings.
+    pub(in crate::provider) fn parse_plural_rules_overrides(
+        bytes: &[u8],
+    ) -> Result<cldr_serde::plurals::Rules, DataError> {
+        let overrides: HashMap<LanguageIdentifier, BTreeMap<String, String>> =
+            serde_json::from_slice(bytes).map_err(|e| {
+                DataError::custom("Plural rule overrides parse error").with_display_context(&e)
+            })?;
+        overrides
+            .into_iter()
+            .map(|(langid, categories)| {
+                let mut rules = cldr_serde::plurals::LocalePluralRules::default();
+                for (category, rule) in categories {
+                    let invalid = |message| {
+                        DataError::custom(message)
+                            .with_display_context(&format!("{langid}: {category}: {rule}"))
+                    };
+                    let slot = match PluralCategory::get_for_cldr_string(&category) {
+                        Some(PluralCategory::Zero) => &mut rules.zero,
+                        Some(PluralCategory::One) => &mut rules.one,
+                        Some(PluralCategory::Two) => &mut rules.two,
+                        Some(PluralCategory::Few) => &mut rules.few,
+                        Some(PluralCategory::Many) => &mut rules.many,
+                        Some(PluralCategory::Other) => {
+                            // `other` is implicit, so it cannot have a condition
+                            if !rule.split('@').next().unwrap_or_default().trim().is_empty() {
+                                return Err(invalid(
+                                    "The other plural category cannot have a condition",
+                                ));
+                            }
+                            continue;
+                        }
+                        None => return Err(invalid("Unknown plural category")),
+                    };
+                    if rule.parse::<Rule>().is_err() {
+                        return Err(invalid("Invalid plural rule"));
+                    }
+                    *slot = Some(rule);
+                }
+                Ok((langid, rules))
+            })
+            .collect::<Result<_, _>>()
+            .map(cldr_serde::plurals::Rules)
+    }
+
     fn get_plural_ranges(&self) -> Result<&cldr_serde::plural_ranges::PluralRanges, DataError> {
         Ok(&self
             .cldr()?
@@ -55,10 +139,7 @@ macro_rules! implement {
                 Ok(DataResponse {
                     metadata: Default::default(),
                     payload: Some(DataPayload::from_owned(PluralRulesV1::from(
-                        self.get_rules_for(<$marker>::KEY)?
-                            .0
-                            .get(&req.locale.get_langid())
-                            .ok_or(DataErrorKind::MissingLocale.into_error())?,
+                        &self.get_locale_rules_for(<$marker>::KEY, &req.locale.get_langid())?,
                     ))),
                 })
             }
@@ -70,6 +151,11 @@ macro_rules! implement {
                     .get_rules_for(<$marker>::KEY)?
                     .0
                     .keys()
+                    .chain(
+                        self.get_rules_overrides_for(<$marker>::KEY)
+                            .into_iter()
+                            .flat_map(|o| o.0.keys()),
+                    )
                     .map(DataLocale::from)
                     .collect())
             }
@@ -271,3 +357,90 @@ fn test_ranges() {
         ))
         .is_none());
 }
+
+#[test]
+fn test_rules_overrides() {
+    use icu_locid::langid;
+    use icu_plurals::{PluralRuleType, PluralRules};
+
+    let path = std::env::temp_dir().join("icu4x_datagen_test_rules_overrides.json");
+    std::fs::write(&path, r#"{ "en": { "two": "n = 2", "other": "" } }"#).unwrap();
+
+    let provider = DatagenProvider::new_testing()
+        .with_plural_rules_overrides(PluralRuleType::Cardinal, path)
+        .unwrap();
+
+    let en_rules: DataPayload<CardinalV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        Some("n = 2".parse().expect("Failed to parse rule")),
+        en_rules.get().two
+    );
+
+    let rules = PluralRules::try_new_cardinal_unstable(&provider, &langid!("en").into()).unwrap();
+    assert_eq!(rules.category_for(1), PluralCategory::One);
+    assert_eq!(rules.category_for(2), PluralCategory::Two);
+    assert_eq!(rules.category_for(3), PluralCategory::Other);
+
+    // Ordinal rules are unaffected
+    let en_ordinals: DataPayload<OrdinalV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        Some(
+            "n % 10 = 2 and n % 100 != 12"
+                .parse()
+                .expect("Failed to parse rule")
+        ),
+        en_ordinals.get().two
+    );
+}
+
+#[test]
+fn test_rules_overrides_invalid() {
+    for overrides in [
+        r#"{ "en": { "two": "n = " } }"#,
+        r#"{ "en": { "other": "n = 2" } }"#,
+        r#"{ "en": { "three": "n = 3" } }"#,
+    ] {
+        assert!(
+            DatagenProvider::parse_plural_rules_overrides(overrides.as_bytes()).is_err(),
+            "{overrides}"
+        );
+    }
+}
+
+#[cfg(feature = "experimental_components")]
+#[test]
+fn test_raw_rules_parse_cldr() {
+    use icu_plurals::rules::raw::PluralRule;
+
+    let provider = DatagenProvider::new_testing();
+
+    for key in [CardinalV1Marker::KEY, OrdinalV1Marker::KEY] {
+        let rules = provider.get_rules_for(key).unwrap();
+        assert!(rules.0.len() > 100, "{key}");
+        for (langid, rules) in &rules.0 {
+            for rule in [&rules.zero, &rules.one, &rules.two, &rules.few, &rules.many]
+                .into_iter()
+                .flatten()
+            {
+                if let Err(e) = PluralRule::parse(rule) {
+                    panic!("{langid} ({key}): {rule}: {e}");
+                }
+            }
+        }
+    }
+}
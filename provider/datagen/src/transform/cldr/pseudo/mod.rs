@@ -0,0 +1,262 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Mechanical pseudo-localization of CLDR strings.
+//!
+//! Pseudo-locales are derived from the `en` data:
+//! * `en-XA` replaces ASCII letters with accented variants and pads every string to test
+//!   UI expansion.
+//! * `ar-XB` wraps every run of text in a right-to-left override to test RTL handling.
+//!
+//! Placeholders such as `{0}` are retained verbatim, so that patterns remain valid after the
+//! transformation. In datetime patterns, only quoted literal text is transformed, as all
+//! unquoted letters are field symbols.
+
+use crate::provider::DatagenProvider;
+use icu_locid::{langid, LanguageIdentifier};
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+
+/// Filler text used to pad `en-XA` strings.
+const PADDING: &str = " one two three four five six seven eight nine ten";
+
+const RIGHT_TO_LEFT_OVERRIDE: char = '\u{202E}';
+const POP_DIRECTIONAL_FORMATTING: char = '\u{202C}';
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(in crate::provider) enum PseudoLocale {
+    /// `en-XA`: accented and elongated
+    Accented { expansion_percent: u16 },
+    /// `ar-XB`: mirrored with bidi overrides
+    Bidi,
+}
+
+impl PseudoLocale {
+    /// The locale from which pseudo-locale data is derived.
+    pub(in crate::provider) fn base_langid(self) -> LanguageIdentifier {
+        langid!("en")
+    }
+
+    /// Transforms all text in the pattern, retaining `{n}` placeholders verbatim.
+    pub(in crate::provider) fn transform(self, pattern: &str) -> String {
+        let mut segments = Vec::new();
+        let mut rest = pattern;
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}').map(|i| i + 1) else {
+                break;
+            };
+            segments.push((&rest[..start], &rest[start..start + len]));
+            rest = &rest[start + len..];
+        }
+
+        let text_len: usize = segments
+            .iter()
+            .map(|(text, _)| text.chars().count())
+            .sum::<usize>()
+            + rest.chars().count();
+
+        let mut out = String::with_capacity(pattern.len() * 2);
+        match self {
+            Self::Accented { expansion_percent } => {
+                for (text, placeholder) in &segments {
+                    out.extend(text.chars().map(accent));
+                    out.push_str(placeholder);
+                }
+                out.extend(rest.chars().map(accent));
+                if text_len > 0 {
+                    // Round up, so that every nonempty string grows
+                    let padding = (text_len * expansion_percent as usize + 99) / 100;
+                    let insert_at = if rest.is_empty() {
+                        // Pad the last text segment, so that a trailing placeholder stays last
+                        out.len() - segments.last().map(|(_, p)| p.len()).unwrap_or_default()
+                    } else {
+                        out.len()
+                    };
+                    out.insert_str(
+                        insert_at,
+                        &PADDING.chars().cycle().take(padding).collect::<String>(),
+                    );
+                }
+            }
+            Self::Bidi => {
+                let push_text = |out: &mut String, text: &str| {
+                    if !text.trim().is_empty() {
+                        out.push(RIGHT_TO_LEFT_OVERRIDE);
+                        out.push_str(text);
+                        out.push(POP_DIRECTIONAL_FORMATTING);
+                    } else {
+                        out.push_str(text);
+                    }
+                };
+                for (text, placeholder) in &segments {
+                    push_text(&mut out, text);
+                    out.push_str(placeholder);
+                }
+                push_text(&mut out, rest);
+            }
+        }
+        out
+    }
+
+    /// Transforms the quoted literal text in a datetime pattern, retaining field symbols
+    /// and `{n}` placeholders verbatim.
+    pub(in crate::provider) fn transform_datetime_pattern(self, pattern: &str) -> String {
+        let mut out = String::with_capacity(pattern.len() * 2);
+        let mut rest = pattern;
+        while let Some(start) = rest.find('\'') {
+            out.push_str(&rest[..=start]);
+            rest = &rest[start + 1..];
+            // Find the closing quote, skipping escaped quotes (`''`) inside the literal
+            let mut end = 0;
+            while let Some(i) = rest[end..].find('\'') {
+                if end + i > 0 && rest[end + i + 1..].starts_with('\'') {
+                    end += i + 2;
+                } else {
+                    end += i;
+                    break;
+                }
+            }
+            if !rest[end..].starts_with('\'') {
+                // Unterminated literal, leave as is
+                break;
+            }
+            out.push_str(&self.transform(&rest[..end]));
+            out.push('\'');
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    }
+}
+
+impl DatagenProvider {
+    /// Returns the pseudo-locale for the given locale, if pseudo-locales are enabled.
+    pub(in crate::provider) fn pseudolocale(&self, locale: &DataLocale) -> Option<PseudoLocale> {
+        let expansion_percent = self.source.pseudolocale_expansion?;
+        let langid = locale.get_langid();
+        if langid == langid!("en-XA") {
+            Some(PseudoLocale::Accented { expansion_percent })
+        } else if langid == langid!("ar-XB") {
+            Some(PseudoLocale::Bidi)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the pseudo-locales to a set of supported locales, if pseudo-locales are enabled
+    /// and the base locale is supported.
+    pub(in crate::provider) fn add_pseudolocales(
+        &self,
+        mut locales: HashSet<DataLocale>,
+    ) -> HashSet<DataLocale> {
+        if self.source.pseudolocale_expansion.is_some()
+            && locales.contains(&DataLocale::from(langid!("en")))
+        {
+            locales.insert(langid!("en-XA").into());
+            locales.insert(langid!("ar-XB").into());
+        }
+        locales
+    }
+}
+
+#[test]
+fn test_transform() {
+    let accented = PseudoLocale::Accented {
+        expansion_percent: 50,
+    };
+    assert_eq!(accented.transform("in {0} days"), "îñ {0} ðáýš one");
+    assert_eq!(accented.transform("{0}, {1}"), "{0},  {1}");
+    assert_eq!(accented.transform("{0}"), "{0}");
+    assert_eq!(accented.transform(""), "");
+
+    assert_eq!(
+        PseudoLocale::Bidi.transform("in {0} days"),
+        "\u{202E}in \u{202C}{0}\u{202E} days\u{202C}"
+    );
+    assert_eq!(PseudoLocale::Bidi.transform("{0} {1}"), "{0} {1}");
+}
+
+#[test]
+fn test_transform_datetime_pattern() {
+    let accented = PseudoLocale::Accented {
+        expansion_percent: 50,
+    };
+    assert_eq!(
+        accented.transform_datetime_pattern("{1} 'at' {0}"),
+        "{1} 'áţ ' {0}"
+    );
+    assert_eq!(accented.transform_datetime_pattern("h:mm a"), "h:mm a");
+    assert_eq!(
+        accented.transform_datetime_pattern("h 'o''clock' a"),
+        "h 'ö''çļöçķ one' a"
+    );
+    assert_eq!(accented.transform_datetime_pattern("h''mm"), "h''mm");
+
+    assert_eq!(
+        PseudoLocale::Bidi.transform_datetime_pattern("{1} 'at' {0}"),
+        "{1} '\u{202E}at\u{202C}' {0}"
+    );
+    assert_eq!(
+        PseudoLocale::Bidi.transform_datetime_pattern("EEEE, MMMM d"),
+        "EEEE, MMMM d"
+    );
+}
@@ -5,9 +5,12 @@
 use std::borrow::Borrow;
 
 use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::transform::cldr::pseudo::PseudoLocale;
 use crate::provider::DatagenProvider;
 use crate::provider::IterableDataProviderInternal;
 use icu_experimental::relativetime::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_plurals::{PluralCategory, PluralRules};
 use icu_provider::prelude::*;
 use once_cell::sync::OnceCell;
 use std::collections::{BTreeMap, HashMap, HashSet};
@@ -75,7 +78,11 @@ macro_rules! make_data_provider {
             impl DataProvider<$marker> for DatagenProvider {
                 fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
                     self.check_req::<$marker>(req)?;
-                    let langid = req.locale.get_langid();
+                    let pseudolocale = self.pseudolocale(req.locale);
+                    let langid = match pseudolocale {
+                        Some(pseudolocale) => pseudolocale.base_langid(),
+                        None => req.locale.get_langid(),
+                    };
                     let resource: &cldr_serde::date_fields::Resource = self
                         .cldr()?
                         .dates("gregorian")
@@ -90,21 +97,34 @@ macro_rules! make_data_provider {
                         "Field not found in relative time format data.",
                     ))?;
 
+                    let data = match pseudolocale {
+                        Some(pseudolocale) => (&pseudolocalize(data, pseudolocale)).try_into()?,
+                        None => data.try_into()?,
+                    };
+
+                    for gap in self.plural_coverage_gaps(&langid, &data) {
+                        log::warn!(
+                            "Relative time data falls back to `other`: {}",
+                            gap.to_json($marker::KEY)
+                        );
+                    }
+
                     Ok(DataResponse {
                         metadata: Default::default(),
-                        payload: Some(DataPayload::from_owned(data.try_into()?)),
+                        payload: Some(DataPayload::from_owned(data)),
                     })
                 }
             }
 
             impl IterableDataProviderInternal<$marker> for DatagenProvider {
                 fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
-                    Ok(self
-                        .cldr()?
-                        .dates("gregorian")
-                        .list_langs()?
-                        .map(DataLocale::from)
-                        .collect())
+                    Ok(self.add_pseudolocales(
+                        self.cldr()?
+                            .dates("gregorian")
+                            .list_langs()?
+                            .map(DataLocale::from)
+                            .collect(),
+                    ))
                 }
             }
         )+
@@ -126,6 +146,111 @@ impl TryFrom<&cldr_serde::date_fields::Field> for RelativeTimePatternDataV1<'_>
     }
 }
 
+/// Plural categories of a locale for which the relative time data of one tense only has the
+/// `other` pattern, which is likely a gap in CLDR.
+#[derive(Debug, PartialEq)]
+struct PluralCoverageGap {
+    langid: LanguageIdentifier,
+    tense: &'static str,
+    missing: Vec<PluralCategory>,
+}
+
+impl PluralCoverageGap {
+    /// A machine-readable description of the gap, for filing CLDR bugs.
+    fn to_json(&self, key: DataKey) -> serde_json::Value {
+        serde_json::json!({
+            "key": key.path().get(),
+            "locale": self.langid.to_string(),
+            "tense": self.tense,
+            "missing": self.missing.iter().map(|c| format!("{c:?}").to_lowercase()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl DatagenProvider {
+    /// Finds the tenses of `data` that only have the `other` pattern, even though the plural
+    /// rules of the locale have more categories.
+    ///
+    /// Locales without plural rules are not audited.
+    fn plural_coverage_gaps(
+        &self,
+        langid: &LanguageIdentifier,
+        data: &RelativeTimePatternDataV1,
+    ) -> Vec<PluralCoverageGap> {
+        // The plural rules are only available for some regional variants
+        let Ok(rules) =
+            PluralRules::try_new_cardinal_unstable(self, &langid.into()).or_else(|_| {
+                PluralRules::try_new_cardinal_unstable(
+                    self,
+                    &LanguageIdentifier::from(langid.language).into(),
+                )
+            })
+        else {
+            return Vec::new();
+        };
+        [("past", &data.past), ("future", &data.future)]
+            .into_iter()
+            .filter_map(|(tense, mapping)| {
+                let PluralRulesCategoryMapping {
+                    zero: None,
+                    one: None,
+                    two: None,
+                    few: None,
+                    many: None,
+                    ..
+                } = mapping
+                else {
+                    return None;
+                };
+                let missing: Vec<_> = rules
+                    .categories()
+                    .filter(|c| *c != PluralCategory::Other)
+                    .collect();
+                (!missing.is_empty()).then(|| PluralCoverageGap {
+                    langid: langid.clone(),
+                    tense,
+                    missing,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Applies a pseudo-locale transform to all patterns in the field.
+fn pseudolocalize(
+    field: &cldr_serde::date_fields::Field,
+    pseudolocale: PseudoLocale,
+) -> cldr_serde::date_fields::Field {
+    let convert = |pattern: &cldr_serde::date_fields::PluralRulesPattern| {
+        let transform = |s: &Option<String>| s.as_deref().map(|s| pseudolocale.transform(s));
+        cldr_serde::date_fields::PluralRulesPattern {
+            zero: transform(&pattern.zero),
+            one: transform(&pattern.one),
+            two: transform(&pattern.two),
+            few: transform(&pattern.few),
+            many: transform(&pattern.many),
+            other: pseudolocale.transform(&pattern.other),
+        }
+    };
+    cldr_serde::date_fields::Field {
+        _display_name: pseudolocale.transform(&field._display_name),
+        _relative_period: field
+            ._relative_period
+            .as_deref()
+            .map(|s| pseudolocale.transform(s)),
+        relatives: field
+            .relatives
+            .iter()
+            .map(|relative| cldr_serde::date_fields::Relative {
+                count: relative.count,
+                pattern: pseudolocale.transform(&relative.pattern),
+            })
+            .collect(),
+        past: convert(&field.past),
+        future: convert(&field.future),
+    }
+}
+
 /// Try to convert an `Option<String>` to [`SingularSubPattern`].
 /// If pattern is `None`, we return `None`
 /// If pattern is `Some(pattern)`, we try to parse the pattern as [`SingularSubPattern`] failing
@@ -208,6 +333,61 @@ mod tests {
         assert_eq!(data.get().future.one.as_ref().unwrap().index, 3u8);
     }
 
+    #[test]
+    fn test_pseudolocales() {
+        let provider = DatagenProvider::new_testing().with_pseudolocales(40);
+        let en: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let en_xa: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("en-XA").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let en_future = &en.get().future.other;
+        let en_xa_future = &en_xa.get().future.other;
+        assert_eq!(en_future.pattern, "in  days");
+        assert_eq!(en_xa_future.pattern, "îñ  ðáýš one");
+        // The placeholder is still in front of the (accented) unit
+        assert_eq!(&en_xa_future.pattern[..en_xa_future.index as usize], "îñ ");
+        assert!(
+            en_xa_future.pattern.chars().count() * 100 >= en_future.pattern.chars().count() * 140
+        );
+
+        let ar_xb: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("ar-XB").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let ar_xb_future = &ar_xb.get().future.other;
+        assert_eq!(
+            &ar_xb_future.pattern[..ar_xb_future.index as usize],
+            "\u{202E}in \u{202C}"
+        );
+
+        // Pseudo-locales are opt-in
+        assert!(DataProvider::<LongDayRelativeTimeFormatDataV1Marker>::load(
+            &DatagenProvider::new_testing(),
+            DataRequest {
+                locale: &langid!("en-XA").into(),
+                metadata: Default::default(),
+            }
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_singular_sub_pattern() {
         let provider = DatagenProvider::new_testing();
@@ -238,4 +418,72 @@ mod tests {
         assert_eq!(data.get().future.other.pattern, "خلال  سنة");
         assert_eq!(data.get().future.other.index, 9u8);
     }
+
+    #[test]
+    fn test_plural_coverage_gaps() {
+        let provider = DatagenProvider::new_testing();
+        for langid in [
+            langid!("ar"),
+            langid!("en"),
+            langid!("fr"),
+            langid!("ru"),
+            langid!("sr"),
+        ] {
+            let data: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+                .load(DataRequest {
+                    locale: &(&langid).into(),
+                    metadata: Default::default(),
+                })
+                .unwrap()
+                .take_payload()
+                .unwrap();
+            assert_eq!(provider.plural_coverage_gaps(&langid, data.get()), vec![]);
+        }
+
+        let ru: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("ru").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let mut data = ru.get().clone();
+        data.future.one = None;
+        data.future.few = None;
+        data.future.many = None;
+        let gaps = provider.plural_coverage_gaps(&langid!("ru"), &data);
+        assert_eq!(
+            gaps,
+            vec![PluralCoverageGap {
+                langid: langid!("ru"),
+                tense: "future",
+                missing: vec![
+                    PluralCategory::One,
+                    PluralCategory::Few,
+                    PluralCategory::Many
+                ],
+            }]
+        );
+        assert_eq!(
+            gaps[0]
+                .to_json(LongDayRelativeTimeFormatDataV1Marker::KEY)
+                .to_string(),
+            r#"{"key":"relativetime/long/day@1","locale":"ru","missing":["one","few","many"],"tense":"future"}"#
+        );
+
+        // Japanese only has `other`, so nothing is missing
+        let ja: DataPayload<LongDayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid!("ja").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            provider.plural_coverage_gaps(&langid!("ja"), ja.get()),
+            vec![]
+        );
+    }
 }
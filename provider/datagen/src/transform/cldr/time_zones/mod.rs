@@ -16,7 +16,7 @@ use icu_timezone::provider::*;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
-mod convert;
+pub(in crate::provider) mod convert;
 mod names;
 
 #[derive(Debug, Copy, Clone)]
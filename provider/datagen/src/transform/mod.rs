@@ -5,6 +5,8 @@
 pub(in crate::provider) mod cldr;
 pub(in crate::provider) mod icuexport;
 pub(in crate::provider) mod segmenter;
+#[cfg(feature = "experimental_components")]
+pub(in crate::provider) mod tzdb;
 
 use crate::provider::DatagenProvider;
 use icu_provider::datagen::*;
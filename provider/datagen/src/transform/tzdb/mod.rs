@@ -0,0 +1,260 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by compiled time zone database (TZif) files.
+
+use crate::provider::transform::cldr::cldr_serde;
+use crate::provider::transform::cldr::time_zones::convert::compute_canonical_tzids_btreemap;
+use crate::provider::DatagenProvider;
+use icu_experimental::timezone_picker::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use tzif::data::posix::{PosixTzString, TransitionDate, TransitionDay};
+use zerovec::ZeroMap2d;
+
+/// The first year of the offset periods. Earlier years are left out to keep the data small.
+const FIRST_YEAR: i64 = 2010;
+
+/// The last year of the offset periods. The last offset of a time zone is also used after
+/// this year.
+const LAST_YEAR: i64 = 2035;
+
+/// The offset changes of a time zone, as pairs of the UTC time of the change in seconds
+/// since the unix epoch and the new offset in seconds. The first entry starts at `i64::MIN`.
+///
+/// Changes derived from the POSIX TZ string are only computed from the year before
+/// [`FIRST_YEAR`] through [`LAST_YEAR`], which determines the offsets in those years.
+#[derive(Debug)]
+struct OffsetChanges(Vec<(i64, i32)>);
+
+impl OffsetChanges {
+    /// Parses a TZif file, see RFC 8536.
+    fn parse(bytes: &[u8]) -> Result<Self, DataError> {
+        let invalid = || DataError::custom("Invalid TZif file");
+
+        let tzif = tzif::parse_tzif_bytes(bytes).map_err(|e| invalid().with_display_context(&e))?;
+
+        // Version 2 and later files repeat the data block with 64-bit times.
+        let block = tzif.data_block2.as_ref().unwrap_or(&tzif.data_block1);
+        let offset = |ty: usize| {
+            block
+                .local_time_type_records
+                .get(ty)
+                .map(|record| record.utoff.0 as i32)
+                .ok_or_else(invalid)
+        };
+
+        // Local time type 0 applies before the first transition.
+        let mut changes = Self(vec![(i64::MIN, offset(0)?)]);
+        for (time, &ty) in block.transition_times.iter().zip(&block.transition_types) {
+            changes.push(time.0, offset(ty)?);
+        }
+
+        // The footer describes the transitions after the last one in the data block.
+        if let Some(PosixTzString {
+            std_info,
+            dst_info: Some(dst_info),
+        }) = &tzif.footer
+        {
+            let last = block.transition_times.last().map_or(i64::MIN, |t| t.0);
+            // POSIX offsets are positive west of Greenwich.
+            let std_offset = -std_info.offset.0;
+            let dst_offset = -dst_info.variant_info.offset.0;
+            let mut footer_changes = Vec::new();
+            for year in FIRST_YEAR - 1..=LAST_YEAR {
+                // The start date is given in standard time, the end date in daylight time.
+                footer_changes.push((
+                    transition_time(year, &dst_info.start_date) - std_offset,
+                    dst_offset as i32,
+                ));
+                footer_changes.push((
+                    transition_time(year, &dst_info.end_date) - dst_offset,
+                    std_offset as i32,
+                ));
+            }
+            // Stable, so that a change at the same time as the previous year's last change wins.
+            footer_changes.sort_by_key(|&(time, _)| time);
+            for (time, offset) in footer_changes {
+                if time > last {
+                    changes.push(time, offset);
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Adds a change, replacing a change at the same time and skipping changes to the current offset.
+    fn push(&mut self, time: i64, offset: i32) {
+        if self.0.last().map(|&(t, _)| t) == Some(time) {
+            self.0.pop();
+        }
+        if self.0.last().map(|&(_, o)| o) != Some(offset) {
+            self.0.push((time, offset));
+        }
+    }
+}
+
+/// The local time of a POSIX TZ transition date in `year`, as seconds since the unix epoch.
+fn transition_time(year: i64, date: &TransitionDate) -> i64 {
+    let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let day = match date.day {
+        // Jn, 1-based, never counting February 29
+        TransitionDay::NoLeap(n) => {
+            let n = i64::from(n);
+            days_since_epoch(year, 1, 1) + n - 1 + i64::from(is_leap && n >= 60)
+        }
+        // n, 0-based, counting February 29
+        TransitionDay::WithLeap(n) => days_since_epoch(year, 1, 1) + i64::from(n),
+        // Mm.w.d, day d (0 is Sunday) of week w (5 is the last) of month m
+        TransitionDay::Mwd(month, week, weekday) => {
+            let first = days_since_epoch(year, month.into(), 1);
+            let next_month = days_since_epoch(year, i64::from(month) + 1, 1);
+            // 1970-01-01 was a Thursday.
+            let mut day = first
+                + (i64::from(weekday) - (first + 4)).rem_euclid(7)
+                + 7 * (i64::from(week) - 1);
+            while day >= next_month {
+                day -= 7;
+            }
+            day
+        }
+    };
+    day * 86400 + date.time.0
+}
+
+/// The number of days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+/// A `month` of 13 is January of the next year.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    // Count years from March, so that leap days come at the end of a year.
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+impl DataProvider<ZoneOffsetPeriodV1Marker> for DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ZoneOffsetPeriodV1Marker>, DataError> {
+        self.check_req::<ZoneOffsetPeriodV1Marker>(req)?;
+
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+
+        let mut offsets = ZeroMap2d::new();
+        for (bcp47, iana) in compute_canonical_tzids_btreemap(&resource.keyword.u.time_zones.values)
+        {
+            // Some time zones, such as `unk`, are not in the time zone database.
+            if !self.tzdb()?.file_exists(&iana)? {
+                continue;
+            }
+            let changes = &self.tzdb()?.read_and_parse(&iana, OffsetChanges::parse)?.0;
+
+            // Periods are keyed by minutes since the unix epoch, starting with the
+            // offset in effect at the start of `FIRST_YEAR`.
+            let first = days_since_epoch(FIRST_YEAR, 1, 1) * 86400;
+            let end = days_since_epoch(LAST_YEAR + 1, 1, 1) * 86400;
+            let start = changes.partition_point(|&(time, _)| time <= first) - 1;
+            offsets.insert(&bcp47, &((first / 60) as i32), &changes[start].1);
+            for &(time, offset) in changes[start + 1..]
+                .iter()
+                .take_while(|&&(time, _)| time < end)
+            {
+                offsets.insert(&bcp47, &((time / 60) as i32), &offset);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ZoneOffsetPeriodV1(offsets))),
+        })
+    }
+}
+
+impl IterableDataProvider<ZoneOffsetPeriodV1Marker> for DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_timezone::TimeZoneBcp47Id;
+    use tinystr::tinystr;
+    use zerovec::ule::AsULE;
+
+    #[test]
+    fn test_offset_periods() {
+        let provider = DatagenProvider::new_testing();
+
+        let offsets: DataPayload<ZoneOffsetPeriodV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let berlin = TimeZoneBcp47Id(tinystr!(8, "deber"));
+        // CET at 2010-01-01T00:00Z, the start of the data
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&berlin, &21038400),
+            Some(3600)
+        );
+        // The test data only has explicit transitions up to 1996; later ones
+        // come from the POSIX TZ string.
+        // CEST from 2024-03-31T01:00Z
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&berlin, &28530780),
+            Some(7200)
+        );
+        // CET from 2024-10-27T01:00Z
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&berlin, &28833180),
+            Some(3600)
+        );
+        // CET from 2035-10-28T01:00Z, the last change
+        let (&last, offset) = offsets
+            .get()
+            .0
+            .get0(&berlin)
+            .unwrap()
+            .iter1_copied()
+            .last()
+            .unwrap();
+        assert_eq!((i32::from_unaligned(last), offset), (34619100, 3600));
+
+        // Daylight time at the start of the year in the southern hemisphere
+        let sydney = TimeZoneBcp47Id(tinystr!(8, "ausyd"));
+        // AEST from 2024-04-06T16:00Z
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&sydney, &28540320),
+            Some(36000)
+        );
+        // AEDT from 2024-10-05T16:00Z
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&sydney, &28802400),
+            Some(39600)
+        );
+
+        // IST since before the start of the data
+        let kolkata = TimeZoneBcp47Id(tinystr!(8, "inccu"));
+        assert_eq!(
+            offsets.get().0.get_copied_2d(&kolkata, &21038400),
+            Some(19800)
+        );
+        assert_eq!(offsets.get().0.get0(&kolkata).unwrap().iter1().count(), 1);
+
+        // Not in the time zone database
+        assert!(offsets
+            .get()
+            .0
+            .get0(&TimeZoneBcp47Id(tinystr!(8, "unk")))
+            .is_none());
+    }
+}
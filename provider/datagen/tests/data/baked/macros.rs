@@ -2105,6 +2105,13 @@ pub use __impl_time_zone_metazone_period_v1 as impl_time_zone_metazone_period_v1
 #[doc(inline)]
 pub use __impliterable_time_zone_metazone_period_v1 as impliterable_time_zone_metazone_period_v1;
 #[macro_use]
+#[path = "macros/time_zone_offset_period_v1.rs.data"]
+mod time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impl_time_zone_offset_period_v1 as impl_time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impliterable_time_zone_offset_period_v1 as impliterable_time_zone_offset_period_v1;
+#[macro_use]
 #[path = "macros/time_zone_specific_long_v1.rs.data"]
 mod time_zone_specific_long_v1;
 #[doc(inline)]
@@ -918,3 +918,50 @@ fn explicit_preresolved_empty() {
     // Should return the exact explicit locales set.
     assert_eq!(exported.keys().collect::<Vec<_>>(), locales);
 }
+
+#[test]
+#[cfg(all(feature = "blob_exporter", feature = "provider"))]
+fn data_version() {
+    use icu::locid::langid;
+    use icu::locid_transform::fallback::LocaleFallbacker;
+    use icu_provider::buf::BufferFormat;
+    use icu_provider_adapters::fallback::LocaleFallbackProvider;
+    use icu_provider_blob::export::BlobExporter;
+    use icu_provider_blob::BlobDataProvider;
+
+    let mut blob = Vec::new();
+    DatagenDriver::new()
+        .with_keys([HelloWorldV1Marker::KEY])
+        .with_locales_and_fallback([LocaleFamily::FULL], Default::default())
+        .with_data_version("CLDR 45.0.0, ICU4X 1.5.0")
+        .export(
+            &TestingProvider::with_decimal_symbol_like_data(),
+            BlobExporter::new_v3_with_sink(Box::new(&mut blob)),
+        )
+        .unwrap();
+
+    let provider = BlobDataProvider::try_new_from_blob(blob.into()).unwrap();
+    assert_eq!(provider.data_version(), Some("CLDR 45.0.0, ICU4X 1.5.0"));
+    let checksum = provider.checksum();
+    assert!(checksum.is_some());
+
+    let provider = LocaleFallbackProvider::new_with_fallbacker(
+        provider,
+        LocaleFallbacker::new().static_to_owned(),
+    );
+    let response = provider
+        .load_buffer(
+            HelloWorldV1Marker::KEY,
+            DataRequest {
+                locale: &langid!("ja-JP").into(),
+                metadata: Default::default(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.metadata.locale, Some(langid!("ja").into()));
+    assert_eq!(
+        response.metadata.buffer_format,
+        Some(BufferFormat::Postcard1)
+    );
+    assert_eq!(response.metadata.checksum, checksum);
+}
@@ -7,6 +7,7 @@ use crate::manifest::Manifest;
 use icu_provider::datagen::*;
 use icu_provider::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Write;
 use std::fs;
 #[allow(deprecated)]
@@ -14,6 +15,7 @@ use std::fs;
 // (we're fine with it not being cryptographically secure since we're just using it to track diffs)
 use std::hash::{Hasher, SipHasher};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
 
 /// Choices of what to do if [`FilesystemExporter`] tries to write to a pre-existing directory.
@@ -34,6 +36,31 @@ impl Default for OverwriteOption {
     }
 }
 
+/// Choices of how [`FilesystemExporter`] stores a file that is identical to another file
+/// of the same key.
+///
+/// After fallback, many locales often have identical data, such as `en-AU` and `en-GB`. Storing
+/// these only once can considerably reduce the size of the output.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum DeduplicationOption {
+    /// Write every file in full.
+    #[default]
+    None,
+    /// Hard-link identical files to the first one that was exported.
+    Hardlink,
+    /// Replace identical files by relative symbolic links to the first one that was exported.
+    ///
+    /// This is only supported on Unix and Windows.
+    Symlink,
+    /// Replace identical files by a `<locale>.redirect` file, which contains the locale of
+    /// the first one that was exported.
+    ///
+    /// This works on every file system. [`FsDataProvider`](crate::FsDataProvider) follows
+    /// redirects transparently, but other readers of the data need to handle them.
+    Redirect,
+}
+
 /// Options bag for initializing a [`FilesystemExporter`].
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -48,6 +75,8 @@ pub struct Options {
     )]
     /// Whether to create a fingerprint file with hashes
     pub fingerprint: bool,
+    /// How to store files that are identical to another file of the same key.
+    pub deduplication: DeduplicationOption,
 }
 
 #[doc(hidden)]
@@ -60,6 +89,7 @@ impl Default for Options {
             root: PathBuf::from("icu4x_data"),
             overwrite: Default::default(),
             fingerprint: false,
+            deduplication: Default::default(),
         }
     }
 }
@@ -81,6 +111,19 @@ pub struct FilesystemExporter {
     manifest: Manifest,
     serializer: Box<dyn AbstractSerializer + Sync>,
     fingerprints: Option<Mutex<Vec<String>>>,
+    deduplication: DeduplicationOption,
+    exported: Mutex<HashMap<DataKey, ExportedFiles>>,
+    /// The sum of the hashes of all files, which does not depend on the order of export
+    checksum: AtomicU64,
+}
+
+/// The files of a key that is being exported with deduplication.
+#[derive(Debug, Default)]
+struct ExportedFiles {
+    /// The locale of the first file with the given contents.
+    originals: HashMap<Vec<u8>, DataLocale>,
+    /// Duplicates, and the locale of their original, which are linked on flush.
+    duplicates: Vec<(DataLocale, DataLocale)>,
 }
 
 impl FilesystemExporter {
@@ -104,8 +147,18 @@ impl FilesystemExporter {
             } else {
                 None
             },
+            deduplication: options.deduplication,
+            exported: Default::default(),
+            checksum: Default::default(),
         };
 
+        #[cfg(not(any(unix, windows)))]
+        if result.deduplication == DeduplicationOption::Symlink {
+            return Err(DataError::custom(
+                "Symlinks are not supported on this platform",
+            ));
+        }
+
         match options.overwrite {
             OverwriteOption::CheckEmpty if result.root.exists() => fs::remove_dir(&result.root),
             OverwriteOption::RemoveAndReplace if result.root.exists() => {
@@ -119,9 +172,44 @@ impl FilesystemExporter {
         result.manifest.write(&result.root)?;
         Ok(result)
     }
+
+    /// Stores the file for `locale` in `dir` as a duplicate of the file for `original`.
+    fn link(
+        &self,
+        dir: &Path,
+        locale: &DataLocale,
+        original: &DataLocale,
+    ) -> Result<(), DataError> {
+        let extension = self.manifest.file_extension;
+        let original_file = format!("{original}.{extension}");
+        let path = dir.join(format!("{locale}.{extension}"));
+        match self.deduplication {
+            DeduplicationOption::None => unreachable!("no duplicates are recorded"),
+            DeduplicationOption::Hardlink => fs::hard_link(dir.join(original_file), &path),
+            #[cfg(unix)]
+            DeduplicationOption::Symlink => std::os::unix::fs::symlink(original_file, &path),
+            #[cfg(windows)]
+            DeduplicationOption::Symlink => {
+                std::os::windows::fs::symlink_file(original_file, &path)
+            }
+            #[cfg(not(any(unix, windows)))]
+            DeduplicationOption::Symlink => unreachable!("checked in the constructor"),
+            DeduplicationOption::Redirect => {
+                let path = dir.join(format!("{locale}.redirect"));
+                return fs::write(&path, original.to_string())
+                    .map_err(|e| DataError::from(e).with_path_context(&path));
+            }
+        }
+        .map_err(|e| DataError::from(e).with_path_context(&path))
+    }
 }
 
 impl DataExporter for FilesystemExporter {
+    fn set_data_version(&mut self, version: &str) -> Result<(), DataError> {
+        self.manifest.data_version = Some(version.into());
+        Ok(())
+    }
+
     fn put_payload(
         &self,
         key: DataKey,
@@ -142,44 +230,71 @@ impl DataExporter for FilesystemExporter {
         fs::create_dir_all(parent_dir)
             .map_err(|e| DataError::from(e).with_path_context(&parent_dir))?;
 
-        let mut file = HashingFile {
-            file: if self.serializer.is_text_format() {
-                Box::new(crlify::BufWriterWithLineEndingFix::new(
-                    fs::File::create(&path_buf)
-                        .map_err(|e| DataError::from(e).with_path_context(&path_buf))?,
-                ))
-            } else {
-                Box::new(std::io::BufWriter::new(
-                    fs::File::create(&path_buf)
-                        .map_err(|e| DataError::from(e).with_path_context(&path_buf))?,
-                ))
-            },
-            hash_size: if self.fingerprints.is_some() {
-                #[allow(deprecated)]
-                Some((SipHasher::new(), 0))
-            } else {
-                None
-            },
-        };
-
+        let mut buf = Vec::new();
         self.serializer
-            .serialize(obj, &mut file)
+            .serialize(obj, &mut buf)
             .map_err(|e| e.with_path_context(&path_buf))?;
-        if let Some((hash, size)) = file.hash_size {
-            self.fingerprints
-                .as_ref()
-                .expect("present iff file.1 is present")
-                .lock()
-                .expect("poison")
-                .push(format!("{key}, {locale}, {size}B, {:x}", hash.finish()));
+
+        #[allow(deprecated)]
+        let mut hash = SipHasher::new();
+        hash.write(format!("{key}/{locale}").as_bytes());
+        hash.write(&buf);
+        self.checksum.fetch_add(hash.finish(), Ordering::Relaxed);
+
+        if let Some(fingerprints) = self.fingerprints.as_ref() {
+            #[allow(deprecated)]
+            let mut hash = SipHasher::new();
+            hash.write(&buf);
+            fingerprints.lock().expect("poison").push(format!(
+                "{key}, {locale}, {}B, {:x}",
+                buf.len(),
+                hash.finish()
+            ));
         }
-        Ok(())
+
+        if self.deduplication != DeduplicationOption::None {
+            let mut exported = self.exported.lock().expect("poison");
+            let files = exported.entry(key).or_default();
+            if let Some(original) = files.originals.get(&buf) {
+                // The original might not have been written yet, so this is linked on flush
+                files.duplicates.push((locale.clone(), original.clone()));
+                return Ok(());
+            }
+            files.originals.insert(buf.clone(), locale.clone());
+        }
+
+        let mut file: Box<dyn std::io::Write> = if self.serializer.is_text_format() {
+            Box::new(crlify::BufWriterWithLineEndingFix::new(
+                fs::File::create(&path_buf)
+                    .map_err(|e| DataError::from(e).with_path_context(&path_buf))?,
+            ))
+        } else {
+            Box::new(std::io::BufWriter::new(
+                fs::File::create(&path_buf)
+                    .map_err(|e| DataError::from(e).with_path_context(&path_buf))?,
+            ))
+        };
+        use std::io::Write;
+        file.write_all(&buf)
+            .and_then(|_| file.flush())
+            .map_err(|e| DataError::from(e).with_path_context(&path_buf))
     }
 
     fn flush(&self, key: DataKey) -> Result<(), DataError> {
         let mut path_buf = self.root.clone().into_os_string();
         write!(&mut path_buf, "/{key}").expect("infallible");
 
+        let duplicates = self
+            .exported
+            .lock()
+            .expect("poison")
+            .remove(&key)
+            .map(|files| files.duplicates)
+            .unwrap_or_default();
+        for (locale, original) in duplicates {
+            self.link(Path::new(&path_buf), &locale, &original)?;
+        }
+
         if !Path::new(&path_buf).exists() {
             fs::create_dir_all(&path_buf)
                 .map_err(|e| DataError::from(e).with_path_context(&path_buf))?;
@@ -191,6 +306,10 @@ impl DataExporter for FilesystemExporter {
     }
 
     fn close(&mut self) -> Result<(), DataError> {
+        if self.manifest.data_version.is_some() {
+            self.manifest.checksum = Some(*self.checksum.get_mut());
+            self.manifest.write(&self.root)?;
+        }
         if let Some(fingerprints) = self.fingerprints.as_mut() {
             let fingerprints = fingerprints.get_mut().expect("poison");
             fingerprints.sort();
@@ -207,23 +326,3 @@ impl DataExporter for FilesystemExporter {
         Ok(())
     }
 }
-
-struct HashingFile {
-    file: Box<dyn std::io::Write>,
-    #[allow(deprecated)]
-    hash_size: Option<(SipHasher, usize)>,
-}
-
-impl std::io::Write for HashingFile {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        if let Some((hash, size)) = self.hash_size.as_mut() {
-            hash.write(buf);
-            *size += buf.len();
-        }
-        self.file.write(buf)
-    }
-
-    fn flush(&mut self) -> std::io::Result<()> {
-        self.file.flush()
-    }
-}
@@ -36,6 +36,9 @@ use std::path::PathBuf;
 ///
 /// assert_writeable_eq!(formatter.format(), "Ave, munde");
 /// ```
+///
+/// Data files that were deduplicated by the exporter are read transparently, including
+/// `<locale>.redirect` files, which contain the locale whose file should be read instead.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FsDataProvider {
     root: PathBuf,
@@ -43,6 +46,9 @@ pub struct FsDataProvider {
 }
 
 impl FsDataProvider {
+    /// The maximum number of redirect files that are followed for a single request.
+    const MAX_REDIRECTS: usize = 8;
+
     /// Create a new [`FsDataProvider`] given a filesystem directory.
     ///
     /// # Examples
@@ -60,6 +66,22 @@ impl FsDataProvider {
             root,
         })
     }
+
+    /// Returns the version of the data in this directory, as set with
+    /// `DataExporter::set_data_version` when the data was exported.
+    ///
+    /// Returns `None` if the data was exported without a data version.
+    pub fn data_version(&self) -> Option<&str> {
+        self.manifest.data_version.as_deref()
+    }
+
+    /// Returns the checksum of the data in this directory, which is also reported in the
+    /// [`DataResponseMetadata`] of every response.
+    ///
+    /// Returns `None` if the data was exported without a data version.
+    pub fn checksum(&self) -> Option<u64> {
+        self.manifest.checksum
+    }
 }
 
 impl BufferProvider for FsDataProvider {
@@ -76,18 +98,40 @@ impl BufferProvider for FsDataProvider {
         if !Path::new(&path).exists() {
             return Err(DataErrorKind::MissingDataKey.with_req(key, req));
         }
-        write!(
-            &mut path,
-            "/{}.{}",
-            req.locale, self.manifest.file_extension
-        )
-        .expect("infallible");
-        if !Path::new(&path).exists() {
-            return Err(DataErrorKind::MissingLocale.with_req(key, req));
-        }
+        let dir = PathBuf::from(path);
+        let mut locale = req.locale.to_string();
+        // Files deduplicated with `DeduplicationOption::Redirect` are replaced by a file
+        // containing the locale of the original. The number of redirects is limited so
+        // that a cycle of redirects results in an error instead of an endless loop.
+        let mut redirects = 0;
+        let path = loop {
+            let path = dir.join(format!("{locale}.{}", self.manifest.file_extension));
+            if path.exists() {
+                break path;
+            }
+            let redirect = dir.join(format!("{locale}.redirect"));
+            if !redirect.exists() {
+                return Err(DataErrorKind::MissingLocale.with_req(key, req));
+            }
+            if redirects == Self::MAX_REDIRECTS {
+                return Err(DataError::custom("Too many redirects")
+                    .with_req(key, req)
+                    .with_path_context(&redirect));
+            }
+            redirects += 1;
+            locale = fs::read_to_string(&redirect)
+                .map_err(|e| DataError::from(e).with_path_context(&redirect))?;
+            // Redirects may only point to files in the same directory
+            if locale.is_empty() || locale.starts_with('.') || locale.contains(['/', '\\']) {
+                return Err(DataError::custom("Invalid redirect")
+                    .with_req(key, req)
+                    .with_path_context(&redirect));
+            }
+        };
         let buffer = fs::read(&path).map_err(|e| DataError::from(e).with_path_context(&path))?;
         let mut metadata = DataResponseMetadata::default();
         metadata.buffer_format = Some(self.manifest.buffer_format);
+        metadata.checksum = self.manifest.checksum;
         Ok(DataResponse {
             metadata,
             payload: Some(DataPayload::from_owned_buffer(buffer.into_boxed_slice())),
@@ -13,12 +13,20 @@ pub struct Manifest {
     pub buffer_format: BufferFormat,
     /// The file extension associated with the given buffer format in the manifest.
     pub file_extension: &'static str,
+    /// The version of the data, if it was exported with one.
+    pub data_version: Option<String>,
+    /// The checksum of the data, if it was exported with a data version.
+    pub checksum: Option<u64>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
-struct JsonManifest {
+struct JsonManifest<'a> {
     #[serde(rename = "syntax")]
     pub buffer_format: BufferFormat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_version: Option<&'a str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<u64>,
 }
 
 impl Manifest {
@@ -40,21 +48,27 @@ impl Manifest {
                         .with_str_context("Format not supported by FsDataProvider"))
                 }
             },
+            data_version: None,
+            checksum: None,
         })
     }
 
     pub fn parse(root: &Path) -> Result<Self, DataError> {
         let path = root.join(Self::NAME);
-        let result: JsonManifest = serde_json_core::from_str(
-            &fs::read_to_string(&path).map_err(|e| DataError::from(e).with_path_context(&path))?,
-        )
-        .map_err(|e| {
-            DataError::custom("FsDataProvider manifest deserialization")
-                .with_path_context(&path)
-                .with_display_context(&e)
-        })?
-        .0;
-        Self::for_format(result.buffer_format)
+        let json =
+            fs::read_to_string(&path).map_err(|e| DataError::from(e).with_path_context(&path))?;
+        let result: JsonManifest = serde_json_core::from_str(&json)
+            .map_err(|e| {
+                DataError::custom("FsDataProvider manifest deserialization")
+                    .with_path_context(&path)
+                    .with_display_context(&e)
+            })?
+            .0;
+        Ok(Self {
+            data_version: result.data_version.map(Into::into),
+            checksum: result.checksum,
+            ..Self::for_format(result.buffer_format)?
+        })
     }
 
     #[cfg(feature = "export")]
@@ -66,6 +80,8 @@ impl Manifest {
         serde::Serialize::serialize(
             &JsonManifest {
                 buffer_format: self.buffer_format,
+                data_version: self.data_version.as_deref(),
+                checksum: self.checksum,
             },
             &mut serde_json::Serializer::pretty(&mut file),
         )
@@ -0,0 +1,189 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "export")]
+
+use icu_locid::langid;
+use icu_provider::datagen::*;
+use icu_provider::dynutil::UpcastDataPayload;
+use icu_provider::hello_world::{HelloWorldV1, HelloWorldV1Marker};
+use icu_provider::prelude::*;
+use icu_provider_fs::export::*;
+use icu_provider_fs::FsDataProvider;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A synthetic dataset in which most locales have the same data as another locale.
+const DATA: &[(&str, &str)] = &[
+    ("en", "Hello World"),
+    ("en-001", "Hello World"),
+    ("en-AU", "Hello World"),
+    ("en-CA", "Hello World"),
+    ("en-GB", "Hello World"),
+    ("en-IN", "Hello World"),
+    ("en-NZ", "Hello World"),
+    ("en-US", "Hello World"),
+    ("en-ZA", "Hello World"),
+    ("en-x-reverse", "Olleh Dlrow"),
+    ("fr", "Bonjour le monde"),
+    ("fr-BE", "Bonjour le monde"),
+    ("fr-CA", "Bonjour le monde"),
+    ("fr-CH", "Bonjour le monde"),
+    ("de", "Hallo Welt"),
+    ("de-AT", "Servus Welt"),
+    ("de-CH", "Hallo Welt"),
+    ("und", "Hello World"),
+];
+
+fn export(name: &str, deduplication: DeduplicationOption) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("icu4x_fs_deduplication_{name}"));
+    let mut options = ExporterOptions::default();
+    options.root.clone_from(&root);
+    options.overwrite = OverwriteOption::RemoveAndReplace;
+    options.deduplication = deduplication;
+    let mut exporter =
+        FilesystemExporter::try_new(Box::<serializers::Json>::default(), options).unwrap();
+
+    for &(locale, message) in DATA {
+        let payload = DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+            message: message.into(),
+        });
+        exporter
+            .put_payload(
+                HelloWorldV1Marker::KEY,
+                &locale.parse::<icu_locid::Locale>().unwrap().into(),
+                &ExportMarker::upcast(payload),
+            )
+            .unwrap();
+    }
+    exporter.flush(HelloWorldV1Marker::KEY).unwrap();
+    exporter.close().unwrap();
+    root
+}
+
+/// Returns the number of bytes stored in the directory, not counting symlinks, and
+/// counting hard-linked files only once.
+fn stored_size(dir: &Path) -> u64 {
+    fn visit(dir: &Path, seen: &mut HashSet<(u64, u64)>) -> u64 {
+        let mut size = 0;
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let metadata = std::fs::symlink_metadata(&path).unwrap();
+            if metadata.is_dir() {
+                size += visit(&path, seen);
+            } else if metadata.is_file() {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::MetadataExt;
+                    if !seen.insert((metadata.dev(), metadata.ino())) {
+                        continue;
+                    }
+                }
+                size += metadata.len();
+            }
+        }
+        size
+    }
+    visit(dir, &mut HashSet::new())
+}
+
+fn check_loads(root: &Path) {
+    let provider = FsDataProvider::try_new(root).unwrap();
+    for &(locale, message) in DATA {
+        let payload: DataPayload<HelloWorldV1Marker> = provider
+            .as_deserializing()
+            .load(DataRequest {
+                locale: &locale.parse::<icu_locid::Locale>().unwrap().into(),
+                metadata: Default::default(),
+            })
+            .unwrap_or_else(|e| panic!("{e}: {locale}"))
+            .take_payload()
+            .unwrap();
+        assert_eq!(payload.get().message, message, "{locale}");
+    }
+}
+
+fn check_deduplication(name: &str, deduplication: DeduplicationOption) {
+    let full = export(&format!("{name}_full"), DeduplicationOption::None);
+    let deduplicated = export(name, deduplication);
+
+    check_loads(&deduplicated);
+
+    // Only one file per distinct message remains, apart from the manifest and
+    // small redirect files
+    let full_size = stored_size(&full);
+    let deduplicated_size = stored_size(&deduplicated);
+    assert!(
+        deduplicated_size * 2 < full_size,
+        "{deduplicated_size}B is not less than half of {full_size}B"
+    );
+
+    let _ = std::fs::remove_dir_all(full);
+    let _ = std::fs::remove_dir_all(deduplicated);
+}
+
+#[test]
+fn test_redirect() {
+    check_deduplication("redirect", DeduplicationOption::Redirect);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_hardlink() {
+    check_deduplication("hardlink", DeduplicationOption::Hardlink);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_symlink() {
+    check_deduplication("symlink", DeduplicationOption::Symlink);
+}
+
+#[test]
+fn test_no_deduplication() {
+    let root = export("none", DeduplicationOption::None);
+    check_loads(&root);
+    let files = std::fs::read_dir(root.join(HelloWorldV1Marker::KEY.path().get()))
+        .unwrap()
+        .count();
+    assert_eq!(files, DATA.len());
+    let _ = std::fs::remove_dir_all(root);
+}
+
+#[test]
+fn test_redirect_errors() {
+    let root = export("redirect_errors", DeduplicationOption::Redirect);
+    let dir = root.join(HelloWorldV1Marker::KEY.path().get());
+    let provider = FsDataProvider::try_new(&root).unwrap();
+    let load = |locale: icu_locid::LanguageIdentifier| {
+        provider
+            .as_deserializing()
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .map(|r: DataResponse<HelloWorldV1Marker>| r.take_payload().unwrap())
+    };
+
+    // A cycle of redirects
+    std::fs::write(dir.join("ja.redirect"), "ko").unwrap();
+    std::fs::write(dir.join("ko.redirect"), "ja").unwrap();
+    assert!(load(langid!("ja")).is_err());
+
+    // A redirect out of the directory
+    std::fs::write(dir.join("it.redirect"), "../../manifest").unwrap();
+    assert!(load(langid!("it")).is_err());
+
+    // A redirect to a missing locale
+    std::fs::write(dir.join("es.redirect"), "pt").unwrap();
+    assert!(matches!(
+        load(langid!("es")),
+        Err(DataError {
+            kind: DataErrorKind::MissingLocale,
+            ..
+        })
+    ));
+
+    let _ = std::fs::remove_dir_all(root);
+}
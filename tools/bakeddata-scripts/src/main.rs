@@ -116,7 +116,8 @@ fn main() {
                     .replace(
                         "_segmenter_lstm_tag_",
                         DatagenProvider::LATEST_TESTED_SEGMENTER_LSTM_TAG,
-                    ),
+                    )
+                    .replace("_tzdb_tag_", DatagenProvider::LATEST_TESTED_TZDB_TAG),
             )
             .unwrap();
         }
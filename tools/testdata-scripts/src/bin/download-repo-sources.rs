@@ -193,6 +193,28 @@ fn main() -> eyre::Result<()> {
         &mut Default::default(),
     )?;
 
+    std::fs::remove_dir_all(out_root.join("tests/data/tzdb"))?;
+    let tzdb_zip = cached(&format!(
+        "https://files.pythonhosted.org/packages/py2.py3/t/tzdata/tzdata-{}-py2.py3-none-any.whl",
+        DatagenProvider::LATEST_TESTED_TZDB_TAG,
+    ))
+    .with_context(|| "Failed to download tzdata wheel".to_owned())?;
+    let mut tzdb_zip = ZipArchive::new(Cursor::new(std::fs::read(&tzdb_zip)?))
+        .with_context(|| format!("Failed to read zip file {:?}", &tzdb_zip))?;
+    for spath in TZDB_GLOB {
+        // TZif files are binary, so they are copied without line ending conversion.
+        let path = out_root.join("tests/data/tzdb").join(spath);
+        fs::create_dir_all(path.parent().unwrap())?;
+        io::copy(
+            &mut tzdb_zip
+                .by_name(spath)
+                .with_context(|| format!("Missing file {spath:?}"))?,
+            &mut File::create(&path)
+                .with_context(|| format!("Failed to create file {:?}", &path))?,
+        )
+        .with_context(|| format!("Failed to write file {:?}", &path))?;
+    }
+
     let cldr_data = cldr_data
         .iter()
         .map(|path| {
@@ -220,6 +242,14 @@ fn main() -> eyre::Result<()> {
         .collect::<Vec<_>>()
         .join(",\n                            ");
 
+    let tzdb_data = TZDB_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../../tests/data/tzdb/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                            ");
+
     write!(&mut crlify::BufWriterWithLineEndingFix::new(File::create(out_root.join("src/provider/tests/data.rs")).unwrap()), "\
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
@@ -229,7 +259,7 @@ fn main() -> eyre::Result<()> {
 
 #![allow(deprecated)]
 
-use crate::provider::source::{{AbstractFs, SerdeCache}};
+use crate::provider::source::{{AbstractFs, SerdeCache, TzdbCache}};
 use crate::provider::transform::cldr::source::CldrCache;
 use crate::provider::{{DatagenProvider, SourceData}};
 use std::sync::Arc;
@@ -258,6 +288,11 @@ impl DatagenProvider {{
                             {lstm_data}
                         ].into_iter().collect(),
                     )))),
+                    tzdb_paths: Some(Arc::new(TzdbCache::new(SerdeCache::new(AbstractFs::Memory(
+                        [
+                            {tzdb_data}
+                        ].into_iter().collect(),
+                    )), \"tzdata/zoneinfo/\"))),
                     ..DatagenProvider::new_custom().source
                 }},
             }})
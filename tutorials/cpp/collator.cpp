@@ -4,11 +4,15 @@
 
 #include "ICU4XCollator.hpp"
 #include "ICU4XDataProvider.hpp"
+#include "ICU4XError.hpp"
 #include "ICU4XLocale.hpp"
 #include "ICU4XLogger.hpp"
 #include "ICU4XOrdering.hpp"
 
+#include <algorithm>
+#include <array>
 #include <iostream>
+#include <string>
 #include <string_view>
 
 int main() {
@@ -63,5 +67,83 @@ int main() {
     return 1;
   }
 
+  // test 03 - sorting UTF-16 strings, with the same expectations as
+  // test_sort_small_list in components/collator/tests/tests.rs
+
+  std::array<std::u16string, 4> words{ u"nube", u"mañana", u"luz", u"manna" };
+  std::array<std::u16string, 4> expected{ u"luz", u"manna", u"mañana", u"nube" };
+
+  locale = ICU4XLocale::create_from_string("es").ok().value();
+  options = {};
+  collator = ICU4XCollator::create_v1(dp, locale, options).ok().value();
+  std::sort(words.begin(), words.end(), [&](const std::u16string& a, const std::u16string& b) {
+    return collator.compare_utf16(a, b) == ICU4XOrdering::Less;
+  });
+
+  if (words != expected) {
+    std::cout << "Expected luz < manna < mañana < nube for locale " << locale.to_string().ok().value() << std::endl;
+    return 1;
+  }
+
+  // Unpaired surrogates compare as U+FFFD
+  std::u16string_view lone_high{ u"\xD801" };
+  std::u16string_view other_lone_high{ u"\xD802" };
+  options.strength = ICU4XCollatorStrength::Quaternary;
+  collator = ICU4XCollator::create_v1(dp, locale, options).ok().value();
+
+  if (collator.compare_utf16(lone_high, other_lone_high) != ICU4XOrdering::Equal) {
+    std::cout << "Expected unpaired surrogates to be equal" << std::endl;
+    return 1;
+  }
+
+  // test 04 - numeric collation, requires non-default CollatorOptions
+
+  std::array<std::string_view, 3> numbered{ "a10", "a2", "a1" };
+  std::array<std::string_view, 3> expected_numbered{ "a1", "a2", "a10" };
+
+  locale = ICU4XLocale::create_from_string("und").ok().value();
+  options = {};
+  options.numeric = ICU4XCollatorNumeric::On;
+  collator = ICU4XCollator::create_v1(dp, locale, options).ok().value();
+  std::sort(numbered.begin(), numbered.end(), [&](std::string_view a, std::string_view b) {
+    return collator.compare(a, b) == ICU4XOrdering::Less;
+  });
+
+  if (numbered != expected_numbered) {
+    std::cout << "Expected a1 < a2 < a10 with numeric collation" << std::endl;
+    return 1;
+  }
+
+  if (collator.resolved_options().numeric != ICU4XCollatorNumeric::On) {
+    std::cout << "Expected numeric collation to be resolved as on" << std::endl;
+    return 1;
+  }
+
+  // test 05 - sorting by sort key, with the same expectations as test 03
+
+  std::array<std::u16string, 4> keyed_words{ u"nube", u"mañana", u"luz", u"manna" };
+
+  locale = ICU4XLocale::create_from_string("es").ok().value();
+  options = {};
+  collator = ICU4XCollator::create_v1(dp, locale, options).ok().value();
+  std::sort(keyed_words.begin(), keyed_words.end(), [&](const std::u16string& a, const std::u16string& b) {
+    return collator.write_sort_key_utf16(a).ok().value() < collator.write_sort_key_utf16(b).ok().value();
+  });
+
+  if (keyed_words != expected) {
+    std::cout << "Expected luz < manna < mañana < nube by sort key for locale " << locale.to_string().ok().value() << std::endl;
+    return 1;
+  }
+
+  if (collator.write_sort_key(manana).ok().value() != collator.write_sort_key_utf16(u"mañana").ok().value()) {
+    std::cout << "Expected the same sort key for UTF-8 and UTF-16 input" << std::endl;
+    return 1;
+  }
+
+  if (collator.write_sort_key_utf16(lone_high).err().value() != ICU4XError::InvalidUtf16Error) {
+    std::cout << "Expected an error for the sort key of an unpaired surrogate" << std::endl;
+    return 1;
+  }
+
   return 0;
 }
@@ -136,6 +136,37 @@ void test_sentence(const std::string_view& str) {
     iterate_breakpoints(iterator);
 }
 
+void test_mixed_utf16() {
+    const std::u16string_view str = u"ภาษาไทยง่ายนิดเดียว Thai is easy.";
+    const auto provider = ICU4XDataProvider::create_compiled();
+    cout << "Segmenting a mixed Thai and English string as UTF-16 ("
+         << str.size() << " code units)" << endl;
+
+    const auto line_segmenter =
+        ICU4XLineSegmenter::create_auto(provider).ok().value();
+    cout << "Line breakpoints:";
+    auto line_iterator = line_segmenter.segment_utf16(str);
+    iterate_breakpoints(line_iterator);
+
+    const auto grapheme_segmenter =
+        ICU4XGraphemeClusterSegmenter::create(provider).ok().value();
+    cout << "Grapheme cluster breakpoints:";
+    auto grapheme_iterator = grapheme_segmenter.segment_utf16(str);
+    iterate_breakpoints(grapheme_iterator);
+
+    const auto word_segmenter =
+        ICU4XWordSegmenter::create_auto(provider).ok().value();
+    cout << "Word breakpoints:";
+    auto word_iterator = word_segmenter.segment_utf16(str);
+    iterate_word_breakpoints(word_iterator);
+
+    const auto sentence_segmenter =
+        ICU4XSentenceSegmenter::create(provider).ok().value();
+    cout << "Sentence breakpoints:";
+    auto sentence_iterator = sentence_segmenter.segment_utf16(str);
+    iterate_breakpoints(sentence_iterator);
+}
+
 int main(int argc, char* argv[]) {
     ICU4XLogger::init_simple_logger();
     std::string_view str;
@@ -156,5 +187,13 @@ int main(int argc, char* argv[]) {
 
     test_sentence(str);
     cout << endl;
+
+    if (argc < 2) {
+        test_word("ภาษาไทยง่ายนิดเดียว Thai is easy.");
+        cout << endl;
+
+        test_mixed_utf16();
+        cout << endl;
+    }
     return 0;
 }
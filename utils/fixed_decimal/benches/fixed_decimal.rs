@@ -61,6 +61,7 @@ fn overview_bench(c: &mut Criterion) {
         to_string_benches(c);
         from_string_benches(c);
         rounding_benches(c);
+        sub_integer_benches(c);
     }
 }
 
@@ -207,5 +208,36 @@ fn rounding_benches(c: &mut Criterion) {
     group.finish()
 }
 
+#[cfg(feature = "bench")]
+fn sub_integer_benches(c: &mut Criterion) {
+    let nums: Vec<_> = triangular_nums(1e9)
+        .into_iter()
+        .map(FixedDecimal::from)
+        .collect();
+    let subtrahends: Vec<_> = triangular_nums(1e4).into_iter().map(|n| n as i64).collect();
+    let mut group = c.benchmark_group("sub_integer");
+
+    group.bench_function("checked_sub_integer", |b| {
+        b.iter(|| {
+            for (num, n) in nums.iter().zip(subtrahends.iter()) {
+                black_box(black_box(num).checked_sub_integer(black_box(*n)).unwrap());
+            }
+        })
+    });
+    group.bench_function("checked_sub_assign", |b| {
+        b.iter(|| {
+            for (num, n) in nums.iter().zip(subtrahends.iter()) {
+                let mut result = black_box(num).clone();
+                result
+                    .checked_sub_assign(&FixedDecimal::from(black_box(*n)))
+                    .unwrap();
+                black_box(result);
+            }
+        })
+    });
+
+    group.finish()
+}
+
 criterion_group!(benches, overview_bench,);
 criterion_main!(benches);
@@ -2,15 +2,17 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::string::String;
 use smallvec::SmallVec;
 
 use core::cmp;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
 use core::fmt;
-use core::ops::RangeInclusive;
+use core::ops::{AddAssign, Div, Mul, MulAssign, RangeInclusive, Rem, SubAssign};
 
 use core::str::FromStr;
+use writeable::Writeable;
 
 use crate::uint_iterator::IntIterator;
 
@@ -84,7 +86,27 @@ compile_error!("The fixed_decimal crate only works if usizes are at least the si
 /// dec.multiply_pow10(-2);
 /// assert_eq!("2.50", dec.to_string());
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// # Equality and Hashing
+///
+/// Equality and hashing are *representational*: two `FixedDecimal`s are equal only if they
+/// have the same digits, padding and sign, so "1.0", "01.00" and "+1" are all different from
+/// "1". This matches how the numbers are formatted.
+///
+/// To compare or hash numbers by their numeric value, for example as keys of a
+/// `HashMap`, canonicalize them first with [`FixedDecimal::canonicalized()`], or use
+/// [`FixedDecimal::canonical_key()`].
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let a = FixedDecimal::from_str("1.0").unwrap();
+/// let b = FixedDecimal::from_str("01.00").unwrap();
+/// assert_ne!(a, b);
+/// assert_eq!(a.canonicalized(), b.canonicalized());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FixedDecimal {
     /// List of digits; digits\[0\] is the most significant.
     ///
@@ -122,7 +144,7 @@ pub struct FixedDecimal {
 }
 
 /// A specification of the sign used when formatting a number.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 #[allow(clippy::exhaustive_enums)]
 // There are only 3 sign values, and they correspond to the low-level data model of FixedDecimal and UTS 35.
 pub enum Sign {
@@ -182,8 +204,8 @@ macro_rules! impl_from_signed_integer_type {
                 } else {
                     Sign::None
                 };
-                let mut result = Self::from_ascending(int_iterator)
-                    .expect("All built-in integer types should fit");
+                let mut result =
+                    Self::from_ascending::<_, { $utype::MAX.ilog10() as usize + 1 }>(int_iterator);
                 result.sign = sign;
                 result
             }
@@ -196,7 +218,7 @@ macro_rules! impl_from_unsigned_integer_type {
         impl From<$utype> for FixedDecimal {
             fn from(value: $utype) -> Self {
                 let int_iterator: IntIterator<$utype> = value.into();
-                Self::from_ascending(int_iterator).expect("All built-in integer types should fit")
+                Self::from_ascending::<_, { $utype::MAX.ilog10() as usize + 1 }>(int_iterator)
             }
         }
     };
@@ -236,6 +258,45 @@ pub enum RoundingIncrement {
     MultiplesOf25,
 }
 
+/// Mode used in a rounding operation, corresponding to the rounding functions on
+/// [`FixedDecimal`], such as [`FixedDecimal::half_even()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Round towards positive infinity; see [`FixedDecimal::ceil()`].
+    Ceil,
+    /// Round away from zero; see [`FixedDecimal::expand()`].
+    Expand,
+    /// Round towards negative infinity; see [`FixedDecimal::floor()`].
+    Floor,
+    /// Round towards zero; see [`FixedDecimal::trunc()`].
+    Trunc,
+    /// Round to the nearest, with ties towards positive infinity; see
+    /// [`FixedDecimal::half_ceil()`].
+    HalfCeil,
+    /// Round to the nearest, with ties away from zero; see [`FixedDecimal::half_expand()`].
+    HalfExpand,
+    /// Round to the nearest, with ties towards negative infinity; see
+    /// [`FixedDecimal::half_floor()`].
+    HalfFloor,
+    /// Round to the nearest, with ties towards zero; see [`FixedDecimal::half_trunc()`].
+    HalfTrunc,
+    /// Round to the nearest, with ties to the even neighbor; see [`FixedDecimal::half_even()`].
+    HalfEven,
+}
+
+/// Options for parsing a [`FixedDecimal`] with [`FixedDecimal::try_from_str_with_options()`].
+///
+/// The default options are the same as those of [`FromStr`], which only accepts ASCII.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Whether to accept U+2212 MINUS SIGN (−) as a leading negative sign, in addition to
+    /// the ASCII hyphen-minus. This sign is used by some locales, such as `sv` and `fa`, so
+    /// it can appear in text copied from locale-formatted numbers.
+    pub accept_unicode_minus: bool,
+}
+
 // Adapters to convert runtime dispatched calls into const-inlined methods.
 // This allows reducing the codesize for the common case of no increment.
 
@@ -270,43 +331,37 @@ impl IncrementLike for NoIncrement {
 }
 
 impl FixedDecimal {
-    /// Initialize a `FixedDecimal` with an iterator of digits in ascending
+    /// Initialize a `FixedDecimal` with an iterator of at most `X` digits in ascending
     /// order of magnitude, starting with the digit at magnitude 0.
     ///
-    /// This method is not public; use `TryFrom::<isize>` instead.
-    fn from_ascending<T>(digits_iter: T) -> Result<Self, Error>
+    /// `X` is the number of digits of the largest value of the integer type, such as 39
+    /// for `u128`.
+    ///
+    /// This method is not public; use `From::<isize>` instead.
+    fn from_ascending<T, const X: usize>(digits_iter: T) -> Self
     where
         T: Iterator<Item = u8>,
     {
-        // TODO: make X a usize generic to customize the size of this array
-        // https://github.com/rust-lang/rust/issues/44580
-        // NOTE: 39 is the size required for u128: ceil(log10(u128::MAX)) == 39.
-        const X: usize = 39;
         // A temporary structure to allow the digits in the iterator to be reversed.
         // The digits are inserted started from the end, and then a slice is copied
         // into its final destination (result.digits).
         let mut mem: [u8; X] = [0u8; X];
+        let mut digits_iter = digits_iter.peekable();
         let mut trailing_zeros: usize = 0;
+        while digits_iter.next_if_eq(&0).is_some() {
+            trailing_zeros += 1;
+        }
         let mut i: usize = 0;
-        for (x, d) in digits_iter.enumerate() {
-            // Take only up to i16::MAX values so that we have enough capacity
-            if x > i16::MAX as usize {
-                return Err(Error::Limit);
-            }
-            // TODO: Should we check here that `d` is between 0 and 9?
-            // That should always be the case if IntIterator is used.
-            if i != 0 || d != 0 {
-                i += 1;
-                match X.checked_sub(i) {
-                    #[allow(clippy::indexing_slicing)] // X - i < X
-                    Some(v) => mem[v] = d,
-                    // This error should be obsolete after X is made generic
-                    None => return Err(Error::Limit),
-                }
-            } else {
-                trailing_zeros += 1;
-            }
+        // TODO: Should we check here that `d` is between 0 and 9?
+        // That should always be the case if IntIterator is used.
+        for (m, d) in mem.iter_mut().rev().zip(&mut digits_iter) {
+            *m = d;
+            i += 1;
         }
+        debug_assert!(
+            digits_iter.next().is_none(),
+            "the integer type has at most X digits"
+        );
         let mut result: Self = Default::default();
         if i != 0 {
             let magnitude = trailing_zeros + i - 1;
@@ -314,12 +369,12 @@ impl FixedDecimal {
             result.magnitude = magnitude as i16;
             result.upper_magnitude = result.magnitude;
             debug_assert!(i <= X);
-            #[allow(clippy::indexing_slicing)] // X - i < X
+            #[allow(clippy::indexing_slicing)] // i <= X
             result.digits.extend_from_slice(&mem[(X - i)..]);
         }
         #[cfg(debug_assertions)]
         result.check_invariants();
-        Ok(result)
+        result
     }
 
     /// Gets the digit at the specified order of magnitude. Returns 0 if the magnitude is out of
@@ -352,6 +407,109 @@ impl FixedDecimal {
         }
     }
 
+    /// Returns an iterator over the digits from `magnitude` down to the lowest visible magnitude,
+    /// in descending order of magnitude, as pairs of magnitude and digit. Leading and trailing
+    /// zeros are included. The iterator is empty if `magnitude` is below the visible digits.
+    ///
+    /// To always get a fixed number of fractional digits, pad the number first with
+    /// [`FixedDecimal::pad_end()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "12.0305".parse().expect("valid syntax");
+    /// dec.pad_end(-6);
+    /// assert_eq!(
+    ///     dec.digits_below_magnitude(-1).collect::<Vec<_>>(),
+    ///     [(-1, 0), (-2, 3), (-3, 0), (-4, 5), (-5, 0), (-6, 0)]
+    /// );
+    /// assert_eq!(
+    ///     dec.digits_below_magnitude(2).take(3).collect::<Vec<_>>(),
+    ///     [(2, 0), (1, 1), (0, 2)]
+    /// );
+    /// assert_eq!(dec.digits_below_magnitude(-7).count(), 0);
+    /// ```
+    pub fn digits_below_magnitude(&self, magnitude: i16) -> impl Iterator<Item = (i16, u8)> + '_ {
+        (self.lower_magnitude..=magnitude)
+            .rev()
+            .map(move |magnitude| (magnitude, self.digit_at(magnitude)))
+    }
+
+    /// Returns the sum of the values of all digits of the number.
+    ///
+    /// Zeros padded at either end of the number do not change the sum, and the sign is
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "12.34".parse().expect("valid syntax");
+    /// assert_eq!(dec.digit_sum(), 10);
+    ///
+    /// let dec: FixedDecimal = "-0900.0500".parse().expect("valid syntax");
+    /// assert_eq!(dec.digit_sum(), 14);
+    /// ```
+    pub fn digit_sum(&self) -> u32 {
+        self.digits.iter().map(|&digit| u32::from(digit)).sum()
+    }
+
+    /// Returns an iterator over the magnitudes at which `digit` appears in the visible range,
+    /// from the most significant to the least significant.
+    ///
+    /// Padded zeros are visible, so they are included when `digit` is 0. If `digit` is greater
+    /// than 9, the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "1221.2".parse().expect("valid syntax");
+    /// assert_eq!(dec.positions_of_digit(2).collect::<Vec<_>>(), [2, 1, -1]);
+    /// assert_eq!(dec.positions_of_digit(1).collect::<Vec<_>>(), [3, 0]);
+    /// assert_eq!(dec.positions_of_digit(7).count(), 0);
+    /// assert_eq!(dec.positions_of_digit(10).count(), 0);
+    /// ```
+    pub fn positions_of_digit(&self, digit: u8) -> impl Iterator<Item = i16> + '_ {
+        self.magnitude_range()
+            .rev()
+            .filter(move |&magnitude| digit <= 9 && self.digit_at(magnitude) == digit)
+    }
+
+    /// Returns all visible digits, including zeros padded at either end, in ascending order of
+    /// magnitude if `ascending` is true and in descending order otherwise.
+    ///
+    /// The digits cover [`FixedDecimal::magnitude_range()`], so the digit at magnitude 0 is
+    /// always included, even for numbers such as 0.05 or 1000. The sign is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "12.3".parse().expect("valid syntax");
+    /// assert_eq!(dec.to_digit_vec(false), [1, 2, 3]);
+    /// assert_eq!(dec.to_digit_vec(true), [3, 2, 1]);
+    ///
+    /// let dec = FixedDecimal::from(5).multiplied_pow10(-2).padded_start(2);
+    /// assert_eq!(dec.to_string(), "00.05");
+    /// assert_eq!(dec.to_digit_vec(false), [0, 0, 0, 5]);
+    /// ```
+    pub fn to_digit_vec(&self, ascending: bool) -> alloc::vec::Vec<u8> {
+        let digits = self
+            .magnitude_range()
+            .map(|magnitude| self.digit_at(magnitude));
+        if ascending {
+            digits.collect()
+        } else {
+            digits.rev().collect()
+        }
+    }
+
     /// Gets the digit at the specified order of next upper magnitude (magnitude + 1).
     /// Returns 0 if the next upper magnitude is out of range of currently visible digits or
     /// the magnitude is equal to `i16::MAX`.
@@ -596,6 +754,34 @@ impl FixedDecimal {
         }
     }
 
+    /// Gets the order of magnitude of the number, which is `floor(log10(|x|))`, or `None` if
+    /// the number is zero.
+    ///
+    /// This is the same as [`FixedDecimal::nonzero_magnitude_start()`] for nonzero numbers, but
+    /// distinguishes zero from numbers between 1 and 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "-012.340".parse().expect("valid syntax");
+    /// assert_eq!(Some(1), dec.order_of_magnitude());
+    ///
+    /// let dec: FixedDecimal = "0.0045".parse().expect("valid syntax");
+    /// assert_eq!(Some(-3), dec.order_of_magnitude());
+    ///
+    /// assert_eq!(Some(0), FixedDecimal::from(7).order_of_magnitude());
+    /// assert_eq!(None, FixedDecimal::from(0).order_of_magnitude());
+    /// ```
+    pub fn order_of_magnitude(&self) -> Option<i16> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.nonzero_magnitude_start())
+        }
+    }
+
     /// Returns whether the number has a numeric value of zero.
     ///
     /// # Examples
@@ -611,6 +797,134 @@ impl FixedDecimal {
         self.digits.is_empty()
     }
 
+    /// Returns whether the absolute value of the number is a power of ten, such as 1, 100 or
+    /// 0.01, independently of its sign and of any leading or trailing zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "100".parse().expect("valid syntax");
+    /// assert!(dec.is_power_of_ten());
+    ///
+    /// let dec: FixedDecimal = "110".parse().expect("valid syntax");
+    /// assert!(!dec.is_power_of_ten());
+    ///
+    /// let dec: FixedDecimal = "0.01".parse().expect("valid syntax");
+    /// assert!(dec.is_power_of_ten());
+    ///
+    /// let dec: FixedDecimal = "-10.000".parse().expect("valid syntax");
+    /// assert!(dec.is_power_of_ten());
+    ///
+    /// assert!(!FixedDecimal::from(0).is_power_of_ten());
+    /// ```
+    #[inline]
+    pub fn is_power_of_ten(&self) -> bool {
+        self.digits.as_slice() == [1]
+    }
+
+    /// Gets the number of visible digits after the decimal separator.
+    ///
+    /// This counts trailing zeros, so [`FixedDecimal::pad_end()`] increases it and
+    /// [`FixedDecimal::trim_end()`] can decrease it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "0.0500".parse().expect("valid syntax");
+    /// assert_eq!(4, dec.fraction_digit_count());
+    ///
+    /// assert_eq!(0, FixedDecimal::from(1000).fraction_digit_count());
+    /// ```
+    pub fn fraction_digit_count(&self) -> usize {
+        -(self.lower_magnitude as i32) as usize
+    }
+
+    /// Gets the number of significant digits, from the largest nonzero digit down to the last
+    /// visible digit. If the number is zero, 0 is returned.
+    ///
+    /// Trailing zeros are significant, so [`FixedDecimal::pad_end()`] increases this count,
+    /// but leading zeros are not, so [`FixedDecimal::pad_start()`] does not change it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "0.0500".parse().expect("valid syntax");
+    /// assert_eq!(3, dec.significant_digit_count());
+    ///
+    /// assert_eq!(4, FixedDecimal::from(1000).significant_digit_count());
+    /// assert_eq!(0, FixedDecimal::from(0).significant_digit_count());
+    /// ```
+    pub fn significant_digit_count(&self) -> usize {
+        if self.is_zero() {
+            0
+        } else {
+            (self.magnitude as i32 - self.lower_magnitude as i32 + 1) as usize
+        }
+    }
+
+    /// Gets the number of visible zeros in the integer part before the largest nonzero digit.
+    /// If the number is zero, all of its integer digits are counted.
+    ///
+    /// Zeros after the decimal separator are not counted, so the "0" before the decimal
+    /// separator of a number between -1 and 1 is the only leading zero unless the number was
+    /// padded with [`FixedDecimal::pad_start()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "0.0500".parse().expect("valid syntax");
+    /// assert_eq!(1, dec.leading_zero_count());
+    ///
+    /// let dec: FixedDecimal = "0012.3".parse().expect("valid syntax");
+    /// assert_eq!(2, dec.leading_zero_count());
+    ///
+    /// assert_eq!(0, FixedDecimal::from(1000).leading_zero_count());
+    /// assert_eq!(1, FixedDecimal::from(0).leading_zero_count());
+    /// ```
+    pub fn leading_zero_count(&self) -> usize {
+        let first_nonzero = if self.is_zero() {
+            -1
+        } else {
+            core::cmp::max(self.magnitude as i32, -1)
+        };
+        (self.upper_magnitude as i32 - first_nonzero) as usize
+    }
+
+    /// Gets the number of visible zeros after the smallest nonzero digit, in the integer part
+    /// as well as after the decimal separator. If the number is zero, its fraction digits are
+    /// counted.
+    ///
+    /// [`FixedDecimal::pad_end()`] increases this count and [`FixedDecimal::trim_end()`]
+    /// removes the zeros after the decimal separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec: FixedDecimal = "0.0500".parse().expect("valid syntax");
+    /// assert_eq!(2, dec.trailing_zero_count());
+    ///
+    /// assert_eq!(3, FixedDecimal::from(1000).trailing_zero_count());
+    /// assert_eq!(0, FixedDecimal::from(0).trailing_zero_count());
+    /// ```
+    pub fn trailing_zero_count(&self) -> usize {
+        let last_nonzero = if self.is_zero() {
+            0
+        } else {
+            self.nonzero_magnitude_end() as i32
+        };
+        (last_nonzero - self.lower_magnitude as i32) as usize
+    }
+
     /// Clears all the fields and sets the number to zero.
     fn clear(&mut self) {
         self.upper_magnitude = 0;
@@ -702,6 +1016,158 @@ impl FixedDecimal {
         self
     }
 
+    /// Shift the digits by a power of 10, modifying self, or return [`Error::Limit`] and leave
+    /// the number unchanged if the shift would move a visible digit out of range.
+    ///
+    /// This is the same positional shift as [`FixedDecimal::multiply_pow10()`], which sets the
+    /// number to zero on overflow instead of returning an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Error;
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// dec.shift_magnitude_checked(-3).unwrap();
+    /// assert_eq!("0.042", dec.to_string());
+    ///
+    /// assert_eq!(Err(Error::Limit), dec.shift_magnitude_checked(i16::MIN));
+    /// assert_eq!("0.042", dec.to_string());
+    /// ```
+    pub fn shift_magnitude_checked(&mut self, delta: i16) -> Result<(), Error> {
+        let in_range = match delta.cmp(&0) {
+            Ordering::Greater => self.upper_magnitude.checked_add(delta).is_some(),
+            Ordering::Less => self.lower_magnitude.checked_add(delta).is_some(),
+            Ordering::Equal => true,
+        };
+        if !in_range {
+            return Err(Error::Limit);
+        }
+        self.multiply_pow10(delta);
+        Ok(())
+    }
+
+    /// Shift the digits and the visible range by a power of 10, modifying self, or return
+    /// [`Error::Limit`] and leave the number unchanged if a visible digit would move out of range.
+    ///
+    /// Unlike [`FixedDecimal::multiply_pow10()`], zeros that are only visible because the digit
+    /// at magnitude 0 is always shown are not carried along: a leading zero before the decimal
+    /// separator of a number below 1, and the trailing zeros of an integer. Only the zeros that
+    /// widen the visible range beyond that are shifted with the digits, so an exact shift by
+    /// `delta` can be undone with an exact shift by `-delta`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(5);
+    /// dec.exact_shift(-3).unwrap();
+    /// assert_eq!("0.005", dec.to_string());
+    ///
+    /// // The zero before the decimal separator is not shifted
+    /// dec.exact_shift(3).unwrap();
+    /// assert_eq!("5", dec.to_string());
+    ///
+    /// // It is with `multiply_pow10`
+    /// let dec = FixedDecimal::from(5).multiplied_pow10(-3).multiplied_pow10(3);
+    /// assert_eq!("0005", dec.to_string());
+    /// ```
+    pub fn exact_shift(&mut self, delta: i16) -> Result<(), Error> {
+        let upper_magnitude = if self.upper_magnitude == 0 && self.magnitude < 0 {
+            self.magnitude
+        } else {
+            self.upper_magnitude
+        };
+        let lower_magnitude = if self.lower_magnitude == 0 {
+            self.nonzero_magnitude_end()
+        } else {
+            self.lower_magnitude
+        };
+        let (Some(upper_magnitude), Some(lower_magnitude)) = (
+            upper_magnitude.checked_add(delta),
+            lower_magnitude.checked_add(delta),
+        ) else {
+            return Err(Error::Limit);
+        };
+        self.upper_magnitude = cmp::max(0, upper_magnitude);
+        self.lower_magnitude = cmp::min(0, lower_magnitude);
+        if !self.is_zero() {
+            // The magnitude is between the shifted bounds, so this does not overflow
+            self.magnitude += delta;
+        }
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+        Ok(())
+    }
+
+    /// Shift the digits by a power of 10, modifying self, stopping when the highest visible
+    /// magnitude reaches `i16::MAX` or the lowest visible magnitude reaches `i16::MIN`.
+    ///
+    /// See [`FixedDecimal::shift_magnitude_checked()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// dec.shift_magnitude_saturating(i16::MAX);
+    /// // The leading digit is at magnitude i16::MAX
+    /// assert_eq!(FixedDecimal::from(42).multiplied_pow10(i16::MAX - 1), dec);
+    /// ```
+    pub fn shift_magnitude_saturating(&mut self, delta: i16) {
+        let delta = match delta.cmp(&0) {
+            Ordering::Greater => cmp::min(delta, i16::MAX - self.upper_magnitude),
+            Ordering::Less => cmp::max(delta, i16::MIN - self.lower_magnitude),
+            Ordering::Equal => 0,
+        };
+        self.multiply_pow10(delta);
+    }
+
+    /// Shift the digits by a power of 10, modifying self, with the magnitudes of the digits
+    /// wrapping around at the boundary of `i16`.
+    ///
+    /// If the shift does not overflow, this is the same as
+    /// [`FixedDecimal::shift_magnitude_checked()`]. Otherwise, the digits that would be above
+    /// `i16::MAX` continue from `i16::MIN`, and vice versa, and padding is removed. If only some
+    /// of the digits wrap around, the number cannot be represented and is set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(3).multiplied_pow10(i16::MAX);
+    /// dec.shift_magnitude_wrapping(1);
+    /// assert_eq!(FixedDecimal::from(3).multiplied_pow10(i16::MIN), dec);
+    ///
+    /// let mut dec = FixedDecimal::from(12).multiplied_pow10(i16::MAX - 1);
+    /// dec.shift_magnitude_wrapping(1);
+    /// assert_eq!(FixedDecimal::from(0), dec);
+    /// ```
+    pub fn shift_magnitude_wrapping(&mut self, delta: i16) {
+        if self.shift_magnitude_checked(delta).is_ok() {
+            return;
+        }
+        if self.is_zero() {
+            self.clear();
+            return;
+        }
+        let high = self.magnitude.wrapping_add(delta);
+        let low = self.nonzero_magnitude_end().wrapping_add(delta);
+        if low > high {
+            self.clear();
+            return;
+        }
+        self.magnitude = high;
+        self.upper_magnitude = cmp::max(high, 0);
+        self.lower_magnitude = cmp::min(low, 0);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
     /// Returns the sign.
     /// # Examples
     /// ```
@@ -838,6 +1304,54 @@ impl FixedDecimal {
         self
     }
 
+    /// Formats the number for display in bidirectional text, such as in Arabic or Hebrew.
+    ///
+    /// The sign and the digits are wrapped in LEFT-TO-RIGHT OVERRIDE (U+202D) and POP
+    /// DIRECTIONAL FORMATTING (U+202C). This keeps the sign to the left of the digits and the
+    /// digits in order, whatever the direction of the surrounding text. That is the convention
+    /// for both Arabic and Hebrew.
+    ///
+    /// A negative sign is written as `minus_char`. A positive sign is written as `plus_char`,
+    /// or omitted if it is `None`. This allows locale-specific sign characters, such as
+    /// U+2212 MINUS SIGN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("-12.5").unwrap();
+    /// assert_eq!(dec.to_bidi_string('-', None), "\u{202D}-12.5\u{202C}");
+    /// assert_eq!(dec.to_bidi_string('\u{2212}', None), "\u{202D}\u{2212}12.5\u{202C}");
+    ///
+    /// let dec = FixedDecimal::from_str("+12.5").unwrap();
+    /// assert_eq!(dec.to_bidi_string('-', Some('+')), "\u{202D}+12.5\u{202C}");
+    /// assert_eq!(dec.to_bidi_string('-', None), "\u{202D}12.5\u{202C}");
+    /// ```
+    pub fn to_bidi_string(&self, minus_char: char, plus_char: Option<char>) -> String {
+        let sign = match self.sign {
+            Sign::Negative => Some(minus_char),
+            Sign::Positive => plus_char,
+            Sign::None => None,
+        };
+        let digits = self.clone().with_sign(Sign::None);
+        let mut result = String::with_capacity(
+            digits.writeable_length_hint().capacity()
+                + '\u{202D}'.len_utf8()
+                + sign.map_or(0, char::len_utf8)
+                + '\u{202C}'.len_utf8(),
+        );
+        result.push('\u{202D}');
+        if let Some(sign) = sign {
+            result.push(sign);
+        }
+        // Writing to a String is infallible
+        let _ = digits.write_to(&mut result);
+        result.push('\u{202C}');
+        result
+    }
+
     /// Remove leading zeroes, consuming self and returning a new object.
     ///
     /// # Examples
@@ -910,6 +1424,9 @@ impl FixedDecimal {
 
     /// Remove trailing zeroes, modifying self.
     ///
+    /// The removed zeros are not remembered. To display trailing zeros again, use
+    /// [`FixedDecimal::pad_end()`] with the lowest magnitude to display.
+    ///
     /// # Examples
     ///
     /// ```
@@ -922,6 +1439,9 @@ impl FixedDecimal {
     ///
     /// dec.trim_end();
     /// assert_eq!("0012.34", dec.to_string());
+    ///
+    /// dec.pad_end(-4);
+    /// assert_eq!("0012.3400", dec.to_string());
     /// ```
     ///
     /// There is no effect if the least significant digit has magnitude more than zero:
@@ -940,17 +1460,75 @@ impl FixedDecimal {
         self.check_invariants();
     }
 
-    /// Zero-pad the number on the left to a particular position,
-    /// returning the result.
+    /// Returns a canonical string for the numeric value of the number, suitable as a key in
+    /// maps and sets.
     ///
-    /// Negative numbers have no effect.
+    /// Numbers that are numerically equal have the same key, even if they are not equal as
+    /// [`FixedDecimal`]s: leading and trailing zeros are removed, and the sign is omitted for
+    /// positive numbers and for zero. The key is the plain decimal notation of the value, so
+    /// it can be parsed back with [`FixedDecimal::from_str()`](FromStr::from_str).
     ///
-    /// Also see [`FixedDecimal::with_max_position()`].
+    /// The key is the string representation of [`FixedDecimal::canonicalized()`]. Its format is
+    /// stable and will not change in future versions.
     ///
     /// # Examples
     ///
     /// ```
-    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::{FixedDecimal, Sign};
+    /// # use std::str::FromStr;
+    ///
+    /// let a = FixedDecimal::from_str("0012.3400").unwrap();
+    /// let b = FixedDecimal::from_str("+12.34").unwrap();
+    /// assert_ne!(a, b);
+    /// assert_eq!("12.34", a.canonical_key());
+    /// assert_eq!("12.34", b.canonical_key());
+    ///
+    /// let negative_zero = FixedDecimal::from_str("-0.00").unwrap();
+    /// assert_eq!("0", negative_zero.canonical_key());
+    /// ```
+    pub fn canonical_key(&self) -> String {
+        self.clone().canonicalized().write_to_string().into_owned()
+    }
+
+    /// Returns the canonical form of the number, which is equal to the canonical form of all
+    /// numerically equal numbers.
+    ///
+    /// Leading and trailing zeros are removed, and the sign is removed for positive numbers
+    /// and for zero. Use this before comparing or hashing numbers by their numeric value; see
+    /// [Equality and Hashing](FixedDecimal#equality-and-hashing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("+0012.3400").unwrap();
+    /// assert_eq!("12.34", dec.canonicalized().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("-0.00").unwrap();
+    /// assert_eq!("0", dec.canonicalized().to_string());
+    /// ```
+    pub fn canonicalized(mut self) -> Self {
+        self.trim_start();
+        self.trim_end();
+        if self.is_zero() || self.sign == Sign::Positive {
+            self.sign = Sign::None;
+        }
+        self
+    }
+
+    /// Zero-pad the number on the left to a particular position,
+    /// returning the result.
+    ///
+    /// Negative numbers have no effect.
+    ///
+    /// Also see [`FixedDecimal::with_max_position()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
     ///
     /// let mut dec = FixedDecimal::from(42);
     /// assert_eq!("42", dec.to_string());
@@ -1148,7 +1726,7 @@ impl FixedDecimal {
     pub fn set_max_position(&mut self, position: i16) {
         self.lower_magnitude = cmp::min(self.lower_magnitude, position);
         self.upper_magnitude = if position <= 0 { 0 } else { position - 1 };
-        if position <= self.nonzero_magnitude_end() {
+        if self.is_zero() || position <= self.nonzero_magnitude_end() {
             self.digits.clear();
             self.magnitude = 0;
             #[cfg(debug_assertions)]
@@ -1169,6 +1747,74 @@ impl FixedDecimal {
         self.check_invariants();
     }
 
+    /// Returns a new number with only the digits within a range of magnitudes, zero-padded to
+    /// that range. The sign is preserved, unless the result is zero, which has no negative sign.
+    ///
+    /// The range is inclusive on both ends, such as `-1..=2` for the tenths up to the hundreds.
+    /// A range written from the most significant magnitude, such as `2..=-1`, selects the same
+    /// digits. Digits keep their magnitudes, so slicing above the ones place leaves trailing
+    /// zeros in the integer part.
+    ///
+    /// Also see [`FixedDecimal::set_max_position()`] and [`FixedDecimal::trunc()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("-1234.56").unwrap();
+    /// assert_eq!("-234.5", dec.slice_magnitudes(-1..=2).to_string());
+    /// assert_eq!("-0.56", dec.slice_magnitudes(-2..=-1).to_string());
+    /// assert_eq!("-1000", dec.slice_magnitudes(3..=3).to_string());
+    /// assert_eq!("-001234.5600", dec.slice_magnitudes(-4..=5).to_string());
+    ///
+    /// // Ranges without any nonzero digits are zero
+    /// assert_eq!("0.000", dec.slice_magnitudes(-3..=-3).to_string());
+    /// ```
+    pub fn slice_magnitudes(&self, range: RangeInclusive<i16>) -> Self {
+        let upper = cmp::max(*range.start(), *range.end());
+        let lower = cmp::min(*range.start(), *range.end());
+        let mut result = self.clone();
+        result.trunc(lower);
+        result.set_max_position(upper.saturating_add(1));
+        result.pad_end(lower);
+        if result.is_zero() && result.sign == Sign::Negative {
+            result.sign = Sign::None;
+        }
+        result
+    }
+
+    /// Keeps only the digits within a range of magnitudes, zero-padding the number to that
+    /// range, and returns whether a nonzero digit was discarded.
+    ///
+    /// The number becomes [`FixedDecimal::slice_magnitudes()`] of the range. A return value of
+    /// `true` means that the displayed number has lost precision or its leading digits, which
+    /// can be used to warn about a lossy display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("12.5").unwrap();
+    /// assert!(!dec.fit_to_range_saturating(-2..=3));
+    /// assert_eq!("0012.50", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("12.345").unwrap();
+    /// assert!(dec.fit_to_range_saturating(-2..=3));
+    /// assert_eq!("0012.34", dec.to_string());
+    /// ```
+    pub fn fit_to_range_saturating(&mut self, range: RangeInclusive<i16>) -> bool {
+        let upper = cmp::max(*range.start(), *range.end());
+        let lower = cmp::min(*range.start(), *range.end());
+        let lossy =
+            !self.is_zero() && (self.magnitude > upper || self.nonzero_magnitude_end() < lower);
+        *self = self.slice_magnitudes(range);
+        lossy
+    }
+
     /// Truncates the number on the right to a particular position, deleting
     /// digits if necessary.
     ///
@@ -2814,6 +3460,162 @@ impl FixedDecimal {
         self
     }
 
+    /// Round the number at a particular position using the given [`RoundingMode`].
+    fn round_with_mode(&mut self, position: i16, mode: RoundingMode) {
+        match mode {
+            RoundingMode::Ceil => self.ceil(position),
+            RoundingMode::Expand => self.expand(position),
+            RoundingMode::Floor => self.floor(position),
+            RoundingMode::Trunc => self.trunc(position),
+            RoundingMode::HalfCeil => self.half_ceil(position),
+            RoundingMode::HalfExpand => self.half_expand(position),
+            RoundingMode::HalfFloor => self.half_floor(position),
+            RoundingMode::HalfTrunc => self.half_trunc(position),
+            RoundingMode::HalfEven => self.half_even(position),
+        }
+    }
+
+    /// Rounds the number at a particular position using the given [`RoundingMode`], and
+    /// returns the discarded remainder, which is the original number minus the rounded number.
+    ///
+    /// The remainder is exact, so adding it to the rounded number gives back the original
+    /// value. This is useful to keep track of rounding residue, for example so that rounded
+    /// amounts still add up to a rounded total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("1.2345").unwrap();
+    /// let remainder = dec.round_with_remainder(-2, RoundingMode::HalfExpand);
+    /// assert_eq!("1.23", dec.to_string());
+    /// assert_eq!("0.0045", remainder.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("-7.5").unwrap();
+    /// let remainder = dec.round_with_remainder(0, RoundingMode::Floor);
+    /// assert_eq!("-8", dec.to_string());
+    /// assert_eq!("0.5", remainder.to_string());
+    /// ```
+    pub fn round_with_remainder(&mut self, position: i16, mode: RoundingMode) -> FixedDecimal {
+        let mut remainder = self.clone();
+        self.round_with_mode(position, mode);
+        // The magnitude of the difference is at most that of the operands, so this cannot fail
+        let result = remainder.checked_sub_assign(self);
+        debug_assert!(result.is_ok());
+        remainder
+    }
+
+    /// Adjusts the number to have the same lowest magnitude as `reference`, rounding with the
+    /// given [`RoundingMode`] if digits need to be removed, and padding with trailing zeros
+    /// otherwise.
+    ///
+    /// This behaves like Python's `Decimal.quantize`. Only the lowest magnitude of `reference`
+    /// is used; its value and sign are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let cent = FixedDecimal::from_str("0.01").unwrap();
+    ///
+    /// let mut dec = FixedDecimal::from_str("1.2").unwrap();
+    /// dec.quantize(&cent, RoundingMode::HalfEven);
+    /// assert_eq!("1.20", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("1.234567").unwrap();
+    /// dec.quantize(&cent, RoundingMode::HalfEven);
+    /// assert_eq!("1.23", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("-1.235").unwrap();
+    /// dec.quantize(&cent, RoundingMode::Floor);
+    /// assert_eq!("-1.24", dec.to_string());
+    /// ```
+    pub fn quantize(&mut self, reference: &FixedDecimal, mode: RoundingMode) {
+        let position = reference.lower_magnitude;
+        self.round_with_mode(position, mode);
+        self.pad_end(position);
+    }
+
+    /// Adjusts the number to have the same lowest magnitude as `reference`, returning the result.
+    ///
+    /// See [`FixedDecimal::quantize()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let reference = FixedDecimal::from_str("0.1").unwrap();
+    /// let dec = FixedDecimal::from_str("2.25").unwrap();
+    /// assert_eq!(
+    ///     "2.2",
+    ///     dec.clone()
+    ///         .quantized(&reference, RoundingMode::HalfEven)
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "2.3",
+    ///     dec.quantized(&reference, RoundingMode::HalfExpand)
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn quantized(mut self, reference: &FixedDecimal, mode: RoundingMode) -> Self {
+        self.quantize(reference, mode);
+        self
+    }
+
+    /// Returns the number with exactly `integer_digits` digits before and `fraction_digits`
+    /// digits after the decimal point, rounding with the given [`RoundingMode`] and padding
+    /// with zeros as needed.
+    ///
+    /// Integer digits above `integer_digits` are removed, like a clock that wraps around, also
+    /// if rounding carries into them. With zero integer digits, a single 0 is still shown
+    /// before the decimal point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("5.7").unwrap();
+    /// assert_eq!(
+    ///     "06",
+    ///     dec.with_exact_digits(2, 0, RoundingMode::HalfExpand)
+    ///         .to_string()
+    /// );
+    ///
+    /// let dec = FixedDecimal::from_str("7.25").unwrap();
+    /// assert_eq!(
+    ///     "007.3",
+    ///     dec.with_exact_digits(3, 1, RoundingMode::HalfExpand)
+    ///         .to_string()
+    /// );
+    ///
+    /// let dec = FixedDecimal::from_str("123.4").unwrap();
+    /// assert_eq!(
+    ///     "23.400",
+    ///     dec.with_exact_digits(2, 3, RoundingMode::Trunc).to_string()
+    /// );
+    /// ```
+    pub fn with_exact_digits(
+        mut self,
+        integer_digits: u8,
+        fraction_digits: u8,
+        mode: RoundingMode,
+    ) -> Self {
+        let position = -i16::from(fraction_digits);
+        self.round_with_mode(position, mode);
+        self.pad_end(position);
+        self.set_max_position(i16::from(integer_digits));
+        self
+    }
+
     /// Concatenate another `FixedDecimal` into the end of this `FixedDecimal`.
     ///
     /// All nonzero digits in `other` must have lower magnitude than nonzero digits in `self`.
@@ -2885,490 +3687,1897 @@ impl FixedDecimal {
         Ok(())
     }
 
-    /// Appends a slice of digits to the end of `self.digits` with optional inner zeroes.
+    /// Multiply the number by an unsigned integer in place.
     ///
-    /// This function does not check invariants.
-    fn append_digits(&mut self, inner_zeroes: usize, new_digits: &[u8]) {
-        let new_len = self.digits.len() + inner_zeroes;
-        self.digits.resize_with(new_len, || 0);
-        self.digits.extend_from_slice(new_digits);
-    }
-
-    /// Assert that the invariants among struct fields are enforced. Returns true if all are okay.
-    /// Call this in any method that mutates the struct fields.
+    /// The result is exact. The lowest magnitude of the number is retained; trailing zeros are
+    /// not added below it. Multiplying by zero removes a negative sign.
     ///
-    /// Example: `debug_assert!(self.check_invariants())`
-    #[cfg(debug_assertions)]
-    #[allow(clippy::indexing_slicing)]
-    fn check_invariants(&self) {
-        // magnitude invariants:
-        debug_assert!(
-            self.upper_magnitude >= self.magnitude,
-            "Upper magnitude too small {self:?}"
-        );
-        debug_assert!(
-            self.lower_magnitude <= self.magnitude,
-            "Lower magnitude too large {self:?}"
-        );
-        debug_assert!(
-            self.upper_magnitude >= 0,
-            "Upper magnitude below zero {self:?}"
-        );
-        debug_assert!(
-            self.lower_magnitude <= 0,
-            "Lower magnitude above zero {self:?}",
-        );
-
-        // digits invariants:
-        debug_assert!(
-            self.digits.len() <= (self.magnitude as i32 - self.lower_magnitude as i32 + 1) as usize,
-            "{self:?}"
-        );
-        if !self.digits.is_empty() {
-            debug_assert_ne!(self.digits[0], 0, "Starts with a zero {self:?}");
-            debug_assert_ne!(
-                self.digits[self.digits.len() - 1],
-                0,
-                "Ends with a zero {self:?}",
-            );
-        } else {
-            debug_assert_eq!(self.magnitude, 0);
-        }
-    }
-}
-
-/// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
-///
-/// # Examples
-///
-/// ```
-/// # use fixed_decimal::FixedDecimal;
-/// # use writeable::assert_writeable_eq;
-/// #
-/// assert_writeable_eq!(FixedDecimal::from(42), "42");
-/// ```
-impl writeable::Writeable for FixedDecimal {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        match self.sign {
-            Sign::Negative => sink.write_char('-')?,
-            Sign::Positive => sink.write_char('+')?,
-            Sign::None => (),
+    /// Returns [`Error::Limit`] and leaves the number unchanged if the magnitude of the result
+    /// does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Error;
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// dec.checked_mul_assign(3).unwrap();
+    /// assert_eq!("126", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+    /// assert_eq!(Err(Error::Limit), dec.checked_mul_assign(2));
+    /// ```
+    pub fn checked_mul_assign(&mut self, rhs: u32) -> Result<(), Error> {
+        if self.is_zero() {
+            return Ok(());
         }
-        for m in self.magnitude_range().rev() {
-            if m == -1 {
-                sink.write_char('.')?;
+        if rhs == 0 {
+            // Retain leading zeros only if `self` was explicitly padded
+            if self.upper_magnitude == self.magnitude {
+                self.upper_magnitude = 0;
             }
-            let d = self.digit_at(m);
-            sink.write_char((b'0' + d) as char)?;
+            self.digits.clear();
+            self.magnitude = 0;
+            if self.sign == Sign::Negative {
+                self.sign = Sign::None;
+            }
+            #[cfg(debug_assertions)]
+            self.check_invariants();
+            return Ok(());
+        }
+        let lowest_nonzero = self.nonzero_magnitude_end();
+        let multiplier = rhs as u64;
+        // Digits of the product, in ascending order of magnitude starting at `lowest_nonzero`.
+        let mut product: SmallVec<[u8; 16]> = SmallVec::new();
+        let mut carry: u64 = 0;
+        for digit in self.digits.iter().rev() {
+            // digit * multiplier + carry < 10 * u32::MAX + u32::MAX, so this cannot overflow
+            let value = *digit as u64 * multiplier + carry;
+            product.push((value % 10) as u8);
+            carry = value / 10;
         }
+        while carry > 0 {
+            product.push((carry % 10) as u8);
+            carry /= 10;
+        }
+        let magnitude = lowest_nonzero as i32 + product.len() as i32 - 1;
+        if magnitude > i16::MAX as i32 {
+            return Err(Error::Limit);
+        }
+        self.magnitude = magnitude as i16;
+        self.upper_magnitude = cmp::max(self.upper_magnitude, self.magnitude);
+        let trailing_zeros = product.iter().position(|d| *d != 0).unwrap_or(0);
+        self.digits.clear();
+        #[allow(clippy::indexing_slicing)] // trailing_zeros < product.len()
+        self.digits
+            .extend(product[trailing_zeros..].iter().rev().copied());
+        #[cfg(debug_assertions)]
+        self.check_invariants();
         Ok(())
     }
 
-    fn writeable_length_hint(&self) -> writeable::LengthHint {
-        writeable::LengthHint::exact(1)
-            + ((self.upper_magnitude as i32 - self.lower_magnitude as i32) as usize)
-            + (self.sign != Sign::None) as usize
-            + (self.lower_magnitude < 0) as usize
-    }
-}
-
-writeable::impl_display_with_writeable!(FixedDecimal);
-
-impl FromStr for FixedDecimal {
-    type Err = Error;
-    fn from_str(input_str: &str) -> Result<Self, Self::Err> {
-        Self::try_from(input_str.as_bytes())
+    /// Multiply the number by two in place.
+    ///
+    /// The result is exact. The lowest magnitude of the number is retained, and the highest
+    /// magnitude grows by one if the doubling carries into a new leading digit, so `0.50`
+    /// becomes `1.00` and `5` becomes `10`.
+    ///
+    /// Returns [`Error::Limit`] and leaves the number unchanged if the magnitude of the result
+    /// does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Error;
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-0.75").unwrap();
+    /// dec.double().unwrap();
+    /// assert_eq!("-1.50", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+    /// assert_eq!(Err(Error::Limit), dec.double());
+    /// ```
+    pub fn double(&mut self) -> Result<(), Error> {
+        self.checked_mul_assign(2)
     }
-}
 
-impl TryFrom<&[u8]> for FixedDecimal {
-    type Error = Error;
-    fn try_from(input_str: &[u8]) -> Result<Self, Self::Error> {
-        // input_str: the input string
-        // no_sign_str: the input string when the sign is removed from it
-        if input_str.is_empty() {
-            return Err(Error::Syntax);
+    /// Divide the number by two in place.
+    ///
+    /// If the lowest nonzero digit is odd, the exact result needs one more digit, which is
+    /// always a 5, one position below it. The number is extended to that position only if it
+    /// is not lower than `position_limit`; otherwise, the result is rounded half-to-even (see
+    /// [`FixedDecimal::half_even()`]) at the position of the lowest nonzero digit.
+    ///
+    /// Leading zeros are retained only if the number was padded, so `12` becomes `6` while `012`
+    /// becomes `006`. The lowest magnitude only grows downwards to fit the extra digit, so `1.0`
+    /// becomes `0.5` and `3` becomes `1.5`. A result that is rounded to zero has no negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-3.00").unwrap();
+    /// dec.halve(i16::MIN);
+    /// assert_eq!("-1.50", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(3);
+    /// dec.halve(i16::MIN);
+    /// assert_eq!("1.5", dec.to_string());
+    ///
+    /// // 1.5 is not allowed, so 3 / 2 is rounded to the even neighbor
+    /// let mut dec = FixedDecimal::from(3);
+    /// dec.halve(0);
+    /// assert_eq!("2", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(-1);
+    /// dec.halve(0);
+    /// assert_eq!("0", dec.to_string());
+    /// ```
+    pub fn halve(&mut self, position_limit: i16) {
+        if self.is_zero() {
+            return;
         }
-        #[allow(clippy::indexing_slicing)] // The string is not empty.
-        let sign = match input_str[0] {
-            b'-' => Sign::Negative,
-            b'+' => Sign::Positive,
-            _ => Sign::None,
-        };
-        #[allow(clippy::indexing_slicing)] // The string is not empty.
-        let no_sign_str = if sign == Sign::None {
-            input_str
-        } else {
-            &input_str[1..]
-        };
-        if no_sign_str.is_empty() {
-            return Err(Error::Syntax);
+        let lowest_nonzero = self.nonzero_magnitude_end();
+        // Digits of the quotient, in descending order of magnitude starting at `self.magnitude`.
+        let mut quotient: SmallVec<[u8; 8]> = SmallVec::new();
+        let mut remainder = 0;
+        for digit in self.digits.iter() {
+            let value = remainder * 10 + *digit;
+            quotient.push(value / 2);
+            remainder = value % 2;
         }
-        // Compute length of each string once and store it, so if you use that multiple times,
-        // you don't compute it multiple times
-        // has_dot: shows if your input has dot in it
-        // has_exponent: shows if your input has an exponent in it
-        // dot_index: gives the index of dot (after removing the sign) -- equal to length of
-        // the no_sign_str if there is no dot
-        // exponent_index: gives the index of exponent (after removing the sign) -- equal to length of
-        // the no_sign_str if there is no dot
-        let mut has_dot = false;
-        let mut has_exponent = false;
-        let mut dot_index = no_sign_str.len();
-        let mut exponent_index = no_sign_str.len();
-        // The following loop computes has_dot, dot_index, and also checks to see if all
-        // characters are digits and if you have at most one dot
-        // Note: Input of format 111_123 is detected as syntax error here
-        // Note: Input starting or ending with a dot is detected as syntax error here (Ex: .123, 123.)
-        for (i, c) in no_sign_str.iter().enumerate() {
-            if *c == b'.' {
-                if has_dot || has_exponent {
-                    // multiple dots or dots after the exponent
-                    return Err(Error::Syntax);
-                }
-                dot_index = i;
-                has_dot = true;
-                // We do support omitting the leading zero,
-                // but not trailing decimal points
-                if i == no_sign_str.len() - 1 {
-                    return Err(Error::Syntax);
-                }
-            } else if *c == b'e' || *c == b'E' {
-                if has_exponent {
-                    // multiple exponents
-                    return Err(Error::Syntax);
-                }
-                exponent_index = i;
-                has_exponent = true;
-                if i == 0 || i == no_sign_str.len() - 1 {
-                    return Err(Error::Syntax);
-                }
-            } else if *c == b'-' {
-                // Allow a single minus sign after the exponent
-                if has_exponent && exponent_index == i - 1 {
-                    continue;
-                } else {
-                    return Err(Error::Syntax);
+        let mut lowest = lowest_nonzero;
+        if remainder == 1 {
+            if lowest_nonzero > position_limit && lowest_nonzero > i16::MIN {
+                quotient.push(5);
+                lowest -= 1;
+            } else if quotient.last().map(|d| d % 2 == 1).unwrap_or(false) {
+                // The leading digit of the quotient is at most 4, so the carry stops there
+                for digit in quotient.iter_mut().rev() {
+                    if *digit == 9 {
+                        *digit = 0;
+                    } else {
+                        *digit += 1;
+                        break;
+                    }
                 }
-            } else if *c < b'0' || *c > b'9' {
-                return Err(Error::Syntax);
             }
         }
-
-        // The string without the exponent (or sign)
-        // We do the bulk of the calculation on this string,
-        // and extract the exponent at the end
-        #[allow(clippy::indexing_slicing)] // exponent_index comes from enumerate
-        let no_exponent_str = &no_sign_str[..exponent_index];
-
-        // If there was no dot, truncate the dot index
-        if dot_index > exponent_index {
-            dot_index = exponent_index;
-        }
-
-        // defining the output dec here and set its sign
-        let mut dec = Self {
-            sign,
-            ..Default::default()
-        };
-
-        // no_dot_str_len: shows length of the string after removing the dot
-        let mut no_dot_str_len = no_exponent_str.len();
-        if has_dot {
-            no_dot_str_len -= 1;
-        }
-
-        // Computing DecimalFixed.upper_magnitude
-        // We support strings like `0.x` and `.x`. The upper magnitude
-        // is always one less than the position of the dot, except in the case where
-        // the 0 is omitted; when dot_index = 0. We use saturating_sub to set
-        // magnitude to 0 in that case.
-        let temp_upper_magnitude = dot_index.saturating_sub(1);
-        if temp_upper_magnitude > i16::MAX as usize {
-            return Err(Error::Limit);
-        }
-        dec.upper_magnitude = temp_upper_magnitude as i16;
-
-        // Computing DecimalFixed.lower_magnitude
-        let temp_lower_magnitude = no_dot_str_len - dot_index;
-        if temp_lower_magnitude > (i16::MIN as u16) as usize {
-            return Err(Error::Limit);
-        }
-        dec.lower_magnitude = (temp_lower_magnitude as i16).wrapping_neg();
-
-        // leftmost_digit: index of the first non-zero digit
-        // rightmost_digit: index of the first element after the last non-zero digit
-        // Example:
-        //     input string    leftmost_digit     rightmost_digit
-        //     00123000              2                  5
-        //     0.0123000             3                  6
-        //     001.23000             2                  6
-        //     001230.00             2                  5
-        // Compute leftmost_digit
-        let leftmost_digit = no_exponent_str
-            .iter()
-            .position(|c| *c != b'.' && *c != b'0');
-
-        // If the input only has zeros (like 000, 00.0, -00.000) we handle the situation here
-        // by returning the dec and don't running the rest of the code
-        let leftmost_digit = if let Some(leftmost_digit) = leftmost_digit {
-            leftmost_digit
+        let leading_zeros = quotient.iter().position(|d| *d != 0);
+        let trailing_zeros = quotient.iter().rev().position(|d| *d != 0).unwrap_or(0);
+        let padded = self.upper_magnitude > self.magnitude;
+        self.digits.clear();
+        if let Some(leading_zeros) = leading_zeros {
+            // leading_zeros < quotient.len() <= self.magnitude - i16::MIN + 1
+            self.magnitude -= leading_zeros as i16;
+            #[allow(clippy::indexing_slicing)] // leading_zeros + trailing_zeros < quotient.len()
+            self.digits
+                .extend_from_slice(&quotient[leading_zeros..quotient.len() - trailing_zeros]);
         } else {
-            return Ok(dec);
-        };
-
-        // Else if the input is not all zeros, we compute its magnitude:
-        // Note that we can cast with "as" here because lower and upper magnitude have been checked already
-        let mut temp_magnitude = ((dot_index as i32) - (leftmost_digit as i32) - 1i32) as i16;
-        if dot_index < leftmost_digit {
-            temp_magnitude += 1;
-        }
-        dec.magnitude = temp_magnitude;
-
-        // Compute the index where the rightmost_digit ends
-        let rightmost_digit_end = no_exponent_str
-            .iter()
-            .rposition(|c| *c != b'.' && *c != b'0')
-            .map(|p| p + 1)
-            .unwrap_or(no_exponent_str.len());
-
-        // digits_str_len: shows the length of digits (Ex. 0012.8900 --> 4)
-        let mut digits_str_len = rightmost_digit_end - leftmost_digit;
-        if leftmost_digit < dot_index && dot_index < rightmost_digit_end {
-            digits_str_len -= 1;
-        }
-
-        // Constructing DecimalFixed.digits
-        #[allow(clippy::indexing_slicing)]
-        // leftmost_digit  and rightmost_digit_end come from Iterator::position and Iterator::rposition.
-        let v: SmallVec<[u8; 8]> = no_exponent_str[leftmost_digit..rightmost_digit_end]
-            .iter()
-            .filter(|c| **c != b'.')
-            .map(|c| c - b'0')
-            .collect();
-
-        let v_len = v.len();
-        debug_assert_eq!(v_len, digits_str_len);
-        dec.digits = v;
-
-        // Extract the exponent part
-        if has_exponent {
-            let mut pow = 0;
-            let mut pos_neg = 1;
-            #[allow(clippy::indexing_slicing)]
-            // exponent_index is exist, then exponent_index + 1 will equal at most no_sign_str.len().
-            for digit in &no_sign_str[exponent_index + 1..] {
-                if *digit == b'-' {
-                    pos_neg = -1;
-                    continue;
-                }
-                pow *= 10;
-                pow += (digit - b'0') as i16;
-            }
-
-            dec.multiply_pow10(pos_neg * pow);
-
-            // Clean up magnitude after multiplication
-            if dec.magnitude > 0 {
-                dec.upper_magnitude = dec.magnitude;
-            }
-            let neg_mag = dec.magnitude - dec.digits.len() as i16 + 1;
-            if neg_mag < 0 {
-                dec.lower_magnitude = neg_mag;
+            self.magnitude = 0;
+            if self.sign == Sign::Negative {
+                self.sign = Sign::None;
             }
         }
-
-        Ok(dec)
+        if !padded {
+            self.upper_magnitude = cmp::max(self.magnitude, 0);
+        }
+        self.lower_magnitude = cmp::min(self.lower_magnitude, lowest);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
     }
-}
 
-/// Specifies the precision of a floating point value when constructing a FixedDecimal.
-///
-/// IEEE 754 is a representation of a point on the number line. On the other hand, FixedDecimal
-/// specifies not only the point on the number line but also the precision of the number to a
-/// specific power of 10. This enum augments a floating-point value with the additional
-/// information required by FixedDecimal.
-#[non_exhaustive]
-#[cfg(feature = "ryu")]
-#[derive(Debug, Clone, Copy)]
-pub enum FloatPrecision {
-    /// Specify that the floating point number is integer-valued.
+    /// Returns the unit in the last place (ULP) of the number, which is `10^lower_magnitude`:
+    /// the value of a 1 at the lowest visible magnitude.
     ///
-    /// If the floating point is not actually integer-valued, an error will be returned.
-    Integer,
-
-    /// Specify that the floating point number is precise to a specific power of 10.
-    /// The number may be rounded or trailing zeros may be added as necessary.
-    Magnitude(i16),
-
-    /// Specify that the floating point number is precise to a specific number of significant digits.
-    /// The number may be rounded or trailing zeros may be added as necessary.
+    /// The result is positive and only depends on the precision of the number, so trailing
+    /// zeros count: the ULP of `3.140` is `0.001`.
     ///
-    /// The number requested may not be zero
-    SignificantDigits(u8),
-
-    /// Specify that the floating point number is precise to the maximum representable by IEEE.
+    /// # Examples
     ///
-    /// This results in a FixedDecimal having enough digits to recover the original floating point
-    /// value, with no trailing zeros.
-    Floating,
-}
-
-#[cfg(feature = "ryu")]
-impl FixedDecimal {
-    /// Construct a [`FixedDecimal`] from an f64.
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
     ///
-    /// Since f64 values do not carry a notion of their precision, the second argument to this
-    /// function specifies the type of precision associated with the f64. For more information,
-    /// see [`FloatPrecision`].
+    /// let dec = FixedDecimal::from_str("-3.14").unwrap();
+    /// assert_eq!("0.01", dec.unit_in_last_place().to_string());
     ///
-    /// This function uses `ryu`, which is an efficient double-to-string algorithm, but other
-    /// implementations may yield higher performance; for more details, see
-    /// [icu4x#166](https://github.com/unicode-org/icu4x/issues/166).
+    /// let dec = FixedDecimal::from(1200);
+    /// assert_eq!("1", dec.unit_in_last_place().to_string());
+    /// ```
+    pub fn unit_in_last_place(&self) -> Self {
+        // lower_magnitude <= 0, so the result fits.
+        FixedDecimal::from(1).multiplied_pow10(self.lower_magnitude)
+    }
+
+    /// Returns half of the unit in the last place, `0.5 * 10^lower_magnitude`, which is the
+    /// largest error introduced by rounding a number to the precision of this one.
     ///
-    /// This function can be made available with the `"ryu"` Cargo feature.
+    /// This is built on [`FixedDecimal::unit_in_last_place()`], so zero is not special: `0`
+    /// returns `0.5`, and `0.00` returns `0.005`. If the lowest visible magnitude is
+    /// `i16::MIN`, half of the unit cannot be represented, and zero is returned.
     ///
-    /// ```rust
-    /// use fixed_decimal::{FixedDecimal, FloatPrecision};
-    /// use writeable::assert_writeable_eq;
+    /// # Examples
     ///
-    /// let decimal =
-    ///     FixedDecimal::try_from_f64(-5.1, FloatPrecision::Magnitude(-2))
-    ///         .expect("Finite quantity with limited precision");
-    /// assert_writeable_eq!(decimal, "-5.10");
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
     ///
-    /// let decimal =
-    ///     FixedDecimal::try_from_f64(0.012345678, FloatPrecision::Floating)
-    ///         .expect("Finite quantity");
-    /// assert_writeable_eq!(decimal, "0.012345678");
+    /// // "measured as 3.14 ± 0.005"
+    /// let dec = FixedDecimal::from_str("3.14").unwrap();
+    /// assert_eq!("0.005", dec.rounding_half_ulp().to_string());
     ///
-    /// let decimal =
-    ///     FixedDecimal::try_from_f64(12345678000., FloatPrecision::Integer)
-    ///         .expect("Finite, integer-valued quantity");
-    /// assert_writeable_eq!(decimal, "12345678000");
+    /// assert_eq!("0.5", FixedDecimal::from(0).rounding_half_ulp().to_string());
     /// ```
+    pub fn rounding_half_ulp(&self) -> Self {
+        let mut result = self.unit_in_last_place();
+        result.halve(i16::MIN);
+        result
+    }
+
+    /// Add another number to this number in place.
     ///
-    /// Negative zero is supported.
+    /// The result is exact. Its lowest and highest magnitudes are the union of those of both
+    /// operands, so `1.5 + 2.25` is `3.75` and `0012 + 1.0` is `0013.0`.
     ///
-    /// ```rust
-    /// use fixed_decimal::{FixedDecimal, FloatPrecision};
-    /// use writeable::assert_writeable_eq;
+    /// Returns [`Error::Limit`] and leaves the number unchanged if the magnitude of the result
+    /// does not fit in an `i16`.
+    ///
+    /// # Examples
     ///
-    /// // IEEE 754 for floating point defines the sign bit separate
-    /// // from the mantissa and exponent, allowing for -0.
-    /// let negative_zero =
-    ///     FixedDecimal::try_from_f64(-0.0, FloatPrecision::Integer)
-    ///         .expect("Negative zero");
-    /// assert_writeable_eq!(negative_zero, "-0");
     /// ```
-    pub fn try_from_f64(float: f64, precision: FloatPrecision) -> Result<Self, Error> {
-        let mut decimal = Self::new_from_f64_raw(float)?;
-        let n_digits = decimal.digits.len();
-        // magnitude of the lowest digit in self.digits
-        let lowest_magnitude = decimal.magnitude - n_digits as i16 + 1;
-        // ryū will usually tack on a `.0` to integers which gets included when parsing.
-        // Explicitly remove it before doing anything else
-        if lowest_magnitude >= 0 && decimal.lower_magnitude < 0 {
-            decimal.lower_magnitude = 0;
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("1.5").unwrap();
+    /// dec.checked_add_assign(&FixedDecimal::from_str("2.25").unwrap())
+    ///     .unwrap();
+    /// assert_eq!("3.75", dec.to_string());
+    ///
+    /// dec.checked_add_assign(&FixedDecimal::from(-5)).unwrap();
+    /// assert_eq!("-1.25", dec.to_string());
+    /// ```
+    pub fn checked_add_assign(&mut self, rhs: &Self) -> Result<(), Error> {
+        self.add_signed(rhs, rhs.sign == Sign::Negative)
+    }
+
+    /// Subtract another number from this number in place.
+    ///
+    /// See [`FixedDecimal::checked_add_assign()`] for the precision of the result.
+    ///
+    /// Returns [`Error::Limit`] and leaves the number unchanged if the magnitude of the result
+    /// does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from(10);
+    /// dec.checked_sub_assign(&FixedDecimal::from_str("0.01").unwrap())
+    ///     .unwrap();
+    /// assert_eq!("9.99", dec.to_string());
+    ///
+    /// dec.checked_sub_assign(&FixedDecimal::from(20)).unwrap();
+    /// assert_eq!("-10.01", dec.to_string());
+    /// ```
+    pub fn checked_sub_assign(&mut self, rhs: &Self) -> Result<(), Error> {
+        self.add_signed(rhs, rhs.sign != Sign::Negative)
+    }
+
+    /// Subtract an integer from this number, returning the result.
+    ///
+    /// The result is the same as with [`FixedDecimal::checked_sub_assign()`], but if `self` is
+    /// an integer that fits in an `i128`, it is computed with integer arithmetic instead of
+    /// digit by digit.
+    ///
+    /// Returns [`Error::Limit`] if the magnitude of the result does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from(10);
+    /// assert_eq!("7", dec.checked_sub_integer(3).unwrap().to_string());
+    /// assert_eq!("-2", dec.checked_sub_integer(12).unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("25.00").unwrap();
+    /// assert_eq!("20.00", dec.checked_sub_integer(5).unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("1.25").unwrap();
+    /// assert_eq!("-0.75", dec.checked_sub_integer(2).unwrap().to_string());
+    /// ```
+    pub fn checked_sub_integer(&self, n: i64) -> Result<Self, Error> {
+        if n == 0 {
+            return Ok(self.clone());
         }
-        match precision {
-            FloatPrecision::Floating => (),
-            FloatPrecision::Integer => {
-                if lowest_magnitude < 0 {
-                    return Err(Error::Limit);
-                }
+        let value = if self.nonzero_magnitude_end() >= 0 {
+            // The digits, followed by the zeros between the last digit and the decimal point
+            self.digits
+                .iter()
+                .map(|d| *d as i128)
+                .chain(core::iter::repeat(0).take(self.nonzero_magnitude_end() as usize))
+                .try_fold(0i128, |acc, d| acc.checked_mul(10)?.checked_add(d))
+                .and_then(|value| {
+                    if self.sign == Sign::Negative {
+                        value.checked_neg()
+                    } else {
+                        Some(value)
+                    }
+                })
+                .and_then(|value| value.checked_sub(n as i128))
+        } else {
+            None
+        };
+        let Some(value) = value else {
+            let mut result = self.clone();
+            result.checked_sub_assign(&Self::from(n))?;
+            return Ok(result);
+        };
+
+        let mut result = Self::from(value);
+        // Retain the precision of `self` as in `add_signed`
+        result.lower_magnitude = self.lower_magnitude;
+        if self.upper_magnitude > self.magnitude {
+            result.upper_magnitude = cmp::max(self.upper_magnitude, result.magnitude);
+        }
+        if value >= 0 && self.sign == Sign::Positive {
+            result.sign = Sign::Positive;
+        }
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Returns the next larger number at the precision of this number, which is this number
+    /// plus one unit at its lowest visible magnitude.
+    ///
+    /// The visible range is kept, except that a carry can extend it upwards. The result of
+    /// stepping from a negative number to zero is unsigned.
+    ///
+    /// Returns [`Error::Limit`] if the magnitude of the result does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("1.99").unwrap();
+    /// assert_eq!("2.00", dec.next_up().unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("-1.99").unwrap();
+    /// assert_eq!("-1.98", dec.next_up().unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("-0.1").unwrap();
+    /// assert_eq!("0.0", dec.next_up().unwrap().to_string());
+    /// ```
+    pub fn next_up(&self) -> Result<Self, Error> {
+        self.step_at_lower_magnitude(false)
+    }
+
+    /// Returns the next smaller number at the precision of this number, which is this number
+    /// minus one unit at its lowest visible magnitude.
+    ///
+    /// See [`FixedDecimal::next_up()`] for the precision and the sign of the result.
+    ///
+    /// Returns [`Error::Limit`] if the magnitude of the result does not fit in an `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("2.00").unwrap();
+    /// assert_eq!("1.99", dec.next_down().unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from_str("-1.99").unwrap();
+    /// assert_eq!("-2.00", dec.next_down().unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from(0);
+    /// assert_eq!("-1", dec.next_down().unwrap().to_string());
+    /// ```
+    pub fn next_down(&self) -> Result<Self, Error> {
+        self.step_at_lower_magnitude(true)
+    }
+
+    /// Adds one unit at the lowest visible magnitude to a copy of `self`, or subtracts it if
+    /// `negative` is set.
+    fn step_at_lower_magnitude(&self, negative: bool) -> Result<Self, Error> {
+        let mut unit = FixedDecimal::from(1);
+        unit.shift_magnitude_checked(self.lower_magnitude)?;
+        let mut result = self.clone();
+        result.add_signed(&unit, negative)?;
+        if result.is_zero() {
+            result.sign = Sign::None;
+        }
+        Ok(result)
+    }
+
+    /// Adds `rhs` to `self`, treating `rhs` as negative if `rhs_negative` is set.
+    fn add_signed(&mut self, rhs: &Self, rhs_negative: bool) -> Result<(), Error> {
+        let lower_magnitude = cmp::min(self.lower_magnitude, rhs.lower_magnitude);
+        // Retain leading zeros only if an operand was explicitly padded
+        let padded_upper_magnitude = |number: &Self| {
+            if number.upper_magnitude > number.magnitude {
+                number.upper_magnitude
+            } else {
+                0
             }
-            FloatPrecision::Magnitude(mag) => {
-                decimal.half_even(mag);
+        };
+        let upper_magnitude = cmp::max(padded_upper_magnitude(self), padded_upper_magnitude(rhs));
+        if rhs.is_zero() {
+            self.lower_magnitude = lower_magnitude;
+            self.upper_magnitude = cmp::max(upper_magnitude, self.magnitude);
+            #[cfg(debug_assertions)]
+            self.check_invariants();
+            return Ok(());
+        }
+
+        let self_negative = self.sign == Sign::Negative;
+        // Digits of both operands in ascending order of magnitude, with room for a carry
+        let low = if self.is_zero() {
+            rhs.nonzero_magnitude_end()
+        } else {
+            cmp::min(self.nonzero_magnitude_end(), rhs.nonzero_magnitude_end())
+        };
+        let high = cmp::max(self.magnitude, rhs.magnitude) as i32 + 1;
+        let digits_of = |number: &Self| -> SmallVec<[u8; 16]> {
+            (low as i32..=high)
+                // The carry position may be out of range for i16, but is zero in both operands
+                .map(|m| i16::try_from(m).map(|m| number.digit_at(m)).unwrap_or(0))
+                .collect()
+        };
+        let lhs_digits = digits_of(self);
+        let rhs_digits = digits_of(rhs);
+
+        let mut result: SmallVec<[u8; 16]> = SmallVec::with_capacity(lhs_digits.len());
+        let result_negative = if self_negative == rhs_negative {
+            let mut carry = 0;
+            for (a, b) in lhs_digits.iter().zip(rhs_digits.iter()) {
+                let sum = a + b + carry;
+                result.push(sum % 10);
+                carry = sum / 10;
             }
-            FloatPrecision::SignificantDigits(sig) => {
-                if sig == 0 {
-                    return Err(Error::Limit);
+            self_negative
+        } else {
+            // Subtract the smaller absolute value from the larger one
+            let (larger, smaller, larger_negative) =
+                match lhs_digits.iter().rev().cmp(rhs_digits.iter().rev()) {
+                    Ordering::Less => (&rhs_digits, &lhs_digits, rhs_negative),
+                    _ => (&lhs_digits, &rhs_digits, self_negative),
+                };
+            let mut borrow = 0;
+            for (a, b) in larger.iter().zip(smaller.iter()) {
+                if *a >= b + borrow {
+                    result.push(a - b - borrow);
+                    borrow = 0;
+                } else {
+                    result.push(a + 10 - b - borrow);
+                    borrow = 1;
                 }
+            }
+            larger_negative
+        };
 
-                let position = decimal.magnitude - (sig as i16) + 1;
-                let old_magnitude = decimal.magnitude;
-                decimal.half_even(position);
+        if let Some(top) = result.iter().rposition(|d| *d != 0) {
+            let magnitude = i16::try_from(low as i32 + top as i32).map_err(|_| Error::Limit)?;
+            let bottom = result.iter().position(|d| *d != 0).unwrap_or(0);
+            self.digits.clear();
+            #[allow(clippy::indexing_slicing)] // bottom <= top < result.len()
+            self.digits
+                .extend(result[bottom..=top].iter().rev().copied());
+            self.magnitude = magnitude;
+            if result_negative {
+                self.sign = Sign::Negative;
+            } else if self.sign == Sign::Negative {
+                self.sign = Sign::None;
+            }
+        } else {
+            self.digits.clear();
+            self.magnitude = 0;
+            if self.sign == Sign::Negative {
+                self.sign = Sign::None;
+            }
+        }
+        self.lower_magnitude = lower_magnitude;
+        self.upper_magnitude = cmp::max(upper_magnitude, self.magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+        Ok(())
+    }
 
-                // This means the significant digits has been increased by 1.
-                if decimal.magnitude > old_magnitude {
-                    decimal.lower_magnitude = cmp::min(0, position + 1);
+    /// Divide the number by an unsigned integer, returning `None` if `rhs` is zero.
+    ///
+    /// If the division is exact, the result retains the precision of `self`, extended by as many
+    /// fraction digits as are needed to represent the quotient exactly. Otherwise, the quotient is
+    /// computed to 6 digits beyond the lowest magnitude of `self` and rounded half-to-even (see
+    /// [`FixedDecimal::half_even()`]).
+    ///
+    /// To configure the number of extra digits, use [`FixedDecimal::checked_div_with_extra_digits()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from(12);
+    /// assert_eq!("4", dec.clone().checked_div(3).unwrap().to_string());
+    /// assert_eq!("3", dec.clone().checked_div(4).unwrap().to_string());
+    /// assert_eq!("1.5", dec.clone().checked_div(8).unwrap().to_string());
+    /// assert_eq!("1.714286", dec.clone().checked_div(7).unwrap().to_string());
+    /// assert_eq!(None, dec.checked_div(0));
+    ///
+    /// let dec = FixedDecimal::from_str("1.00").unwrap();
+    /// assert_eq!("0.50", dec.checked_div(2).unwrap().to_string());
+    /// ```
+    pub fn checked_div(self, rhs: u32) -> Option<Self> {
+        self.checked_div_with_extra_digits(rhs, 6)
+    }
+
+    /// Divide the number by an unsigned integer, computing at most `extra_digits` digits beyond
+    /// the lowest magnitude of `self`. Returns `None` if `rhs` is zero.
+    ///
+    /// If the division is exact, the result retains the precision of `self`, extended by as many
+    /// fraction digits as are needed to represent the quotient exactly, up to `extra_digits`.
+    /// Otherwise, the quotient is rounded half-to-even (see [`FixedDecimal::half_even()`]) at
+    /// the lowest magnitude of `self` minus `extra_digits`.
+    /// A quotient that is zero has no negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec = FixedDecimal::from(2);
+    /// assert_eq!("1", dec.clone().checked_div_with_extra_digits(2, 0).unwrap().to_string());
+    /// assert_eq!("0", dec.clone().checked_div_with_extra_digits(8, 0).unwrap().to_string());
+    /// assert_eq!("0.2", dec.clone().checked_div_with_extra_digits(8, 1).unwrap().to_string());
+    /// assert_eq!("0.25", dec.clone().checked_div_with_extra_digits(8, 2).unwrap().to_string());
+    /// assert_eq!("0.25", dec.checked_div_with_extra_digits(8, 3).unwrap().to_string());
+    /// ```
+    pub fn checked_div_with_extra_digits(self, rhs: u32, extra_digits: u16) -> Option<Self> {
+        if rhs == 0 {
+            return None;
+        }
+        let divisor = rhs as u64;
+        let position = cmp::max(
+            self.lower_magnitude as i32 - extra_digits as i32,
+            i16::MIN as i32,
+        ) as i16;
+        let lowest_nonzero = self.nonzero_magnitude_end();
+
+        // Long division, from the most significant digit down to `position`, stopping early
+        // if the division becomes exact.
+        let mut quotient: SmallVec<[u8; 8]> = SmallVec::new();
+        let mut remainder: u64 = 0;
+        let mut m = self.magnitude;
+        let end = loop {
+            // remainder < divisor <= u32::MAX, so this cannot overflow
+            remainder = remainder * 10 + self.digit_at(m) as u64;
+            quotient.push((remainder / divisor) as u8);
+            remainder %= divisor;
+            if m == position || (remainder == 0 && m <= lowest_nonzero) {
+                break m;
+            }
+            m -= 1;
+        };
+
+        let mut magnitude = self.magnitude;
+        let lower_magnitude = if remainder == 0 {
+            cmp::min(self.lower_magnitude, end)
+        } else {
+            let last_odd = quotient.last().map(|d| d % 2 == 1).unwrap_or(false);
+            let round_up = match (remainder * 2).cmp(&divisor) {
+                Ordering::Greater => true,
+                Ordering::Equal => last_odd,
+                Ordering::Less => false,
+            };
+            if round_up {
+                let mut carry = true;
+                for digit in quotient.iter_mut().rev() {
+                    if *digit == 9 {
+                        *digit = 0;
+                    } else {
+                        *digit += 1;
+                        carry = false;
+                        break;
+                    }
+                }
+                if carry {
+                    quotient.insert(0, 1);
+                    magnitude = magnitude.checked_add(1)?;
                 }
             }
+            position
+        };
+
+        let leading_zeros = quotient.iter().position(|d| *d != 0);
+        let mut result = Self {
+            sign: self.sign,
+            lower_magnitude: cmp::min(lower_magnitude, 0),
+            ..Default::default()
+        };
+        // Retain leading zeros only if `self` was explicitly padded
+        if self.upper_magnitude > self.magnitude {
+            result.upper_magnitude = self.upper_magnitude;
+        }
+        if let Some(leading_zeros) = leading_zeros {
+            let trailing = quotient.iter().rposition(|d| *d != 0).unwrap_or(0) + 1;
+            #[allow(clippy::indexing_slicing)] // leading_zeros < trailing <= quotient.len()
+            result
+                .digits
+                .extend_from_slice(&quotient[leading_zeros..trailing]);
+            result.magnitude = crate::ops::i16_sub_unsigned(magnitude, leading_zeros as u16);
+            result.upper_magnitude = cmp::max(result.upper_magnitude, result.magnitude);
+        } else if result.sign == Sign::Negative {
+            result.sign = Sign::None;
         }
         #[cfg(debug_assertions)]
-        decimal.check_invariants();
-        Ok(decimal)
+        result.check_invariants();
+        Some(result)
     }
 
-    /// Internal function for parsing directly from floats using ryū
-    fn new_from_f64_raw(float: f64) -> Result<Self, Error> {
-        if !float.is_finite() {
-            return Err(Error::Limit);
+    /// Returns whether the number is an exact multiple of `divisor`, that is, whether the
+    /// remainder of dividing by `divisor` is zero.
+    ///
+    /// Signs and trailing zeros are ignored. Returns [`Error::DivisionByZero`] if `divisor` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Error;
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let increment = FixedDecimal::from_str("0.05").unwrap();
+    ///
+    /// let dec = FixedDecimal::from_str("1.25").unwrap();
+    /// assert_eq!(Ok(true), dec.is_divisible_by(&increment));
+    ///
+    /// let dec = FixedDecimal::from_str("1.23").unwrap();
+    /// assert_eq!(Ok(false), dec.is_divisible_by(&increment));
+    ///
+    /// assert_eq!(
+    ///     Err(Error::DivisionByZero),
+    ///     dec.is_divisible_by(&FixedDecimal::from(0))
+    /// );
+    /// ```
+    pub fn is_divisible_by(&self, divisor: &Self) -> Result<bool, Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
         }
-        // note: this does not heap allocate
-        let mut buf = ryu::Buffer::new();
-        let formatted = buf.format_finite(float);
-        Self::from_str(formatted)
+        if self.is_zero() {
+            return Ok(true);
+        }
+        if divisor.magnitude > self.magnitude {
+            return Ok(false);
+        }
+
+        Ok(self.remainder_digits(divisor).1.is_empty())
     }
-}
 
-#[cfg(feature = "ryu")]
-#[test]
-fn test_float() {
-    #[derive(Debug)]
-    struct TestCase {
-        pub input: f64,
-        pub precision: FloatPrecision,
-        pub expected: &'static str,
+    /// Computes the remainder of the absolute values of `self` and a nonzero `divisor`.
+    ///
+    /// Both numbers are scaled by the same power of ten so that they are integers, and the
+    /// remainder of their long division is returned as the magnitude of its last digit
+    /// together with its digits in descending order of magnitude, without leading zeros.
+    fn remainder_digits(&self, divisor: &Self) -> (i16, SmallVec<[u8; 16]>) {
+        let low = cmp::min(
+            self.nonzero_magnitude_end(),
+            divisor.nonzero_magnitude_end(),
+        );
+        let divisor_digits: SmallVec<[u8; 16]> = (low..=divisor.magnitude)
+            .rev()
+            .map(|m| divisor.digit_at(m))
+            .collect();
+        let mut remainder: SmallVec<[u8; 16]> = SmallVec::new();
+        for m in (low..=self.magnitude).rev() {
+            let digit = self.digit_at(m);
+            if !remainder.is_empty() || digit != 0 {
+                remainder.push(digit);
+            }
+            // The remainder is less than 10 times the divisor, so this loops at most 9 times
+            while remainder
+                .len()
+                .cmp(&divisor_digits.len())
+                .then_with(|| remainder.cmp(&divisor_digits))
+                != Ordering::Less
+            {
+                let mut borrow = 0;
+                let mut subtrahend = divisor_digits.iter().rev();
+                for digit in remainder.iter_mut().rev() {
+                    let b = subtrahend.next().copied().unwrap_or(0) + borrow;
+                    if *digit >= b {
+                        *digit -= b;
+                        borrow = 0;
+                    } else {
+                        *digit += 10 - b;
+                        borrow = 1;
+                    }
+                }
+                let leading_zeros = remainder.iter().take_while(|d| **d == 0).count();
+                remainder.drain(..leading_zeros);
+            }
+        }
+        (low, remainder)
     }
-    let cases = [
-        TestCase {
-            input: 1.234567,
-            precision: FloatPrecision::Floating,
-            expected: "1.234567",
-        },
-        TestCase {
-            input: 888999.,
-            precision: FloatPrecision::Floating,
-            expected: "888999",
-        },
-        // HalfExpand tests
-        TestCase {
-            input: 1.234567,
-            precision: FloatPrecision::Magnitude(-2),
-            expected: "1.23",
-        },
-        TestCase {
-            input: 1.235567,
-            precision: FloatPrecision::Magnitude(-2),
-            expected: "1.24",
-        },
-        TestCase {
-            input: 1.2002,
-            precision: FloatPrecision::Magnitude(-3),
-            expected: "1.200",
-        },
-        TestCase {
-            input: 888999.,
-            precision: FloatPrecision::Magnitude(2),
-            expected: "889000",
-        },
-        TestCase {
-            input: 888999.,
-            precision: FloatPrecision::Magnitude(4),
-            expected: "890000",
-        },
-        TestCase {
-            input: 0.9,
-            precision: FloatPrecision::Magnitude(0),
-            expected: "1",
-        },
-        TestCase {
-            input: 0.9,
-            precision: FloatPrecision::Magnitude(2),
-            expected: "00",
-        },
+
+    /// Computes the remainder of dividing this number by another number, returning
+    /// [`Error::DivisionByZero`] if `rhs` is zero.
+    ///
+    /// The result is exact and, like the `%` operator on integers, has the sign of `self`: it is
+    /// `self` minus `rhs` times the quotient truncated towards zero. Its lowest magnitude is the
+    /// lower of the lowest magnitudes of the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Error;
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("7.5").unwrap();
+    /// let rhs = FixedDecimal::from(2);
+    /// assert_eq!("1.5", dec.checked_rem(&rhs).unwrap().to_string());
+    ///
+    /// let dec = FixedDecimal::from(-7);
+    /// let rhs = FixedDecimal::from_str("0.25").unwrap();
+    /// assert_eq!("0.00", dec.checked_rem(&rhs).unwrap().to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(Error::DivisionByZero),
+    ///     dec.checked_rem(&FixedDecimal::from(0))
+    /// );
+    /// ```
+    pub fn checked_rem(&self, rhs: &Self) -> Result<Self, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        let mut result = self.clone();
+        result.lower_magnitude = cmp::min(self.lower_magnitude, rhs.lower_magnitude);
+        if !self.is_zero() {
+            let (low, mut remainder) = self.remainder_digits(rhs);
+            let trailing_zeros = remainder.iter().rev().take_while(|d| **d == 0).count();
+            remainder.truncate(remainder.len() - trailing_zeros);
+            result.digits.clear();
+            result.digits.extend(remainder.iter().copied());
+            result.magnitude = if remainder.is_empty() {
+                0
+            } else {
+                // The remainder is less than both operands, so this is at most `self.magnitude`
+                low + (remainder.len() + trailing_zeros) as i16 - 1
+            };
+            // Retain leading zeros only if `self` was explicitly padded
+            if self.upper_magnitude == self.magnitude {
+                result.upper_magnitude = cmp::max(result.magnitude, 0);
+            }
+        }
+        if result.is_zero() && result.sign == Sign::Negative {
+            result.sign = Sign::None;
+        }
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Checks the invariants among the internal fields of the number, returning a description
+    /// of the first violation.
+    ///
+    /// Unlike the checks after each mutation, which only run with `debug_assertions`, this can
+    /// be called in any build, for example to check the results of a fuzzer in optimized builds.
+    /// A violation indicates a bug in this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-0012.3400").unwrap();
+    /// assert_eq!(dec.validate(), Ok(()));
+    ///
+    /// dec.multiply_pow10(-3);
+    /// assert_eq!(dec.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        // magnitude invariants:
+        if self.upper_magnitude < self.magnitude {
+            return Err("Upper magnitude too small");
+        }
+        if self.lower_magnitude > self.magnitude {
+            return Err("Lower magnitude too large");
+        }
+        if self.upper_magnitude < 0 {
+            return Err("Upper magnitude below zero");
+        }
+        if self.lower_magnitude > 0 {
+            return Err("Lower magnitude above zero");
+        }
+
+        // digits invariants:
+        if self.digits.len() > (self.magnitude as i32 - self.lower_magnitude as i32 + 1) as usize {
+            return Err("Too many digits for the magnitude range");
+        }
+        if self.digits.iter().any(|digit| *digit > 9) {
+            return Err("Digit out of range");
+        }
+        match (self.digits.first(), self.digits.last()) {
+            (Some(0), _) => Err("Starts with a zero"),
+            (_, Some(0)) => Err("Ends with a zero"),
+            (None, _) if self.magnitude != 0 => Err("Zero with a nonzero magnitude"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Appends a slice of digits to the end of `self.digits` with optional inner zeroes.
+    ///
+    /// This function does not check invariants.
+    fn append_digits(&mut self, inner_zeroes: usize, new_digits: &[u8]) {
+        let new_len = self.digits.len() + inner_zeroes;
+        self.digits.resize_with(new_len, || 0);
+        self.digits.extend_from_slice(new_digits);
+    }
+
+    /// Assert that the invariants among struct fields are enforced.
+    /// Call this in any method that mutates the struct fields.
+    ///
+    /// Example: `#[cfg(debug_assertions)] self.check_invariants();`
+    #[cfg(debug_assertions)]
+    fn check_invariants(&self) {
+        debug_assert_eq!(self.validate(), Ok(()), "{self:?}");
+    }
+}
+
+/// Multiply a `FixedDecimal` by an unsigned integer.
+///
+/// The result is exact. The lowest magnitude of the number is retained; trailing zeros are
+/// not added below it.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`, like all arithmetic operators
+/// on `FixedDecimal`. Use [`FixedDecimal::checked_mul_assign()`] to handle this case.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let dec = FixedDecimal::from(42) * 3;
+/// assert_eq!("126", dec.to_string());
+///
+/// let dec = FixedDecimal::from_str("-1.50").unwrap() * 40;
+/// assert_eq!("-60.00", dec.to_string());
+/// ```
+impl Mul<u32> for FixedDecimal {
+    type Output = Self;
+    fn mul(mut self, rhs: u32) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+/// Add a `FixedDecimal` to another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_add_assign()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`. Use
+/// [`FixedDecimal::checked_add_assign()`] to handle this case.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let mut total = FixedDecimal::from(0);
+/// for price in ["1.99", "-0.50", "10"] {
+///     total += FixedDecimal::from_str(price).unwrap();
+/// }
+/// assert_eq!("11.49", total.to_string());
+/// ```
+impl AddAssign<FixedDecimal> for FixedDecimal {
+    fn add_assign(&mut self, rhs: FixedDecimal) {
+        *self += &rhs;
+    }
+}
+
+/// Add a `FixedDecimal` to another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_add_assign()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`.
+impl AddAssign<&FixedDecimal> for FixedDecimal {
+    fn add_assign(&mut self, rhs: &FixedDecimal) {
+        #[allow(clippy::expect_used)] // documented panic, consistent with integer overflow
+        self.checked_add_assign(rhs)
+            .expect("attempt to add with overflow")
+    }
+}
+
+/// Subtract a `FixedDecimal` from another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_add_assign()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`. Use
+/// [`FixedDecimal::checked_sub_assign()`] to handle this case.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let mut balance = FixedDecimal::from(5);
+/// balance -= FixedDecimal::from(8);
+/// assert_eq!("-3", balance.to_string());
+/// ```
+impl SubAssign<FixedDecimal> for FixedDecimal {
+    fn sub_assign(&mut self, rhs: FixedDecimal) {
+        *self -= &rhs;
+    }
+}
+
+/// Subtract a `FixedDecimal` from another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_add_assign()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`.
+impl SubAssign<&FixedDecimal> for FixedDecimal {
+    fn sub_assign(&mut self, rhs: &FixedDecimal) {
+        #[allow(clippy::expect_used)] // documented panic, consistent with integer overflow
+        self.checked_sub_assign(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+/// Multiply a `FixedDecimal` by an unsigned integer in place.
+///
+/// # Panics
+///
+/// Panics if the magnitude of the result does not fit in an `i16`. See [`Mul`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let mut dec = FixedDecimal::from(-25);
+/// dec *= 4;
+/// assert_eq!("-100", dec.to_string());
+/// ```
+impl MulAssign<u32> for FixedDecimal {
+    fn mul_assign(&mut self, rhs: u32) {
+        #[allow(clippy::expect_used)] // documented panic, consistent with integer overflow
+        self.checked_mul_assign(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+/// Divide a `FixedDecimal` by an unsigned integer.
+///
+/// Non-exact divisions are rounded half-to-even. See [`FixedDecimal::checked_div()`] for details
+/// on the precision of the result.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let dec = FixedDecimal::from(12) / 3;
+/// assert_eq!("4", dec.to_string());
+///
+/// let dec = FixedDecimal::from(2) / 3;
+/// assert_eq!("0.666667", dec.to_string());
+/// ```
+impl Div<u32> for FixedDecimal {
+    type Output = Self;
+    fn div(self, rhs: u32) -> Self {
+        #[allow(clippy::expect_used)] // documented panic, consistent with integer division
+        self.checked_div(rhs).expect("attempt to divide by zero")
+    }
+}
+
+/// Compute the remainder of dividing a `FixedDecimal` by another `FixedDecimal`.
+///
+/// Like the `%` operator on integers, the remainder has the sign of the dividend. See
+/// [`FixedDecimal::checked_rem()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero. Use [`FixedDecimal::checked_rem()`] to handle this case.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let angle = FixedDecimal::from_str("370.5").unwrap();
+/// let dec = angle % FixedDecimal::from(360);
+/// assert_eq!("10.5", dec.to_string());
+///
+/// let dec = FixedDecimal::from(-7) % FixedDecimal::from(3);
+/// assert_eq!("-1", dec.to_string());
+/// ```
+impl Rem<FixedDecimal> for FixedDecimal {
+    type Output = Self;
+    fn rem(self, rhs: FixedDecimal) -> Self {
+        &self % &rhs
+    }
+}
+
+/// Compute the remainder of dividing a `FixedDecimal` by another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_rem()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Rem<&FixedDecimal> for FixedDecimal {
+    type Output = Self;
+    fn rem(self, rhs: &FixedDecimal) -> Self {
+        &self % rhs
+    }
+}
+
+/// Compute the remainder of dividing a `FixedDecimal` by another `FixedDecimal`.
+///
+/// See [`FixedDecimal::checked_rem()`] for the precision of the result.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Rem<&FixedDecimal> for &FixedDecimal {
+    type Output = FixedDecimal;
+    fn rem(self, rhs: &FixedDecimal) -> FixedDecimal {
+        #[allow(clippy::expect_used)] // documented panic, consistent with integer remainder
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+/// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
+///
+/// # Examples
+///
+/// ```
+/// # use fixed_decimal::FixedDecimal;
+/// # use writeable::assert_writeable_eq;
+/// #
+/// assert_writeable_eq!(FixedDecimal::from(42), "42");
+/// ```
+impl writeable::Writeable for FixedDecimal {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self.sign {
+            Sign::Negative => sink.write_char('-')?,
+            Sign::Positive => sink.write_char('+')?,
+            Sign::None => (),
+        }
+        for m in self.magnitude_range().rev() {
+            if m == -1 {
+                sink.write_char('.')?;
+            }
+            let d = self.digit_at(m);
+            sink.write_char((b'0' + d) as char)?;
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        writeable::LengthHint::exact(1)
+            + ((self.upper_magnitude as i32 - self.lower_magnitude as i32) as usize)
+            + (self.sign != Sign::None) as usize
+            + (self.lower_magnitude < 0) as usize
+    }
+}
+
+writeable::impl_display_with_writeable!(FixedDecimal);
+
+/// Formats the number in scientific notation with a lowercase `e`, such as `1.23e4`.
+///
+/// There is exactly one digit before the decimal point. Without a precision, all digits down
+/// to the last nonzero digit are written, like for integers and floats. With a precision, such
+/// as `{:.3e}`, the number is rounded half-to-even to that many digits after the decimal point.
+///
+/// The fill, alignment, width, `0`, and `+` flags behave as for other numbers. In particular,
+/// a plus sign is only written with the `+` flag, even if the sign is [`Sign::Positive`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let dec = FixedDecimal::from(-12340);
+/// assert_eq!(format!("{dec:e}"), "-1.234e4");
+/// assert_eq!(format!("{dec:.1e}"), "-1.2e4");
+/// assert_eq!(format!("{dec:.5e}"), "-1.23400e4");
+///
+/// let dec = FixedDecimal::from(5).multiplied_pow10(-3);
+/// assert_eq!(format!("{dec:e}"), "5e-3");
+/// ```
+impl fmt::LowerExp for FixedDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+/// Formats the number in scientific notation with an uppercase `E`, such as `1.23E4`.
+///
+/// See [`LowerExp`](fmt::LowerExp) for details.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let dec = FixedDecimal::from(12340);
+/// assert_eq!(format!("{dec:E}"), "1.234E4");
+/// assert_eq!(format!("{dec:.2E}"), "1.23E4");
+/// ```
+impl fmt::UpperExp for FixedDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'E')
+    }
+}
+
+impl FixedDecimal {
+    fn fmt_exp(&self, f: &mut fmt::Formatter<'_>, exponent_char: char) -> fmt::Result {
+        use fmt::Write;
+
+        let mut rounded;
+        let dec = match f.precision() {
+            Some(precision) => {
+                let position = (self.nonzero_magnitude_start() as i32)
+                    .saturating_sub(i32::try_from(precision).unwrap_or(i32::MAX));
+                rounded = self.clone();
+                rounded.half_even(i16::try_from(position).unwrap_or(i16::MIN));
+                &rounded
+            }
+            None => self,
+        };
+
+        let (start, end) = if dec.is_zero() {
+            (0, 0)
+        } else {
+            (dec.nonzero_magnitude_start(), dec.nonzero_magnitude_end())
+        };
+        let end = match f.precision() {
+            Some(precision) => {
+                (start as i32).saturating_sub(i32::try_from(precision).unwrap_or(i32::MAX))
+            }
+            None => end as i32,
+        };
+
+        let mut body = String::new();
+        for m in (end..=start as i32).rev() {
+            let digit = i16::try_from(m).map_or(0, |m| dec.digit_at(m));
+            body.push((b'0' + digit) as char);
+            if m == start as i32 && m > end {
+                body.push('.');
+            }
+        }
+        write!(body, "{exponent_char}{start}")?;
+
+        f.pad_integral(dec.sign != Sign::Negative, "", &body)
+    }
+}
+
+impl FixedDecimal {
+    /// Parses a `FixedDecimal` from a string, with the given [`ParseOptions`].
+    ///
+    /// With the default options, this is the same as [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::FixedDecimalError;
+    /// use fixed_decimal::ParseOptions;
+    ///
+    /// let mut options = ParseOptions::default();
+    /// assert_eq!(
+    ///     Err(FixedDecimalError::Syntax),
+    ///     FixedDecimal::try_from_str_with_options("\u{2212}5", options)
+    /// );
+    ///
+    /// options.accept_unicode_minus = true;
+    /// let dec = FixedDecimal::try_from_str_with_options("\u{2212}5", options).unwrap();
+    /// assert_eq!("-5", dec.to_string());
+    /// ```
+    pub fn try_from_str_with_options(s: &str, options: ParseOptions) -> Result<Self, Error> {
+        match s.strip_prefix('\u{2212}') {
+            Some(magnitude_str) if options.accept_unicode_minus => {
+                Self::from_magnitude_str(true, magnitude_str)
+            }
+            _ => Self::from_str(s),
+        }
+    }
+
+    /// Parses a `FixedDecimal` from a magnitude string and a separately provided sign.
+    ///
+    /// The magnitude uses the same syntax as [`FromStr`], except that it must not start with
+    /// a sign character; [`Error::Syntax`] is returned if it does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::FixedDecimalError;
+    ///
+    /// let dec = FixedDecimal::from_magnitude_str(true, "12.50").unwrap();
+    /// assert_eq!("-12.50", dec.to_string());
+    ///
+    /// let dec = FixedDecimal::from_magnitude_str(false, "1.5e3").unwrap();
+    /// assert_eq!("1500", dec.to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(FixedDecimalError::Syntax),
+    ///     FixedDecimal::from_magnitude_str(true, "-12.50")
+    /// );
+    /// ```
+    pub fn from_magnitude_str(is_negative: bool, magnitude_str: &str) -> Result<Self, Error> {
+        if magnitude_str.starts_with(['-', '+']) {
+            return Err(Error::Syntax);
+        }
+        let mut result = Self::from_str(magnitude_str)?;
+        result.set_sign(if is_negative {
+            Sign::Negative
+        } else {
+            Sign::None
+        });
+        Ok(result)
+    }
+
+    /// Parses a `FixedDecimal` from a string like [`FromStr`], and checks that it has exactly
+    /// `scale` fraction digits, including trailing zeros.
+    ///
+    /// The number is not padded or rounded to the scale; [`Error::ScaleMismatch`] is returned
+    /// if the string has more or fewer fraction digits. A `scale` of zero requires an integer
+    /// without a decimal separator, and a negative `scale` cannot be matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::FixedDecimalError;
+    ///
+    /// let dec = FixedDecimal::from_str_with_scale("1.50", 2).unwrap();
+    /// assert_eq!("1.50", dec.to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(FixedDecimalError::ScaleMismatch),
+    ///     FixedDecimal::from_str_with_scale("1.5", 2)
+    /// );
+    /// assert_eq!(
+    ///     Err(FixedDecimalError::ScaleMismatch),
+    ///     FixedDecimal::from_str_with_scale("1.500", 2)
+    /// );
+    /// ```
+    pub fn from_str_with_scale(s: &str, scale: i16) -> Result<Self, Error> {
+        let result = Self::from_str(s)?;
+        if scale >= 0 && result.fraction_digit_count() == scale as usize {
+            Ok(result)
+        } else {
+            Err(Error::ScaleMismatch)
+        }
+    }
+
+    /// Parses a `FixedDecimal` from a string with a custom decimal separator and grouping
+    /// separators, such as a number formatted for a locale.
+    ///
+    /// `sizes` are the sizes of the integer digit groups from right to left, where the last size
+    /// repeats: `&[3]` for the 3-3-3 grouping used in most locales, and `&[3, 2]` for the
+    /// 3-2-2 grouping of the Indian numbering system. If grouping separators are used, they
+    /// must all be at the correct positions. Numbers without grouping separators are accepted,
+    /// and an empty `sizes` rejects any grouping separator.
+    ///
+    /// The string may start with a sign, and must otherwise only contain ASCII digits and the
+    /// separators; grouping separators in the fraction part are rejected. [`Error::Syntax`] is
+    /// returned if the string is invalid, or if the separators are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::FixedDecimalError;
+    ///
+    /// let dec =
+    ///     FixedDecimal::from_str_with_grouping("-1.234.567,50", ',', '.', &[3]).unwrap();
+    /// assert_eq!("-1234567.50", dec.to_string());
+    ///
+    /// let dec =
+    ///     FixedDecimal::from_str_with_grouping("12,34,567.8", '.', ',', &[3, 2]).unwrap();
+    /// assert_eq!("1234567.8", dec.to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(FixedDecimalError::Syntax),
+    ///     FixedDecimal::from_str_with_grouping("1234,567.8", '.', ',', &[3, 2])
+    /// );
+    /// ```
+    pub fn from_str_with_grouping(
+        s: &str,
+        decimal: char,
+        grouping: char,
+        sizes: &[u8],
+    ) -> Result<Self, Error> {
+        if decimal == grouping || sizes.contains(&0) {
+            return Err(Error::Syntax);
+        }
+        let (sign, unsigned) = if let Some(rest) = s.strip_prefix('-') {
+            ("-", rest)
+        } else if let Some(rest) = s.strip_prefix('+') {
+            ("+", rest)
+        } else {
+            ("", s)
+        };
+        let (integer, fraction) = match unsigned.split_once(decimal) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut normalized = String::with_capacity(s.len());
+        normalized.push_str(sign);
+        for c in integer.chars().filter(|c| *c != grouping) {
+            if !c.is_ascii_digit() {
+                return Err(Error::Syntax);
+            }
+            normalized.push(c);
+        }
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            for c in fraction.chars() {
+                // This also rejects grouping separators in the fraction part
+                if !c.is_ascii_digit() {
+                    return Err(Error::Syntax);
+                }
+                normalized.push(c);
+            }
+        }
+
+        if integer.contains(grouping) {
+            let last_size = sizes.last().ok_or(Error::Syntax)?;
+            let mut sizes = sizes.iter().chain(core::iter::repeat(last_size));
+            let mut groups = integer.rsplit(grouping).peekable();
+            while let (Some(group), Some(&size)) = (groups.next(), sizes.next()) {
+                // Only the leftmost group may be shorter than its size
+                let is_valid = if groups.peek().is_some() {
+                    group.len() == size as usize
+                } else {
+                    !group.is_empty() && group.len() <= size as usize
+                };
+                if !is_valid {
+                    return Err(Error::Syntax);
+                }
+            }
+        }
+
+        Self::from_str(&normalized)
+    }
+
+    /// Returns a `FixedDecimal` for displaying a UTC offset in minutes as `±HH:MM`.
+    ///
+    /// The integer part holds the hours, padded to two digits, and the two fraction digits
+    /// hold the minutes, so that the decimal separator can be displayed as the `:`. The sign
+    /// matches the sign of `minutes`: positive offsets have [`Sign::Positive`], and a zero
+    /// offset has [`Sign::None`].
+    ///
+    /// This is a display convenience, not an arithmetic conversion: `5.30` means 5 hours and 30
+    /// minutes, not 5.3 hours. See [`FixedDecimal::to_minutes_offset()`] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// assert_eq!("+05.30", FixedDecimal::from_minutes_offset(330).to_string());
+    /// assert_eq!("-03.00", FixedDecimal::from_minutes_offset(-180).to_string());
+    /// assert_eq!("00.00", FixedDecimal::from_minutes_offset(0).to_string());
+    /// ```
+    pub fn from_minutes_offset(minutes: i32) -> Self {
+        let abs = minutes.unsigned_abs();
+        let hours = u64::from(abs / 60);
+        let minutes_in_hour = u64::from(abs % 60);
+        let sign = match minutes.cmp(&0) {
+            Ordering::Less => Sign::Negative,
+            Ordering::Equal => Sign::None,
+            Ordering::Greater => Sign::Positive,
+        };
+        let mut result = Self::from(hours * 100 + minutes_in_hour)
+            .multiplied_pow10(-2)
+            .with_sign(sign);
+        result.pad_start(2);
+        result.pad_end(-2);
+        result
+    }
+
+    /// Returns the UTC offset in minutes that is displayed by this `FixedDecimal` as `±HH:MM`,
+    /// reversing [`FixedDecimal::from_minutes_offset()`].
+    ///
+    /// Returns `None` if the number has nonzero digits below the hundredths, if its two
+    /// fraction digits are not a valid number of minutes (`00` to `59`), or if the offset does
+    /// not fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("-5.45").unwrap();
+    /// assert_eq!(Some(-345), dec.to_minutes_offset());
+    ///
+    /// let dec = FixedDecimal::from_str("14").unwrap();
+    /// assert_eq!(Some(840), dec.to_minutes_offset());
+    ///
+    /// let dec = FixedDecimal::from_str("5.75").unwrap();
+    /// assert_eq!(None, dec.to_minutes_offset());
+    /// ```
+    pub fn to_minutes_offset(&self) -> Option<i32> {
+        if self.nonzero_magnitude_end() < -2 {
+            return None;
+        }
+        let minutes = i64::from(self.digit_at(-1)) * 10 + i64::from(self.digit_at(-2));
+        if minutes >= 60 {
+            return None;
+        }
+        let mut hours: i64 = 0;
+        for magnitude in (0..=self.nonzero_magnitude_start()).rev() {
+            hours = hours
+                .checked_mul(10)?
+                .checked_add(i64::from(self.digit_at(magnitude)))?;
+        }
+        let result = hours.checked_mul(60)?.checked_add(minutes)?;
+        i32::try_from(if self.sign == Sign::Negative {
+            -result
+        } else {
+            result
+        })
+        .ok()
+    }
+}
+
+impl FromStr for FixedDecimal {
+    type Err = Error;
+    fn from_str(input_str: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input_str.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for FixedDecimal {
+    type Error = Error;
+    fn try_from(input_str: &[u8]) -> Result<Self, Self::Error> {
+        // input_str: the input string
+        // no_sign_str: the input string when the sign is removed from it
+        if input_str.is_empty() {
+            return Err(Error::Syntax);
+        }
+        #[allow(clippy::indexing_slicing)] // The string is not empty.
+        let sign = match input_str[0] {
+            b'-' => Sign::Negative,
+            b'+' => Sign::Positive,
+            _ => Sign::None,
+        };
+        #[allow(clippy::indexing_slicing)] // The string is not empty.
+        let no_sign_str = if sign == Sign::None {
+            input_str
+        } else {
+            &input_str[1..]
+        };
+        if no_sign_str.is_empty() {
+            return Err(Error::Syntax);
+        }
+        // Compute length of each string once and store it, so if you use that multiple times,
+        // you don't compute it multiple times
+        // has_dot: shows if your input has dot in it
+        // has_exponent: shows if your input has an exponent in it
+        // dot_index: gives the index of dot (after removing the sign) -- equal to length of
+        // the no_sign_str if there is no dot
+        // exponent_index: gives the index of exponent (after removing the sign) -- equal to length of
+        // the no_sign_str if there is no dot
+        let mut has_dot = false;
+        let mut has_exponent = false;
+        let mut dot_index = no_sign_str.len();
+        let mut exponent_index = no_sign_str.len();
+        // The following loop computes has_dot, dot_index, and also checks to see if all
+        // characters are digits and if you have at most one dot
+        // Note: Input of format 111_123 is detected as syntax error here
+        // Note: Input starting or ending with a dot is detected as syntax error here (Ex: .123, 123.)
+        for (i, c) in no_sign_str.iter().enumerate() {
+            if *c == b'.' {
+                if has_dot || has_exponent {
+                    // multiple dots or dots after the exponent
+                    return Err(Error::Syntax);
+                }
+                dot_index = i;
+                has_dot = true;
+                // We do support omitting the leading zero,
+                // but not trailing decimal points
+                if i == no_sign_str.len() - 1 {
+                    return Err(Error::Syntax);
+                }
+            } else if *c == b'e' || *c == b'E' {
+                if has_exponent {
+                    // multiple exponents
+                    return Err(Error::Syntax);
+                }
+                exponent_index = i;
+                has_exponent = true;
+                if i == 0 || i == no_sign_str.len() - 1 {
+                    return Err(Error::Syntax);
+                }
+            } else if *c == b'-' {
+                // Allow a single minus sign after the exponent
+                if has_exponent && exponent_index == i - 1 {
+                    continue;
+                } else {
+                    return Err(Error::Syntax);
+                }
+            } else if *c < b'0' || *c > b'9' {
+                return Err(Error::Syntax);
+            }
+        }
+
+        // The string without the exponent (or sign)
+        // We do the bulk of the calculation on this string,
+        // and extract the exponent at the end
+        #[allow(clippy::indexing_slicing)] // exponent_index comes from enumerate
+        let no_exponent_str = &no_sign_str[..exponent_index];
+
+        // If there was no dot, truncate the dot index
+        if dot_index > exponent_index {
+            dot_index = exponent_index;
+        }
+
+        // defining the output dec here and set its sign
+        let mut dec = Self {
+            sign,
+            ..Default::default()
+        };
+
+        // no_dot_str_len: shows length of the string after removing the dot
+        let mut no_dot_str_len = no_exponent_str.len();
+        if has_dot {
+            no_dot_str_len -= 1;
+        }
+
+        // Computing DecimalFixed.upper_magnitude
+        // We support strings like `0.x` and `.x`. The upper magnitude
+        // is always one less than the position of the dot, except in the case where
+        // the 0 is omitted; when dot_index = 0. We use saturating_sub to set
+        // magnitude to 0 in that case.
+        let temp_upper_magnitude = dot_index.saturating_sub(1);
+        if temp_upper_magnitude > i16::MAX as usize {
+            return Err(Error::Limit);
+        }
+        dec.upper_magnitude = temp_upper_magnitude as i16;
+
+        // Computing DecimalFixed.lower_magnitude
+        let temp_lower_magnitude = no_dot_str_len - dot_index;
+        if temp_lower_magnitude > (i16::MIN as u16) as usize {
+            return Err(Error::Limit);
+        }
+        dec.lower_magnitude = (temp_lower_magnitude as i16).wrapping_neg();
+
+        // leftmost_digit: index of the first non-zero digit
+        // rightmost_digit: index of the first element after the last non-zero digit
+        // Example:
+        //     input string    leftmost_digit     rightmost_digit
+        //     00123000              2                  5
+        //     0.0123000             3                  6
+        //     001.23000             2                  6
+        //     001230.00             2                  5
+        // Compute leftmost_digit
+        let leftmost_digit = no_exponent_str
+            .iter()
+            .position(|c| *c != b'.' && *c != b'0');
+
+        // If the input only has zeros (like 000, 00.0, -00.000) we handle the situation here
+        // by returning the dec and don't running the rest of the code
+        let leftmost_digit = if let Some(leftmost_digit) = leftmost_digit {
+            leftmost_digit
+        } else {
+            return Ok(dec);
+        };
+
+        // Else if the input is not all zeros, we compute its magnitude:
+        // Note that we can cast with "as" here because lower and upper magnitude have been checked already
+        let mut temp_magnitude = ((dot_index as i32) - (leftmost_digit as i32) - 1i32) as i16;
+        if dot_index < leftmost_digit {
+            temp_magnitude += 1;
+        }
+        dec.magnitude = temp_magnitude;
+
+        // Compute the index where the rightmost_digit ends
+        let rightmost_digit_end = no_exponent_str
+            .iter()
+            .rposition(|c| *c != b'.' && *c != b'0')
+            .map(|p| p + 1)
+            .unwrap_or(no_exponent_str.len());
+
+        // digits_str_len: shows the length of digits (Ex. 0012.8900 --> 4)
+        let mut digits_str_len = rightmost_digit_end - leftmost_digit;
+        if leftmost_digit < dot_index && dot_index < rightmost_digit_end {
+            digits_str_len -= 1;
+        }
+
+        // Constructing DecimalFixed.digits
+        #[allow(clippy::indexing_slicing)]
+        // leftmost_digit  and rightmost_digit_end come from Iterator::position and Iterator::rposition.
+        let v: SmallVec<[u8; 8]> = no_exponent_str[leftmost_digit..rightmost_digit_end]
+            .iter()
+            .filter(|c| **c != b'.')
+            .map(|c| c - b'0')
+            .collect();
+
+        let v_len = v.len();
+        debug_assert_eq!(v_len, digits_str_len);
+        dec.digits = v;
+
+        // Extract the exponent part
+        if has_exponent {
+            let mut pow = 0;
+            let mut pos_neg = 1;
+            #[allow(clippy::indexing_slicing)]
+            // exponent_index is exist, then exponent_index + 1 will equal at most no_sign_str.len().
+            for digit in &no_sign_str[exponent_index + 1..] {
+                if *digit == b'-' {
+                    pos_neg = -1;
+                    continue;
+                }
+                pow *= 10;
+                pow += (digit - b'0') as i16;
+            }
+
+            dec.multiply_pow10(pos_neg * pow);
+
+            // Clean up leading zeros after multiplication. Trailing zeros are retained, since
+            // they are significant in the mantissa (`1.230e2` is `123.0`).
+            if dec.magnitude > 0 {
+                dec.upper_magnitude = dec.magnitude;
+            }
+        }
+
+        Ok(dec)
+    }
+}
+
+/// Specifies the precision of a floating point value when constructing a FixedDecimal.
+///
+/// IEEE 754 is a representation of a point on the number line. On the other hand, FixedDecimal
+/// specifies not only the point on the number line but also the precision of the number to a
+/// specific power of 10. This enum augments a floating-point value with the additional
+/// information required by FixedDecimal.
+#[non_exhaustive]
+#[cfg(feature = "ryu")]
+#[derive(Debug, Clone, Copy)]
+pub enum FloatPrecision {
+    /// Specify that the floating point number is integer-valued.
+    ///
+    /// If the floating point is not actually integer-valued, an error will be returned.
+    Integer,
+
+    /// Specify that the floating point number is precise to a specific power of 10.
+    /// The number may be rounded or trailing zeros may be added as necessary.
+    Magnitude(i16),
+
+    /// Specify that the floating point number is precise to a specific number of significant digits.
+    /// The number may be rounded or trailing zeros may be added as necessary.
+    ///
+    /// The number requested may not be zero
+    SignificantDigits(u8),
+
+    /// Specify that the floating point number is precise to the maximum representable by IEEE.
+    ///
+    /// This results in a FixedDecimal having enough digits to recover the original floating point
+    /// value, with no trailing zeros.
+    Floating,
+}
+
+#[cfg(feature = "ryu")]
+impl FixedDecimal {
+    /// Construct a [`FixedDecimal`] from an f64.
+    ///
+    /// Since f64 values do not carry a notion of their precision, the second argument to this
+    /// function specifies the type of precision associated with the f64. For more information,
+    /// see [`FloatPrecision`].
+    ///
+    /// This function uses `ryu`, which is an efficient double-to-string algorithm, but other
+    /// implementations may yield higher performance; for more details, see
+    /// [icu4x#166](https://github.com/unicode-org/icu4x/issues/166).
+    ///
+    /// This function can be made available with the `"ryu"` Cargo feature.
+    ///
+    /// ```rust
+    /// use fixed_decimal::{FixedDecimal, FloatPrecision};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let decimal =
+    ///     FixedDecimal::try_from_f64(-5.1, FloatPrecision::Magnitude(-2))
+    ///         .expect("Finite quantity with limited precision");
+    /// assert_writeable_eq!(decimal, "-5.10");
+    ///
+    /// let decimal =
+    ///     FixedDecimal::try_from_f64(0.012345678, FloatPrecision::Floating)
+    ///         .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "0.012345678");
+    ///
+    /// let decimal =
+    ///     FixedDecimal::try_from_f64(12345678000., FloatPrecision::Integer)
+    ///         .expect("Finite, integer-valued quantity");
+    /// assert_writeable_eq!(decimal, "12345678000");
+    /// ```
+    ///
+    /// Negative zero is supported.
+    ///
+    /// ```rust
+    /// use fixed_decimal::{FixedDecimal, FloatPrecision};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// // IEEE 754 for floating point defines the sign bit separate
+    /// // from the mantissa and exponent, allowing for -0.
+    /// let negative_zero =
+    ///     FixedDecimal::try_from_f64(-0.0, FloatPrecision::Integer)
+    ///         .expect("Negative zero");
+    /// assert_writeable_eq!(negative_zero, "-0");
+    /// ```
+    pub fn try_from_f64(float: f64, precision: FloatPrecision) -> Result<Self, Error> {
+        // note: this does not heap allocate
+        let mut decimal = Self::from_ryu_buffer(&mut ryu::Buffer::new(), float)?;
+        match precision {
+            FloatPrecision::Floating => (),
+            FloatPrecision::Integer => {
+                if decimal.nonzero_magnitude_end() < 0 {
+                    return Err(Error::Limit);
+                }
+            }
+            FloatPrecision::Magnitude(mag) => {
+                decimal.half_even(mag);
+            }
+            FloatPrecision::SignificantDigits(sig) => {
+                if sig == 0 {
+                    return Err(Error::Limit);
+                }
+
+                let position = decimal.magnitude - (sig as i16) + 1;
+                let old_magnitude = decimal.magnitude;
+                decimal.half_even(position);
+
+                // This means the significant digits has been increased by 1.
+                if decimal.magnitude > old_magnitude {
+                    decimal.lower_magnitude = cmp::min(0, position + 1);
+                }
+            }
+        }
+        #[cfg(debug_assertions)]
+        decimal.check_invariants();
+        Ok(decimal)
+    }
+
+    /// Construct a [`FixedDecimal`] from an f64 with [`FloatPrecision::Floating`], formatting it
+    /// into a caller-provided `ryu` buffer.
+    ///
+    /// The ASCII output of `ryu` is parsed directly from the buffer, without an intermediate
+    /// string or UTF-8 validation. Reusing the buffer across calls makes this the most efficient
+    /// way to convert many f64 values.
+    ///
+    /// `ryu::Buffer` does not give access to previously formatted output, so the buffer is
+    /// written by this function.
+    ///
+    /// Returns [`Error::Limit`] if the value is not finite.
+    ///
+    /// This function can be made available with the `"ryu"` Cargo feature.
+    ///
+    /// ```rust
+    /// use fixed_decimal::FixedDecimal;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let mut buf = ryu::Buffer::new();
+    ///
+    /// let decimal = FixedDecimal::from_ryu_buffer(&mut buf, -5.1)
+    ///     .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "-5.1");
+    ///
+    /// let decimal = FixedDecimal::from_ryu_buffer(&mut buf, 1e21)
+    ///     .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "1000000000000000000000");
+    ///
+    /// assert!(FixedDecimal::from_ryu_buffer(&mut buf, f64::NAN).is_err());
+    /// ```
+    pub fn from_ryu_buffer(buf: &mut ryu::Buffer, value: f64) -> Result<Self, Error> {
+        if !value.is_finite() {
+            return Err(Error::Limit);
+        }
+        let mut decimal = Self::try_from(buf.format_finite(value).as_bytes())?;
+        // ryū will usually tack on a `.0` to integers which gets included when parsing.
+        // Explicitly remove it before doing anything else
+        if decimal.nonzero_magnitude_end() >= 0 && decimal.lower_magnitude < 0 {
+            decimal.lower_magnitude = 0;
+        }
+        Ok(decimal)
+    }
+}
+
+#[cfg(feature = "ryu")]
+#[test]
+fn test_float() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: f64,
+        pub precision: FloatPrecision,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: 1.234567,
+            precision: FloatPrecision::Floating,
+            expected: "1.234567",
+        },
+        TestCase {
+            input: 888999.,
+            precision: FloatPrecision::Floating,
+            expected: "888999",
+        },
+        // HalfExpand tests
+        TestCase {
+            input: 1.234567,
+            precision: FloatPrecision::Magnitude(-2),
+            expected: "1.23",
+        },
+        TestCase {
+            input: 1.235567,
+            precision: FloatPrecision::Magnitude(-2),
+            expected: "1.24",
+        },
+        TestCase {
+            input: 1.2002,
+            precision: FloatPrecision::Magnitude(-3),
+            expected: "1.200",
+        },
+        TestCase {
+            input: 888999.,
+            precision: FloatPrecision::Magnitude(2),
+            expected: "889000",
+        },
+        TestCase {
+            input: 888999.,
+            precision: FloatPrecision::Magnitude(4),
+            expected: "890000",
+        },
+        TestCase {
+            input: 0.9,
+            precision: FloatPrecision::Magnitude(0),
+            expected: "1",
+        },
+        TestCase {
+            input: 0.9,
+            precision: FloatPrecision::Magnitude(2),
+            expected: "00",
+        },
         TestCase {
             input: 0.009,
             precision: FloatPrecision::Magnitude(-2),
@@ -3492,6 +5701,33 @@ fn test_float() {
     }
 }
 
+#[cfg(feature = "ryu")]
+#[test]
+fn test_from_ryu_buffer() {
+    let mut buf = ryu::Buffer::new();
+    for (input, expected) in [
+        (1.234567, "1.234567"),
+        (888999., "888999"),
+        (-0.0, "-0"),
+        (0.0000009, "0.0000009"),
+        (1e21, "1000000000000000000000"),
+        (-1.5e-10, "-0.00000000015"),
+    ] {
+        let decimal = FixedDecimal::from_ryu_buffer(&mut buf, input).unwrap();
+        writeable::assert_writeable_eq!(decimal, expected, "{input}");
+        assert_eq!(
+            decimal,
+            FixedDecimal::try_from_f64(input, FloatPrecision::Floating).unwrap()
+        );
+    }
+    for input in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            FixedDecimal::from_ryu_buffer(&mut buf, input),
+            Err(Error::Limit)
+        );
+    }
+}
+
 #[test]
 fn test_basic() {
     #[derive(Debug)]
@@ -3773,13 +6009,36 @@ fn test_from_str_scientific() {
         },
         TestCase {
             input_str: "-9000E-10",
-            output: "-0.0000009",
+            output: "-0.0000009000",
+        },
+        // Trailing zeros in the mantissa are significant
+        TestCase {
+            input_str: "1.230e2",
+            output: "123.0",
+        },
+        TestCase {
+            input_str: "1.23e2",
+            output: "123",
+        },
+        TestCase {
+            input_str: "1.2300e1",
+            output: "12.300",
+        },
+        TestCase {
+            input_str: "1.0e-2",
+            output: "0.010",
         },
     ];
     for cas in &cases {
         let input_str_roundtrip = FixedDecimal::from_str(cas.input_str).unwrap().to_string();
         assert_eq!(cas.output, input_str_roundtrip);
     }
+
+    let dec = FixedDecimal::from_str("1.230e2").unwrap();
+    assert_eq!(dec.magnitude_range(), -1..=2);
+    assert_eq!(dec.nonzero_magnitude_end(), 0);
+    let dec = FixedDecimal::from_str("1.23e2").unwrap();
+    assert_eq!(dec.magnitude_range(), 0..=2);
 }
 
 #[test]
@@ -3811,6 +6070,138 @@ fn test_ui128_limits() {
     }
 }
 
+#[test]
+fn test_u128_boundary() {
+    // 39-digit values fill the whole intake buffer of `from_ascending`
+    let cases: [(u128, &str, i16, i16); 5] = [
+        (
+            10u128.pow(38),
+            "100000000000000000000000000000000000000",
+            38,
+            38,
+        ),
+        (
+            3 * 10u128.pow(38),
+            "300000000000000000000000000000000000000",
+            38,
+            38,
+        ),
+        (
+            10u128.pow(38) + 1,
+            "100000000000000000000000000000000000001",
+            38,
+            0,
+        ),
+        (
+            u128::MAX - 5,
+            "340282366920938463463374607431768211450",
+            38,
+            1,
+        ),
+        (u128::MAX, "340282366920938463463374607431768211455", 38, 0),
+    ];
+    for (num, expected, magnitude, nonzero_low) in cases {
+        let dec = FixedDecimal::from(num);
+        assert_eq!(dec.to_string(), expected);
+        assert_eq!(dec.nonzero_magnitude_start(), magnitude);
+        assert_eq!(dec.nonzero_magnitude_end(), nonzero_low);
+    }
+
+    let dec = FixedDecimal::from(i128::MIN);
+    assert_eq!(dec.to_string(), "-170141183460469231731687303715884105728");
+    assert_eq!(dec.nonzero_magnitude_start(), 38);
+    let dec = FixedDecimal::from(-(10i128.pow(38)));
+    assert_eq!(dec.to_string(), "-100000000000000000000000000000000000000");
+    assert_eq!(dec.nonzero_magnitude_end(), 38);
+}
+
+#[test]
+fn test_digit_counts() {
+    // (input, fraction, significant, leading, trailing)
+    let cases = [
+        ("0", 0, 0, 1, 0),
+        ("000.000", 3, 0, 3, 3),
+        ("-0.00", 2, 0, 1, 2),
+        ("0.0500", 4, 3, 1, 2),
+        ("1000", 0, 4, 0, 3),
+        ("1000.0", 1, 5, 0, 4),
+        ("0012.340", 3, 5, 2, 1),
+        ("5", 0, 1, 0, 0),
+    ];
+    for (input, fraction, significant, leading, trailing) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.fraction_digit_count(), fraction, "{input}");
+        assert_eq!(dec.significant_digit_count(), significant, "{input}");
+        assert_eq!(dec.leading_zero_count(), leading, "{input}");
+        assert_eq!(dec.trailing_zero_count(), trailing, "{input}");
+    }
+
+    // Padding adds zeros without changing the nonzero digits
+    let dec = FixedDecimal::from_str("0.0500")
+        .unwrap()
+        .padded_start(3)
+        .padded_end(-6);
+    assert_eq!(dec.to_string(), "000.050000");
+    assert_eq!(dec.fraction_digit_count(), 6);
+    assert_eq!(dec.significant_digit_count(), 5);
+    assert_eq!(dec.leading_zero_count(), 3);
+    assert_eq!(dec.trailing_zero_count(), 4);
+
+    let dec = dec.trimmed_start().trimmed_end();
+    assert_eq!(dec.to_string(), "0.05");
+    assert_eq!(dec.fraction_digit_count(), 2);
+    assert_eq!(dec.significant_digit_count(), 1);
+    assert_eq!(dec.leading_zero_count(), 1);
+    assert_eq!(dec.trailing_zero_count(), 0);
+}
+
+#[test]
+fn test_from_str_with_scale() {
+    for (input, scale, expected) in [
+        ("1.50", 2, Ok("1.50")),
+        ("-0.00", 2, Ok("-0.00")),
+        ("42", 0, Ok("42")),
+        ("1.5e-2", 3, Ok("0.015")),
+        ("1.5", 2, Err(Error::ScaleMismatch)),
+        ("1.500", 2, Err(Error::ScaleMismatch)),
+        ("42.0", 0, Err(Error::ScaleMismatch)),
+        ("1000", -3, Err(Error::ScaleMismatch)),
+        ("1,50", 2, Err(Error::Syntax)),
+    ] {
+        assert_eq!(
+            FixedDecimal::from_str_with_scale(input, scale).map(|dec| dec.to_string()),
+            expected.map(String::from),
+            "{input} {scale}"
+        );
+    }
+}
+
+#[test]
+fn test_rounding_half_ulp() {
+    let cases = [
+        ("3.14", "0.01", "0.005"),
+        ("-3.140", "0.001", "0.0005"),
+        ("1200", "1", "0.5"),
+        ("0012.5", "0.1", "0.05"),
+        ("0", "1", "0.5"),
+        ("0.00", "0.01", "0.005"),
+        ("-0.0", "0.1", "0.05"),
+    ];
+    for (input, ulp, half_ulp) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.unit_in_last_place().to_string(), ulp, "{input}");
+        assert_eq!(dec.rounding_half_ulp().to_string(), half_ulp, "{input}");
+    }
+
+    // The half unit below the lowest magnitude cannot be represented
+    let dec = FixedDecimal::from(1).multiplied_pow10(i16::MIN);
+    assert_eq!(
+        dec.unit_in_last_place(),
+        FixedDecimal::from(1).multiplied_pow10(i16::MIN)
+    );
+    assert!(dec.rounding_half_ulp().is_zero());
+}
+
 #[test]
 fn test_upper_magnitude_bounds() {
     let mut dec: FixedDecimal = 98765.into();
@@ -3980,20 +6371,90 @@ fn test_syntax_error() {
             expected_err: Some(Error::Syntax),
         },
         TestCase {
-            input_str: "-1",
-            expected_err: None,
+            input_str: "-1",
+            expected_err: None,
+        },
+    ];
+    for cas in &cases {
+        match FixedDecimal::from_str(cas.input_str) {
+            Ok(dec) => {
+                assert_eq!(cas.expected_err, None, "{cas:?}");
+                assert_eq!(cas.input_str, dec.to_string(), "{cas:?}");
+            }
+            Err(err) => {
+                assert_eq!(cas.expected_err, Some(err), "{cas:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_magnitude_str() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub is_negative: bool,
+        pub magnitude_str: &'static str,
+        pub expected: Result<&'static str, Error>,
+    }
+    let cases = [
+        TestCase {
+            is_negative: false,
+            magnitude_str: "1234.56",
+            expected: Ok("1234.56"),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "1234.56",
+            expected: Ok("-1234.56"),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "000.0100",
+            expected: Ok("-000.0100"),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "0",
+            expected: Ok("-0"),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "1.23e2",
+            expected: Ok("-123"),
+        },
+        TestCase {
+            is_negative: false,
+            magnitude_str: "-1",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "+1",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            is_negative: true,
+            magnitude_str: "--1",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            is_negative: false,
+            magnitude_str: "",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            is_negative: false,
+            magnitude_str: "1.",
+            expected: Err(Error::Syntax),
         },
     ];
     for cas in &cases {
-        match FixedDecimal::from_str(cas.input_str) {
-            Ok(dec) => {
-                assert_eq!(cas.expected_err, None, "{cas:?}");
-                assert_eq!(cas.input_str, dec.to_string(), "{cas:?}");
-            }
-            Err(err) => {
-                assert_eq!(cas.expected_err, Some(err), "{cas:?}");
-            }
-        }
+        let result = FixedDecimal::from_magnitude_str(cas.is_negative, cas.magnitude_str);
+        assert_eq!(
+            cas.expected.map(String::from),
+            result.map(|dec| dec.to_string()),
+            "{cas:?}"
+        );
     }
 }
 
@@ -5253,248 +7714,2056 @@ fn test_rounding_increment() {
     dec.half_even_to_increment(2, RoundingIncrement::MultiplesOf2);
     assert_eq!("00000", dec.to_string());
 
-    let mut dec = FixedDecimal::from_str("-99.999").unwrap();
+    let mut dec = FixedDecimal::from_str("-99.999").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("-100.00", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1234.56").unwrap();
+    dec.half_even_to_increment(-1, RoundingIncrement::MultiplesOf2);
+    assert_eq!("1234.6", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.009").unwrap();
+    dec.half_even_to_increment(-1, RoundingIncrement::MultiplesOf5);
+    assert_eq!("0.0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.60").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("0.50", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.40").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("0.50", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.7000000099").unwrap();
+    dec.half_even_to_increment(-3, RoundingIncrement::MultiplesOf2);
+    assert_eq!("0.700", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("5").unwrap();
+    dec.half_even_to_increment(0, RoundingIncrement::MultiplesOf25);
+    assert_eq!("0", dec.to_string());
+
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MIN);
+    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf2);
+    assert_eq!(FixedDecimal::from(8).multiplied_pow10(i16::MIN), dec);
+
+    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MIN);
+    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf5);
+    assert_eq!(FixedDecimal::from(10).multiplied_pow10(i16::MIN), dec);
+
+    let mut dec = FixedDecimal::from(70).multiplied_pow10(i16::MIN);
+    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf25);
+    assert_eq!(FixedDecimal::from(75).multiplied_pow10(i16::MIN), dec);
+
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MAX);
+    dec.half_even_to_increment(i16::MAX, RoundingIncrement::MultiplesOf2);
+    assert_eq!(FixedDecimal::from(8).multiplied_pow10(i16::MAX), dec);
+
+    // Test specific cases
+    let mut dec = FixedDecimal::from_str("1.108").unwrap();
+    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("1.12", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1.108").unwrap();
+    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf5);
+    assert_eq!("1.15", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1.108").unwrap();
+    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("1.25", dec.to_string());
+
+    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX - 1);
+    dec.expand_to_increment(i16::MAX - 1, RoundingIncrement::MultiplesOf25);
+    assert_eq!(FixedDecimal::from(25).multiplied_pow10(i16::MAX - 1), dec);
+
+    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
+    dec.expand_to_increment(i16::MAX, RoundingIncrement::MultiplesOf25);
+    assert_eq!(FixedDecimal::from(0).multiplied_pow10(i16::MAX), dec);
+
+    let mut dec = FixedDecimal::from_str("0").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
+    assert_eq!("0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
+    assert_eq!("25", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
+    assert_eq!("25", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("4", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("4").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("4", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("4").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("4.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("6", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("4.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("5").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("6", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("5").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("5.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("6", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("5.1").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("10", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("6").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
+    assert_eq!("6", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("6").unwrap();
+    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
+    assert_eq!("10", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("0.50").unwrap();
+    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("0.50", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1.1025").unwrap();
+    dec.half_trunc_to_increment(-3, RoundingIncrement::MultiplesOf5);
+    assert_eq!("1.100", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1.10125").unwrap();
+    dec.half_expand_to_increment(-4, RoundingIncrement::MultiplesOf25);
+    assert_eq!("1.1025", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("-1.25").unwrap();
+    dec.half_ceil_to_increment(-1, RoundingIncrement::MultiplesOf5);
+    assert_eq!("-1.0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("-1.251").unwrap();
+    dec.half_ceil_to_increment(-1, RoundingIncrement::MultiplesOf5);
+    assert_eq!("-1.5", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("-1.125").unwrap();
+    dec.half_floor_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("-1.25", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.71").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.72", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.73").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.72", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.75").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.76", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.77").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.76", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.79").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.80", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.41").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.40", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.43").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.44", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.45").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.44", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.47").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.48", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.49").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
+    assert_eq!("2.48", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.725").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf5);
+    assert_eq!("2.70", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.775").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf5);
+    assert_eq!("2.80", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.875").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("3.00", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.375").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("2.50", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.125").unwrap();
+    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
+    assert_eq!("2.00", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("2.625").unwrap();
     dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("-100.00", dec.to_string());
+    assert_eq!("2.50", dec.to_string());
+}
 
-    let mut dec = FixedDecimal::from_str("1234.56").unwrap();
-    dec.half_even_to_increment(-1, RoundingIncrement::MultiplesOf2);
-    assert_eq!("1234.6", dec.to_string());
+#[test]
+fn test_mul_u32() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub rhs: u32,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "0",
+            rhs: 5,
+            expected: "0",
+        },
+        TestCase {
+            input: "0.00",
+            rhs: 5,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "3",
+            rhs: 0,
+            expected: "0",
+        },
+        TestCase {
+            input: "12.34",
+            rhs: 0,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "0012",
+            rhs: 0,
+            expected: "0000",
+        },
+        // The sign of a zero product is dropped
+        TestCase {
+            input: "-3",
+            rhs: 0,
+            expected: "0",
+        },
+        TestCase {
+            input: "-1.50",
+            rhs: 0,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "+3",
+            rhs: 0,
+            expected: "+0",
+        },
+        TestCase {
+            input: "3",
+            rhs: 3,
+            expected: "9",
+        },
+        TestCase {
+            input: "5",
+            rhs: 2,
+            expected: "10",
+        },
+        TestCase {
+            input: "-1.25",
+            rhs: 4,
+            expected: "-5.00",
+        },
+        TestCase {
+            input: "+0.5",
+            rhs: 3,
+            expected: "+1.5",
+        },
+        TestCase {
+            input: "0099",
+            rhs: 11,
+            expected: "1089",
+        },
+        TestCase {
+            input: "0.001",
+            rhs: 1000,
+            expected: "1.000",
+        },
+        TestCase {
+            input: "4294967295",
+            rhs: u32::MAX,
+            expected: "18446744065119617025",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap() * cas.rhs;
+        writeable::assert_writeable_eq!(dec, cas.expected, "{:?}", cas);
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("0.009").unwrap();
-    dec.half_even_to_increment(-1, RoundingIncrement::MultiplesOf5);
-    assert_eq!("0.0", dec.to_string());
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn test_mul_u32_overflow() {
+    let _ = FixedDecimal::from(9).multiplied_pow10(i16::MAX) * 2;
+}
 
-    let mut dec = FixedDecimal::from_str("0.60").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("0.50", dec.to_string());
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn test_mul_assign_overflow() {
+    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
+    dec *= 2;
+}
 
-    let mut dec = FixedDecimal::from_str("0.40").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("0.50", dec.to_string());
+#[test]
+fn test_div_u32() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub rhs: u32,
+        pub extra_digits: u16,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "0",
+            rhs: 7,
+            extra_digits: 6,
+            expected: "0",
+        },
+        TestCase {
+            input: "0.00",
+            rhs: 7,
+            extra_digits: 6,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "12",
+            rhs: 3,
+            extra_digits: 6,
+            expected: "4",
+        },
+        TestCase {
+            input: "0012",
+            rhs: 3,
+            extra_digits: 6,
+            expected: "0004",
+        },
+        TestCase {
+            input: "12.00",
+            rhs: 3,
+            extra_digits: 6,
+            expected: "4.00",
+        },
+        TestCase {
+            input: "1",
+            rhs: 4,
+            extra_digits: 6,
+            expected: "0.25",
+        },
+        TestCase {
+            input: "1",
+            rhs: 3,
+            extra_digits: 6,
+            expected: "0.333333",
+        },
+        TestCase {
+            input: "-2",
+            rhs: 3,
+            extra_digits: 6,
+            expected: "-0.666667",
+        },
+        TestCase {
+            input: "1",
+            rhs: 3,
+            extra_digits: 0,
+            expected: "0",
+        },
+        // The sign of a zero quotient is dropped
+        TestCase {
+            input: "-1",
+            rhs: 3,
+            extra_digits: 0,
+            expected: "0",
+        },
+        TestCase {
+            input: "-0.01",
+            rhs: 4,
+            extra_digits: 0,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "2",
+            rhs: 3,
+            extra_digits: 0,
+            expected: "1",
+        },
+        // Ties are rounded to even
+        TestCase {
+            input: "5",
+            rhs: 2,
+            extra_digits: 0,
+            expected: "2",
+        },
+        TestCase {
+            input: "7",
+            rhs: 2,
+            extra_digits: 0,
+            expected: "4",
+        },
+        TestCase {
+            input: "0.125",
+            rhs: 1,
+            extra_digits: 0,
+            expected: "0.125",
+        },
+        TestCase {
+            input: "1",
+            rhs: 8,
+            extra_digits: 2,
+            expected: "0.12",
+        },
+        TestCase {
+            input: "3",
+            rhs: 8,
+            extra_digits: 2,
+            expected: "0.38",
+        },
+        // Rounding carries into a new digit
+        TestCase {
+            input: "19.99",
+            rhs: 2,
+            extra_digits: 0,
+            expected: "10.00",
+        },
+        TestCase {
+            input: "1",
+            rhs: u32::MAX,
+            extra_digits: 12,
+            expected: "0.000000000233",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input)
+            .unwrap()
+            .checked_div_with_extra_digits(cas.rhs, cas.extra_digits)
+            .unwrap();
+        writeable::assert_writeable_eq!(dec, cas.expected, "{:?}", cas);
+    }
 
-    let mut dec = FixedDecimal::from_str("0.7000000099").unwrap();
-    dec.half_even_to_increment(-3, RoundingIncrement::MultiplesOf2);
-    assert_eq!("0.700", dec.to_string());
+    let dec = FixedDecimal::from_str("-0.0").unwrap() / 3;
+    assert_eq!(dec.to_string(), "0.0");
+    let mut dec = FixedDecimal::from(-5);
+    dec.checked_mul_assign(0).unwrap();
+    assert_eq!(dec.to_string(), "0");
 
-    let mut dec = FixedDecimal::from_str("5").unwrap();
-    dec.half_even_to_increment(0, RoundingIncrement::MultiplesOf25);
-    assert_eq!("0", dec.to_string());
+    assert_eq!(FixedDecimal::from(1).checked_div(0), None);
+    assert_eq!((FixedDecimal::from(7) / 7).to_string(), "1");
+}
 
-    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MIN);
-    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf2);
-    assert_eq!(FixedDecimal::from(8).multiplied_pow10(i16::MIN), dec);
+#[test]
+fn test_add_sub_assign() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub lhs: &'static str,
+        pub rhs: &'static str,
+        pub expected_sum: &'static str,
+        pub expected_difference: &'static str,
+    }
+    let cases = [
+        TestCase {
+            lhs: "0",
+            rhs: "0",
+            expected_sum: "0",
+            expected_difference: "0",
+        },
+        TestCase {
+            lhs: "1.5",
+            rhs: "2.25",
+            expected_sum: "3.75",
+            expected_difference: "-0.75",
+        },
+        TestCase {
+            lhs: "0012",
+            rhs: "1.0",
+            expected_sum: "0013.0",
+            expected_difference: "0011.0",
+        },
+        TestCase {
+            lhs: "999",
+            rhs: "1",
+            expected_sum: "1000",
+            expected_difference: "998",
+        },
+        TestCase {
+            lhs: "-5",
+            rhs: "3",
+            expected_sum: "-2",
+            expected_difference: "-8",
+        },
+        TestCase {
+            lhs: "-5",
+            rhs: "-5",
+            expected_sum: "-10",
+            expected_difference: "0",
+        },
+        TestCase {
+            lhs: "+2",
+            rhs: "-3",
+            expected_sum: "-1",
+            expected_difference: "+5",
+        },
+        TestCase {
+            lhs: "1000",
+            rhs: "0.001",
+            expected_sum: "1000.001",
+            expected_difference: "999.999",
+        },
+        TestCase {
+            lhs: "0.10",
+            rhs: "0.1",
+            expected_sum: "0.20",
+            expected_difference: "0.00",
+        },
+        TestCase {
+            lhs: "0",
+            rhs: "-7.5",
+            expected_sum: "-7.5",
+            expected_difference: "7.5",
+        },
+    ];
+    for cas in &cases {
+        let lhs = FixedDecimal::from_str(cas.lhs).unwrap();
+        let rhs = FixedDecimal::from_str(cas.rhs).unwrap();
 
-    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MIN);
-    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf5);
-    assert_eq!(FixedDecimal::from(10).multiplied_pow10(i16::MIN), dec);
+        let mut sum = lhs.clone();
+        sum += &rhs;
+        writeable::assert_writeable_eq!(sum, cas.expected_sum, "{:?}", cas);
 
-    let mut dec = FixedDecimal::from(70).multiplied_pow10(i16::MIN);
-    dec.half_even_to_increment(i16::MIN, RoundingIncrement::MultiplesOf25);
-    assert_eq!(FixedDecimal::from(75).multiplied_pow10(i16::MIN), dec);
+        let mut difference = lhs.clone();
+        difference -= rhs;
+        writeable::assert_writeable_eq!(difference, cas.expected_difference, "{:?}", cas);
+    }
 
-    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MAX);
-    dec.half_even_to_increment(i16::MAX, RoundingIncrement::MultiplesOf2);
-    assert_eq!(FixedDecimal::from(8).multiplied_pow10(i16::MAX), dec);
+    // Accumulation with mixed signs
+    let mut total = FixedDecimal::from(0);
+    for price in ["19.99", "-5", "0.01", "-15.5", "+0.50"] {
+        total += FixedDecimal::from_str(price).unwrap();
+    }
+    assert_eq!(total.to_string(), "0.00");
+    total -= FixedDecimal::from_str("1.25").unwrap();
+    total *= 3;
+    assert_eq!(total.to_string(), "-3.75");
 
-    // Test specific cases
-    let mut dec = FixedDecimal::from_str("1.108").unwrap();
-    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("1.12", dec.to_string());
+    // Overflow leaves the number unchanged
+    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
+    let before = dec.clone();
+    assert_eq!(
+        dec.checked_add_assign(&FixedDecimal::from(1).multiplied_pow10(i16::MAX)),
+        Err(Error::Limit)
+    );
+    assert_eq!(dec.checked_mul_assign(2), Err(Error::Limit));
+    assert_eq!(dec, before);
+    dec.checked_sub_assign(&FixedDecimal::from(1).multiplied_pow10(i16::MAX))
+        .unwrap();
+    assert_eq!(dec, FixedDecimal::from(8).multiplied_pow10(i16::MAX));
+}
 
-    let mut dec = FixedDecimal::from_str("1.108").unwrap();
-    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf5);
-    assert_eq!("1.15", dec.to_string());
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_add_assign_overflow() {
+    let mut dec = FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+    dec += FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+}
+
+#[test]
+fn test_quantize() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub reference: &'static str,
+        pub mode: RoundingMode,
+        pub expected: &'static str,
+    }
+    // Expected values match Python's `Decimal(input).quantize(Decimal(reference), mode)`
+    let cases = [
+        TestCase {
+            input: "1.2",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "1.20",
+        },
+        TestCase {
+            input: "1.234567",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "1.23",
+        },
+        TestCase {
+            input: "1.235",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "1.24",
+        },
+        TestCase {
+            input: "1.245",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "1.24",
+        },
+        TestCase {
+            input: "1.245",
+            reference: "0.01",
+            mode: RoundingMode::HalfExpand,
+            expected: "1.25",
+        },
+        TestCase {
+            input: "-1.245",
+            reference: "0.01",
+            mode: RoundingMode::HalfTrunc,
+            expected: "-1.24",
+        },
+        TestCase {
+            input: "-1.241",
+            reference: "0.01",
+            mode: RoundingMode::Floor,
+            expected: "-1.25",
+        },
+        TestCase {
+            input: "-1.249",
+            reference: "0.01",
+            mode: RoundingMode::Ceil,
+            expected: "-1.24",
+        },
+        TestCase {
+            input: "1.201",
+            reference: "0.01",
+            mode: RoundingMode::Expand,
+            expected: "1.21",
+        },
+        TestCase {
+            input: "1.209",
+            reference: "0.01",
+            mode: RoundingMode::Trunc,
+            expected: "1.20",
+        },
+        TestCase {
+            input: "2.5",
+            reference: "1",
+            mode: RoundingMode::HalfEven,
+            expected: "2",
+        },
+        TestCase {
+            input: "9.996",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "10.00",
+        },
+        TestCase {
+            input: "0",
+            reference: "0.001",
+            mode: RoundingMode::HalfEven,
+            expected: "0.000",
+        },
+        TestCase {
+            input: "0.004",
+            reference: "0.01",
+            mode: RoundingMode::HalfEven,
+            expected: "0.00",
+        },
+        // The value and sign of the reference are ignored
+        TestCase {
+            input: "3",
+            reference: "-7.50",
+            mode: RoundingMode::HalfEven,
+            expected: "3.00",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        let reference = FixedDecimal::from_str(cas.reference).unwrap();
+        writeable::assert_writeable_eq!(
+            dec.quantized(&reference, cas.mode),
+            cas.expected,
+            "{:?}",
+            cas
+        );
+    }
+}
+
+#[test]
+fn test_is_divisible_by() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub divisor: &'static str,
+        pub expected: Result<bool, Error>,
+    }
+    let cases = [
+        TestCase {
+            input: "1.25",
+            divisor: "0.05",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "1.23",
+            divisor: "0.05",
+            expected: Ok(false),
+        },
+        TestCase {
+            input: "-1.25",
+            divisor: "0.05",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "1.25",
+            divisor: "-0.25",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "1.2500",
+            divisor: "0.050",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "0",
+            divisor: "7",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "3",
+            divisor: "7",
+            expected: Ok(false),
+        },
+        TestCase {
+            input: "1000",
+            divisor: "0.008",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "123456789012345678901234567890",
+            divisor: "1234567890",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "123456789012345678901234567891",
+            divisor: "1234567890",
+            expected: Ok(false),
+        },
+        TestCase {
+            input: "99999999999999999999",
+            divisor: "99999",
+            expected: Ok(true),
+        },
+        TestCase {
+            input: "0.1",
+            divisor: "0.3",
+            expected: Ok(false),
+        },
+        TestCase {
+            input: "1.5",
+            divisor: "0",
+            expected: Err(Error::DivisionByZero),
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        let divisor = FixedDecimal::from_str(cas.divisor).unwrap();
+        assert_eq!(cas.expected, dec.is_divisible_by(&divisor), "{cas:?}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("1.108").unwrap();
-    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("1.25", dec.to_string());
+#[test]
+fn test_rem() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub divisor: &'static str,
+        pub expected: Result<&'static str, Error>,
+    }
+    let cases = [
+        TestCase {
+            input: "7.5",
+            divisor: "2",
+            expected: Ok("1.5"),
+        },
+        TestCase {
+            input: "1.23",
+            divisor: "0.05",
+            expected: Ok("0.03"),
+        },
+        TestCase {
+            input: "1.2500",
+            divisor: "0.05",
+            expected: Ok("0.0000"),
+        },
+        TestCase {
+            input: "10",
+            divisor: "0.3",
+            expected: Ok("0.1"),
+        },
+        TestCase {
+            input: "0.1",
+            divisor: "0.3",
+            expected: Ok("0.1"),
+        },
+        TestCase {
+            input: "-0.1",
+            divisor: "3",
+            expected: Ok("-0.1"),
+        },
+        TestCase {
+            input: "+5",
+            divisor: "-3",
+            expected: Ok("+2"),
+        },
+        TestCase {
+            input: "-6",
+            divisor: "3",
+            expected: Ok("0"),
+        },
+        TestCase {
+            input: "0012",
+            divisor: "5",
+            expected: Ok("0002"),
+        },
+        TestCase {
+            input: "0",
+            divisor: "0.7",
+            expected: Ok("0.0"),
+        },
+        TestCase {
+            input: "123456789012345678901234567891",
+            divisor: "1234567890",
+            expected: Ok("1"),
+        },
+        TestCase {
+            input: "1000000000000000000000.01",
+            divisor: "7",
+            expected: Ok("6.01"),
+        },
+        TestCase {
+            input: "1.5",
+            divisor: "0",
+            expected: Err(Error::DivisionByZero),
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        let divisor = FixedDecimal::from_str(cas.divisor).unwrap();
+        let actual = dec.checked_rem(&divisor).map(|r| r.to_string());
+        assert_eq!(cas.expected.map(String::from), actual, "{cas:?}");
+        if let Ok(expected) = cas.expected {
+            assert_eq!(expected, (&dec % &divisor).to_string(), "{cas:?}");
+            assert_eq!(expected, (dec.clone() % &divisor).to_string(), "{cas:?}");
+            assert_eq!(expected, (dec % divisor).to_string(), "{cas:?}");
+        }
+    }
 
-    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX - 1);
-    dec.expand_to_increment(i16::MAX - 1, RoundingIncrement::MultiplesOf25);
-    assert_eq!(FixedDecimal::from(25).multiplied_pow10(i16::MAX - 1), dec);
+    // The sign follows the dividend, like the `%` operator on integers
+    for a in -12i32..=12 {
+        for b in (-5i32..=5).filter(|b| *b != 0) {
+            let actual = FixedDecimal::from(a) % FixedDecimal::from(b);
+            assert_eq!(FixedDecimal::from(a % b), actual, "{a} % {b}");
+            // The same holds when both operands are scaled
+            let actual = FixedDecimal::from(a).multiplied_pow10(-2)
+                % FixedDecimal::from(b).multiplied_pow10(-1);
+            assert_eq!(
+                FixedDecimal::from(a % (b * 10)).multiplied_pow10(-2),
+                actual,
+                "{a}e-2 % {b}e-1"
+            );
+        }
+    }
+}
 
-    let mut dec = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
-    dec.expand_to_increment(i16::MAX, RoundingIncrement::MultiplesOf25);
-    assert_eq!(FixedDecimal::from(0).multiplied_pow10(i16::MAX), dec);
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn test_rem_by_zero() {
+    let _ = FixedDecimal::from(1) % FixedDecimal::from(0);
+}
 
-    let mut dec = FixedDecimal::from_str("0").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("0", dec.to_string());
+#[test]
+fn test_canonical_key() {
+    let cases = [
+        ("1", "1"),
+        ("001.000", "1"),
+        ("+1", "1"),
+        ("-1.50", "-1.5"),
+        ("0", "0"),
+        ("-0", "0"),
+        ("+000.000", "0"),
+        ("1200", "1200"),
+        ("0.0010", "0.001"),
+        ("1.2e3", "1200"),
+        ("-0.05e-2", "-0.0005"),
+    ];
+    for (input, expected) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        let key = dec.canonical_key();
+        assert_eq!(expected, key, "{input}");
+
+        // The key round-trips to a numerically equal value
+        let mut difference = FixedDecimal::from_str(&key).unwrap();
+        difference.checked_sub_assign(&dec).unwrap();
+        assert!(difference.is_zero(), "{input}");
+        assert_eq!(key, FixedDecimal::from_str(&key).unwrap().canonical_key());
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("0").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("0", dec.to_string());
+#[test]
+fn test_hash_canonicalized() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(dec: &FixedDecimal) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        dec.hash(&mut hasher);
+        hasher.finish()
+    }
 
-    let mut dec = FixedDecimal::from_str("0").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
-    assert_eq!("0", dec.to_string());
+    let a = FixedDecimal::from_str("1.0").unwrap();
+    let b = FixedDecimal::from(10).multiplied_pow10(-1);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+
+    let c = FixedDecimal::from_str("01.00").unwrap();
+    assert_ne!(a, c);
+    assert_eq!(a.clone().canonicalized(), c.clone().canonicalized());
+    assert_eq!(hash(&a.canonicalized()), hash(&c.canonicalized()));
+
+    for (input, expected) in [
+        ("+1", "1"),
+        ("-1.50", "-1.5"),
+        ("-0.0", "0"),
+        ("+000", "0"),
+        ("0120", "120"),
+    ] {
+        let dec = FixedDecimal::from_str(input).unwrap().canonicalized();
+        assert_eq!(dec, FixedDecimal::from_str(expected).unwrap(), "{input}");
+    }
 
-    let mut dec = FixedDecimal::from_str("0.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2", dec.to_string());
+    let mut map = std::collections::HashMap::new();
+    for input in ["2.50", "+2.5", "002.5", "2.500"] {
+        *map.entry(FixedDecimal::from_str(input).unwrap().canonicalized())
+            .or_insert(0) += 1;
+    }
+    assert_eq!(map.len(), 1);
+}
 
-    let mut dec = FixedDecimal::from_str("0.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+#[test]
+fn test_from_str_with_grouping() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub decimal: char,
+        pub grouping: char,
+        pub sizes: &'static [u8],
+        pub expected: Result<&'static str, Error>,
+    }
+    let cases = [
+        TestCase {
+            input: "1,234,567.89",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Ok("1234567.89"),
+        },
+        TestCase {
+            input: "-1.234.567,50",
+            decimal: ',',
+            grouping: '.',
+            sizes: &[3],
+            expected: Ok("-1234567.50"),
+        },
+        TestCase {
+            input: "+12\u{a0}345",
+            decimal: ',',
+            grouping: '\u{a0}',
+            sizes: &[3],
+            expected: Ok("+12345"),
+        },
+        TestCase {
+            input: "1,23,45,678",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3, 2],
+            expected: Ok("12345678"),
+        },
+        TestCase {
+            input: "12,345",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3, 2],
+            expected: Ok("12345"),
+        },
+        // Ungrouped numbers are accepted
+        TestCase {
+            input: "1234567.5",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Ok("1234567.5"),
+        },
+        TestCase {
+            input: "1234567",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[],
+            expected: Ok("1234567"),
+        },
+        // Groups at the wrong positions
+        TestCase {
+            input: "1,2345",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1234,567",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1,234,567",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3, 2],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: ",234",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1,,234",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1,234",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[],
+            expected: Err(Error::Syntax),
+        },
+        // Grouping in the fraction part
+        TestCase {
+            input: "1.234,5",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        // Other invalid input
+        TestCase {
+            input: "1.5e3",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1.2.3",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1,234",
+            decimal: ',',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "",
+            decimal: '.',
+            grouping: ',',
+            sizes: &[3],
+            expected: Err(Error::Syntax),
+        },
+    ];
+    for cas in &cases {
+        let result =
+            FixedDecimal::from_str_with_grouping(cas.input, cas.decimal, cas.grouping, cas.sizes);
+        assert_eq!(
+            cas.expected.map(String::from),
+            result.map(|d| d.to_string()),
+            "{cas:?}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("0.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
-    assert_eq!("25", dec.to_string());
+#[test]
+fn test_checked_sub_integer() {
+    for input in [
+        "0",
+        "-0",
+        "+0",
+        "7",
+        "+7",
+        "-7",
+        "25.00",
+        "-25.00",
+        "0012",
+        "1.25",
+        "-0.5",
+        "1200",
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105728",
+        "99999999999999999999999999999999999999999",
+        "1e300",
+    ] {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        for n in [0, 1, -1, 7, -7, 12, 1000, i64::MAX, i64::MIN] {
+            let mut expected = dec.clone();
+            expected.checked_sub_assign(&FixedDecimal::from(n)).unwrap();
+            assert_eq!(dec.checked_sub_integer(n), Ok(expected), "{input} - {n}");
+        }
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2", dec.to_string());
+#[test]
+fn test_exp_format() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub format: fn(&FixedDecimal) -> String,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "0",
+            format: |d| format!("{d:e}"),
+            expected: "0e0",
+        },
+        TestCase {
+            input: "0.00",
+            format: |d| format!("{d:.2e}"),
+            expected: "0.00e0",
+        },
+        TestCase {
+            input: "-0",
+            format: |d| format!("{d:e}"),
+            expected: "-0e0",
+        },
+        TestCase {
+            input: "1200",
+            format: |d| format!("{d:e}"),
+            expected: "1.2e3",
+        },
+        TestCase {
+            input: "12340",
+            format: |d| format!("{d:E}"),
+            expected: "1.234E4",
+        },
+        TestCase {
+            input: "+12340",
+            format: |d| format!("{d:e}"),
+            expected: "1.234e4",
+        },
+        TestCase {
+            input: "12340",
+            format: |d| format!("{d:+e}"),
+            expected: "+1.234e4",
+        },
+        TestCase {
+            input: "0.00123",
+            format: |d| format!("{d:e}"),
+            expected: "1.23e-3",
+        },
+        TestCase {
+            input: "-7",
+            format: |d| format!("{d:.3e}"),
+            expected: "-7.000e0",
+        },
+        TestCase {
+            input: "12.5",
+            format: |d| format!("{d:.0e}"),
+            expected: "1e1",
+        },
+        TestCase {
+            input: "125",
+            format: |d| format!("{d:.1e}"),
+            expected: "1.2e2",
+        },
+        TestCase {
+            input: "135",
+            format: |d| format!("{d:.1E}"),
+            expected: "1.4E2",
+        },
+        TestCase {
+            input: "9.99",
+            format: |d| format!("{d:.1e}"),
+            expected: "1.0e1",
+        },
+        TestCase {
+            input: "-1.5",
+            format: |d| format!("{d:>8e}"),
+            expected: "  -1.5e0",
+        },
+        TestCase {
+            input: "-1.5",
+            format: |d| format!("{d:08e}"),
+            expected: "-001.5e0",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        assert_eq!((cas.format)(&dec), cas.expected, "{cas:?}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+#[test]
+#[allow(clippy::reversed_empty_ranges)] // descending ranges are accepted
+fn test_slice_magnitudes() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub range: RangeInclusive<i16>,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "1234.56",
+            range: 2..=-1,
+            expected: "234.5",
+        },
+        TestCase {
+            input: "1234.56",
+            range: -1..=2,
+            expected: "234.5",
+        },
+        TestCase {
+            input: "1234.56",
+            range: 0..=0,
+            expected: "4",
+        },
+        TestCase {
+            input: "1234.56",
+            range: 2..=3,
+            expected: "1200",
+        },
+        TestCase {
+            input: "1234.56",
+            range: -2..=-2,
+            expected: "0.06",
+        },
+        TestCase {
+            input: "1234.56",
+            range: -3..=6,
+            expected: "0001234.560",
+        },
+        TestCase {
+            input: "-1234.56",
+            range: 0..=1,
+            expected: "-34",
+        },
+        // Entirely above the nonzero digits
+        TestCase {
+            input: "1234.56",
+            range: 4..=6,
+            expected: "0000000",
+        },
+        // Entirely below the nonzero digits
+        TestCase {
+            input: "1234.56",
+            range: -5..=-3,
+            expected: "0.00000",
+        },
+        TestCase {
+            input: "-1234.56",
+            range: -5..=-3,
+            expected: "0.00000",
+        },
+        // Only zero digits within the nonzero digits
+        TestCase {
+            input: "100.001",
+            range: -2..=1,
+            expected: "00.00",
+        },
+        TestCase {
+            input: "-100.001",
+            range: -2..=1,
+            expected: "00.00",
+        },
+        TestCase {
+            input: "+100.001",
+            range: -2..=1,
+            expected: "+00.00",
+        },
+        TestCase {
+            input: "0",
+            range: -2..=2,
+            expected: "000.00",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        assert_eq!(
+            dec.slice_magnitudes(cas.range.clone()).to_string(),
+            cas.expected,
+            "{cas:?}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf25);
-    assert_eq!("25", dec.to_string());
+#[test]
+fn test_to_bidi_string() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub minus_char: char,
+        pub plus_char: Option<char>,
+        pub expected: &'static str,
+    }
+    let cases = [
+        // Persian, with U+2212 MINUS SIGN
+        TestCase {
+            input: "-1234.5",
+            minus_char: '\u{2212}',
+            plus_char: None,
+            expected: "\u{202D}\u{2212}1234.5\u{202C}",
+        },
+        TestCase {
+            input: "+1234.5",
+            minus_char: '\u{2212}',
+            plus_char: Some('+'),
+            expected: "\u{202D}+1234.5\u{202C}",
+        },
+        // Hebrew, with HYPHEN-MINUS
+        TestCase {
+            input: "-0.05",
+            minus_char: '-',
+            plus_char: Some('+'),
+            expected: "\u{202D}-0.05\u{202C}",
+        },
+        TestCase {
+            input: "+7",
+            minus_char: '-',
+            plus_char: None,
+            expected: "\u{202D}7\u{202C}",
+        },
+        TestCase {
+            input: "7",
+            minus_char: '-',
+            plus_char: Some('+'),
+            expected: "\u{202D}7\u{202C}",
+        },
+        TestCase {
+            input: "-0",
+            minus_char: '-',
+            plus_char: None,
+            expected: "\u{202D}-0\u{202C}",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input).unwrap();
+        assert_eq!(
+            dec.to_bidi_string(cas.minus_char, cas.plus_char),
+            cas.expected,
+            "{cas:?}"
+        );
+    }
 
-    let mut dec = FixedDecimal::from_str("2").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2", dec.to_string());
+    // Embedded in right-to-left text, the number is a single left-to-right run
+    let dec = FixedDecimal::from_str("-3").unwrap();
+    assert_eq!(
+        format!("הטמפרטורה {} מעלות", dec.to_bidi_string('-', None)),
+        "הטמפרטורה \u{202D}-3\u{202C} מעלות"
+    );
 
-    let mut dec = FixedDecimal::from_str("2").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+    // The capacity covers the controls and a multi-byte sign without reallocating
+    let dec = FixedDecimal::from_str("-1234.5").unwrap();
+    let bidi = dec.to_bidi_string('\u{2212}', None);
+    assert_eq!(bidi.len(), 3 + 3 + 6 + 3);
+    assert_eq!(bidi.capacity(), bidi.len());
+}
 
-    let mut dec = FixedDecimal::from_str("2.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("4", dec.to_string());
+#[test]
+fn test_unicode_minus() {
+    let strict = ParseOptions::default();
+    let lenient = ParseOptions {
+        accept_unicode_minus: true,
+    };
 
-    let mut dec = FixedDecimal::from_str("2.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+    // The default is as strict as FromStr
+    assert_eq!(
+        FixedDecimal::try_from_str_with_options("\u{2212}5", strict),
+        Err(Error::Syntax)
+    );
+    assert_eq!(FixedDecimal::from_str("\u{2212}5"), Err(Error::Syntax));
 
-    let mut dec = FixedDecimal::from_str("4").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("4", dec.to_string());
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub expected: Result<&'static str, Error>,
+    }
+    let cases = [
+        TestCase {
+            input: "\u{2212}5",
+            expected: Ok("-5"),
+        },
+        TestCase {
+            input: "\u{2212}0.50",
+            expected: Ok("-0.50"),
+        },
+        TestCase {
+            input: "\u{2212}1.5e3",
+            expected: Ok("-1500"),
+        },
+        TestCase {
+            input: "-5",
+            expected: Ok("-5"),
+        },
+        TestCase {
+            input: "+5",
+            expected: Ok("+5"),
+        },
+        TestCase {
+            input: "\u{2212}",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "\u{2212}-5",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "5\u{2212}",
+            expected: Err(Error::Syntax),
+        },
+    ];
+    for cas in &cases {
+        let result =
+            FixedDecimal::try_from_str_with_options(cas.input, lenient).map(|dec| dec.to_string());
+        assert_eq!(result.as_deref().map_err(|e| *e), cas.expected, "{cas:?}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("4").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+#[test]
+fn test_minutes_offset() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub minutes: i32,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            minutes: 330,
+            expected: "+05.30",
+        },
+        TestCase {
+            minutes: 345,
+            expected: "+05.45",
+        },
+        TestCase {
+            minutes: -570,
+            expected: "-09.30",
+        },
+        TestCase {
+            minutes: 840,
+            expected: "+14.00",
+        },
+        TestCase {
+            minutes: -45,
+            expected: "-00.45",
+        },
+        TestCase {
+            minutes: 0,
+            expected: "00.00",
+        },
+        TestCase {
+            minutes: 6000,
+            expected: "+100.00",
+        },
+        TestCase {
+            minutes: i32::MAX,
+            expected: "+35791394.07",
+        },
+        TestCase {
+            minutes: i32::MIN,
+            expected: "-35791394.08",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_minutes_offset(cas.minutes);
+        assert_eq!(dec.to_string(), cas.expected, "{cas:?}");
+        assert_eq!(dec.to_minutes_offset(), Some(cas.minutes), "{cas:?}");
+    }
 
-    let mut dec = FixedDecimal::from_str("4.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("6", dec.to_string());
+    for (input, expected) in [
+        ("5.3", Some(330)),
+        ("+5", Some(300)),
+        ("-0.01", Some(-1)),
+        ("0", Some(0)),
+        ("5.60", None),
+        ("5.305", None),
+        ("35791394.08", None),
+        ("1e20", None),
+    ] {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.to_minutes_offset(), expected, "{input}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("4.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+#[test]
+fn test_order_of_magnitude() {
+    for (input, expected) in [
+        ("1234.5", Some(3)),
+        ("-1", Some(0)),
+        ("9.99", Some(0)),
+        ("0.1", Some(-1)),
+        ("-0.00012", Some(-4)),
+        ("1e300", Some(300)),
+        ("0", None),
+        ("-0.000", None),
+        ("00100.00", Some(2)),
+    ] {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.order_of_magnitude(), expected, "{input}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("5").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("6", dec.to_string());
+#[test]
+fn test_round_with_remainder() {
+    use core::str::FromStr;
+
+    let modes = [
+        RoundingMode::Ceil,
+        RoundingMode::Expand,
+        RoundingMode::Floor,
+        RoundingMode::Trunc,
+        RoundingMode::HalfCeil,
+        RoundingMode::HalfExpand,
+        RoundingMode::HalfFloor,
+        RoundingMode::HalfTrunc,
+        RoundingMode::HalfEven,
+    ];
+    let inputs = [
+        "0",
+        "1.2345",
+        "-1.2345",
+        "2.5",
+        "-2.5",
+        "999.99",
+        "-999.99",
+        "0.0001",
+        "-0.0001",
+        "1234",
+        "-1234",
+        "000120.500",
+    ];
+    for input in inputs {
+        let original = FixedDecimal::from_str(input).unwrap();
+        for mode in modes {
+            for position in [-3, -1, 0, 1, 2, 5] {
+                let mut rounded = original.clone();
+                let remainder = rounded.round_with_remainder(position, mode);
+
+                let mut expected = original.clone();
+                expected.round_with_mode(position, mode);
+                assert_eq!(expected, rounded, "{input} {mode:?} {position}");
+
+                let mut sum = rounded.clone();
+                sum += &remainder;
+                assert_eq!(
+                    original.canonical_key(),
+                    sum.canonical_key(),
+                    "{input} {mode:?} {position}: {rounded} + {remainder}"
+                );
+            }
+        }
+    }
 
-    let mut dec = FixedDecimal::from_str("5").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("5", dec.to_string());
+    let cases: [(&str, i16, RoundingMode, &str, &str); 6] = [
+        ("1.2345", -2, RoundingMode::HalfExpand, "1.23", "0.0045"),
+        ("1.235", -2, RoundingMode::HalfEven, "1.24", "-0.005"),
+        ("-1.235", -2, RoundingMode::HalfEven, "-1.24", "0.005"),
+        ("-7.5", 0, RoundingMode::Floor, "-8", "0.5"),
+        ("5", 3, RoundingMode::Expand, "1000", "-995"),
+        ("12.34", -2, RoundingMode::Trunc, "12.34", "0.00"),
+    ];
+    for (input, position, mode, expected_rounded, expected_remainder) in cases {
+        let mut dec = FixedDecimal::from_str(input).unwrap();
+        let remainder = dec.round_with_remainder(position, mode);
+        assert_eq!(expected_rounded, dec.to_string(), "{input} {mode:?}");
+        assert_eq!(
+            expected_remainder,
+            remainder.to_string(),
+            "{input} {mode:?}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("5.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("6", dec.to_string());
+#[test]
+fn test_double_halve() {
+    use core::str::FromStr;
+
+    let cases: [(&str, &str, &str); 9] = [
+        ("0", "0", "0"),
+        ("1", "2", "0.5"),
+        ("-3.00", "-6.00", "-1.50"),
+        ("0.5", "1.0", "0.25"),
+        ("5", "10", "2.5"),
+        ("999.99", "1999.98", "499.995"),
+        ("-0.0001", "-0.0002", "-0.00005"),
+        ("1200", "2400", "600"),
+        ("0019", "0038", "0009.5"),
+    ];
+    for (input, doubled, halved) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        let mut actual = dec.clone();
+        actual.double().unwrap();
+        assert_eq!(doubled, actual.to_string(), "{input}");
+        let mut actual = dec;
+        actual.halve(i16::MIN);
+        assert_eq!(halved, actual.to_string(), "{input}");
+    }
 
-    let mut dec = FixedDecimal::from_str("5.1").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("10", dec.to_string());
+    // Position limit
+    let cases: [(&str, i16, &str); 11] = [
+        ("3", 0, "2"),
+        ("5", 0, "2"),
+        ("7", 0, "4"),
+        ("19", 0, "10"),
+        ("-1", 0, "0"),
+        ("-0.01", -2, "0.00"),
+        ("-3", 0, "-2"),
+        ("+1", 0, "+0"),
+        ("3", -1, "1.5"),
+        ("0.03", 0, "0.02"),
+        ("0.03", -3, "0.015"),
+    ];
+    for (input, position_limit, expected) in cases {
+        let mut dec = FixedDecimal::from_str(input).unwrap();
+        dec.halve(position_limit);
+        assert_eq!(expected, dec.to_string(), "{input} {position_limit}");
+    }
 
-    let mut dec = FixedDecimal::from_str("6").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf2);
-    assert_eq!("6", dec.to_string());
+    // Limits
+    let mut dec = FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+    assert_eq!(Err(Error::Limit), dec.double());
+    assert_eq!(FixedDecimal::from(5).multiplied_pow10(i16::MAX), dec);
+    let mut dec = FixedDecimal::from(1).multiplied_pow10(i16::MIN);
+    dec.halve(i16::MIN);
+    assert_eq!(FixedDecimal::from(0).padded_end(i16::MIN), dec);
 
-    let mut dec = FixedDecimal::from_str("6").unwrap();
-    dec.expand_to_increment(0, RoundingIncrement::MultiplesOf5);
-    assert_eq!("10", dec.to_string());
+    // Round trips
+    let inputs = [
+        "0",
+        "1",
+        "-1",
+        "7",
+        "0.5",
+        "-0.05",
+        "123.456",
+        "-999.999",
+        "4999",
+        "10000.0001",
+    ];
+    for input in inputs {
+        let original = FixedDecimal::from_str(input).unwrap();
+
+        let mut dec = original.clone();
+        dec.double().unwrap();
+        dec.halve(i16::MIN);
+        assert_eq!(original.canonical_key(), dec.canonical_key(), "{input}");
+        assert_eq!(original.lower_magnitude, dec.lower_magnitude, "{input}");
+
+        let mut dec = original.clone();
+        dec.halve(i16::MIN);
+        dec.double().unwrap();
+        assert_eq!(original.canonical_key(), dec.canonical_key(), "{input}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("0.50").unwrap();
-    dec.expand_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("0.50", dec.to_string());
+#[test]
+fn test_shift_magnitude() {
+    use core::str::FromStr;
+
+    let cases: [(&str, i16, &str); 5] = [
+        ("42", 3, "42000"),
+        ("42", -3, "0.042"),
+        ("-1.50", 1, "-15.0"),
+        ("0012", -1, "001.2"),
+        ("0.00", 2, "000"),
+    ];
+    for (input, delta, expected) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        let mut checked = dec.clone();
+        checked.shift_magnitude_checked(delta).unwrap();
+        assert_eq!(
+            dec.clone().multiplied_pow10(delta),
+            checked,
+            "{input} {delta}"
+        );
+        assert_eq!(expected, checked.to_string(), "{input} {delta}");
+        let mut saturating = dec.clone();
+        saturating.shift_magnitude_saturating(delta);
+        assert_eq!(checked, saturating, "{input} {delta}");
+        let mut wrapping = dec;
+        wrapping.shift_magnitude_wrapping(delta);
+        assert_eq!(checked, wrapping, "{input} {delta}");
+    }
 
-    let mut dec = FixedDecimal::from_str("1.1025").unwrap();
-    dec.half_trunc_to_increment(-3, RoundingIncrement::MultiplesOf5);
-    assert_eq!("1.100", dec.to_string());
+    // Checked
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MAX);
+    assert_eq!(Err(Error::Limit), dec.shift_magnitude_checked(1));
+    assert_eq!(FixedDecimal::from(7).multiplied_pow10(i16::MAX), dec);
+    let mut dec = FixedDecimal::from_str("0.5").unwrap();
+    assert_eq!(Err(Error::Limit), dec.shift_magnitude_checked(i16::MIN));
+    assert_eq!("0.5", dec.to_string());
 
-    let mut dec = FixedDecimal::from_str("1.10125").unwrap();
-    dec.half_expand_to_increment(-4, RoundingIncrement::MultiplesOf25);
-    assert_eq!("1.1025", dec.to_string());
+    // Saturating
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MAX - 2);
+    dec.shift_magnitude_saturating(10);
+    assert_eq!(FixedDecimal::from(7).multiplied_pow10(i16::MAX), dec);
+    let mut dec = FixedDecimal::from_str("1.25").unwrap();
+    dec.shift_magnitude_saturating(i16::MIN);
+    assert_eq!(i16::MIN, dec.nonzero_magnitude_end());
+    assert_eq!(i16::MIN + 2, dec.magnitude);
+
+    // Wrapping
+    let mut dec = FixedDecimal::from(-7).multiplied_pow10(i16::MAX);
+    dec.shift_magnitude_wrapping(3);
+    assert_eq!(FixedDecimal::from(-7).multiplied_pow10(i16::MIN + 2), dec);
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MIN);
+    dec.shift_magnitude_wrapping(-1);
+    assert_eq!(FixedDecimal::from(7).multiplied_pow10(i16::MAX), dec);
+    let mut dec = FixedDecimal::from(75).multiplied_pow10(i16::MAX - 1);
+    dec.shift_magnitude_wrapping(1);
+    assert_eq!(FixedDecimal::from(0), dec);
+}
 
-    let mut dec = FixedDecimal::from_str("-1.25").unwrap();
-    dec.half_ceil_to_increment(-1, RoundingIncrement::MultiplesOf5);
-    assert_eq!("-1.0", dec.to_string());
+#[test]
+fn test_exact_shift() {
+    use core::str::FromStr;
+
+    let cases: [(&str, i16, &str, &str); 8] = [
+        // input, delta, exact_shift, multiply_pow10
+        ("5", -3, "0.005", "0.005"),
+        ("0.005", 3, "5", "0005"),
+        ("5000", -3, "5", "5.000"),
+        ("0.05", 3, "50", "0050"),
+        ("-1.50", 1, "-15.0", "-15.0"),
+        ("0012", -1, "001.2", "001.2"),
+        ("12.340", -2, "0.12340", "0.12340"),
+        ("0.00", 2, "000", "000"),
+    ];
+    for (input, delta, expected, expected_pow10) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        let mut exact = dec.clone();
+        exact.exact_shift(delta).unwrap();
+        assert_eq!(expected, exact.to_string(), "{input} {delta}");
+        assert_eq!(
+            expected_pow10,
+            dec.multiplied_pow10(delta).to_string(),
+            "{input} {delta}"
+        );
+    }
 
-    let mut dec = FixedDecimal::from_str("-1.251").unwrap();
-    dec.half_ceil_to_increment(-1, RoundingIncrement::MultiplesOf5);
-    assert_eq!("-1.5", dec.to_string());
+    // "0.005" has exactly three fraction digits
+    let mut dec = FixedDecimal::from(5);
+    dec.exact_shift(-3).unwrap();
+    assert_eq!(-3..=0, dec.magnitude_range());
+    dec.exact_shift(3).unwrap();
+    assert_eq!(FixedDecimal::from(5), dec);
 
-    let mut dec = FixedDecimal::from_str("-1.125").unwrap();
-    dec.half_floor_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("-1.25", dec.to_string());
+    let mut dec = FixedDecimal::from(7).multiplied_pow10(i16::MAX);
+    assert_eq!(Err(Error::Limit), dec.exact_shift(1));
+    assert_eq!(FixedDecimal::from(7).multiplied_pow10(i16::MAX), dec);
+    let mut dec = FixedDecimal::from_str("0.5").unwrap();
+    assert_eq!(Err(Error::Limit), dec.exact_shift(i16::MIN));
+    assert_eq!("0.5", dec.to_string());
+}
 
-    let mut dec = FixedDecimal::from_str("2.71").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.72", dec.to_string());
+#[test]
+fn test_fit_to_range_saturating() {
+    use core::str::FromStr;
+
+    let cases: [(&str, RangeInclusive<i16>, &str, bool); 10] = [
+        // Lossless
+        ("12.5", -2..=3, "0012.50", false),
+        ("-12.50", -1..=1, "-12.5", false),
+        ("0.000", -1..=0, "0.0", false),
+        ("1200", 2..=3, "1200", false),
+        ("0.05", -2..=-1, "0.05", false),
+        // Lossy
+        ("12.345", -2..=3, "0012.34", true),
+        ("-12.5", 0..=0, "-2", true),
+        ("1234", 0..=2, "234", true),
+        ("0.05", -1..=0, "0.0", true),
+        ("-0.05", -1..=0, "0.0", true),
+    ];
+    for (input, range, expected, lossy) in cases {
+        let mut dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(
+            lossy,
+            dec.fit_to_range_saturating(range.clone()),
+            "{input} {range:?}"
+        );
+        assert_eq!(expected, dec.to_string(), "{input} {range:?}");
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("2.73").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.72", dec.to_string());
+#[test]
+fn test_validate() {
+    use core::str::FromStr;
 
-    let mut dec = FixedDecimal::from_str("2.75").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.76", dec.to_string());
+    for input in ["0", "-0.000", "00123.4500", "1e-20", "-9999999999999999999"] {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(Ok(()), dec.validate(), "{input}");
+    }
 
-    let mut dec = FixedDecimal::from_str("2.77").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.76", dec.to_string());
+    type Corruption = fn(&mut FixedDecimal);
+
+    let valid = FixedDecimal::from_str("012.30").unwrap();
+    let cases: [(Corruption, &str); 8] = [
+        (|dec| dec.upper_magnitude = 0, "Upper magnitude too small"),
+        (|dec| dec.lower_magnitude = 2, "Lower magnitude too large"),
+        (
+            |dec| {
+                dec.digits.clear();
+                dec.upper_magnitude = -1;
+                dec.magnitude = -1;
+            },
+            "Upper magnitude below zero",
+        ),
+        (
+            |dec| {
+                dec.digits = SmallVec::from_slice(&[1]);
+                dec.magnitude = 1;
+                dec.lower_magnitude = 1;
+            },
+            "Lower magnitude above zero",
+        ),
+        (
+            |dec| dec.digits.extend_from_slice(&[4, 5, 6]),
+            "Too many digits for the magnitude range",
+        ),
+        (|dec| dec.digits[1] = 10, "Digit out of range"),
+        (|dec| dec.digits.insert(0, 0), "Starts with a zero"),
+        (|dec| dec.digits.push(0), "Ends with a zero"),
+    ];
+    for (corrupt, expected) in cases {
+        let mut dec = valid.clone();
+        corrupt(&mut dec);
+        assert_eq!(Err(expected), dec.validate());
+    }
 
-    let mut dec = FixedDecimal::from_str("2.79").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.80", dec.to_string());
+    let mut dec = FixedDecimal::from(0);
+    dec.magnitude = 1;
+    dec.upper_magnitude = 1;
+    assert_eq!(Err("Zero with a nonzero magnitude"), dec.validate());
+}
 
-    let mut dec = FixedDecimal::from_str("2.41").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.40", dec.to_string());
+#[test]
+fn test_digits_below_magnitude() {
+    use core::str::FromStr;
+
+    type Digits = &'static [(i16, u8)];
+
+    let cases: [(&str, i16, Digits); 7] = [
+        ("12.0305", -1, &[(-1, 0), (-2, 3), (-3, 0), (-4, 5)]),
+        ("12.0305", 0, &[(0, 2), (-1, 0), (-2, 3), (-3, 0), (-4, 5)]),
+        ("12.0305", -4, &[(-4, 5)]),
+        ("12.0305", -5, &[]),
+        (
+            "0012.50",
+            3,
+            &[(3, 0), (2, 0), (1, 1), (0, 2), (-1, 5), (-2, 0)],
+        ),
+        ("-0.000", -1, &[(-1, 0), (-2, 0), (-3, 0)]),
+        ("100", 0, &[(0, 0)]),
+    ];
+    for (input, magnitude, expected) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(
+            expected,
+            dec.digits_below_magnitude(magnitude).collect::<Vec<_>>(),
+            "{input} {magnitude}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("2.43").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.44", dec.to_string());
+#[test]
+fn test_to_digit_vec() {
+    use core::str::FromStr;
+
+    let cases: [(&str, &[u8]); 6] = [
+        ("12.3", &[3, 2, 1]),
+        ("-12.3", &[3, 2, 1]),
+        ("0", &[0]),
+        ("0.050", &[0, 5, 0, 0]),
+        ("1000", &[0, 0, 0, 1]),
+        ("0012.340", &[0, 4, 3, 2, 1, 0, 0]),
+    ];
+    for (input, ascending) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.to_digit_vec(true), ascending, "{input}");
+        let mut descending = ascending.to_vec();
+        descending.reverse();
+        assert_eq!(dec.to_digit_vec(false), descending, "{input}");
+        assert_eq!(
+            dec.to_digit_vec(false).len(),
+            dec.magnitude_range().len(),
+            "{input}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("2.45").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.44", dec.to_string());
+#[test]
+fn test_with_exact_digits() {
+    use core::str::FromStr;
 
-    let mut dec = FixedDecimal::from_str("2.47").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.48", dec.to_string());
+    let cases = [
+        ("5.7", 2, 0, RoundingMode::HalfExpand, "06"),
+        ("5.7", 2, 0, RoundingMode::Trunc, "05"),
+        ("-5.7", 2, 0, RoundingMode::HalfExpand, "-06"),
+        ("0", 2, 2, RoundingMode::HalfExpand, "00.00"),
+        ("59.96", 2, 1, RoundingMode::HalfExpand, "60.0"),
+        ("99.96", 2, 1, RoundingMode::HalfExpand, "00.0"),
+        ("1234", 2, 0, RoundingMode::HalfExpand, "34"),
+        ("0.123456", 0, 3, RoundingMode::HalfEven, "0.123"),
+        ("42", 6, 0, RoundingMode::HalfEven, "000042"),
+        ("0012.3400", 2, 2, RoundingMode::HalfEven, "12.34"),
+    ];
+    for (input, integer_digits, fraction_digits, mode, expected) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(
+            expected,
+            dec.with_exact_digits(integer_digits, fraction_digits, mode)
+                .to_string(),
+            "{input} {integer_digits} {fraction_digits} {mode:?}"
+        );
+    }
+}
 
-    let mut dec = FixedDecimal::from_str("2.49").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf2);
-    assert_eq!("2.48", dec.to_string());
+#[test]
+fn test_digit_sum() {
+    use core::str::FromStr;
 
-    let mut dec = FixedDecimal::from_str("2.725").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf5);
-    assert_eq!("2.70", dec.to_string());
+    let cases = [
+        ("0", 0),
+        ("0.000", 0),
+        ("7", 7),
+        ("-7", 7),
+        ("12.34", 10),
+        ("1000000", 1),
+        ("0.00000001", 1),
+        ("99999999999999999999", 180),
+        ("00123.45600", 21),
+    ];
+    for (input, expected) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.digit_sum(), expected, "{input}");
+    }
 
-    let mut dec = FixedDecimal::from_str("2.775").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf5);
-    assert_eq!("2.80", dec.to_string());
+    let mut dec = FixedDecimal::from(4321);
+    dec.multiply_pow10(-10);
+    assert_eq!(dec.digit_sum(), 10);
+    dec.pad_start(5);
+    dec.pad_end(-20);
+    assert_eq!(dec.digit_sum(), 10);
+}
 
-    let mut dec = FixedDecimal::from_str("2.875").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("3.00", dec.to_string());
+#[test]
+fn test_next_up_down() {
+    use core::str::FromStr;
 
-    let mut dec = FixedDecimal::from_str("2.375").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("2.50", dec.to_string());
+    let cases = [
+        // input, next_up, next_down
+        ("0", "1", "-1"),
+        ("1.99", "2.00", "1.98"),
+        ("-1.99", "-1.98", "-2.00"),
+        ("9.99", "10.00", "9.98"),
+        ("-9.99", "-9.98", "-10.00"),
+        ("1.00", "1.01", "0.99"),
+        ("-0.01", "0.00", "-0.02"),
+        ("0.01", "0.02", "0.00"),
+        ("0.00", "0.01", "-0.01"),
+        ("+1.5", "+1.6", "+1.4"),
+        ("00099", "00100", "00098"),
+        ("1000", "1001", "999"),
+    ];
+    for (input, up, down) in cases {
+        let dec = FixedDecimal::from_str(input).unwrap();
+        assert_eq!(dec.next_up().unwrap().to_string(), up, "{input}");
+        assert_eq!(dec.next_down().unwrap().to_string(), down, "{input}");
+        // Stepping back returns to the input value
+        assert_eq!(
+            dec.next_up().unwrap().next_down().unwrap().to_string(),
+            input,
+            "{input}"
+        );
+    }
 
-    let mut dec = FixedDecimal::from_str("2.125").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("2.00", dec.to_string());
+    // Trimmed fraction digits are not visible
+    let dec = FixedDecimal::from(1200).multiplied_pow10(-2).trimmed_end();
+    assert_eq!(dec.next_up().unwrap().to_string(), "13");
 
-    let mut dec = FixedDecimal::from_str("2.625").unwrap();
-    dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
-    assert_eq!("2.50", dec.to_string());
+    // Overflow
+    let dec = FixedDecimal::from_str(&"9".repeat(i16::MAX as usize + 1)).unwrap();
+    assert_eq!(dec.next_up(), Err(Error::Limit));
+    assert!(dec.next_down().is_ok());
 }
@@ -51,10 +51,12 @@
     )
 )]
 
+extern crate alloc;
+
 mod compact;
 mod decimal;
 mod integer;
-mod ops;
+pub mod ops;
 mod scientific;
 mod uint_iterator;
 
@@ -67,7 +69,9 @@ pub use FloatPrecision as DoublePrecision;
 
 pub use compact::CompactDecimal;
 pub use decimal::FixedDecimal;
+pub use decimal::ParseOptions;
 pub use decimal::RoundingIncrement;
+pub use decimal::RoundingMode;
 pub use decimal::Sign;
 pub use decimal::SignDisplay;
 use displaydoc::Display;
@@ -106,6 +110,13 @@ pub enum FixedDecimalError {
     /// 123 (or 123.0) must be used.
     #[displaydoc("Failed to parse the input string")]
     Syntax,
+    /// The divisor of an operation such as [`FixedDecimal::is_divisible_by()`] is zero.
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
+    /// The number of fraction digits of a parsed string differs from the expected scale, as
+    /// in [`FixedDecimal::from_str_with_scale()`].
+    #[displaydoc("Number of fraction digits does not match the expected scale")]
+    ScaleMismatch,
 }
 
 #[doc(no_inline)]
@@ -2,13 +2,13 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Int operations that are not yet in the standard library.
+//! Integer operations that are useful alongside [`FixedDecimal`](crate::FixedDecimal).
 
 /// Computes `a - b` where `a` is signed and `b` is unsigned.
 ///
 /// If overflow occurs, panics in debug mode and wraps in release mode.
 #[inline(always)]
-pub fn i16_sub_unsigned(a: i16, b: u16) -> i16 {
+pub(crate) fn i16_sub_unsigned(a: i16, b: u16) -> i16 {
     let c = a.wrapping_sub(b as i16);
     debug_assert_eq!(a as i32 - b as i32, c as i32);
     c
@@ -42,7 +42,7 @@ fn test_i16_sub_unsigned() {
 ///
 /// If `a < b`, panics in debug mode and wraps in release mode.
 #[inline(always)]
-pub fn i16_abs_sub(a: i16, b: i16) -> u16 {
+pub(crate) fn i16_abs_sub(a: i16, b: i16) -> u16 {
     debug_assert!(a >= b);
     let c = (a as u16).wrapping_sub(b as u16);
     debug_assert_eq!(a as i32 - b as i32, c as i32);
@@ -68,3 +68,93 @@ fn test_i16_abs_sub() {
     assert_eq!(i16_abs_sub(i16::MAX, i16::MAX - 1), 1);
     assert_eq!(i16_abs_sub(i16::MAX, i16::MAX), 0);
 }
+
+/// Returns the number of decimal digits in `value`.
+///
+/// This is the number of digits a [`FixedDecimal`] constructed from `value` has before
+/// padding. Zero has one digit.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::ops::decimal_digit_count_u128;
+///
+/// assert_eq!(decimal_digit_count_u128(0), 1);
+/// assert_eq!(decimal_digit_count_u128(42), 2);
+/// assert_eq!(decimal_digit_count_u128(u128::MAX), 39);
+/// ```
+///
+/// [`FixedDecimal`]: crate::FixedDecimal
+pub const fn decimal_digit_count_u128(value: u128) -> u8 {
+    match value.checked_ilog10() {
+        Some(log) => log as u8 + 1,
+        None => 1,
+    }
+}
+
+/// Returns the number of decimal digits in `value`, not counting the sign.
+///
+/// See [`decimal_digit_count_u128`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::ops::decimal_digit_count_i128;
+///
+/// assert_eq!(decimal_digit_count_i128(-42), 2);
+/// assert_eq!(decimal_digit_count_i128(i128::MIN), 39);
+/// ```
+pub const fn decimal_digit_count_i128(value: i128) -> u8 {
+    decimal_digit_count_u128(value.unsigned_abs())
+}
+
+/// Returns the number of decimal digits in `value`.
+///
+/// See [`decimal_digit_count_u128`].
+pub const fn decimal_digit_count_u64(value: u64) -> u8 {
+    decimal_digit_count_u128(value as u128)
+}
+
+/// Returns the number of decimal digits in `value`, not counting the sign.
+///
+/// See [`decimal_digit_count_u128`].
+pub const fn decimal_digit_count_i64(value: i64) -> u8 {
+    decimal_digit_count_u128(value.unsigned_abs() as u128)
+}
+
+#[test]
+fn test_decimal_digit_count() {
+    use crate::FixedDecimal;
+
+    assert_eq!(decimal_digit_count_u128(0), 1);
+    assert_eq!(decimal_digit_count_u128(1), 1);
+    assert_eq!(decimal_digit_count_u128(9), 1);
+    assert_eq!(decimal_digit_count_u128(10), 2);
+    assert_eq!(decimal_digit_count_u128(99), 2);
+    assert_eq!(decimal_digit_count_u128(100), 3);
+    assert_eq!(decimal_digit_count_u128(u64::MAX as u128), 20);
+    assert_eq!(decimal_digit_count_u128(10u128.pow(38) - 1), 38);
+    assert_eq!(decimal_digit_count_u128(10u128.pow(38)), 39);
+    assert_eq!(decimal_digit_count_u128(u128::MAX), 39);
+
+    assert_eq!(decimal_digit_count_i128(0), 1);
+    assert_eq!(decimal_digit_count_i128(-9), 1);
+    assert_eq!(decimal_digit_count_i128(-10), 2);
+    assert_eq!(decimal_digit_count_i128(i128::MAX), 39);
+    assert_eq!(decimal_digit_count_i128(i128::MIN), 39);
+
+    assert_eq!(decimal_digit_count_u64(0), 1);
+    assert_eq!(decimal_digit_count_u64(u64::MAX), 20);
+    assert_eq!(decimal_digit_count_i64(i64::MIN), 19);
+    assert_eq!(decimal_digit_count_i64(i64::MAX), 19);
+
+    // Agrees with the digits of a FixedDecimal
+    for value in [0, 9, 10, 12345, u128::MAX] {
+        let decimal = FixedDecimal::from(value);
+        assert_eq!(
+            decimal_digit_count_u128(value) as i16,
+            decimal.nonzero_magnitude_start() + 1,
+            "{value}"
+        );
+    }
+}
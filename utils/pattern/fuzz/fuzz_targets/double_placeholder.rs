@@ -0,0 +1,50 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![no_main]
+use core::str::FromStr;
+use icu_pattern::DoublePlaceholderKey;
+use icu_pattern::DoublePlaceholderParts;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_pattern::PatternItem;
+use libfuzzer_sys::fuzz_target;
+use writeable::Writeable;
+
+fuzz_target!(|data: &str| {
+    let Ok(pattern) = DoublePlaceholderPattern::<String>::from_str(data) else {
+        return;
+    };
+
+    // Each placeholder occurs at most once, and the literals are interpolated as they are
+    let mut expected = String::new();
+    let mut seen = [false; 2];
+    for item in pattern.iter() {
+        match item {
+            PatternItem::Literal(s) => expected.push_str(s),
+            PatternItem::Placeholder(key) => {
+                let index = match key {
+                    DoublePlaceholderKey::Place0 => 0,
+                    DoublePlaceholderKey::Place1 => 1,
+                };
+                assert!(!seen[index], "{data:?}");
+                seen[index] = true;
+                expected.push_str(["αβ", "→"][index]);
+            }
+        }
+    }
+    let interpolated = pattern.interpolate(DoublePlaceholderParts("αβ", '→'));
+    assert_eq!(interpolated.write_to_string(), expected, "{data:?}");
+    let hint = interpolated.writeable_length_hint();
+    assert!(
+        hint.0 <= expected.len() && hint.1.map_or(true, |upper| expected.len() <= upper),
+        "{data:?}"
+    );
+
+    // The store is valid
+    let store = pattern.take_store();
+    assert!(
+        DoublePlaceholderPattern::try_from_store(store.as_str()).is_ok(),
+        "{data:?}"
+    );
+});
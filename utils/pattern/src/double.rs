@@ -108,6 +108,76 @@ where
     }
 }
 
+/// Annotation for the value of the placeholder `{0}` in a [`DoublePlaceholder`] pattern.
+///
+/// See [`DoublePlaceholderParts`].
+pub const PATTERN_PLACEHOLDER_0_PART: writeable::Part = writeable::Part {
+    category: "pattern",
+    value: "placeholder0",
+};
+
+/// Annotation for the value of the placeholder `{1}` in a [`DoublePlaceholder`] pattern.
+///
+/// See [`DoublePlaceholderParts`].
+pub const PATTERN_PLACEHOLDER_1_PART: writeable::Part = writeable::Part {
+    category: "pattern",
+    value: "placeholder1",
+};
+
+/// The values of a [`DoublePlaceholder`] pattern, annotated with different [`Part`]s.
+///
+/// The tuple `(W0, W1)` annotates both values with [`PATTERN_PLACEHOLDER_PART`]. This type
+/// annotates the value of `{0}` with [`PATTERN_PLACEHOLDER_0_PART`] and the value of `{1}`
+/// with [`PATTERN_PLACEHOLDER_1_PART`], so that they can be told apart in patterns that
+/// swap them.
+///
+/// # Examples
+///
+/// ```
+/// use core::str::FromStr;
+/// use icu_pattern::DoublePlaceholderParts;
+/// use icu_pattern::DoublePlaceholderPattern;
+/// use icu_pattern::PATTERN_LITERAL_PART;
+/// use icu_pattern::PATTERN_PLACEHOLDER_0_PART;
+/// use icu_pattern::PATTERN_PLACEHOLDER_1_PART;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let pattern = DoublePlaceholderPattern::from_str("{1}، {0}").unwrap();
+///
+/// assert_writeable_parts_eq!(
+///     pattern.interpolate(DoublePlaceholderParts("Alice", 42)),
+///     "42، Alice",
+///     [
+///         (0, 2, PATTERN_PLACEHOLDER_1_PART),
+///         (2, 5, PATTERN_LITERAL_PART),
+///         (5, 10, PATTERN_PLACEHOLDER_0_PART),
+///     ]
+/// );
+/// ```
+///
+/// [`Part`]: writeable::Part
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct DoublePlaceholderParts<W0, W1>(pub W0, pub W1);
+
+impl<W0, W1> PlaceholderValueProvider<DoublePlaceholderKey> for DoublePlaceholderParts<W0, W1>
+where
+    W0: Writeable,
+    W1: Writeable,
+{
+    type Error = Infallible;
+    type W<'a> = WriteableAsTryWriteableInfallible<Either<&'a W0, &'a W1>> where W0: 'a, W1: 'a;
+    const LITERAL_PART: writeable::Part = crate::PATTERN_LITERAL_PART;
+    #[inline]
+    fn value_for(&self, key: DoublePlaceholderKey) -> (Self::W<'_>, writeable::Part) {
+        let (writeable, part) = match key {
+            DoublePlaceholderKey::Place0 => (Either::Left(&self.0), PATTERN_PLACEHOLDER_0_PART),
+            DoublePlaceholderKey::Place1 => (Either::Right(&self.1), PATTERN_PLACEHOLDER_1_PART),
+        };
+        (WriteableAsTryWriteableInfallible(writeable), part)
+    }
+}
+
 /// Internal representation of a placeholder
 #[derive(Debug, Copy, Clone)]
 struct DoublePlaceholderInfo {
@@ -708,6 +778,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parts() {
+        use writeable::assert_writeable_parts_eq;
+        type Parts = &'static [(usize, usize, writeable::Part)];
+        let cases: [(&str, &str, Parts); 3] = [
+            (
+                "{0} – {1}",
+                "10 h – ß",
+                &[
+                    (0, 4, PATTERN_PLACEHOLDER_0_PART),
+                    (4, 9, PATTERN_LITERAL_PART),
+                    (9, 11, PATTERN_PLACEHOLDER_1_PART),
+                ],
+            ),
+            (
+                "{1}〜{0}",
+                "ß〜10 h",
+                &[
+                    (0, 2, PATTERN_PLACEHOLDER_1_PART),
+                    (2, 5, PATTERN_LITERAL_PART),
+                    (5, 9, PATTERN_PLACEHOLDER_0_PART),
+                ],
+            ),
+            (
+                "'{'{0}'}'{1}",
+                "{10 h}ß",
+                &[
+                    (0, 1, PATTERN_LITERAL_PART),
+                    (1, 5, PATTERN_PLACEHOLDER_0_PART),
+                    (5, 6, PATTERN_LITERAL_PART),
+                    (6, 8, PATTERN_PLACEHOLDER_1_PART),
+                ],
+            ),
+        ];
+        for (pattern, expected, parts) in cases {
+            let pattern = DoublePlaceholderPattern::from_str(pattern).unwrap();
+            assert_writeable_parts_eq!(
+                pattern.interpolate(DoublePlaceholderParts("10 h", 'ß')),
+                expected,
+                parts
+            );
+            // The tuple produces the same string with the same part for both values
+            assert_eq!(
+                pattern.interpolate(("10 h", 'ß')).write_to_string(),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn test_invalid() {
         let cases = [
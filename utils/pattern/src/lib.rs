@@ -69,6 +69,9 @@ pub use common::PATTERN_LITERAL_PART;
 pub use common::PATTERN_PLACEHOLDER_PART;
 pub use double::DoublePlaceholder;
 pub use double::DoublePlaceholderKey;
+pub use double::DoublePlaceholderParts;
+pub use double::PATTERN_PLACEHOLDER_0_PART;
+pub use double::PATTERN_PLACEHOLDER_1_PART;
 pub use error::PatternError;
 pub use frontend::Pattern;
 pub use multi_named::MissingNamedPlaceholderError;
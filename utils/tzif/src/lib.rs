@@ -49,6 +49,11 @@ pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     Ok(parse::tzif::tzif().parse(stream)?.0)
 }
 
+/// Parses a `TZif` file from the given bytes.
+pub fn parse_tzif_bytes(bytes: &[u8]) -> Result<TzifData, Error> {
+    Ok(parse::tzif::tzif().parse(bytes)?.0)
+}
+
 /// Parses a POSIX time-zone string from the given bytes.
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
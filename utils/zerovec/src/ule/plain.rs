@@ -84,7 +84,7 @@ macro_rules! impl_byte_slice_size {
 }
 
 macro_rules! impl_const_constructors {
-    ($base:ty, $size:literal) => {
+    ($base:ty, $size:literal $(, |$byte:ident| $valid:expr)?) => {
         impl ZeroSlice<$base> {
             /// This function can be used for constructing ZeroVecs in a const context, avoiding
             /// parsing checks.
@@ -97,13 +97,49 @@ macro_rules! impl_const_constructors {
             pub const fn try_from_bytes(bytes: &[u8]) -> Result<&Self, ZeroVecError> {
                 let len = bytes.len();
                 #[allow(clippy::modulo_one)]
-                if len % $size == 0 {
-                    Ok(unsafe { Self::from_bytes_unchecked(bytes) })
-                } else {
-                    Err(ZeroVecError::InvalidLength {
+                if len % $size != 0 {
+                    return Err(ZeroVecError::InvalidLength {
                         ty: concat!("<const construct: ", $size, ">"),
                         len,
-                    })
+                    });
+                }
+                $(
+                    let mut i = 0;
+                    while i < len {
+                        #[allow(clippy::indexing_slicing)] // i < len
+                        let $byte = bytes[i];
+                        if !$valid {
+                            return Err(ZeroVecError::ParseError {
+                                ty: stringify!($base),
+                            });
+                        }
+                        i += 1;
+                    }
+                )?
+                Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+            }
+
+            /// Like [`Self::try_from_bytes()`], but panics if the bytes are invalid.
+            ///
+            /// When used to initialize a `const` or `static`, invalid bytes are a compile-time
+            /// error instead of a panic.
+            ///
+            /// See [`ZeroSlice`] for an example.
+            ///
+            /// # Panics
+            ///
+            /// Panics if [`Self::try_from_bytes()`] returns an error.
+            pub const fn from_bytes_const(bytes: &[u8]) -> &Self {
+                #[allow(clippy::panic)] // documented
+                match Self::try_from_bytes(bytes) {
+                    Ok(slice) => slice,
+                    Err(ZeroVecError::InvalidLength { .. }) => panic!(concat!(
+                        "the length of the bytes is not a multiple of ",
+                        $size,
+                        ", the size of ",
+                        stringify!($base)
+                    )),
+                    Err(_) => panic!(concat!("the bytes are not a valid ", stringify!($base))),
                 }
             }
         }
@@ -173,11 +209,16 @@ impl_const_constructors!(u16, 2);
 impl_const_constructors!(u32, 4);
 impl_const_constructors!(u64, 8);
 impl_const_constructors!(u128, 16);
+impl_const_constructors!(i8, 1);
+impl_const_constructors!(i16, 2);
+impl_const_constructors!(i32, 4);
+impl_const_constructors!(i64, 8);
+impl_const_constructors!(i128, 16);
 
 // Note: The f32 and f64 const constructors currently have limited use because
 // `f32::to_le_bytes` is not yet const.
 
-impl_const_constructors!(bool, 1);
+impl_const_constructors!(bool, 1, |b| b <= 1);
 
 // Safety (based on the safety checklist on the ULE trait):
 //  1. u8 does not include any uninitialized or padding bytes.
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::components::VarZeroVecComponents;
+use super::components::{VarZeroVecComponents, LENGTH_WIDTH, METADATA_WIDTH};
 use super::*;
 use crate::ule::*;
 use alloc::boxed::Box;
@@ -130,6 +130,97 @@ impl<T: VarULE + ?Sized, F: VarZeroVecFormat> VarZeroSlice<T, F> {
         mem::transmute(bytes)
     }
 
+    /// Uses a `&[u8]` buffer as a `VarZeroSlice<T>` in a const context, checking the length and
+    /// the indices of the buffer but not its elements.
+    ///
+    /// Unlike the `from_bytes_const` constructors of [`ZeroSlice`](crate::ZeroSlice), this
+    /// cannot fully validate the buffer, since [`VarULE::validate_byte_slice()`] cannot be
+    /// called in a const context. The checks are best-effort: they catch truncated buffers and
+    /// inconsistent indices, but not elements that are invalid for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::VarZeroSlice;
+    ///
+    /// // Two elements, indices 0 and 2, followed by the elements "ab" and "c"
+    /// const DATA: &VarZeroSlice<str> = unsafe {
+    ///     VarZeroSlice::from_bytes_const(&[2, 0, 0, 0, 0, 0, 2, 0, b'a', b'b', b'c'])
+    /// };
+    ///
+    /// assert_eq!(DATA.get(0), Some("ab"));
+    /// assert_eq!(DATA.get(1), Some("c"));
+    /// ```
+    ///
+    /// A truncated buffer fails to compile:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerovec::VarZeroSlice;
+    ///
+    /// const DATA: &VarZeroSlice<str> =
+    ///     unsafe { VarZeroSlice::from_bytes_const(&[2, 0, 0, 0, 0, 0]) };
+    ///
+    /// assert_eq!(DATA.len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length or the indices of the buffer are invalid. When used to initialize a
+    /// `const` or `static`, this is a compile-time error instead of a panic.
+    ///
+    /// # Safety
+    ///
+    /// Each element of the buffer must be valid for `T`, i.e. pass [`VarULE::validate_byte_slice()`].
+    pub const unsafe fn from_bytes_const(bytes: &[u8]) -> &Self {
+        /// Reads a little-endian integer of `width` bytes, which must be in bounds.
+        const fn read_le(bytes: &[u8], start: usize, width: usize) -> usize {
+            let mut value = 0;
+            let mut i = 0;
+            while i < width {
+                #[allow(clippy::indexing_slicing)] // in bounds by contract
+                let byte = bytes[start + i];
+                value |= (byte as usize) << (8 * i);
+                i += 1;
+            }
+            value
+        }
+
+        // The empty VZV is special-cased to the empty slice
+        if bytes.is_empty() {
+            return Self::from_bytes_unchecked(bytes);
+        }
+        #[allow(clippy::panic)] // documented
+        if bytes.len() < LENGTH_WIDTH + METADATA_WIDTH {
+            panic!("the buffer is too short to contain the length of the VarZeroSlice")
+        }
+        let len = read_le(bytes, 0, LENGTH_WIDTH);
+        let indices_start = LENGTH_WIDTH + METADATA_WIDTH;
+        let things_start = match len.checked_mul(F::INDEX_WIDTH) {
+            Some(indices_len) if indices_len <= bytes.len() - indices_start => {
+                indices_start + indices_len
+            }
+            #[allow(clippy::panic)] // documented
+            _ => panic!("the buffer is too short to contain the indices of the VarZeroSlice"),
+        };
+        let things_len = bytes.len() - things_start;
+        let mut previous = 0;
+        let mut i = 0;
+        while i < len {
+            let index = read_le(bytes, indices_start + i * F::INDEX_WIDTH, F::INDEX_WIDTH);
+            #[allow(clippy::panic)] // documented
+            if (i == 0 && index != 0) || index < previous || index > things_len {
+                panic!("the indices of the VarZeroSlice are out of order or out of bounds")
+            }
+            previous = index;
+            i += 1;
+        }
+        #[allow(clippy::panic)] // documented
+        if len == 0 && things_len != 0 {
+            panic!("the VarZeroSlice has no elements but trailing bytes")
+        }
+        Self::from_bytes_unchecked(bytes)
+    }
+
     /// Get the number of elements in this slice
     ///
     /// # Example
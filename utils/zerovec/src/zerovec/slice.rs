@@ -31,6 +31,32 @@ use core::ops::Range;
 ///
 /// assert_eq!(DATA.get(1), Some(281));
 /// ```
+///
+/// Const-construct a ZeroSlice of u32 from little-endian bytes, such as hand-written baked data:
+///
+/// ```
+/// use zerovec::ZeroSlice;
+///
+/// const DATA: &ZeroSlice<u32> =
+///     ZeroSlice::<u32>::from_bytes_const(&[0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00]);
+///
+/// assert_eq!(DATA.get(1), Some(65535));
+/// ```
+///
+/// The bytes are validated at compile time, so a length that is not a multiple of the element
+/// size fails to compile:
+///
+/// ```compile_fail,E0080
+/// use zerovec::ZeroSlice;
+///
+/// const DATA: &ZeroSlice<u32> =
+///     ZeroSlice::<u32>::from_bytes_const(&[0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF]);
+///
+/// assert_eq!(DATA.len(), 1);
+/// ```
+///
+/// `from_bytes_const` is only available for element types whose validity can be checked in a
+/// const context: the integer types and `bool`.
 #[repr(transparent)]
 pub struct ZeroSlice<T: AsULE>([T::ULE]);
 
@@ -593,4 +619,32 @@ mod test {
             assert_eq!(EXPECTED_VALUE, DATA.split_first().unwrap());
         }
     }
+
+    #[test]
+    fn test_from_bytes_const() {
+        const SIGNED: &ZeroSlice<i16> =
+            ZeroSlice::<i16>::from_bytes_const(&[0xFF, 0xFF, 0x02, 0x00]);
+        assert_eq!(SIGNED.iter().collect::<Vec<_>>(), [-1, 2]);
+
+        const BOOLS: &ZeroSlice<bool> = ZeroSlice::<bool>::from_bytes_const(&[1, 0]);
+        assert_eq!(BOOLS.iter().collect::<Vec<_>>(), [true, false]);
+
+        assert_eq!(
+            ZeroSlice::<u32>::try_from_bytes(&[1, 2, 3]),
+            Err(ZeroVecError::InvalidLength {
+                ty: "<const construct: 4>",
+                len: 3
+            })
+        );
+        assert_eq!(
+            ZeroSlice::<bool>::try_from_bytes(&[1, 2]),
+            Err(ZeroVecError::ParseError { ty: "bool" })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the bytes are not a valid bool")]
+    fn test_from_bytes_const_panic() {
+        let _ = ZeroSlice::<bool>::from_bytes_const(&[2]);
+    }
 }